@@ -16,7 +16,11 @@
 
 extern crate criterion;
 
-use snarkvm_algorithms::fft::{DensePolynomial, EvaluationDomain};
+use snarkvm_algorithms::fft::{
+    domain::{log_six_step_fft_threshold, set_log_six_step_fft_threshold},
+    DensePolynomial,
+    EvaluationDomain,
+};
 use snarkvm_curves::bls12_377::Fr as Bls12_377_Fr;
 use snarkvm_fields::PrimeField;
 
@@ -122,5 +126,37 @@ fn bench_bls12_377(c: &mut Criterion) {
     fft_benches::<Bls12_377_Fr>(c, "BLS12-377 - radix-2");
 }
 
-criterion_group!(benches, bench_bls12_377);
+/// Benchmarks a 2^{22} FFT with the radix-2 and six-step algorithms
+/// on thread pools of 1, 2, 4, ... threads, up to the size of the global pool.
+fn bench_bls12_377_thread_scaling(c: &mut Criterion) {
+    let (domain, mut a) = create_evaluation_domain::<Bls12_377_Fr>(BENCHMARK_MAX_DEGREE);
+    let default_threshold = log_six_step_fft_threshold();
+
+    let mut num_threads = vec![];
+    let mut threads = 1;
+    while threads < rayon::current_num_threads() {
+        num_threads.push(threads);
+        threads *= 2;
+    }
+    num_threads.push(rayon::current_num_threads());
+
+    for (algorithm, threshold) in [("radix-2", u32::MAX), ("six-step", 0)] {
+        set_log_six_step_fft_threshold(threshold);
+
+        let mut group =
+            c.benchmark_group(format!("BLS12-377 - {} - fft_in_place of size {}", algorithm, BENCHMARK_MAX_DEGREE));
+        group.sample_size(10);
+        for threads in &num_threads {
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(*threads).build().unwrap();
+            group.bench_with_input(BenchmarkId::new("threads", threads), threads, |b, _| {
+                pool.install(|| b.iter(|| domain.fft_in_place(&mut a)));
+            });
+        }
+        group.finish();
+    }
+
+    set_log_six_step_fft_threshold(default_threshold);
+}
+
+criterion_group!(benches, bench_bls12_377, bench_bls12_377_thread_scaling);
 criterion_main!(benches);
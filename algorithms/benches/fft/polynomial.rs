@@ -0,0 +1,64 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+#[macro_use]
+extern crate criterion;
+
+use snarkvm_algorithms::fft::DensePolynomial;
+use snarkvm_curves::bls12_377::Fr;
+use snarkvm_utilities::UniformRand;
+
+use criterion::Criterion;
+use rand::thread_rng;
+
+const NUM_POINTS: usize = 1 << 12;
+
+fn evaluate_many(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let polynomial = DensePolynomial::<Fr>::rand(NUM_POINTS - 1, rng);
+    let points: Vec<Fr> = (0..NUM_POINTS).map(|_| Fr::rand(rng)).collect();
+
+    c.bench_function("DensePolynomial::evaluate_many - 2^12 points", move |b| {
+        b.iter(|| polynomial.evaluate_many(&points))
+    });
+}
+
+fn evaluate_naive(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let polynomial = DensePolynomial::<Fr>::rand(NUM_POINTS - 1, rng);
+    let points: Vec<Fr> = (0..NUM_POINTS).map(|_| Fr::rand(rng)).collect();
+
+    c.bench_function("DensePolynomial::evaluate - 2^12 points", move |b| {
+        b.iter(|| points.iter().map(|x| polynomial.evaluate(*x)).collect::<Vec<_>>())
+    });
+}
+
+fn interpolate(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+    let points: Vec<(Fr, Fr)> = (0..NUM_POINTS).map(|_| (Fr::rand(rng), Fr::rand(rng))).collect();
+
+    c.bench_function("DensePolynomial::interpolate - 2^12 points", move |b| {
+        b.iter(|| DensePolynomial::interpolate(&points).unwrap())
+    });
+}
+
+criterion_group! {
+    name = polynomial;
+    config = Criterion::default().sample_size(10);
+    targets = evaluate_many, evaluate_naive, interpolate
+}
+
+criterion_main!(polynomial);
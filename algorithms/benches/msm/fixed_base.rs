@@ -0,0 +1,56 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use criterion::Criterion;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use snarkvm_algorithms::msm::*;
+use snarkvm_curves::bls12_377::{Fr, G1Projective};
+use snarkvm_utilities::rand::UniformRand;
+use std::ops::Mul;
+
+#[macro_use]
+extern crate criterion;
+
+const SAMPLES: usize = 1000;
+
+fn fixed_base(c: &mut Criterion) {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G1Projective::rand(&mut rng);
+
+    c.bench_function("MSM Fixed Base", move |b| b.iter(|| FixedBaseMSM::msm(g, &v)));
+}
+
+fn fixed_base_naive(c: &mut Criterion) {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let v = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    let g = G1Projective::rand(&mut rng);
+
+    c.bench_function("MSM Fixed Base (per-scalar mul)", move |b| {
+        b.iter(|| v.iter().map(|scalar| g.mul(*scalar)).collect::<Vec<_>>())
+    });
+}
+
+criterion_group! {
+    name = fixed_base_group;
+    config = Criterion::default().sample_size(10);
+    targets = fixed_base, fixed_base_naive
+}
+
+criterion_main!(fixed_base_group);
@@ -17,7 +17,7 @@
 #[macro_use]
 extern crate criterion;
 
-use snarkvm_algorithms::{snark::groth16::Groth16, SNARK, SRS};
+use snarkvm_algorithms::{snark::groth16::Groth16, Prepare, SNARK, SRS};
 use snarkvm_curves::bls12_377::{Bls12_377, Fr};
 use snarkvm_fields::Field;
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
@@ -129,10 +129,61 @@ fn snark_prove(c: &mut Criterion) {
     });
 }
 
+fn snark_verify_batched(c: &mut Criterion) {
+    type Groth16VecSNARK = Groth16<Bls12_377, Vec<Fr>>;
+
+    let num_inputs = 10;
+    let num_constraints = 100;
+    let num_proofs = 50;
+    let rng = &mut thread_rng();
+
+    let params = Groth16VecSNARK::setup(
+        &Benchmark::<Fr> {
+            inputs: vec![None; num_inputs],
+            num_constraints,
+        },
+        &mut SRS::CircuitSpecific(rng),
+    )
+    .unwrap();
+    let verifying_key = params.1.prepare();
+
+    // Prove the same circuit on `num_proofs` different inputs.
+    let mut public_inputs = Vec::with_capacity(num_proofs);
+    let mut proofs = Vec::with_capacity(num_proofs);
+    for _ in 0..num_proofs {
+        let inputs: Vec<Fr> = (0..num_inputs).map(|_| rng.gen()).collect();
+        let circuit = Benchmark {
+            inputs: inputs.iter().copied().map(Some).collect(),
+            num_constraints,
+        };
+        proofs.push(Groth16VecSNARK::prove(&params.0, &circuit, rng).unwrap());
+        public_inputs.push(inputs);
+    }
+    let inputs_and_proofs: Vec<_> = public_inputs.iter().zip(&proofs).collect();
+
+    c.bench_function("snark_verify_50_individually", |b| {
+        b.iter(|| {
+            for (input, proof) in &inputs_and_proofs {
+                assert!(Groth16VecSNARK::verify_prepared(&verifying_key, input, proof).unwrap());
+            }
+        })
+    });
+
+    c.bench_function("snark_verify_50_batched", |b| {
+        b.iter(|| assert!(Groth16VecSNARK::verify_batch_prepared(&verifying_key, &inputs_and_proofs, rng).unwrap()))
+    });
+}
+
 criterion_group! {
     name = groth16_snark;
     config = Criterion::default().sample_size(50);
     targets = snark_setup, snark_prove
 }
 
-criterion_main!(groth16_snark);
+criterion_group! {
+    name = groth16_snark_verify;
+    config = Criterion::default().sample_size(10);
+    targets = snark_verify_batched
+}
+
+criterion_main!(groth16_snark, groth16_snark_verify);
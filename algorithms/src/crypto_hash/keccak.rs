@@ -0,0 +1,404 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Keccak-256 and SHA3-256, with streaming interfaces over bytes and bits.
+//!
+//! This implementation follows the SHA-3 standard in FIPS 202. Bits are absorbed
+//! in little-endian order within each byte, so hashing the little-endian bits of
+//! a byte string yields the same digest as hashing the byte string itself.
+
+/// The round constants of Keccak-f[1600].
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000_0000_0000_0001,
+    0x0000_0000_0000_8082,
+    0x8000_0000_0000_808a,
+    0x8000_0000_8000_8000,
+    0x0000_0000_0000_808b,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8009,
+    0x0000_0000_0000_008a,
+    0x0000_0000_0000_0088,
+    0x0000_0000_8000_8009,
+    0x0000_0000_8000_000a,
+    0x0000_0000_8000_808b,
+    0x8000_0000_0000_008b,
+    0x8000_0000_0000_8089,
+    0x8000_0000_0000_8003,
+    0x8000_0000_0000_8002,
+    0x8000_0000_0000_0080,
+    0x0000_0000_0000_800a,
+    0x8000_0000_8000_000a,
+    0x8000_0000_8000_8081,
+    0x8000_0000_0000_8080,
+    0x0000_0000_8000_0001,
+    0x8000_0000_8000_8008,
+];
+
+/// The rotation offsets of the lanes, in the order they are visited by the pi step.
+const ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+/// The order in which the lanes are visited by the pi step, starting from lane 1.
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Applies the Keccak-f[1600] permutation to the given state.
+fn keccak_f1600(state: &mut [u64; 25]) {
+    for round_constant in ROUND_CONSTANTS.iter() {
+        // Theta
+        let mut columns = [0u64; 5];
+        for (x, column) in columns.iter_mut().enumerate() {
+            *column = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = columns[(x + 4) % 5] ^ columns[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[5 * y + x] ^= d;
+            }
+        }
+
+        // Rho and pi
+        let mut last = state[1];
+        for (lane, rotation) in PI_LANES.iter().zip(ROTATIONS.iter()) {
+            let next = state[*lane];
+            state[*lane] = last.rotate_left(*rotation);
+            last = next;
+        }
+
+        // Chi
+        for y in 0..5 {
+            let mut row = [0u64; 5];
+            row.copy_from_slice(&state[5 * y..5 * y + 5]);
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // Iota
+        state[0] ^= round_constant;
+    }
+}
+
+/// A Keccak-f[1600] sponge that absorbs bits at the given rate, and pads them with
+/// the given domain separation suffix followed by the `pad10*1` rule.
+#[derive(Clone)]
+struct KeccakSponge<const RATE: usize> {
+    state: [u64; 25],
+    /// The bytes of the current block that have been absorbed.
+    block: [u8; RATE],
+    /// The number of bits of the current block that have been absorbed.
+    num_bits: usize,
+    /// The bits appended to the message before padding.
+    suffix: &'static [bool],
+}
+
+impl<const RATE: usize> KeccakSponge<RATE> {
+    fn new(suffix: &'static [bool]) -> Self {
+        Self {
+            state: [0u64; 25],
+            block: [0u8; RATE],
+            num_bits: 0,
+            suffix,
+        }
+    }
+
+    /// Absorbs the given bytes.
+    fn update(&mut self, bytes: &[u8]) {
+        match self.num_bits % 8 {
+            // If the current block is byte-aligned, absorb the bytes whole.
+            0 => {
+                for byte in bytes {
+                    self.block[self.num_bits / 8] = *byte;
+                    self.num_bits += 8;
+                    if self.num_bits == RATE * 8 {
+                        self.permute();
+                    }
+                }
+            }
+            // Otherwise, absorb the bytes bit by bit.
+            _ => {
+                for byte in bytes {
+                    for i in 0..8 {
+                        self.absorb_bit((byte >> i) & 1 == 1);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Absorbs the given bits.
+    fn update_bits(&mut self, bits: &[bool]) {
+        for bit in bits {
+            self.absorb_bit(*bit);
+        }
+    }
+
+    /// Pads the message, and returns the first `N` bytes of the output.
+    fn finalize<const N: usize>(mut self) -> [u8; N] {
+        debug_assert!(N <= RATE, "The output length must not exceed the rate");
+
+        // Append the suffix and the first bit of the padding.
+        for bit in self.suffix {
+            self.absorb_bit(*bit);
+        }
+        self.absorb_bit(true);
+
+        // Pad with zeros up to the last bit of the block, which is set.
+        while self.num_bits != RATE * 8 - 1 {
+            self.absorb_bit(false);
+        }
+        self.absorb_bit(true);
+
+        let mut output = [0u8; N];
+        for (i, byte) in output.iter_mut().enumerate() {
+            *byte = self.state[i / 8].to_le_bytes()[i % 8];
+        }
+        output
+    }
+
+    /// Absorbs one bit, permuting the state if the current block is full.
+    fn absorb_bit(&mut self, bit: bool) {
+        let byte = &mut self.block[self.num_bits / 8];
+        match self.num_bits % 8 {
+            0 => *byte = bit as u8,
+            i => *byte |= (bit as u8) << i,
+        }
+        self.num_bits += 1;
+        if self.num_bits == RATE * 8 {
+            self.permute();
+        }
+    }
+
+    /// Absorbs the current block into the state, and permutes the state.
+    fn permute(&mut self) {
+        for (lane, chunk) in self.state.iter_mut().zip(self.block.chunks(8)) {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            *lane ^= u64::from_le_bytes(bytes);
+        }
+        keccak_f1600(&mut self.state);
+        self.num_bits = 0;
+    }
+}
+
+macro_rules! impl_keccak_hasher {
+    ($name:ident, $description:expr, $suffix:expr) => {
+        #[doc = $description]
+        ///
+        /// The digest of a message can be computed in one call with `hash_bytes` or `hash_bits`,
+        /// or incrementally with `update` and `update_bits`, followed by `finalize`.
+        #[derive(Clone)]
+        pub struct $name(KeccakSponge<136>);
+
+        impl $name {
+            /// Initializes a new hasher.
+            pub fn new() -> Self {
+                Self(KeccakSponge::new($suffix))
+            }
+
+            /// Absorbs the given bytes.
+            pub fn update(&mut self, bytes: &[u8]) {
+                self.0.update(bytes)
+            }
+
+            /// Absorbs the given bits. The message does not need to be a whole number of bytes.
+            pub fn update_bits(&mut self, bits: &[bool]) {
+                self.0.update_bits(bits)
+            }
+
+            /// Returns the digest of the absorbed message.
+            pub fn finalize(self) -> [u8; 32] {
+                self.0.finalize()
+            }
+
+            /// Returns the digest of the given bytes.
+            pub fn hash_bytes(input: &[u8]) -> [u8; 32] {
+                let mut hasher = Self::new();
+                hasher.update(input);
+                hasher.finalize()
+            }
+
+            /// Returns the digest of the given bits.
+            pub fn hash_bits(input: &[bool]) -> [u8; 32] {
+                let mut hasher = Self::new();
+                hasher.update_bits(input);
+                hasher.finalize()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+    };
+}
+
+impl_keccak_hasher!(
+    Keccak256,
+    "The original Keccak-256 hash function, as used by Ethereum.",
+    &[]
+);
+impl_keccak_hasher!(
+    Sha3_256,
+    "The SHA3-256 hash function, as specified in FIPS 202.",
+    &[false, true]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+    use snarkvm_fields::PrimeField;
+    use snarkvm_utilities::{ToBits, ToBytes, UniformRand};
+
+    use rand::{thread_rng, Rng};
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns the little-endian bits of each byte of the given bytes.
+    fn bytes_to_bits_le(bytes: &[u8]) -> Vec<bool> {
+        bytes
+            .iter()
+            .flat_map(|byte| (0..8).map(move |i| (byte >> i) & 1 == 1))
+            .collect()
+    }
+
+    #[test]
+    fn test_keccak256_vectors() {
+        let vectors: [(&[u8], &str); 3] = [
+            (b"", "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"),
+            (
+                b"abc",
+                "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45",
+            ),
+            (
+                b"The quick brown fox jumps over the lazy dog",
+                "4d741b6f1eb29cb2a9b9911c82f56fa8d73b04959d3d9d222895df6c0b28aa15",
+            ),
+        ];
+        for (input, expected) in vectors.iter() {
+            assert_eq!(*expected, hex::encode(Keccak256::hash_bytes(input)));
+        }
+    }
+
+    #[test]
+    fn test_sha3_256_vectors() {
+        // The byte-oriented vectors from the NIST SHA-3 examples.
+        let vectors: [(&[u8], &str); 3] = [
+            (b"", "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a"),
+            (
+                b"abc",
+                "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532",
+            ),
+            (
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+                "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376",
+            ),
+        ];
+        for (input, expected) in vectors.iter() {
+            assert_eq!(*expected, hex::encode(Sha3_256::hash_bytes(input)));
+        }
+
+        // One million repetitions of 'a', absorbed in uneven chunks.
+        let mut hasher = Sha3_256::new();
+        let input = vec![b'a'; 1_000_000];
+        for chunk in input.chunks(997) {
+            hasher.update(chunk);
+        }
+        assert_eq!(
+            "5c8875ae474a3634ba4fd55ec85bffd661f32aca75c6d699d0cdcb6c115891c1",
+            hex::encode(hasher.finalize())
+        );
+    }
+
+    #[test]
+    fn test_sha3_256_bit_vectors() {
+        // The bit-oriented vectors from the NIST SHA-3 examples.
+        let vectors: [(&str, &str); 2] = [
+            (
+                "11001",
+                "7b0047cf5a456882363cbf0fb05322cf65f4b7059a46365e830132e3b5d957af",
+            ),
+            (
+                "110010100001101011011110100110",
+                "c8242fef409e5ae9d1f1c857ae4dc624b92b19809f62aa8c07411c54a078b1d0",
+            ),
+        ];
+        for (input, expected) in vectors.iter() {
+            let bits: Vec<bool> = input.chars().map(|c| c == '1').collect();
+            assert_eq!(*expected, hex::encode(Sha3_256::hash_bits(&bits)));
+        }
+    }
+
+    #[test]
+    fn test_bytes_and_bits_agree() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random message, with a length that crosses block boundaries.
+            let num_bytes = rng.gen_range(0..3 * 136);
+            let bytes: Vec<u8> = (0..num_bytes).map(|_| rng.gen()).collect();
+            let bits = bytes_to_bits_le(&bytes);
+
+            assert_eq!(Keccak256::hash_bytes(&bytes), Keccak256::hash_bits(&bits));
+            assert_eq!(Sha3_256::hash_bytes(&bytes), Sha3_256::hash_bits(&bits));
+        }
+
+        for _ in 0..ITERATIONS {
+            // Ensure the bits of a field element hash the same as its bytes.
+            let element = Fr::rand(rng);
+            let bytes = element.to_bytes_le().unwrap();
+            let bits = element.to_repr().to_bits_le();
+
+            assert_eq!(Keccak256::hash_bytes(&bytes), Keccak256::hash_bits(&bits));
+            assert_eq!(Sha3_256::hash_bytes(&bytes), Sha3_256::hash_bits(&bits));
+        }
+    }
+
+    #[test]
+    fn test_streaming() {
+        let rng = &mut thread_rng();
+
+        for _ in 0..ITERATIONS {
+            // Sample a random message that is not a whole number of bytes.
+            let num_bits = rng.gen_range(0..3 * 136 * 8);
+            let bits: Vec<bool> = (0..num_bits).map(|_| rng.gen()).collect();
+            let expected = Sha3_256::hash_bits(&bits);
+
+            // Absorb the message in random chunks, alternating between bits and bytes where aligned.
+            let mut hasher = Sha3_256::new();
+            let mut remaining = &bits[..];
+            while !remaining.is_empty() {
+                let (chunk, rest) = remaining.split_at(rng.gen_range(1..=remaining.len()));
+                match chunk.len() % 8 == 0 && rng.gen() {
+                    true => hasher.update(
+                        &chunk
+                            .chunks(8)
+                            .map(|byte| byte.iter().rev().fold(0u8, |acc, bit| (acc << 1) | *bit as u8))
+                            .collect::<Vec<_>>(),
+                    ),
+                    false => hasher.update_bits(chunk),
+                }
+                remaining = rest;
+            }
+            assert_eq!(expected, hasher.finalize());
+        }
+    }
+}
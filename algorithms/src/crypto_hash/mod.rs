@@ -20,6 +20,9 @@ pub use blake2xs::*;
 pub mod grain_lfsr;
 pub use grain_lfsr::*;
 
+pub mod keccak;
+pub use keccak::*;
+
 pub mod poseidon;
 pub use poseidon::*;
 
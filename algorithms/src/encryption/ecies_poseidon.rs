@@ -33,7 +33,6 @@ use snarkvm_utilities::{
     ops::Mul,
     serialize::*,
     BitIteratorBE,
-    FromBits,
     FromBytes,
     Read,
     SerializationError,
@@ -46,6 +45,7 @@ use snarkvm_utilities::{
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
 use std::sync::Arc;
+use zeroize::Zeroize;
 
 #[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
 #[derivative(
@@ -114,6 +114,8 @@ where
 impl<TE: TwistedEdwardsParameters> EncryptionScheme for ECIESPoseidonEncryption<TE>
 where
     TE::BaseField: PoseidonDefaultParametersField,
+    TE::BaseField: Zeroize,
+    TE::ScalarField: Zeroize,
 {
     type CiphertextRandomizer = TE::BaseField;
     type Parameters = TEAffine<TE>;
@@ -168,6 +170,7 @@ where
             batch[0].into_affine().to_x_coordinate(),
             batch[1].into_affine().to_x_coordinate(),
         );
+        batch[1].zeroize();
 
         (randomness, ciphertext_randomizer, symmetric_key)
     }
@@ -201,10 +204,12 @@ where
         }
 
         randomizer.map(|randomizer| {
-            randomizer
+            let mut shared_secret = randomizer
                 .mul_bits(BitIteratorBE::new_without_leading_zeros(private_key.to_repr()))
-                .into_affine()
-                .to_x_coordinate()
+                .into_affine();
+            let symmetric_key = shared_secret.to_x_coordinate();
+            shared_secret.zeroize();
+            symmetric_key
         })
     }
 
@@ -252,7 +257,7 @@ where
         let num_ciphertext_elements = (plaintext_bits.len() + capacity - 1) / capacity;
 
         // Obtain random field elements from Poseidon.
-        let sponge_randomizers = sponge.squeeze_field_elements(num_ciphertext_elements);
+        let mut sponge_randomizers = sponge.squeeze_field_elements(num_ciphertext_elements);
         assert_eq!(sponge_randomizers.len(), num_ciphertext_elements);
 
         // Pack the bits into field elements and add the random field elements to the packed bits.
@@ -260,12 +265,15 @@ where
             .chunks(capacity)
             .zip_eq(sponge_randomizers.iter())
             .flat_map(|(chunk, sponge_randomizer)| {
-                let plaintext_element =
-                    TE::BaseField::from_repr(<TE::BaseField as PrimeField>::BigInteger::from_bits_le(chunk)).unwrap();
+                let plaintext_element = TE::BaseField::from_bits_le_checked(chunk).unwrap();
                 (plaintext_element + sponge_randomizer).to_bytes_le().unwrap()
             })
             .collect();
 
+        // Zeroize the plaintext bits and the keystream.
+        plaintext_bits.zeroize();
+        sponge_randomizers.iter_mut().for_each(Zeroize::zeroize);
+
         Ok(ciphertext)
     }
 
@@ -284,7 +292,7 @@ where
         let num_field_elements = ciphertext.len() / per_field_element_bytes;
 
         // Obtain random field elements from Poseidon.
-        let sponge_randomizers = sponge.squeeze_field_elements(num_field_elements);
+        let mut sponge_randomizers = sponge.squeeze_field_elements(num_field_elements);
 
         // Subtract the random field elements to the packed bits.
         let mut plaintext_elements = Vec::with_capacity(num_field_elements);
@@ -296,6 +304,7 @@ where
         for (i, sponge_randomizer) in sponge_randomizers.iter().enumerate() {
             plaintext_elements[i] -= sponge_randomizer;
         }
+        sponge_randomizers.iter_mut().for_each(Zeroize::zeroize);
 
         // Unpack the packed bits.
         if plaintext_elements.is_empty() {
@@ -343,6 +352,10 @@ where
             message.push(byte);
         }
 
+        // Zeroize the intermediate plaintext.
+        plaintext_elements.iter_mut().for_each(Zeroize::zeroize);
+        bits.zeroize();
+
         Ok(message)
     }
 
@@ -31,7 +31,10 @@ use snarkvm_fields::{batch_inversion, FftField, FftParameters, Field};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
 use rand::Rng;
-use std::fmt;
+use std::{
+    fmt,
+    sync::atomic::{AtomicU32, Ordering},
+};
 
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
@@ -59,6 +62,25 @@ pub fn log2(x: usize) -> u32 {
     }
 }
 
+/// The default base-2 logarithm of the smallest domain size for which (I)FFTs
+/// use the six-step algorithm.
+pub const DEFAULT_LOG_SIX_STEP_FFT_THRESHOLD: u32 = 20;
+
+static LOG_SIX_STEP_FFT_THRESHOLD: AtomicU32 = AtomicU32::new(DEFAULT_LOG_SIX_STEP_FFT_THRESHOLD);
+
+/// Returns the base-2 logarithm of the smallest domain size for which (I)FFTs
+/// use the six-step algorithm.
+pub fn log_six_step_fft_threshold() -> u32 {
+    LOG_SIX_STEP_FFT_THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Sets the base-2 logarithm of the smallest domain size for which (I)FFTs
+/// use the six-step algorithm. Smaller domains use the radix-2 FFT, whose
+/// working set fits in cache. The output does not depend on this setting.
+pub fn set_log_six_step_fft_threshold(log_size: u32) {
+    LOG_SIX_STEP_FFT_THRESHOLD.store(log_size, Ordering::Relaxed);
+}
+
 // minimum size of a parallelized chunk
 #[allow(unused)]
 #[cfg(feature = "parallel")]
@@ -371,6 +393,11 @@ impl<F: FftField> EvaluationDomain<F> {
 
         let log_len = log2(x_s.len());
 
+        if ord == II && log_len >= log_six_step_fft_threshold() {
+            self.six_step_fft_in_place(x_s, self.group_gen);
+            return;
+        }
+
         if ord == OI {
             self.oi_helper(x_s, self.group_gen);
         } else {
@@ -390,6 +417,11 @@ impl<F: FftField> EvaluationDomain<F> {
 
         let log_len = log2(x_s.len());
 
+        if ord == II && log_len >= log_six_step_fft_threshold() {
+            self.six_step_fft_in_place(x_s, self.group_gen_inv);
+            return;
+        }
+
         if ord == II {
             derange(x_s, log_len);
         }
@@ -604,6 +636,61 @@ impl<F: FftField> EvaluationDomain<F> {
             gap *= 2;
         }
     }
+
+    /// Computes an in-order radix-2 FFT of `x_s` with respect to `root`.
+    pub(crate) fn radix2_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], root: F) {
+        self.io_helper(x_s, root);
+        derange(x_s, log2(x_s.len()));
+    }
+
+    /// Computes an in-order FFT of `x_s` with respect to `root`, using the six-step algorithm.
+    ///
+    /// The input of size `n = n_1 * n_2` is viewed as an `n_2 x n_1` matrix. After a transpose,
+    /// the `n_1` rows of length `n_2` are transformed independently and multiplied by twiddle
+    /// factors; after a second transpose, the `n_2` rows of length `n_1` are transformed,
+    /// and a final transpose puts the output in order. Every sub-FFT fits in cache,
+    /// and the rows are processed in parallel.
+    pub(crate) fn six_step_fft_in_place<T: DomainCoeff<F>>(&self, x_s: &mut [T], root: F) {
+        let log_len = log2(x_s.len());
+        if log_len < 2 {
+            return self.radix2_fft_in_place(x_s, root);
+        }
+        let log_rows = log_len / 2;
+        let log_cols = log_len - log_rows;
+        let (rows, cols) = (1 << log_rows, 1 << log_cols);
+
+        // The roots of unity for the sub-FFTs of length `cols` and `rows`.
+        let col_root = root.pow([rows as u64]);
+        let row_root = root.pow([cols as u64]);
+        let col_roots = compute_powers_serial(cols / 2, col_root);
+        let row_roots = compute_powers_serial(rows / 2, row_root);
+
+        let mut scratch = vec![T::zero(); x_s.len()];
+
+        // Step 1: `scratch[j_1][j_2] = x_s[j_1 + rows * j_2]`.
+        transpose(x_s, &mut scratch, cols, rows);
+
+        // Steps 2 and 3: transform each row, then multiply entry `k_2` of row `j_1` by `root^(j_1 * k_2)`.
+        cfg_chunks_mut!(scratch, cols).enumerate().for_each(|(j_1, row)| {
+            serial_radix2_fft(row, &col_roots, log_cols);
+            let twiddle = root.pow([j_1 as u64]);
+            let mut power = F::one();
+            row.iter_mut().for_each(|x| {
+                *x *= power;
+                power *= twiddle;
+            });
+        });
+
+        // Step 4: `x_s[k_2][j_1] = scratch[j_1][k_2]`.
+        transpose(&scratch, x_s, rows, cols);
+
+        // Step 5: transform each row.
+        cfg_chunks_mut!(x_s, rows).for_each(|row| serial_radix2_fft(row, &row_roots, log_rows));
+
+        // Step 6: `x_s[k_2 + cols * k_1] = scratch[k_1][k_2]`.
+        transpose(x_s, &mut scratch, cols, rows);
+        x_s.copy_from_slice(&scratch);
+    }
 }
 
 /// The minimum number of chunks at which root compaction
@@ -632,6 +719,52 @@ fn derange<T>(xi: &mut [T], log_len: u32) {
     }
 }
 
+/// The side length of the square tiles in which `transpose` copies elements.
+const TRANSPOSE_TILE_SIZE: usize = 16;
+
+/// Writes the transpose of `src`, an `rows x cols` row-major matrix, into `dst`.
+fn transpose<T: Copy + Send + Sync>(src: &[T], dst: &mut [T], rows: usize, cols: usize) {
+    assert_eq!(src.len(), rows * cols);
+    assert_eq!(dst.len(), rows * cols);
+
+    // Each chunk holds `TRANSPOSE_TILE_SIZE` rows of `dst`, i.e. columns of `src`.
+    let tile = core::cmp::min(TRANSPOSE_TILE_SIZE, cols);
+    cfg_chunks_mut!(dst, tile * rows).enumerate().for_each(|(i, dst)| {
+        let col_start = i * tile;
+        for row_start in (0..rows).step_by(tile) {
+            for col in col_start..(col_start + tile) {
+                for row in row_start..core::cmp::min(row_start + tile, rows) {
+                    dst[(col - col_start) * rows + row] = src[row * cols + col];
+                }
+            }
+        }
+    });
+}
+
+/// Computes an in-order radix-2 FFT of `xi` on the current thread,
+/// where `roots` holds the first `xi.len() / 2` powers of the root of unity.
+fn serial_radix2_fft<F: FftField, T: DomainCoeff<F>>(xi: &mut [T], roots: &[F], log_len: u32) {
+    derange(xi, log_len);
+
+    let mut gap = 1;
+    while gap < xi.len() {
+        let step = xi.len() / (2 * gap);
+        xi.chunks_mut(2 * gap).for_each(|chunk| {
+            let (lo, hi) = chunk.split_at_mut(gap);
+            lo.iter_mut()
+                .zip(hi)
+                .zip(roots.iter().step_by(step))
+                .for_each(|((lo, hi), root)| {
+                    *hi *= *root;
+                    let neg = *lo - *hi;
+                    *lo += *hi;
+                    *hi = neg;
+                });
+        });
+        gap *= 2;
+    }
+}
+
 #[derive(PartialEq, Eq, Debug)]
 enum FFTOrder {
     /// Both the input and the output of the FFT must be in-order.
@@ -16,10 +16,12 @@
 
 //! A polynomial represented in coefficient form.
 
+use super::subproduct_tree::{horner_evaluate, SubproductTree};
 use crate::fft::{DenseOrSparsePolynomial, EvaluationDomain, Evaluations};
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
+use anyhow::anyhow;
 use rand::Rng;
 use std::{
     fmt,
@@ -29,6 +31,10 @@ use std::{
 #[cfg(feature = "parallel")]
 use rayon::prelude::*;
 
+/// The minimum number of points and degree for which `DensePolynomial::evaluate_many`
+/// uses the subproduct tree instead of Horner's rule.
+const MULTIPOINT_EVALUATION_THRESHOLD: usize = 64;
+
 /// Stores a polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
 pub struct DensePolynomial<F: Field> {
@@ -159,6 +165,30 @@ impl<F: PrimeField> DensePolynomial<F> {
         let vanishing_poly = DenseOrSparsePolynomial::from(domain.vanishing_polynomial());
         self_poly.divide_with_q_and_r(&vanishing_poly)
     }
+
+    /// Evaluates `self` at each of the given `points`.
+    ///
+    /// Uses the subproduct tree algorithm, which takes `O(n log^2 n)` time for `n` points,
+    /// unless the number of points or the degree of `self` is small enough for Horner's rule.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if self.is_zero() || core::cmp::min(points.len(), self.degree()) < MULTIPOINT_EVALUATION_THRESHOLD {
+            return cfg_iter!(points).map(|point| horner_evaluate(self, *point)).collect();
+        }
+        SubproductTree::new(points).evaluate(self)
+    }
+
+    /// Returns the unique polynomial of degree less than `points.len()` that passes through
+    /// each of the given `(x, y)` points, using the subproduct tree algorithm.
+    /// Returns an error if two points share an `x`-coordinate.
+    pub fn interpolate(points: &[(F, F)]) -> anyhow::Result<Self> {
+        if points.is_empty() {
+            return Ok(Self::zero());
+        }
+        let (xs, ys): (Vec<F>, Vec<F>) = points.iter().copied().unzip();
+        SubproductTree::new(&xs)
+            .interpolate(&ys)
+            .ok_or_else(|| anyhow!("Cannot interpolate over points with duplicate x-coordinates"))
+    }
 }
 
 impl<'a, 'b, F: Field> Add<&'a DensePolynomial<F>> for &'b DensePolynomial<F> {
@@ -513,4 +543,42 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn evaluate_many_polynomials() {
+        let rng = &mut thread_rng();
+        for (degree, num_points) in [(0, 10), (10, 100), (100, 10), (200, 300), (1000, 1000), (3000, 500)] {
+            let p = DensePolynomial::<Fr>::rand(degree, rng);
+            let points: Vec<Fr> = (0..num_points).map(|_| Fr::rand(rng)).collect();
+            let expected: Vec<Fr> = points.iter().map(|x| p.evaluate(*x)).collect();
+            assert_eq!(p.evaluate_many(&points), expected);
+        }
+        assert_eq!(DensePolynomial::<Fr>::zero().evaluate_many(&[Fr::one()]), vec![Fr::zero()]);
+    }
+
+    #[test]
+    fn interpolate_polynomials() {
+        let rng = &mut thread_rng();
+        for num_points in [1, 2, 17, 100, 500] {
+            let p = DensePolynomial::<Fr>::rand(num_points - 1, rng);
+            let points: Vec<(Fr, Fr)> = (0..num_points)
+                .map(|_| {
+                    let x = Fr::rand(rng);
+                    (x, p.evaluate(x))
+                })
+                .collect();
+            assert_eq!(DensePolynomial::interpolate(&points).unwrap(), p);
+        }
+        assert_eq!(DensePolynomial::<Fr>::interpolate(&[]).unwrap(), DensePolynomial::zero());
+    }
+
+    #[test]
+    fn interpolate_duplicate_x_coordinates() {
+        let rng = &mut thread_rng();
+        for num_points in [2, 100] {
+            let mut points: Vec<(Fr, Fr)> = (0..num_points).map(|_| (Fr::rand(rng), Fr::rand(rng))).collect();
+            points[num_points - 1].0 = points[0].0;
+            assert!(DensePolynomial::interpolate(&points).is_err());
+        }
+    }
 }
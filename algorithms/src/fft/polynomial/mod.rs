@@ -29,6 +29,8 @@ pub use dense::DensePolynomial;
 mod sparse;
 pub use sparse::SparsePolynomial;
 
+mod subproduct_tree;
+
 /// Represents either a sparse polynomial or a dense one.
 #[derive(Clone)]
 pub enum DenseOrSparsePolynomial<'a, F: 'a + Field> {
@@ -20,7 +20,7 @@ use crate::fft::{DenseOrSparsePolynomial, DensePolynomial, EvaluationDomain, Eva
 use snarkvm_fields::{Field, PrimeField};
 use snarkvm_utilities::{errors::SerializationError, serialize::*};
 
-use std::fmt;
+use std::{collections::BTreeMap, fmt};
 
 /// Stores a sparse polynomial in coefficient form.
 #[derive(Clone, PartialEq, Eq, Hash, Default, CanonicalSerialize, CanonicalDeserialize)]
@@ -56,6 +56,18 @@ impl<F: Field> SparsePolynomial<F> {
         self.coeffs.is_empty() || self.coeffs.iter().all(|(_, c)| c.is_zero())
     }
 
+    /// Constructs a new polynomial from `(i, a_i)` pairs given in any order.
+    /// Coefficients for the same power of `x` are summed, and zero coefficients are dropped.
+    pub fn from_coefficients(coeffs: impl IntoIterator<Item = (usize, F)>) -> Self {
+        let mut result = BTreeMap::new();
+        for (i, coeff) in coeffs {
+            *result.entry(i).or_insert_with(F::zero) += coeff;
+        }
+        Self {
+            coeffs: result.into_iter().filter(|(_, c)| !c.is_zero()).collect(),
+        }
+    }
+
     /// Constructs a new polynomial from a list of coefficients.
     pub fn from_coefficients_slice(coeffs: &[(usize, F)]) -> Self {
         Self::from_coefficients_vec(coeffs.to_vec())
@@ -95,6 +107,27 @@ impl<F: Field> SparsePolynomial<F> {
         total
     }
 
+    /// Returns the sum of `self` and `other`.
+    pub fn add(&self, other: &Self) -> Self {
+        Self::from_coefficients(self.coeffs.iter().chain(&other.coeffs).copied())
+    }
+
+    /// Perform a naive multiplication of `self` by the dense polynomial `other`,
+    /// in time proportional to the number of terms in `self` times the degree of `other`.
+    pub fn mul_by_dense(&self, other: &DensePolynomial<F>) -> DensePolynomial<F> {
+        if self.is_zero() || other.is_zero() {
+            DensePolynomial::zero()
+        } else {
+            let mut result = vec![F::zero(); self.degree() + other.degree() + 1];
+            for (i, self_coeff) in self.coeffs.iter() {
+                for (j, other_coeff) in other.coeffs.iter().enumerate() {
+                    result[i + j] += *self_coeff * other_coeff;
+                }
+            }
+            DensePolynomial::from_coefficients_vec(result)
+        }
+    }
+
     /// Perform a naive n^2 multiplicatoin of `self` by `other`.
     pub fn mul(&self, other: &Self) -> Self {
         if self.is_zero() || other.is_zero() {
@@ -145,7 +178,62 @@ impl<F: Field> Into<DensePolynomial<F>> for SparsePolynomial<F> {
 mod tests {
     use crate::fft::{DensePolynomial, EvaluationDomain, SparsePolynomial};
     use snarkvm_curves::bls12_377::Fr;
-    use snarkvm_fields::One;
+    use snarkvm_fields::{One, Zero};
+    use snarkvm_utilities::rand::UniformRand;
+
+    use rand::{thread_rng, Rng};
+
+    fn rand_sparse_poly<R: Rng>(num_terms: usize, max_degree: usize, rng: &mut R) -> SparsePolynomial<Fr> {
+        SparsePolynomial::from_coefficients((0..num_terms).map(|_| (rng.gen_range(0..=max_degree), Fr::rand(rng))))
+    }
+
+    #[test]
+    fn from_coefficients() {
+        let two = Fr::one() + Fr::one();
+        let sparse_poly =
+            SparsePolynomial::from_coefficients(vec![(5, Fr::one()), (0, two), (5, Fr::one()), (3, Fr::zero())]);
+        assert_eq!(sparse_poly.coeffs, vec![(0, two), (5, two)]);
+
+        let sparse_poly = SparsePolynomial::from_coefficients(vec![(2, Fr::one()), (2, -Fr::one())]);
+        assert!(sparse_poly.coeffs.is_empty());
+    }
+
+    #[test]
+    fn add_polynomials() {
+        let rng = &mut thread_rng();
+        for _ in 0..20 {
+            let a = rand_sparse_poly(10, 100, rng);
+            let b = rand_sparse_poly(10, 100, rng);
+            let (a_dense, b_dense): (DensePolynomial<Fr>, DensePolynomial<Fr>) = (a.clone().into(), b.clone().into());
+            let sum: DensePolynomial<Fr> = a.add(&b).into();
+            assert_eq!(sum, &a_dense + &b_dense);
+        }
+        let a = rand_sparse_poly(10, 100, rng);
+        let neg_a = SparsePolynomial::from_coefficients(a.coeffs.iter().map(|(i, c)| (*i, -*c)));
+        assert!(a.add(&neg_a).is_zero());
+    }
+
+    #[test]
+    fn mul_by_dense_polynomials() {
+        let rng = &mut thread_rng();
+        for degree in 0..20 {
+            let a = rand_sparse_poly(10, 100, rng);
+            let b = DensePolynomial::<Fr>::rand(degree, rng);
+            let a_dense: DensePolynomial<Fr> = a.clone().into();
+            assert_eq!(a.mul_by_dense(&b), &a_dense * &b);
+        }
+    }
+
+    #[test]
+    fn evaluate_polynomials() {
+        let rng = &mut thread_rng();
+        for _ in 0..20 {
+            let a = rand_sparse_poly(10, 100, rng);
+            let point = Fr::rand(rng);
+            let a_dense: DensePolynomial<Fr> = a.clone().into();
+            assert_eq!(a.evaluate(point), a_dense.evaluate(point));
+        }
+    }
 
     #[test]
     fn evaluate_over_domain() {
@@ -0,0 +1,250 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A subproduct tree over a set of points, used for fast multi-point evaluation
+//! and interpolation.
+
+use crate::fft::{DenseOrSparsePolynomial, DensePolynomial};
+use snarkvm_fields::{batch_inversion, PrimeField};
+
+/// The maximum number of points at a leaf of the subproduct tree.
+/// Leaves are handled with quadratic-time algorithms.
+const LEAF_SIZE: usize = 16;
+
+/// The degree below which polynomial multiplication uses the schoolbook algorithm.
+const FAST_MULTIPLICATION_THRESHOLD: usize = 32;
+
+/// The degree below which polynomial division uses schoolbook long division.
+const FAST_DIVISION_THRESHOLD: usize = 64;
+
+/// A binary tree whose leaves partition a set of points `x_0, ..., x_{n-1}`,
+/// and whose nodes store the product of `(X - x_i)` over the points below them.
+pub(super) struct SubproductTree<'a, F: PrimeField> {
+    /// The points below this node.
+    points: &'a [F],
+    /// The product of `(X - x_i)` over `points`.
+    product: DensePolynomial<F>,
+    /// The left and right subtrees, or `None` if this node is a leaf.
+    children: Option<Box<(SubproductTree<'a, F>, SubproductTree<'a, F>)>>,
+}
+
+impl<'a, F: PrimeField> SubproductTree<'a, F> {
+    /// Constructs the subproduct tree over `points`, which must be non-empty.
+    pub(super) fn new(points: &'a [F]) -> Self {
+        assert!(!points.is_empty());
+        if points.len() <= LEAF_SIZE {
+            let product = points
+                .iter()
+                .fold(DensePolynomial::from_coefficients_vec(vec![F::one()]), |acc, x| {
+                    mul_by_linear(&acc, *x)
+                });
+            Self {
+                points,
+                product,
+                children: None,
+            }
+        } else {
+            let (left, right) = points.split_at(points.len() / 2);
+            let (left, right) = join(|| Self::new(left), || Self::new(right));
+            let product = mul(&left.product, &right.product);
+            Self {
+                points,
+                product,
+                children: Some(Box::new((left, right))),
+            }
+        }
+    }
+
+    /// Evaluates `poly` at every point in the tree, in order.
+    pub(super) fn evaluate(&self, poly: &DensePolynomial<F>) -> Vec<F> {
+        let remainder = fast_remainder(poly, &self.product);
+        match &self.children {
+            Some(children) => {
+                let (mut left, right) = join(|| children.0.evaluate(&remainder), || children.1.evaluate(&remainder));
+                left.extend(right);
+                left
+            }
+            None => self.points.iter().map(|x| horner_evaluate(&remainder, *x)).collect(),
+        }
+    }
+
+    /// Returns the polynomial of degree less than the number of points that takes the value
+    /// `values[i]` at the `i`-th point. Returns `None` if the points are not distinct.
+    pub(super) fn interpolate(&self, values: &[F]) -> Option<DensePolynomial<F>> {
+        assert_eq!(values.len(), self.points.len());
+
+        // The barycentric weights are `1 / product'(x_i)`, which is zero exactly when `x_i` is a repeated root.
+        let mut weights = self.evaluate(&derivative(&self.product));
+        if weights.iter().any(|w| w.is_zero()) {
+            return None;
+        }
+        batch_inversion(&mut weights);
+
+        let coefficients: Vec<F> = weights.iter().zip(values).map(|(w, y)| *w * y).collect();
+        Some(self.linear_combination(&coefficients))
+    }
+
+    /// Returns `sum_i c_i * product / (X - x_i)`.
+    fn linear_combination(&self, coefficients: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            Some(children) => {
+                let (left_coefficients, right_coefficients) = coefficients.split_at(children.0.points.len());
+                let (left, right) = join(
+                    || mul(&children.0.linear_combination(left_coefficients), &children.1.product),
+                    || mul(&children.1.linear_combination(right_coefficients), &children.0.product),
+                );
+                let sum = &left + &right;
+                DensePolynomial::from_coefficients_vec(sum.coeffs)
+            }
+            None => {
+                let mut result = DensePolynomial::zero();
+                for (i, c) in coefficients.iter().enumerate() {
+                    let term = self
+                        .points
+                        .iter()
+                        .enumerate()
+                        .filter(|(j, _)| *j != i)
+                        .fold(DensePolynomial::from_coefficients_vec(vec![*c]), |acc, (_, x)| {
+                            mul_by_linear(&acc, *x)
+                        });
+                    result += &term;
+                }
+                DensePolynomial::from_coefficients_vec(result.coeffs)
+            }
+        }
+    }
+}
+
+/// Runs `a` and `b`, in parallel if the `parallel` feature is enabled.
+fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+where
+    A: FnOnce() -> RA + Send,
+    B: FnOnce() -> RB + Send,
+    RA: Send,
+    RB: Send,
+{
+    #[cfg(feature = "parallel")]
+    return rayon::join(a, b);
+
+    #[cfg(not(feature = "parallel"))]
+    return (a(), b());
+}
+
+/// Returns `a * b`, using schoolbook multiplication when either factor has small degree.
+fn mul<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if a.is_zero() || b.is_zero() {
+        return DensePolynomial::zero();
+    }
+    if core::cmp::min(a.degree(), b.degree()) >= FAST_MULTIPLICATION_THRESHOLD {
+        return a * b;
+    }
+    let mut coeffs = vec![F::zero(); a.coeffs.len() + b.coeffs.len() - 1];
+    for (i, a) in a.coeffs.iter().enumerate() {
+        for (j, b) in b.coeffs.iter().enumerate() {
+            coeffs[i + j] += *a * b;
+        }
+    }
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Evaluates `poly` at `point` using Horner's rule.
+pub(super) fn horner_evaluate<F: PrimeField>(poly: &DensePolynomial<F>, point: F) -> F {
+    poly.coeffs.iter().rev().fold(F::zero(), |acc, c| acc * point + c)
+}
+
+/// Returns `poly * (X - x)`.
+fn mul_by_linear<F: PrimeField>(poly: &DensePolynomial<F>, x: F) -> DensePolynomial<F> {
+    let mut coeffs = vec![F::zero(); poly.coeffs.len() + 1];
+    for (i, c) in poly.coeffs.iter().enumerate() {
+        coeffs[i + 1] += c;
+        coeffs[i] -= x * c;
+    }
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns the formal derivative of `poly`.
+fn derivative<F: PrimeField>(poly: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let coeffs = poly
+        .coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, c)| F::from(i as u64) * c)
+        .collect();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns `poly mod X^k`.
+fn truncate<F: PrimeField>(mut poly: DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    poly.coeffs.truncate(k);
+    DensePolynomial::from_coefficients_vec(poly.coeffs)
+}
+
+/// Returns the polynomial with the coefficients of `poly`, padded to length `len`, in reverse order.
+fn reverse<F: PrimeField>(poly: &DensePolynomial<F>, len: usize) -> DensePolynomial<F> {
+    let mut coeffs = poly.coeffs.clone();
+    coeffs.resize(len, F::zero());
+    coeffs.reverse();
+    DensePolynomial::from_coefficients_vec(coeffs)
+}
+
+/// Returns the inverse of `poly` modulo `X^k`, using Newton iteration.
+/// The constant term of `poly` must be non-zero.
+fn inverse_mod_x_pow<F: PrimeField>(poly: &DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    let two = F::one().double();
+    let mut inverse = DensePolynomial::from_coefficients_vec(vec![poly.coeffs[0].inverse().unwrap()]);
+    let mut precision = 1;
+    while precision < k {
+        precision = core::cmp::min(2 * precision, k);
+        // inverse <- inverse * (2 - poly * inverse) mod X^precision
+        let poly = truncate(poly.clone(), precision);
+        let mut error = -truncate(mul(&poly, &inverse), precision);
+        if error.coeffs.is_empty() {
+            error.coeffs.push(F::zero());
+        }
+        error.coeffs[0] += two;
+        inverse = truncate(
+            mul(&inverse, &DensePolynomial::from_coefficients_vec(error.coeffs)),
+            precision,
+        );
+    }
+    inverse
+}
+
+/// Returns `dividend mod divisor`, using Newton iteration on the reversed divisor
+/// when both the divisor and the quotient have large degree.
+fn fast_remainder<F: PrimeField>(dividend: &DensePolynomial<F>, divisor: &DensePolynomial<F>) -> DensePolynomial<F> {
+    if dividend.is_zero() || dividend.degree() < divisor.degree() {
+        return dividend.clone();
+    }
+    let quotient_degree = dividend.degree() - divisor.degree();
+    if core::cmp::min(quotient_degree, divisor.degree()) < FAST_DIVISION_THRESHOLD {
+        let (dividend, divisor) = (
+            DenseOrSparsePolynomial::from(dividend),
+            DenseOrSparsePolynomial::from(divisor),
+        );
+        return dividend.divide_with_q_and_r(&divisor).expect("division failed").1;
+    }
+
+    // rev(quotient) = rev(dividend) * rev(divisor)^{-1} mod X^{quotient_degree + 1}
+    let k = quotient_degree + 1;
+    let reversed_dividend = truncate(reverse(dividend, dividend.degree() + 1), k);
+    let reversed_divisor = reverse(divisor, divisor.degree() + 1);
+    let reversed_quotient = truncate(mul(&reversed_dividend, &inverse_mod_x_pow(&reversed_divisor, k)), k);
+    let quotient = reverse(&reversed_quotient, k);
+
+    DensePolynomial::from_coefficients_vec((dividend - &mul(divisor, &quotient)).coeffs)
+}
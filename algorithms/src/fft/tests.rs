@@ -336,3 +336,44 @@ fn fft_composition() {
     test_fft_composition::<Fr, Fr, _>(rng, 10);
     test_fft_composition::<Fr, G1Projective, _>(rng, 10);
 }
+
+#[test]
+fn six_step_fft_consistency() {
+    let rng = &mut test_rng();
+
+    for log_size in 10..=22 {
+        let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+        let v: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+        let mut expected = v.clone();
+        domain.radix2_fft_in_place(&mut expected, domain.group_gen);
+        let mut actual = v.clone();
+        domain.six_step_fft_in_place(&mut actual, domain.group_gen);
+        assert_eq!(expected, actual, "FFT mismatch for domain size 2^{}", log_size);
+
+        let mut expected = v.clone();
+        domain.radix2_fft_in_place(&mut expected, domain.group_gen_inv);
+        let mut actual = v;
+        domain.six_step_fft_in_place(&mut actual, domain.group_gen_inv);
+        assert_eq!(expected, actual, "IFFT mismatch for domain size 2^{}", log_size);
+    }
+}
+
+#[test]
+fn six_step_fft_composition() {
+    let rng = &mut test_rng();
+
+    // Sizes at and above the default threshold use the six-step algorithm.
+    let log_size = DEFAULT_LOG_SIX_STEP_FFT_THRESHOLD;
+    let domain = EvaluationDomain::<Fr>::new(1 << log_size).unwrap();
+    let v: Vec<Fr> = (0..domain.size()).map(|_| Fr::rand(rng)).collect();
+
+    let mut v2 = v.clone();
+    domain.fft_in_place(&mut v2);
+    domain.ifft_in_place(&mut v2);
+    assert_eq!(v, v2, "ifft(fft(.)) != iden");
+
+    domain.coset_fft_in_place(&mut v2);
+    domain.coset_ifft_in_place(&mut v2);
+    assert_eq!(v, v2, "coset_ifft(coset_fft(.)) != iden");
+}
@@ -38,10 +38,16 @@ pub fn hash_to_curve<G: AffineCurve>(input: &str) -> (G, String, usize) {
 /// Executes one round of hash-to-curve and returns a generator on success.
 #[inline]
 pub fn try_hash_to_curve<G: AffineCurve>(input: &str) -> Option<G> {
+    try_hash_bytes_to_curve(input.as_bytes())
+}
+
+/// Executes one round of hash-to-curve on the given bytes and returns a generator on success.
+#[inline]
+pub fn try_hash_bytes_to_curve<G: AffineCurve>(input: &[u8]) -> Option<G> {
     debug_assert!(G::SERIALIZED_SIZE > 0);
 
     // Compute the digest for sampling the generator.
-    let digest = Blake2Xs::evaluate(input.as_bytes(), G::SERIALIZED_SIZE as u16, "AleoHtC0".as_bytes());
+    let digest = Blake2Xs::evaluate(input, G::SERIALIZED_SIZE as u16, "AleoHtC0".as_bytes());
     debug_assert!(digest.len() == G::SERIALIZED_SIZE);
 
     // Attempt to use the digest to derive a generator.
@@ -59,6 +59,9 @@ pub mod msm;
 #[cfg(feature = "prf")]
 pub mod prf;
 
+#[cfg(feature = "reference")]
+pub mod reference;
+
 #[cfg(feature = "signature")]
 pub mod signature;
 
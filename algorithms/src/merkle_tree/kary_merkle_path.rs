@@ -0,0 +1,86 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{errors::MerkleError, merkle_tree::MerkleTreeDigest, traits::MerkleParameters};
+use snarkvm_utilities::ToBytes;
+
+use std::sync::Arc;
+
+/// Stores the siblings of each node on the path from a leaf to the root of a Merkle tree,
+/// in which each inner node has `ARITY` children.
+#[derive(Clone, Debug)]
+pub struct KaryMerklePath<P: MerkleParameters, const ARITY: u8> {
+    pub parameters: Arc<P>,
+    /// `siblings[i]` holds the `ARITY - 1` siblings of the `i`th node from bottom to top, from left to right.
+    pub siblings: Vec<Vec<MerkleTreeDigest<P>>>,
+    pub leaf_index: u64,
+}
+
+impl<P: MerkleParameters, const ARITY: u8> KaryMerklePath<P, ARITY> {
+    pub fn verify<L: ToBytes>(&self, root_hash: &MerkleTreeDigest<P>, leaf: &L) -> Result<bool, MerkleError> {
+        // Ensure the path has the expected shape, and that the leaf index is within the tree.
+        if ARITY < 2
+            || self.siblings.len() != P::DEPTH
+            || self
+                .siblings
+                .iter()
+                .any(|siblings| siblings.len() != ARITY as usize - 1)
+            || !self.is_leaf_index_in_range()
+        {
+            return Ok(false);
+        }
+
+        let mut curr_path_node = self.parameters.hash_leaf::<L>(leaf)?;
+        for (position, siblings) in self.position_list().zip(&self.siblings) {
+            let children = Self::children(position, &curr_path_node, siblings);
+            curr_path_node = self.parameters.hash_children(&children)?;
+        }
+
+        Ok(&curr_path_node == root_hash)
+    }
+
+    /// The position of each on-path node among its siblings, from bottom to top.
+    /// `position[i]` is `j` iff the `i`th on-path node is the `j`th child of its parent, from left to right.
+    ///
+    /// This function simply converts `self.leaf_index` to its little-endian digits in base `ARITY`.
+    pub fn position_list(&self) -> impl Iterator<Item = u8> + '_ {
+        let arity = ARITY as u64;
+        (0..self.siblings.len()).scan(self.leaf_index, move |index, _| {
+            let position = *index % arity;
+            *index /= arity;
+            Some(position as u8)
+        })
+    }
+
+    /// Returns `true` if `self.leaf_index` is less than the number of leaves of a tree with this depth.
+    fn is_leaf_index_in_range(&self) -> bool {
+        match (ARITY as u64).checked_pow(self.siblings.len() as u32) {
+            Some(num_leaves) => self.leaf_index < num_leaves,
+            None => true,
+        }
+    }
+
+    /// Returns the children of an inner node, given the position and hash of one child, and the hashes of its siblings.
+    pub(crate) fn children(
+        position: u8,
+        hash: &MerkleTreeDigest<P>,
+        siblings: &[MerkleTreeDigest<P>],
+    ) -> Vec<MerkleTreeDigest<P>> {
+        let mut children = siblings.to_vec();
+        children.insert(position as usize, *hash);
+        children
+    }
+}
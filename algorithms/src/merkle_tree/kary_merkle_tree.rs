@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    errors::MerkleError,
+    merkle_tree::{KaryMerklePath, MerkleTreeDigest},
+    traits::{MerkleParameters, CRH},
+};
+use snarkvm_utilities::ToBytes;
+
+use std::sync::Arc;
+
+/// A Merkle tree of depth `P::DEPTH`, in which each inner node has `ARITY` children.
+///
+/// Leaves are filled from the left, and every missing node is the root of an empty subtree of its height,
+/// so only the nodes above the given leaves are computed.
+pub struct KaryMerkleTree<P: MerkleParameters, const ARITY: u8> {
+    /// The computed root of the Merkle tree.
+    root: MerkleTreeDigest<P>,
+    /// The non-empty nodes of each level, from the hashed leaves to the children of the root.
+    levels: Vec<Vec<MerkleTreeDigest<P>>>,
+    /// The root of an empty subtree at each level, from an empty leaf to an empty child of the root.
+    empty_hashes: Vec<MerkleTreeDigest<P>>,
+    /// The Merkle tree parameters (e.g. the hash function).
+    parameters: Arc<P>,
+}
+
+impl<P: MerkleParameters, const ARITY: u8> KaryMerkleTree<P, ARITY> {
+    pub const DEPTH: usize = P::DEPTH;
+
+    pub fn new<L: ToBytes>(parameters: Arc<P>, leaves: &[L]) -> Result<Self, MerkleError> {
+        if ARITY < 2 {
+            return Err(MerkleError::Message(format!(
+                "Invalid Merkle tree arity: {}. Must be at least 2",
+                ARITY
+            )));
+        }
+
+        // Ensure the leaves fit in a tree of the given depth.
+        let tree_depth = tree_depth(leaves.len(), ARITY);
+        if tree_depth > Self::DEPTH {
+            return Err(MerkleError::InvalidTreeDepth(tree_depth, Self::DEPTH));
+        }
+
+        // Compute the root of an empty subtree at each level.
+        let mut empty_hashes = Vec::with_capacity(Self::DEPTH + 1);
+        empty_hashes.push(parameters.hash_empty()?);
+        for level in 0..Self::DEPTH {
+            let children = vec![empty_hashes[level]; ARITY as usize];
+            empty_hashes.push(parameters.hash_children(&children)?);
+        }
+
+        // Compute the hash values for each leaf, and then for every non-empty node, up to the root.
+        let mut levels = Vec::with_capacity(Self::DEPTH + 1);
+        levels.push(
+            leaves
+                .iter()
+                .map(|leaf| parameters.hash_leaf(leaf))
+                .collect::<Result<Vec<_>, _>>()?,
+        );
+        for level in 0..Self::DEPTH {
+            let parents = levels[level]
+                .chunks(ARITY as usize)
+                .map(|children| {
+                    let mut children = children.to_vec();
+                    children.resize(ARITY as usize, empty_hashes[level]);
+                    parameters.hash_children(&children)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            levels.push(parents);
+        }
+
+        let root = levels
+            .pop()
+            .unwrap_or_default()
+            .first()
+            .copied()
+            .unwrap_or(empty_hashes[Self::DEPTH]);
+        empty_hashes.truncate(Self::DEPTH);
+
+        Ok(Self {
+            root,
+            levels,
+            empty_hashes,
+            parameters,
+        })
+    }
+
+    #[inline]
+    pub fn root(&self) -> &<P::H as CRH>::Output {
+        &self.root
+    }
+
+    #[inline]
+    pub fn hashed_leaves(&self) -> &[<P::H as CRH>::Output] {
+        self.levels.first().map(|leaves| &leaves[..]).unwrap_or(&[])
+    }
+
+    pub fn generate_proof<L: ToBytes>(&self, index: usize, leaf: &L) -> Result<KaryMerklePath<P, ARITY>, MerkleError> {
+        // Check that the given index corresponds to the correct leaf.
+        let leaf_hash = self.parameters.hash_leaf(leaf)?;
+        if self.hashed_leaves().get(index) != Some(&leaf_hash) {
+            return Err(MerkleError::IncorrectLeafIndex(index));
+        }
+
+        // Iterate from the leaf up to the children of the root, storing the siblings at each level.
+        let arity = ARITY as usize;
+        let mut current_index = index;
+        let mut siblings = Vec::with_capacity(Self::DEPTH);
+        for (level, empty_hash) in self.levels.iter().zip(&self.empty_hashes) {
+            let first_sibling = current_index - current_index % arity;
+            siblings.push(
+                (first_sibling..first_sibling + arity)
+                    .filter(|sibling| *sibling != current_index)
+                    .map(|sibling| *level.get(sibling).unwrap_or(empty_hash))
+                    .collect(),
+            );
+            current_index /= arity;
+        }
+
+        Ok(KaryMerklePath {
+            parameters: self.parameters.clone(),
+            siblings,
+            leaf_index: index as u64,
+        })
+    }
+}
+
+/// Returns the minimum depth of a tree with the given arity that has at least `num_leaves` leaves.
+fn tree_depth(num_leaves: usize, arity: u8) -> usize {
+    let mut depth = 0;
+    let mut capacity = 1usize;
+    while capacity < num_leaves {
+        capacity = capacity.saturating_mul(arity as usize);
+        depth += 1;
+    }
+    depth
+}
@@ -14,6 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod kary_merkle_path;
+pub use kary_merkle_path::*;
+
+pub mod kary_merkle_tree;
+pub use kary_merkle_tree::*;
+
 pub mod masked_merkle_tree_parameters;
 pub use masked_merkle_tree_parameters::*;
 
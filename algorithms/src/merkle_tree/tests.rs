@@ -15,8 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
-    crh::{PedersenCRH, PedersenCompressedCRH},
-    merkle_tree::{MerkleTree, MerkleTreeParameters},
+    crh::{PedersenCRH, PedersenCompressedCRH, PoseidonCRH},
+    merkle_tree::{KaryMerkleTree, MerkleTree, MerkleTreeParameters},
     traits::{MerkleParameters, CRH},
 };
 use snarkvm_utilities::{to_bytes_le, ToBytes};
@@ -251,3 +251,123 @@ mod pedersen_compressed_crh_on_projective {
         assert_eq!(tree.root(), new_tree_2.root());
     }
 }
+
+/// Generates a valid k-ary Merkle tree and verifies the Merkle path witness for each leaf.
+fn generate_kary_merkle_tree<P: MerkleParameters, L: ToBytes, const ARITY: u8>(
+    leaves: &[L],
+    parameters: &P,
+) -> KaryMerkleTree<P, ARITY> {
+    let tree = KaryMerkleTree::<P, ARITY>::new(Arc::new(parameters.clone()), leaves).unwrap();
+    for (i, leaf) in leaves.iter().enumerate() {
+        let proof = tree.generate_proof(i, &leaf).unwrap();
+        assert_eq!(P::DEPTH, proof.siblings.len());
+        assert!(
+            proof
+                .siblings
+                .iter()
+                .all(|siblings| siblings.len() == ARITY as usize - 1)
+        );
+        assert!(proof.verify(tree.root(), &leaf).unwrap());
+        assert!(!proof.verify(&<P::H as CRH>::Output::default(), &leaf).unwrap());
+    }
+    tree
+}
+
+fn run_kary_good_root_test<P: MerkleParameters, const ARITY: u8>() {
+    let parameters = &P::setup("merkle_tree_test");
+
+    generate_kary_merkle_tree::<P, [u8; 8], ARITY>(&[], parameters);
+    for num_leaves in [1, ARITY as usize, ARITY as usize + 1, 10] {
+        let leaves = generate_random_leaves!(num_leaves, 8);
+        generate_kary_merkle_tree::<P, _, ARITY>(&leaves, parameters);
+    }
+}
+
+fn run_kary_bad_path_test<P: MerkleParameters, const ARITY: u8>() {
+    let parameters = &P::setup("merkle_tree_test");
+    let leaves = generate_random_leaves!(10, 8);
+    let tree = generate_kary_merkle_tree::<P, _, ARITY>(&leaves, parameters);
+
+    for (i, leaf) in leaves.iter().enumerate() {
+        let proof = tree.generate_proof(i, &leaf).unwrap();
+
+        // Ensure a different leaf is rejected.
+        let other_leaf = &leaves[(i + 1) % leaves.len()];
+        assert!(!proof.verify(tree.root(), &other_leaf).unwrap());
+
+        // Ensure a different leaf index is rejected.
+        let mut bad_proof = proof.clone();
+        bad_proof.leaf_index ^= 1;
+        assert!(!bad_proof.verify(tree.root(), &leaf).unwrap());
+
+        // Ensure a path with swapped siblings is rejected.
+        let mut bad_proof = proof.clone();
+        let siblings = &mut bad_proof.siblings[0];
+        if ARITY > 2 && siblings[0] != siblings[1] {
+            siblings.swap(0, 1);
+            assert!(!bad_proof.verify(tree.root(), &leaf).unwrap());
+        }
+    }
+    assert!(tree.generate_proof(0, &leaves[1]).is_err());
+    assert!(tree.generate_proof(leaves.len(), &leaves[0]).is_err());
+}
+
+fn kary_merkle_tree_matches_hashing_test<P: MerkleParameters>() {
+    let parameters = &P::setup("merkle_tree_test");
+    let leaves = generate_random_leaves!(5, 8);
+    let tree = generate_kary_merkle_tree::<P, _, 3>(&leaves, parameters);
+
+    // Compute the root of a depth-2 ternary tree with 5 leaves, padding each level with empty subtrees.
+    let empty = parameters.hash_empty().unwrap();
+    let empty_1 = parameters.hash_children(&[empty, empty, empty]).unwrap();
+    let hashes: Vec<_> = leaves.iter().map(|leaf| parameters.hash_leaf(leaf).unwrap()).collect();
+    let node_0 = parameters.hash_children(&hashes[0..3]).unwrap();
+    let node_1 = parameters.hash_children(&[hashes[3], hashes[4], empty]).unwrap();
+    let expected_root = parameters.hash_children(&[node_0, node_1, empty_1]).unwrap();
+
+    assert_eq!(tree.root(), &expected_root);
+
+    // Ensure the tree rejects more leaves than it can hold.
+    let leaves = generate_random_leaves!(10, 8);
+    assert!(KaryMerkleTree::<P, 3>::new(Arc::new(parameters.clone()), &leaves).is_err());
+}
+
+fn binary_kary_merkle_tree_matches_merkle_tree_test<P: MerkleParameters>() {
+    let parameters = &P::setup("merkle_tree_test");
+    let leaves = generate_random_leaves!(4, 8);
+
+    let tree = MerkleTree::<P>::new(Arc::new(parameters.clone()), &leaves).unwrap();
+    let kary_tree = generate_kary_merkle_tree::<P, _, 2>(&leaves, parameters);
+    assert_eq!(tree.root(), kary_tree.root());
+}
+
+mod poseidon_crh_kary {
+    use super::*;
+    use snarkvm_curves::bls12_377::Fr;
+
+    type MTParameters<const DEPTH: usize> = MerkleTreeParameters<PoseidonCRH<Fr, 6>, DEPTH>;
+
+    #[test]
+    fn good_root_test() {
+        run_kary_good_root_test::<MTParameters<4>, 2>();
+        run_kary_good_root_test::<MTParameters<3>, 3>();
+        run_kary_good_root_test::<MTParameters<2>, 4>();
+    }
+
+    #[test]
+    fn bad_path_test() {
+        run_kary_bad_path_test::<MTParameters<4>, 2>();
+        run_kary_bad_path_test::<MTParameters<3>, 3>();
+        run_kary_bad_path_test::<MTParameters<2>, 4>();
+    }
+
+    #[test]
+    fn depth2_ternary_merkle_tree_matches_hashing_test() {
+        kary_merkle_tree_matches_hashing_test::<MTParameters<2>>();
+    }
+
+    #[test]
+    fn binary_merkle_tree_matches_merkle_tree_test() {
+        binary_kary_merkle_tree_matches_merkle_tree_test::<MTParameters<2>>();
+    }
+}
@@ -78,6 +78,21 @@ impl FixedBaseMSM {
         res
     }
 
+    /// Returns the product of the given base with each of the given scalars.
+    ///
+    /// The window table for `base` is built once and shared by every multiplication.
+    pub fn msm<T: ProjectiveCurve>(base: T, scalars: &[T::ScalarField]) -> Vec<T> {
+        if scalars.is_empty() {
+            return Vec::new();
+        }
+
+        let scalar_size = <T::ScalarField as PrimeField>::size_in_bits();
+        let window = Self::get_mul_window_size(scalars.len());
+        let table = Self::get_window_table(scalar_size, window, base);
+
+        Self::multi_scalar_mul(scalar_size, window, &table, scalars)
+    }
+
     pub fn multi_scalar_mul<T: ProjectiveCurve>(
         scalar_size: usize,
         window: usize,
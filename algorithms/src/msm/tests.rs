@@ -19,11 +19,12 @@ use snarkvm_curves::{
     bls12_377::{Fr, G1Projective},
     traits::{AffineCurve, ProjectiveCurve},
 };
-use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_fields::{One, PrimeField, Zero};
 use snarkvm_utilities::{rand::UniformRand, BitIteratorBE};
 
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
+use std::ops::Mul;
 
 fn naive_variable_base_msm<G: AffineCurve>(
     bases: &[G],
@@ -72,3 +73,33 @@ fn variable_base_test_with_bls12_unequal_numbers() {
 
     assert_eq!(naive.into_affine(), fast.into_affine());
 }
+
+#[test]
+fn fixed_base_test_with_bls12() {
+    const SAMPLES: usize = 1 << 8;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let mut v = (0..SAMPLES).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+    // Include the zero and maximal scalars.
+    v.push(Fr::zero());
+    v.push(-Fr::one());
+    let g = G1Projective::rand(&mut rng);
+
+    let naive = v.iter().map(|scalar| g.mul(*scalar)).collect::<Vec<_>>();
+    let fast = FixedBaseMSM::msm(g, &v);
+
+    assert_eq!(naive, fast);
+    assert!(fast[SAMPLES].is_zero());
+    assert_eq!(fast[SAMPLES + 1], -g);
+}
+
+#[test]
+fn fixed_base_test_with_few_scalars() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+
+    let g = G1Projective::rand(&mut rng);
+    assert!(FixedBaseMSM::msm(g, &[]).is_empty());
+    assert_eq!(FixedBaseMSM::msm(g, &[Fr::one()]), vec![g]);
+    assert_eq!(FixedBaseMSM::msm(g, &[Fr::zero()]), vec![G1Projective::zero()]);
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{crh::BOWE_HOPWOOD_CHUNK_SIZE, CRHError};
+use snarkvm_curves::{AffineCurve, ProjectiveCurve};
+
+/// Returns the BHP hash of the given bits, adding each encoded chunk into the sum
+/// by repeated addition of its window generator, without lookup tables.
+pub fn bhp_hash_bits<G: ProjectiveCurve, const NUM_WINDOWS: usize, const WINDOW_SIZE: usize>(
+    bases: &[Vec<G>],
+    input: &[bool],
+) -> Result<<G::Affine as AffineCurve>::BaseField, CRHError> {
+    if input.len() > WINDOW_SIZE * NUM_WINDOWS {
+        return Err(CRHError::IncorrectInputLength(input.len(), WINDOW_SIZE, NUM_WINDOWS));
+    }
+
+    // Pad the input with zeros, so that every window is covered.
+    let mut bits = input.to_vec();
+    bits.resize(WINDOW_SIZE * NUM_WINDOWS, false);
+
+    let mut output = G::zero();
+    for (index, chunk) in bits.chunks(BOWE_HOPWOOD_CHUNK_SIZE).enumerate() {
+        let generator = bases[index / WINDOW_SIZE][index % WINDOW_SIZE];
+        // Pad the last chunk with zeros, if it is partial.
+        let bit = |i: usize| chunk.get(i).copied().unwrap_or(false);
+
+        // Encode the chunk (b0, b1, b2) as (1 - 2 * b2) * (1 + b0 + 2 * b1).
+        let magnitude = 1 + bit(0) as usize + 2 * bit(1) as usize;
+        let mut encoded = G::zero();
+        for _ in 0..magnitude {
+            encoded += generator;
+        }
+        if bit(2) {
+            encoded = -encoded;
+        }
+        output += encoded;
+    }
+
+    Ok(output.into_affine().to_x_coordinate())
+}
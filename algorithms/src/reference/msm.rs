@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{
+    reference::{sw_add, sw_mul_bits},
+    templates::short_weierstrass_jacobian::Affine,
+    traits::ShortWeierstrassParameters,
+};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::BitIteratorBE;
+
+/// Returns the sum of the products of the given bases and scalars, computing each product
+/// separately with the affine group law. Extra bases or scalars are ignored.
+pub fn msm<P: ShortWeierstrassParameters>(
+    bases: &[Affine<P>],
+    scalars: &[<P::ScalarField as PrimeField>::BigInteger],
+) -> Affine<P> {
+    bases.iter().zip(scalars).fold(Affine::zero(), |acc, (base, scalar)| {
+        sw_add(&acc, &sw_mul_bits(base, BitIteratorBE::new(*scalar)))
+    })
+}
@@ -0,0 +1,55 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::crypto_hash::PoseidonParameters;
+use snarkvm_fields::PrimeField;
+
+/// Returns the Poseidon permutation of the given state, ordered as the capacity elements
+/// followed by the rate elements.
+pub fn poseidon_permutation<F: PrimeField, const RATE: usize, const CAPACITY: usize>(
+    parameters: &PoseidonParameters<F, RATE, CAPACITY>,
+    state: &[F],
+) -> Vec<F> {
+    assert_eq!(state.len(), RATE + CAPACITY);
+    let mut state = state.to_vec();
+
+    // The partial rounds are preceded and followed by half of the full rounds.
+    let num_rounds = parameters.full_rounds + parameters.partial_rounds;
+    let first_partial_round = parameters.full_rounds / 2;
+    let last_partial_round = first_partial_round + parameters.partial_rounds;
+
+    for round in 0..num_rounds {
+        // Add the round constants.
+        for (element, constant) in state.iter_mut().zip(&parameters.ark[round]) {
+            *element += constant;
+        }
+
+        // Apply the S-box to every element in a full round, and to the first element in a partial round.
+        let is_full_round = round < first_partial_round || round >= last_partial_round;
+        let num_s_boxes = if is_full_round { state.len() } else { 1 };
+        for element in state.iter_mut().take(num_s_boxes) {
+            *element = element.pow([parameters.alpha]);
+        }
+
+        // Multiply by the MDS matrix.
+        state = parameters
+            .mds
+            .iter()
+            .map(|row| row.iter().zip(&state).map(|(entry, element)| *entry * element).sum())
+            .collect();
+    }
+    state
+}
@@ -0,0 +1,163 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    crh::BHPCRH,
+    crypto_hash::PoseidonSponge,
+    msm::VariableBaseMSM,
+    reference::*,
+    AlgebraicSponge,
+    CRH,
+};
+use snarkvm_curves::{
+    bls12_377::{Bls12_377G1Parameters, Bls12_377G2Parameters, Fr},
+    edwards_bls12::EdwardsProjective,
+    templates::short_weierstrass_jacobian::Affine,
+    traits::{AffineCurve, ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{One, PrimeField, Zero};
+use snarkvm_utilities::rand::UniformRand;
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+const ITERATIONS: usize = 10;
+
+/// Returns random bases and scalars, including identity bases and zero and maximal scalars.
+fn sample_msm_inputs<P: ShortWeierstrassParameters>(
+    rng: &mut XorShiftRng,
+    num_elements: usize,
+) -> (Vec<Affine<P>>, Vec<<P::ScalarField as PrimeField>::BigInteger>)
+where
+    Affine<P>: AffineCurve<ScalarField = P::ScalarField>,
+{
+    let bases = (0..num_elements)
+        .map(|_| match rng.gen_range(0..4) {
+            0 => Affine::zero(),
+            _ => <Affine<P> as AffineCurve>::Projective::rand(rng).into_affine(),
+        })
+        .collect();
+    let scalars = (0..num_elements)
+        .map(|_| match rng.gen_range(0..5) {
+            0 => P::ScalarField::zero(),
+            1 => P::ScalarField::one(),
+            2 => -P::ScalarField::one(),
+            _ => P::ScalarField::rand(rng),
+        })
+        .map(|scalar| scalar.to_repr())
+        .collect();
+    (bases, scalars)
+}
+
+fn check_msm<P: ShortWeierstrassParameters>(rng: &mut XorShiftRng)
+where
+    Affine<P>: AffineCurve<ScalarField = P::ScalarField>,
+{
+    for num_elements in [0, 1, 2, 3, 17, 64, 129] {
+        let (bases, scalars) = sample_msm_inputs::<P>(rng, num_elements);
+        let expected = msm(&bases, &scalars);
+        assert_eq!(
+            expected,
+            VariableBaseMSM::multi_scalar_mul(&bases, &scalars).into_affine()
+        );
+
+        // Ensure extra bases are ignored.
+        if num_elements > 0 {
+            let expected = msm(&bases, &scalars[..num_elements - 1]);
+            let candidate = VariableBaseMSM::multi_scalar_mul(&bases, &scalars[..num_elements - 1]);
+            assert_eq!(expected, candidate.into_affine());
+        }
+    }
+
+    // Ensure all zero scalars and all identity bases give the identity.
+    let (bases, scalars) = sample_msm_inputs::<P>(rng, 32);
+    let zeros = vec![P::ScalarField::zero().to_repr(); 32];
+    assert!(msm(&bases, &zeros).is_zero());
+    assert!(VariableBaseMSM::multi_scalar_mul(&bases, &zeros).is_zero());
+    let identities = vec![Affine::<P>::zero(); 32];
+    assert!(msm(&identities, &scalars).is_zero());
+    assert!(VariableBaseMSM::multi_scalar_mul(&identities, &scalars).is_zero());
+}
+
+#[test]
+fn test_msm_bls12_377_g1() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+    check_msm::<Bls12_377G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_msm_bls12_377_g2() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+    check_msm::<Bls12_377G2Parameters>(&mut rng);
+}
+
+fn check_poseidon_permutation<const RATE: usize>(rng: &mut XorShiftRng) {
+    let parameters = PoseidonSponge::<Fr, RATE, 1>::sample_default_parameters();
+
+    let states = (0..ITERATIONS)
+        .map(|_| (0..RATE + 1).map(|_| Fr::rand(rng)).collect::<Vec<_>>())
+        .chain([vec![Fr::zero(); RATE + 1], vec![-Fr::one(); RATE + 1]]);
+
+    for state in states {
+        let expected = poseidon_permutation(&parameters, &state);
+
+        // Load the state into a fresh sponge, which permutes it once on the first squeeze.
+        let mut sponge = PoseidonSponge::<Fr, RATE, 1>::with_parameters(&parameters);
+        sponge
+            .state
+            .iter_mut()
+            .zip(&state)
+            .for_each(|(element, value)| *element = *value);
+        let output = sponge.squeeze_field_elements(RATE);
+
+        assert_eq!(expected[1..], output[..]);
+        assert_eq!(expected, sponge.state.iter().copied().collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn test_poseidon_permutation() {
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+    check_poseidon_permutation::<2>(&mut rng);
+    check_poseidon_permutation::<4>(&mut rng);
+    check_poseidon_permutation::<8>(&mut rng);
+}
+
+#[test]
+fn test_bhp_hash_bits() {
+    const NUM_WINDOWS: usize = 8;
+    const WINDOW_SIZE: usize = 63;
+
+    let mut rng = XorShiftRng::seed_from_u64(234872845u64);
+    let crh = BHPCRH::<EdwardsProjective, NUM_WINDOWS, WINDOW_SIZE>::setup("test_bhp_hash_bits");
+    let hash = |input: &[bool]| bhp_hash_bits::<_, NUM_WINDOWS, WINDOW_SIZE>(crh.parameters(), input);
+
+    let max_bits = NUM_WINDOWS * WINDOW_SIZE;
+    let mut inputs = vec![vec![], vec![false; max_bits], vec![true; max_bits], vec![true]];
+    for _ in 0..ITERATIONS {
+        let num_bits = rng.gen_range(0..=max_bits);
+        inputs.push((0..num_bits).map(|_| rng.gen()).collect());
+    }
+
+    for input in inputs {
+        assert_eq!(crh.hash_bits(&input).unwrap(), hash(&input).unwrap());
+    }
+
+    // Ensure inputs that are too long are rejected.
+    let input = vec![false; max_bits + 1];
+    assert!(crh.hash_bits(&input).is_err());
+    assert!(hash(&input).is_err());
+}
@@ -44,6 +44,7 @@ use snarkvm_utilities::{
 use anyhow::Result;
 use itertools::Itertools;
 use rand::{CryptoRng, Rng};
+use zeroize::Zeroize;
 
 #[derive(Derivative)]
 #[derivative(
@@ -147,6 +148,7 @@ where
 impl<TE: TwistedEdwardsParameters> SignatureScheme for AleoSignatureScheme<TE>
 where
     TE::BaseField: PoseidonDefaultParametersField,
+    TE::ScalarField: Zeroize,
 {
     type Parameters = Vec<TEProjective<TE>>;
     type PrivateKey = (TE::ScalarField, TE::ScalarField);
@@ -222,7 +224,7 @@ where
         rng: &mut R,
     ) -> Result<Self::Signature> {
         // Sample a random scalar field element.
-        let r = TE::ScalarField::rand(rng);
+        let mut r = TE::ScalarField::rand(rng);
 
         // Compute G^r.
         let g_r = self.g_scalar_multiply(&r);
@@ -261,6 +263,9 @@ where
         // Compute the prover response.
         let prover_response = r - (verifier_challenge * sk_sig);
 
+        // Zeroize the nonce, as it reveals sk_sig given the signature.
+        r.zeroize();
+
         Ok(AleoSignature {
             prover_response,
             verifier_challenge,
@@ -0,0 +1,522 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! BLS signatures over BLS12-377, with public keys in G1 and signatures in G2.
+//!
+//! Public keys are in the smaller group, as validators aggregate many public keys that sign the same
+//! message, and only one signature per message. A public key is 48 bytes compressed, and a signature is 96 bytes.
+//!
+//! Aggregating public keys that sign the same message is vulnerable to rogue-key attacks, in which
+//! a signer chooses their public key as a function of the public keys of others. Each public key must
+//! therefore have a verified proof of possession of its private key before it is used in
+//! [`BLSSignature::fast_aggregate_verify`], which only accepts a [`VerifiedPublicKey`], as returned by
+//! [`BLSPublicKey::verify_proof_of_possession`]. Aggregates of signatures on distinct messages do not
+//! need proofs of possession, and [`BLSSignature::aggregate_verify`] rejects repeated messages.
+
+use crate::hash_to_curve::try_hash_bytes_to_curve;
+use snarkvm_curves::{
+    bls12_377::{Bls12_377, Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+    AffineCurve,
+    PairingCurve,
+    PairingEngine,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
+
+use rand::{CryptoRng, Rng};
+use std::{
+    collections::HashSet,
+    fmt,
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+};
+use zeroize::Zeroize;
+
+/// The domain separator for hashing messages to sign.
+const SIGNATURE_DOMAIN: &[u8] = b"AleoBLSSignature";
+/// The domain separator for hashing public keys to prove possession of.
+const PROOF_OF_POSSESSION_DOMAIN: &[u8] = b"AleoBLSProofOfPossession";
+
+/// A BLS private key, which is a nonzero scalar.
+#[derive(Clone, PartialEq, Eq)]
+pub struct BLSPrivateKey(Fr);
+
+/// A BLS public key, which is the private key times the generator of G1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BLSPublicKey(G1Affine);
+
+/// A BLS public key with a verified proof of possession of its private key,
+/// which may be aggregated with other public keys that sign the same message.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VerifiedPublicKey(BLSPublicKey);
+
+/// A BLS signature, which is the private key times the hash of the message in G2.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BLSSignature(G2Affine);
+
+impl BLSPrivateKey {
+    /// Samples a new private key.
+    pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
+        loop {
+            let private_key = Fr::rand(rng);
+            if !private_key.is_zero() {
+                return Self(private_key);
+            }
+        }
+    }
+
+    /// Returns the public key of this private key.
+    pub fn to_public_key(&self) -> BLSPublicKey {
+        BLSPublicKey((G1Affine::prime_subgroup_generator().into_projective() * self.0).into_affine())
+    }
+
+    /// Returns the signature of this private key on the given message.
+    pub fn sign(&self, message: &[u8]) -> BLSSignature {
+        BLSSignature((hash_to_g2(SIGNATURE_DOMAIN, message).into_projective() * self.0).into_affine())
+    }
+
+    /// Returns a proof of possession of this private key, which is a signature on its public key
+    /// under a separate domain, so that it can never be confused with a signature on a message.
+    pub fn prove_possession(&self) -> BLSSignature {
+        let public_key = self
+            .to_public_key()
+            .to_bytes_le()
+            .expect("Failed to serialize a public key");
+        BLSSignature((hash_to_g2(PROOF_OF_POSSESSION_DOMAIN, &public_key).into_projective() * self.0).into_affine())
+    }
+}
+
+impl BLSPublicKey {
+    /// Returns the public key as a point in G1.
+    pub fn to_affine(&self) -> G1Affine {
+        self.0
+    }
+
+    /// Returns `true` if the signature on the given message is valid under this public key.
+    pub fn verify(&self, message: &[u8], signature: &BLSSignature) -> bool {
+        !self.0.is_zero() && check_pairings(&[(self.0, hash_to_g2(SIGNATURE_DOMAIN, message))], signature.0)
+    }
+
+    /// Returns this public key as a verified public key, if the given proof of possession
+    /// of its private key is valid, and `None` otherwise.
+    pub fn verify_proof_of_possession(&self, proof: &BLSSignature) -> Option<VerifiedPublicKey> {
+        let public_key = self.to_bytes_le().ok()?;
+        let is_valid = !self.0.is_zero()
+            && check_pairings(
+                &[(self.0, hash_to_g2(PROOF_OF_POSSESSION_DOMAIN, &public_key))],
+                proof.0,
+            );
+        match is_valid {
+            true => Some(VerifiedPublicKey(*self)),
+            false => None,
+        }
+    }
+
+    /// Returns the sum of the given public keys, which verifies the aggregate of their signatures on a message.
+    ///
+    /// Each public key has a verified proof of possession, see the module documentation.
+    pub fn aggregate(public_keys: &[VerifiedPublicKey]) -> Self {
+        Self(
+            public_keys
+                .iter()
+                .map(|public_key| public_key.public_key().0.into_projective())
+                .sum::<G1Projective>()
+                .into_affine(),
+        )
+    }
+}
+
+impl VerifiedPublicKey {
+    /// Returns the public key.
+    pub fn public_key(&self) -> &BLSPublicKey {
+        &self.0
+    }
+}
+
+impl BLSSignature {
+    /// Returns the signature as a point in G2.
+    pub fn to_affine(&self) -> G2Affine {
+        self.0
+    }
+
+    /// Returns the hash of the given message in G2, which is the point a private key signs.
+    pub fn hash_message(message: &[u8]) -> G2Affine {
+        hash_to_g2(SIGNATURE_DOMAIN, message)
+    }
+
+    /// Returns the sum of the given signatures.
+    pub fn aggregate(signatures: &[Self]) -> Self {
+        Self(
+            signatures
+                .iter()
+                .map(|signature| signature.0.into_projective())
+                .sum::<G2Projective>()
+                .into_affine(),
+        )
+    }
+
+    /// Returns `true` if this is a valid aggregate of signatures on the same message by the given public keys.
+    ///
+    /// Each public key has a verified proof of possession, see the module documentation.
+    pub fn fast_aggregate_verify(&self, public_keys: &[VerifiedPublicKey], message: &[u8]) -> bool {
+        !public_keys.is_empty() && BLSPublicKey::aggregate(public_keys).verify(message, self)
+    }
+
+    /// Returns `true` if this is a valid aggregate of signatures on the given messages by the given public keys.
+    ///
+    /// This costs one pairing per message, and returns `false` if any message is repeated,
+    /// which ensures rogue-key attacks are not possible without proofs of possession.
+    pub fn aggregate_verify(&self, public_keys: &[BLSPublicKey], messages: &[&[u8]]) -> bool {
+        if public_keys.is_empty() || public_keys.len() != messages.len() {
+            return false;
+        }
+        let mut distinct_messages = HashSet::with_capacity(messages.len());
+        if !messages.iter().all(|message| distinct_messages.insert(*message)) {
+            return false;
+        }
+        if public_keys.iter().any(|public_key| public_key.0.is_zero()) {
+            return false;
+        }
+
+        let pairs: Vec<_> = public_keys
+            .iter()
+            .zip(messages)
+            .map(|(public_key, message)| (public_key.0, hash_to_g2(SIGNATURE_DOMAIN, message)))
+            .collect();
+        check_pairings(&pairs, self.0)
+    }
+
+    /// Returns `true` if every signature on the given message is valid under the given public key.
+    ///
+    /// The signatures are combined with random scalars, so that they are checked with one pairing per
+    /// signature and a single final exponentiation. An invalid signature is accepted with negligible probability.
+    pub fn batch_verify<R: Rng + CryptoRng>(
+        public_keys: &[BLSPublicKey],
+        messages: &[&[u8]],
+        signatures: &[BLSSignature],
+        rng: &mut R,
+    ) -> bool {
+        if public_keys.len() != messages.len() || public_keys.len() != signatures.len() {
+            return false;
+        }
+        if public_keys.iter().any(|public_key| public_key.0.is_zero()) {
+            return false;
+        }
+
+        let mut pairs = Vec::with_capacity(public_keys.len());
+        let mut combined_signature = G2Projective::zero();
+        for ((public_key, message), signature) in public_keys.iter().zip(messages).zip(signatures) {
+            let randomizer = Fr::rand(rng);
+            pairs.push((
+                (public_key.0.into_projective() * randomizer).into_affine(),
+                hash_to_g2(SIGNATURE_DOMAIN, message),
+            ));
+            combined_signature += signature.0.into_projective() * randomizer;
+        }
+        check_pairings(&pairs, combined_signature.into_affine())
+    }
+}
+
+/// Returns `true` if the product of the pairings of the given pairs equals the pairing of the generator of G1 and `signature`.
+fn check_pairings(pairs: &[(G1Affine, G2Affine)], signature: G2Affine) -> bool {
+    let mut prepared: Vec<_> = pairs.iter().map(|(g1, g2)| (g1.prepare(), g2.prepare())).collect();
+    prepared.push(((-G1Affine::prime_subgroup_generator()).prepare(), signature.prepare()));
+
+    let product = Bls12_377::miller_loop(prepared.iter().map(|(g1, g2)| (g1, g2)));
+    matches!(Bls12_377::final_exponentiation(&product), Some(product) if product.is_one())
+}
+
+/// Returns the hash of the given message in G2, under the given domain.
+fn hash_to_g2(domain: &[u8], message: &[u8]) -> G2Affine {
+    // Attempt to increment the counter at most 256 times, which fails with probability 2^-256.
+    let mut input = Vec::with_capacity(domain.len() + message.len() + 9);
+    (domain.len() as u64)
+        .write_le(&mut input)
+        .expect("Failed to write the domain length");
+    input.extend_from_slice(domain);
+    input.extend_from_slice(message);
+    input.push(0);
+
+    for counter in 0..=u8::MAX {
+        *input.last_mut().unwrap() = counter;
+        if let Some(hash) = try_hash_bytes_to_curve::<G2Affine>(&input) {
+            return hash;
+        }
+    }
+    panic!("Unable to hash the message to G2")
+}
+
+impl ToBytes for BLSPrivateKey {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+impl FromBytes for BLSPrivateKey {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let private_key = Fr::read_le(reader)?;
+        match private_key.is_zero() {
+            true => Err(Error::new(ErrorKind::InvalidData, "Invalid BLS private key")),
+            false => Ok(Self(private_key)),
+        }
+    }
+}
+
+impl Zeroize for BLSPrivateKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize-on-drop")]
+impl Drop for BLSPrivateKey {
+    /// Zeroizes the private key when it goes out of scope.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize-on-drop")]
+impl zeroize::ZeroizeOnDrop for BLSPrivateKey {}
+
+impl fmt::Debug for BLSPrivateKey {
+    /// Formats the private key without its scalar, so that it is not leaked into logs.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "BLSPrivateKey(..)")
+    }
+}
+
+impl ToBytes for BLSPublicKey {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+impl FromBytes for BLSPublicKey {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let public_key = G1Affine::read_le(reader)?;
+        match !public_key.is_zero() && public_key.is_on_curve() && public_key.is_in_correct_subgroup_assuming_on_curve()
+        {
+            true => Ok(Self(public_key)),
+            false => Err(Error::new(ErrorKind::InvalidData, "Invalid BLS public key")),
+        }
+    }
+}
+
+impl ToBytes for BLSSignature {
+    #[inline]
+    fn write_le<W: Write>(&self, writer: W) -> IoResult<()> {
+        self.0.write_le(writer)
+    }
+}
+
+impl FromBytes for BLSSignature {
+    #[inline]
+    fn read_le<R: Read>(reader: R) -> IoResult<Self> {
+        let signature = G2Affine::read_le(reader)?;
+        match signature.is_on_curve() && signature.is_in_correct_subgroup_assuming_on_curve() {
+            true => Ok(Self(signature)),
+            false => Err(Error::new(ErrorKind::InvalidData, "Invalid BLS signature")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::thread_rng;
+
+    const NUM_SIGNERS: usize = 100;
+
+    #[test]
+    fn test_sign_and_verify() {
+        let rng = &mut thread_rng();
+        let private_key = BLSPrivateKey::new(rng);
+        let public_key = private_key.to_public_key();
+
+        let signature = private_key.sign(b"message");
+        assert!(public_key.verify(b"message", &signature));
+        assert!(!public_key.verify(b"other message", &signature));
+        assert!(!BLSPrivateKey::new(rng).to_public_key().verify(b"message", &signature));
+
+        // Ensure the keys and signature round trip through their byte encodings.
+        assert_eq!(
+            private_key,
+            BLSPrivateKey::read_le(&private_key.to_bytes_le().unwrap()[..]).unwrap()
+        );
+        assert_eq!(
+            public_key,
+            BLSPublicKey::read_le(&public_key.to_bytes_le().unwrap()[..]).unwrap()
+        );
+        assert_eq!(
+            signature,
+            BLSSignature::read_le(&signature.to_bytes_le().unwrap()[..]).unwrap()
+        );
+
+        // Ensure the identity is not a valid public key.
+        let identity = BLSPublicKey(G1Affine::zero());
+        assert!(BLSPublicKey::read_le(&identity.to_bytes_le().unwrap()[..]).is_err());
+        assert!(!identity.verify(b"message", &BLSSignature(G2Affine::zero())));
+    }
+
+    #[test]
+    fn test_private_key_is_redacted_and_zeroized() {
+        let mut private_key = BLSPrivateKey::new(&mut thread_rng());
+        assert_eq!("BLSPrivateKey(..)", format!("{:?}", private_key));
+
+        private_key.zeroize();
+        assert!(private_key.0.is_zero());
+    }
+
+    #[test]
+    fn test_proof_of_possession() {
+        let rng = &mut thread_rng();
+        let private_key = BLSPrivateKey::new(rng);
+        let public_key = private_key.to_public_key();
+
+        let proof = private_key.prove_possession();
+        let verified_public_key = public_key.verify_proof_of_possession(&proof).unwrap();
+        assert_eq!(&public_key, verified_public_key.public_key());
+        assert!(
+            BLSPrivateKey::new(rng)
+                .to_public_key()
+                .verify_proof_of_possession(&proof)
+                .is_none()
+        );
+
+        // Ensure a proof of possession is not a signature on the public key, and vice versa.
+        let public_key_bytes = public_key.to_bytes_le().unwrap();
+        assert!(!public_key.verify(&public_key_bytes, &proof));
+        assert!(
+            public_key
+                .verify_proof_of_possession(&private_key.sign(&public_key_bytes))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let rng = &mut thread_rng();
+        let private_keys: Vec<_> = (0..NUM_SIGNERS).map(|_| BLSPrivateKey::new(rng)).collect();
+        let verified_public_keys: Vec<_> = private_keys
+            .iter()
+            .map(|private_key| {
+                private_key
+                    .to_public_key()
+                    .verify_proof_of_possession(&private_key.prove_possession())
+                    .unwrap()
+            })
+            .collect();
+        let public_keys: Vec<_> = verified_public_keys
+            .iter()
+            .map(|public_key| *public_key.public_key())
+            .collect();
+
+        // Aggregate signatures on the same message.
+        let signatures: Vec<_> = private_keys
+            .iter()
+            .map(|private_key| private_key.sign(b"block"))
+            .collect();
+        let signature = BLSSignature::aggregate(&signatures);
+        assert!(signature.fast_aggregate_verify(&verified_public_keys, b"block"));
+        assert!(!signature.fast_aggregate_verify(&verified_public_keys, b"other block"));
+        assert!(!signature.fast_aggregate_verify(&verified_public_keys[1..], b"block"));
+        assert!(!BLSSignature::aggregate(&signatures[1..]).fast_aggregate_verify(&verified_public_keys, b"block"));
+
+        // Aggregate signatures on distinct messages.
+        let messages: Vec<_> = (0..NUM_SIGNERS).map(|i| format!("block {}", i).into_bytes()).collect();
+        let messages: Vec<_> = messages.iter().map(|message| &message[..]).collect();
+        let signatures: Vec<_> = private_keys
+            .iter()
+            .zip(&messages)
+            .map(|(key, message)| key.sign(message))
+            .collect();
+        let signature = BLSSignature::aggregate(&signatures);
+        assert!(signature.aggregate_verify(&public_keys, &messages));
+        assert!(!signature.aggregate_verify(&public_keys[1..], &messages[1..]));
+
+        let mut swapped_messages = messages.clone();
+        swapped_messages.swap(0, 1);
+        assert!(!signature.aggregate_verify(&public_keys, &swapped_messages));
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let rng = &mut thread_rng();
+        let private_keys: Vec<_> = (0..10).map(|_| BLSPrivateKey::new(rng)).collect();
+        let public_keys: Vec<_> = private_keys
+            .iter()
+            .map(|private_key| private_key.to_public_key())
+            .collect();
+        let messages: Vec<_> = (0..10).map(|i| format!("transaction {}", i).into_bytes()).collect();
+        let messages: Vec<_> = messages.iter().map(|message| &message[..]).collect();
+        let mut signatures: Vec<_> = private_keys
+            .iter()
+            .zip(&messages)
+            .map(|(key, message)| key.sign(message))
+            .collect();
+
+        assert!(BLSSignature::batch_verify(&public_keys, &messages, &signatures, rng));
+        assert!(!BLSSignature::batch_verify(
+            &public_keys,
+            &messages,
+            &signatures[1..],
+            rng
+        ));
+
+        // Ensure a batch with a signature on the wrong message is rejected.
+        signatures.swap(0, 1);
+        assert!(!BLSSignature::batch_verify(&public_keys, &messages, &signatures, rng));
+    }
+
+    #[test]
+    fn test_rogue_key_attack() {
+        let rng = &mut thread_rng();
+        let victim = BLSPrivateKey::new(rng).to_public_key();
+
+        // The attacker chooses their public key so that the aggregate public key is one they know the private key of.
+        let attacker = BLSPrivateKey::new(rng);
+        let rogue_key =
+            BLSPublicKey((attacker.to_public_key().0.into_projective() - victim.0.into_projective()).into_affine());
+        let aggregate_key = BLSPublicKey((victim.0.into_projective() + rogue_key.0.into_projective()).into_affine());
+        assert_eq!(attacker.to_public_key(), aggregate_key);
+
+        // Without proofs of possession, the attacker forges an aggregate signature of the victim.
+        let forgery = attacker.sign(b"message");
+        assert!(aggregate_key.verify(b"message", &forgery));
+
+        // The attacker cannot prove possession of the rogue key, as they do not know its private key,
+        // so the rogue key is never verified, and can not be aggregated.
+        assert!(
+            rogue_key
+                .verify_proof_of_possession(&attacker.prove_possession())
+                .is_none()
+        );
+        let rogue_key_bytes = rogue_key.to_bytes_le().unwrap();
+        let attempt = BLSSignature(
+            (hash_to_g2(PROOF_OF_POSSESSION_DOMAIN, &rogue_key_bytes).into_projective() * attacker.0).into_affine(),
+        );
+        assert!(rogue_key.verify_proof_of_possession(&attempt).is_none());
+
+        // Aggregates of distinct messages reject the repeated message of the attack.
+        assert!(!forgery.aggregate_verify(&[victim, rogue_key], &[b"message", b"message"]));
+    }
+}
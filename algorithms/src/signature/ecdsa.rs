@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! ECDSA signature verification over secp256k1, as used by Bitcoin and Ethereum.
+
+use snarkvm_curves::{
+    secp256k1::{Fq, Fr, Secp256k1Affine},
+    AffineCurve,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{Field, FieldParameters, PrimeField, Zero};
+use snarkvm_utilities::biginteger::{BigInteger, BigInteger256};
+
+/// The size of a compressed SEC1 public key, `0x02 || x` or `0x03 || x`.
+pub const ECDSA_COMPRESSED_PUBLIC_KEY_SIZE: usize = 33;
+/// The size of an uncompressed SEC1 public key, `0x04 || x || y`.
+pub const ECDSA_UNCOMPRESSED_PUBLIC_KEY_SIZE: usize = 65;
+/// The size of a signature `r || s`, optionally followed by a recovery ID.
+pub const ECDSA_SIGNATURE_SIZE: usize = 64;
+
+/// Returns `true` if `signature` is a valid ECDSA signature on the 32-byte `message_hash`
+/// under the secp256k1 public key `public_key`.
+///
+/// The public key is a compressed or uncompressed SEC1 encoding. The signature is the
+/// big-endian encoding of `r || s`, optionally followed by a recovery ID, which is ignored.
+/// Both high and low `s` values are accepted, as the malleability rule of EIP-2 is
+/// a transaction validity rule rather than a property of the signature scheme.
+pub fn ecdsa_verify(public_key: &[u8], message_hash: &[u8; 32], signature: &[u8]) -> bool {
+    let public_key = match ecdsa_public_key_from_bytes(public_key) {
+        Some(public_key) => public_key,
+        None => return false,
+    };
+
+    // Ensure the signature is well-formed, and that 1 <= r, s < n.
+    if signature.len() != ECDSA_SIGNATURE_SIZE && signature.len() != ECDSA_SIGNATURE_SIZE + 1 {
+        return false;
+    }
+    let (r, s) = match (
+        Fr::from_repr(from_bytes_be(&signature[..32])),
+        Fr::from_repr(from_bytes_be(&signature[32..64])),
+    ) {
+        (Some(r), Some(s)) if !r.is_zero() && !s.is_zero() => (r, s),
+        _ => return false,
+    };
+
+    // Compute u1 = z / s and u2 = r / s, where z is the message hash reduced modulo n.
+    let z = reduce_scalar(from_bytes_be(message_hash));
+    let s_inverse = match s.inverse() {
+        Some(s_inverse) => s_inverse,
+        None => return false,
+    };
+    let u1 = z * s_inverse;
+    let u2 = r * s_inverse;
+
+    // Ensure the x-coordinate of u1 * G + u2 * Q, reduced modulo n, is r.
+    let candidate = (Secp256k1Affine::prime_subgroup_generator().into_projective() * u1
+        + public_key.into_projective() * u2)
+        .into_affine();
+    if candidate.is_zero() {
+        return false;
+    }
+    reduce_scalar(candidate.to_x_coordinate().to_repr()) == r
+}
+
+/// Returns the secp256k1 public key for the given compressed or uncompressed SEC1 encoding,
+/// or `None` if the encoding is invalid or the point is not on the curve.
+pub fn ecdsa_public_key_from_bytes(bytes: &[u8]) -> Option<Secp256k1Affine> {
+    let x = Fq::from_repr(from_bytes_be(bytes.get(1..33)?))?;
+    let public_key = match (bytes[0], bytes.len()) {
+        (0x02, ECDSA_COMPRESSED_PUBLIC_KEY_SIZE) | (0x03, ECDSA_COMPRESSED_PUBLIC_KEY_SIZE) => {
+            let point = Secp256k1Affine::from_x_coordinate(x, true)?;
+            // The prefix encodes the parity of the y-coordinate.
+            match point.to_y_coordinate().to_repr().is_odd() == (bytes[0] == 0x03) {
+                true => point,
+                false => -point,
+            }
+        }
+        (0x04, ECDSA_UNCOMPRESSED_PUBLIC_KEY_SIZE) => {
+            let y = Fq::from_repr(from_bytes_be(&bytes[33..65]))?;
+            let point = Secp256k1Affine::new(x, y, false);
+            match point.is_on_curve() {
+                true => point,
+                false => return None,
+            }
+        }
+        _ => return None,
+    };
+    Some(public_key)
+}
+
+/// Returns the integer with the given 32-byte big-endian encoding.
+fn from_bytes_be(bytes: &[u8]) -> BigInteger256 {
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+        *limb = chunk.iter().fold(0, |acc, byte| (acc << 8) | *byte as u64);
+    }
+    BigInteger256(limbs)
+}
+
+/// Returns the given 256-bit integer modulo the order of secp256k1. As the order exceeds 2^255,
+/// a single subtraction suffices.
+fn reduce_scalar(mut integer: BigInteger256) -> Fr {
+    let modulus = <Fr as PrimeField>::Parameters::MODULUS;
+    if integer >= modulus {
+        integer.sub_noborrow(&modulus);
+    }
+    Fr::from_repr(integer).expect("the integer is reduced")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto_hash::Keccak256;
+
+    use std::convert::TryInto;
+
+    // The transaction signed in the example of EIP-155, with the secret key `0x46...46`.
+    const PUBLIC_KEY: &str = "044bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382ce28cab79ad7119ee1ad3ebcdb98a16805211530ecc6cfefa1b88e6dff99232a";
+    const MESSAGE_HASH: &str = "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53";
+    const SIGNATURE: &str = "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa63627667cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d8325";
+
+    fn test_vector() -> (Vec<u8>, [u8; 32], Vec<u8>) {
+        (
+            hex::decode(PUBLIC_KEY).unwrap(),
+            hex::decode(MESSAGE_HASH).unwrap().try_into().unwrap(),
+            hex::decode(SIGNATURE).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_ecdsa_verify() {
+        let (public_key, message_hash, signature) = test_vector();
+        assert!(ecdsa_verify(&public_key, &message_hash, &signature));
+        // Ensure the recovery ID is optional.
+        assert!(ecdsa_verify(&public_key, &message_hash, &signature[..64]));
+    }
+
+    #[test]
+    fn test_ecdsa_verify_compressed_public_key() {
+        let (public_key, message_hash, signature) = test_vector();
+        // The y-coordinate is even.
+        let mut compressed_public_key = vec![0x02];
+        compressed_public_key.extend_from_slice(&public_key[1..33]);
+        assert!(ecdsa_verify(&compressed_public_key, &message_hash, &signature));
+
+        compressed_public_key[0] = 0x03;
+        assert!(!ecdsa_verify(&compressed_public_key, &message_hash, &signature));
+    }
+
+    #[test]
+    fn test_ecdsa_verify_rejects_tampering() {
+        let (public_key, message_hash, signature) = test_vector();
+
+        let mut bad_message_hash = message_hash;
+        bad_message_hash[31] ^= 1;
+        assert!(!ecdsa_verify(&public_key, &bad_message_hash, &signature));
+
+        for i in [0, 31, 32, 63] {
+            let mut bad_signature = signature.clone();
+            bad_signature[i] ^= 1;
+            assert!(!ecdsa_verify(&public_key, &message_hash, &bad_signature));
+        }
+
+        let mut bad_public_key = public_key.clone();
+        bad_public_key[64] ^= 1;
+        assert!(!ecdsa_verify(&bad_public_key, &message_hash, &signature));
+    }
+
+    #[test]
+    fn test_ecdsa_verify_rejects_malformed_input() {
+        let (public_key, message_hash, signature) = test_vector();
+
+        // Ensure r and s must lie in [1, n).
+        let order: Vec<u8> = hex::decode("fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141").unwrap();
+        for range in [0..32, 32..64] {
+            let mut bad_signature = signature.clone();
+            bad_signature[range.clone()].copy_from_slice(&[0u8; 32]);
+            assert!(!ecdsa_verify(&public_key, &message_hash, &bad_signature));
+            bad_signature[range].copy_from_slice(&order);
+            assert!(!ecdsa_verify(&public_key, &message_hash, &bad_signature));
+        }
+
+        assert!(!ecdsa_verify(&public_key, &message_hash, &signature[..63]));
+        assert!(!ecdsa_verify(&public_key[..64], &message_hash, &signature));
+        assert!(!ecdsa_verify(&[], &message_hash, &signature));
+
+        let mut bad_public_key = public_key;
+        bad_public_key[0] = 0x05;
+        assert!(!ecdsa_verify(&bad_public_key, &message_hash, &signature));
+    }
+
+    #[test]
+    fn test_ethereum_address() {
+        // The sender of the transaction in the example of EIP-155.
+        let (public_key, _, _) = test_vector();
+        assert!(ecdsa_public_key_from_bytes(&public_key).is_some());
+        let address = &Keccak256::hash_bytes(&public_key[1..])[12..];
+        assert_eq!("9d8a62f656a8d1615c1294fd71e9cfb3e4855a4f", hex::encode(address));
+    }
+}
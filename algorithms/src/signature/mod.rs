@@ -17,5 +17,11 @@
 pub mod aleo;
 pub use aleo::*;
 
+pub mod bls;
+pub use bls::*;
+
+pub mod ecdsa;
+pub use ecdsa::*;
+
 #[cfg(test)]
 mod tests;
@@ -18,6 +18,7 @@ use super::{
     create_random_proof,
     generate_random_parameters,
     verify_proof,
+    verify_proofs_batched,
     PreparedVerifyingKey,
     Proof,
     ProvingKey,
@@ -91,4 +92,26 @@ impl<E: PairingEngine, V: ToConstraintField<E::Fr> + Clone> SNARK for Groth16<E,
         end_timer!(verify_time);
         Ok(result)
     }
+
+    fn verify_batch_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        let verify_time = start_timer!(|| format!("{{Groth 2016}}::Verify batch of {}", inputs_and_proofs.len()));
+        let conversion_time = start_timer!(|| "Convert inputs to E::Fr");
+        let inputs = inputs_and_proofs
+            .iter()
+            .map(|(input, _)| input.to_field_elements())
+            .collect::<Result<Vec<_>, _>>()?;
+        end_timer!(conversion_time);
+        let proofs_and_inputs: Vec<_> = inputs_and_proofs
+            .iter()
+            .zip(&inputs)
+            .map(|((_, proof), input)| (*proof, &input[..]))
+            .collect();
+        let result = verify_proofs_batched(prepared_verifying_key, &proofs_and_inputs, rng)?;
+        end_timer!(verify_time);
+        Ok(result)
+    }
 }
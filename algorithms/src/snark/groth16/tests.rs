@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{Field, Zero};
+use snarkvm_fields::{Field, One, Zero};
 use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
 
 struct MySillyCircuit<F: Field> {
@@ -55,6 +55,7 @@ mod bls12_377 {
         generate_random_parameters,
         prepare_verifying_key,
         verify_proof,
+        verify_proofs_batched,
         Proof,
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
@@ -80,6 +81,43 @@ mod bls12_377 {
         }
     }
 
+    #[test]
+    fn prove_and_verify_batched() {
+        let rng = &mut thread_rng();
+        let parameters =
+            generate_random_parameters::<Bls12_377, _, _>(&MySillyCircuit { a: None, b: None }, rng).unwrap();
+        let pvk = prepare_verifying_key::<Bls12_377>(parameters.vk.clone());
+
+        let mut proofs = Vec::new();
+        let mut inputs = Vec::new();
+        for _ in 0..10 {
+            let (a, b) = (Fr::rand(rng), Fr::rand(rng));
+            proofs.push(create_random_proof(&MySillyCircuit { a: Some(a), b: Some(b) }, &parameters, rng).unwrap());
+            inputs.push([a * b]);
+        }
+        let batch: Vec<_> = proofs
+            .iter()
+            .zip(&inputs)
+            .map(|(proof, input)| (proof, &input[..]))
+            .collect();
+
+        assert!(verify_proofs_batched(&pvk, &batch, rng).unwrap());
+        assert!(verify_proofs_batched(&pvk, &[], rng).unwrap());
+
+        // Ensure a single invalid proof fails the batch.
+        let incorrect_input = [Fr::one()];
+        for i in 0..batch.len() {
+            let mut batch = batch.clone();
+            batch[i].1 = &incorrect_input;
+            assert!(!verify_proofs_batched(&pvk, &batch, rng).unwrap());
+        }
+
+        // Ensure malformed inputs are rejected.
+        let mut malformed = batch;
+        malformed[0].1 = &[];
+        assert!(verify_proofs_batched(&pvk, &malformed, rng).is_err());
+    }
+
     #[test]
     fn test_serde_json() {
         let expected_proof = {
@@ -15,10 +15,12 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use super::{PreparedVerifyingKey, Proof, VerifyingKey};
-use snarkvm_curves::traits::{PairingCurve, PairingEngine};
+use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
+use snarkvm_fields::{Field, PrimeField, Zero};
 use snarkvm_r1cs::errors::SynthesisError;
 
 use core::ops::{AddAssign, Mul, Neg};
+use rand::{CryptoRng, Rng};
 
 pub fn prepare_verifying_key<E: PairingEngine>(vk: VerifyingKey<E>) -> PreparedVerifyingKey<E> {
     let alpha_g1_beta_g2 = E::pairing(vk.alpha_g1, vk.beta_g2);
@@ -64,3 +66,65 @@ pub fn verify_proof<E: PairingEngine>(
 
     Ok(test == pvk.alpha_g1_beta_g2)
 }
+
+/// Verifies every proof in `proofs_and_inputs` against `pvk`, using a single final exponentiation.
+///
+/// The verification equations are combined with random 128-bit weights sampled from `rng`,
+/// so the batch succeeds if and only if every proof is valid, except with negligible probability.
+/// The weights must be unpredictable to the prover, so `rng` must be cryptographically secure.
+pub fn verify_proofs_batched<E: PairingEngine, R: Rng + CryptoRng>(
+    pvk: &PreparedVerifyingKey<E>,
+    proofs_and_inputs: &[(&Proof<E>, &[E::Fr])],
+    rng: &mut R,
+) -> Result<bool, SynthesisError> {
+    for (_, public_inputs) in proofs_and_inputs {
+        if (public_inputs.len() + 1) != pvk.gamma_abc_g1().len() {
+            return Err(SynthesisError::MalformedVerifyingKey(
+                public_inputs.len() + 1,
+                pvk.gamma_abc_g1().len(),
+            ));
+        }
+    }
+    if proofs_and_inputs.is_empty() {
+        return Ok(true);
+    }
+
+    let weights: Vec<E::Fr> = proofs_and_inputs
+        .iter()
+        .map(|_| E::Fr::from(rng.gen::<u128>()))
+        .collect();
+
+    // Combine the public inputs, so that `g_ic` is computed once for the whole batch.
+    let mut input_weights = vec![E::Fr::zero(); pvk.gamma_abc_g1().len()];
+    let mut c = E::G1Projective::zero();
+    let mut prepared_a = Vec::with_capacity(proofs_and_inputs.len());
+    let mut prepared_b = Vec::with_capacity(proofs_and_inputs.len());
+    for ((proof, public_inputs), weight) in proofs_and_inputs.iter().zip(&weights) {
+        input_weights[0] += weight;
+        for (input_weight, input) in input_weights[1..].iter_mut().zip(public_inputs.iter()) {
+            *input_weight += *weight * input;
+        }
+        c.add_assign(proof.c.into_projective().mul(*weight));
+        prepared_a.push(proof.a.into_projective().mul(*weight).into_affine().prepare());
+        prepared_b.push(proof.b.prepare());
+    }
+
+    let mut g_ic = E::G1Projective::zero();
+    for (input_weight, b) in input_weights.iter().zip(pvk.gamma_abc_g1().iter()) {
+        g_ic.add_assign(b.into_projective().mul(*input_weight));
+    }
+    let g_ic = g_ic.into_affine().prepare();
+    let c = c.into_affine().prepare();
+
+    let qap = E::miller_loop(
+        prepared_a
+            .iter()
+            .zip(prepared_b.iter())
+            .chain([(&g_ic, &pvk.gamma_g2_neg_pc), (&c, &pvk.delta_g2_neg_pc)]),
+    );
+
+    let test = E::final_exponentiation(&qap).ok_or(SynthesisError::UnexpectedIdentity)?;
+
+    let weight_sum: E::Fr = weights.iter().sum();
+    Ok(test == pvk.alpha_g1_beta_g2.pow(weight_sum.to_repr()))
+}
@@ -53,6 +53,12 @@ pub trait MerkleParameters: Clone + Debug + Send + Sync {
         Ok(self.crh().hash(&to_bytes_le![left, right]?)?)
     }
 
+    /// Returns the output hash, given the hash values of the children of an inner node, from left to right.
+    /// For two children, this is equivalent to `hash_inner_node`.
+    fn hash_children(&self, children: &[<Self::H as CRH>::Output]) -> Result<<Self::H as CRH>::Output, MerkleError> {
+        Ok(self.crh().hash(&to_bytes_le![children]?)?)
+    }
+
     fn hash_empty(&self) -> Result<<Self::H as CRH>::Output, MerkleError> {
         // TODO (howardwu): TEMPORARY - This choice of a 64 byte buffer is a temporary fix.
         //  Previously, the size was `<Self::H as CRH>::INPUT_SIZE_BITS / 8` which was also incorrect.
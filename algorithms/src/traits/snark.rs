@@ -91,6 +91,21 @@ pub trait SNARK: Clone + Debug {
         proof: &Self::Proof,
     ) -> Result<bool, SNARKError>;
 
+    /// Verifies a batch of proofs against the same verifying key, returning `true` if every proof is valid.
+    /// Proof systems that support native batching override this to amortize the cost over the batch.
+    fn verify_batch_prepared<R: Rng + CryptoRng>(
+        prepared_verifying_key: &Self::PreparedVerifyingKey,
+        inputs_and_proofs: &[(&Self::VerifierInput, &Self::Proof)],
+        _rng: &mut R,
+    ) -> Result<bool, SNARKError> {
+        for (input, proof) in inputs_and_proofs {
+            if !Self::verify_prepared(prepared_verifying_key, input, proof)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
     fn verify(
         verifying_key: &Self::VerifyingKey,
         input: &Self::VerifierInput,
@@ -0,0 +1,110 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::convert::TryInto;
+
+impl<E: Environment, T: Clone + Ternary<Boolean = Boolean<E>, Output = T>, const M: usize, const N: usize>
+    Concat<[T; N]> for [T; M]
+{
+    type Element = T;
+
+    ///
+    /// Returns the elements of `self`, followed by the elements of `other`.
+    ///
+    /// This method costs no constraints, as the output elements are the input elements.
+    ///
+    fn concat<const LEN: usize>(&self, other: &[T; N]) -> [T; LEN] {
+        if M + N != LEN {
+            E::halt(format!(
+                "Attempted to concatenate arrays of length {} and {} into length {}",
+                M, N, LEN
+            ))
+        }
+
+        let output: Vec<T> = self.iter().chain(other.iter()).cloned().collect();
+        match output.try_into() {
+            Ok(output) => output,
+            Err(_) => unreachable!(
+                "The concatenation of arrays of length {} and {} has length {}",
+                M, N, LEN
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseField, Circuit};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_concat<const M: usize, const N: usize, const LEN: usize>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let first: [<Circuit as Environment>::BaseField; M] = [(); M].map(|_| UniformRand::rand(&mut thread_rng()));
+            let second: [<Circuit as Environment>::BaseField; N] =
+                [(); N].map(|_| UniformRand::rand(&mut thread_rng()));
+
+            let a = first.map(|value| BaseField::<Circuit>::new(mode, value));
+            let b = second.map(|value| BaseField::<Circuit>::new(mode, value));
+
+            Circuit::scoped(&format!("{:?} Concat {} {} {}", mode, M, N, i), |scope| {
+                let candidate: [BaseField<Circuit>; LEN] = a.concat(&b);
+                let expected: Vec<_> = first.iter().chain(second.iter()).cloned().collect();
+                assert_eq!(
+                    expected,
+                    candidate
+                        .iter()
+                        .map(|element| element.eject_value())
+                        .collect::<Vec<_>>()
+                );
+
+                // Ensure the concatenation costs no constraints.
+                assert_eq!(0, scope.num_constraints_in_scope());
+                assert!(scope.is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_concat() {
+        check_concat::<0, 0, 0>(Mode::Private);
+        check_concat::<0, 8, 8>(Mode::Private);
+        check_concat::<8, 0, 8>(Mode::Private);
+        check_concat::<1, 1, 2>(Mode::Constant);
+        check_concat::<8, 4, 12>(Mode::Constant);
+        check_concat::<1, 1, 2>(Mode::Public);
+        check_concat::<8, 4, 12>(Mode::Public);
+        check_concat::<1, 1, 2>(Mode::Private);
+        check_concat::<8, 4, 12>(Mode::Private);
+        check_concat::<32, 32, 64>(Mode::Private);
+    }
+
+    #[test]
+    fn test_concat_wrong_length_halts() {
+        let a = [(); 4].map(|_| BaseField::<Circuit>::new(Mode::Private, UniformRand::rand(&mut thread_rng())));
+        let b = [(); 4].map(|_| BaseField::<Circuit>::new(Mode::Private, UniformRand::rand(&mut thread_rng())));
+
+        assert!(Circuit::catch_halt(|| a.concat::<7>(&b)).is_err());
+        assert!(Circuit::catch_halt(|| a.concat::<9>(&b)).is_err());
+        assert!(Circuit::catch_halt(|| a.concat::<8>(&b)).is_ok());
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, T: Equal<Boolean = Boolean<E>, Output = Boolean<E>>, const N: usize> Equal<Self> for [T; N] {
+    type Boolean = Boolean<E>;
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if every element of `self` is equal to the corresponding element of `other`.
+    ///
+    /// This method costs the constraints of the element comparisons, and `N - 1` conjunctions.
+    ///
+    fn is_eq(&self, other: &Self) -> Self::Output {
+        self.iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.is_eq(b))
+            .reduce(|is_eq, is_element_eq| is_eq.and(&is_element_eq))
+            .unwrap_or_else(|| Boolean::new(Mode::Constant, true))
+    }
+
+    ///
+    /// Returns `true` if any element of `self` is *not* equal to the corresponding element of `other`.
+    ///
+    /// This method costs the constraints of the element comparisons, and `N - 1` conjunctions.
+    ///
+    fn is_neq(&self, other: &Self) -> Self::Output {
+        !self.is_eq(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseField, Circuit};
+    use snarkvm_fields::One;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_is_eq<const N: usize>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let values: [<Circuit as Environment>::BaseField; N] =
+                [(); N].map(|_| UniformRand::rand(&mut thread_rng()));
+
+            let a = values.map(|value| BaseField::<Circuit>::new(mode, value));
+            let b = values.map(|value| BaseField::<Circuit>::new(mode, value));
+
+            // Change only the last element of `c`.
+            let mut c = values;
+            if let Some(last) = c.last_mut() {
+                *last += <Circuit as Environment>::BaseField::one();
+            }
+            let c = c.map(|value| BaseField::<Circuit>::new(mode, value));
+
+            Circuit::scoped(&format!("{:?} Equals {} {}", mode, N, i), |scope| {
+                assert!(a.is_eq(&b).eject_value());
+                assert_eq!(N == 0, a.is_eq(&c).eject_value());
+                assert_eq!(N != 0, a.is_neq(&c).eject_value());
+
+                // Ensure the number of constraints is linear in the number of elements. Each of the
+                // three comparisons costs 3 constraints per element, and 2 constraints per conjunction.
+                match mode.is_constant() {
+                    true => assert_eq!(0, scope.num_constraints_in_scope()),
+                    false => assert_eq!(3 * (3 * N + 2 * N.saturating_sub(1)), scope.num_constraints_in_scope()),
+                }
+                assert!(scope.is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_is_eq() {
+        check_is_eq::<0>(Mode::Constant);
+        check_is_eq::<1>(Mode::Constant);
+        check_is_eq::<8>(Mode::Constant);
+        check_is_eq::<1>(Mode::Public);
+        check_is_eq::<8>(Mode::Public);
+        check_is_eq::<1>(Mode::Private);
+        check_is_eq::<8>(Mode::Private);
+    }
+}
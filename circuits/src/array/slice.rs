@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::convert::TryInto;
+
+impl<E: Environment, T: Clone + Ternary<Boolean = Boolean<E>, Output = T>, const N: usize> Slice for [T; N] {
+    type Element = T;
+
+    ///
+    /// Returns the `LEN` elements of `self` starting at index `START`.
+    ///
+    /// This method costs no constraints, as the output elements are the input elements.
+    ///
+    fn slice<const START: usize, const LEN: usize>(&self) -> [T; LEN] {
+        match START.checked_add(LEN) {
+            Some(end) if end <= N => match self[START..end].to_vec().try_into() {
+                Ok(output) => output,
+                Err(_) => unreachable!("A slice of length {} has length {}", LEN, LEN),
+            },
+            _ => E::halt(format!(
+                "Attempted to slice {} elements at index {} from an array of length {}",
+                LEN, START, N
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseField, Circuit};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_slice<const N: usize, const START: usize, const LEN: usize>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let values: [<Circuit as Environment>::BaseField; N] =
+                [(); N].map(|_| UniformRand::rand(&mut thread_rng()));
+
+            let a = values.map(|value| BaseField::<Circuit>::new(mode, value));
+
+            Circuit::scoped(&format!("{:?} Slice {} {} {} {}", mode, N, START, LEN, i), |scope| {
+                let candidate = a.slice::<START, LEN>();
+                assert_eq!(
+                    &values[START..START + LEN],
+                    &candidate.map(|element| element.eject_value())[..]
+                );
+
+                // Ensure the slice costs no constraints.
+                assert_eq!(0, scope.num_constraints_in_scope());
+                assert!(scope.is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_slice() {
+        check_slice::<0, 0, 0>(Mode::Private);
+        check_slice::<8, 8, 0>(Mode::Private);
+        check_slice::<1, 0, 1>(Mode::Constant);
+        check_slice::<8, 2, 4>(Mode::Constant);
+        check_slice::<1, 0, 1>(Mode::Public);
+        check_slice::<8, 2, 4>(Mode::Public);
+        check_slice::<1, 0, 1>(Mode::Private);
+        check_slice::<8, 2, 4>(Mode::Private);
+        check_slice::<8, 0, 8>(Mode::Private);
+        check_slice::<64, 32, 32>(Mode::Private);
+    }
+
+    #[test]
+    fn test_slice_out_of_bounds_halts() {
+        let a = [(); 8].map(|_| BaseField::<Circuit>::new(Mode::Private, UniformRand::rand(&mut thread_rng())));
+
+        assert!(Circuit::catch_halt(|| a.slice::<0, 9>()).is_err());
+        assert!(Circuit::catch_halt(|| a.slice::<1, 8>()).is_err());
+        assert!(Circuit::catch_halt(|| a.slice::<9, 0>()).is_err());
+        assert!(Circuit::catch_halt(|| a.slice::<{ usize::MAX }, 1>()).is_err());
+        assert!(Circuit::catch_halt(|| a.slice::<7, 1>()).is_ok());
+        assert!(Circuit::catch_halt(|| a.slice::<8, 0>()).is_ok());
+    }
+}
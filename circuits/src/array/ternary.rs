@@ -0,0 +1,88 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::convert::TryInto;
+
+impl<E: Environment, T: Ternary<Boolean = Boolean<E>, Output = T>, const N: usize> Ternary for [T; N] {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        let output: Vec<T> = first
+            .iter()
+            .zip(second.iter())
+            .map(|(a, b)| T::ternary(condition, a, b))
+            .collect();
+
+        match output.try_into() {
+            Ok(output) => output,
+            Err(_) => unreachable!("The ternary of two arrays of length {} has length {}", N, N),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BaseField, Circuit};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_ternary<const N: usize>(mode: Mode) {
+        for i in 0..ITERATIONS {
+            let first: [<Circuit as Environment>::BaseField; N] = [(); N].map(|_| UniformRand::rand(&mut thread_rng()));
+            let second: [<Circuit as Environment>::BaseField; N] =
+                [(); N].map(|_| UniformRand::rand(&mut thread_rng()));
+
+            let a = first.map(|value| BaseField::<Circuit>::new(mode, value));
+            let b = second.map(|value| BaseField::<Circuit>::new(mode, value));
+
+            for flag in [true, false] {
+                let condition = Boolean::<Circuit>::new(Mode::Private, flag);
+
+                Circuit::scoped(&format!("{:?} Ternary {} {} {}", mode, N, flag, i), |scope| {
+                    let candidate = <[BaseField<Circuit>; N]>::ternary(&condition, &a, &b);
+                    let expected = if flag { first } else { second };
+                    assert_eq!(expected, candidate.map(|element| element.eject_value()));
+
+                    // Ensure the number of constraints is linear in the number of elements.
+                    match mode.is_constant() {
+                        true => assert_eq!(0, scope.num_constraints_in_scope()),
+                        false => assert_eq!(N, scope.num_constraints_in_scope()),
+                    }
+                    assert!(scope.is_satisfied());
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_ternary() {
+        check_ternary::<0>(Mode::Private);
+        check_ternary::<1>(Mode::Constant);
+        check_ternary::<8>(Mode::Constant);
+        check_ternary::<1>(Mode::Public);
+        check_ternary::<8>(Mode::Public);
+        check_ternary::<1>(Mode::Private);
+        check_ternary::<8>(Mode::Private);
+    }
+}
@@ -0,0 +1,120 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Add<Self> for Secp256k1Point<E> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment> Add<&Self> for Secp256k1Point<E> {
+    type Output = Self;
+
+    fn add(self, other: &Self) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment> Add<&Secp256k1Point<E>> for &Secp256k1Point<E> {
+    type Output = Secp256k1Point<E>;
+
+    ///
+    /// Returns `self + other`, which halts if the x-coordinates of `self` and `other` are equal,
+    /// as the sum is then either a doubling or the point at infinity.
+    ///
+    /// If both points are constants, this method costs 0 constraints. Otherwise, the slope
+    /// `(y2 - y1) / (x2 - x1)` is computed with an inversion, which also enforces that the x-coordinates
+    /// are distinct. The sum costs the constraints of an inversion, 3 multiplications, and the reductions
+    /// of its coordinates, which keep the limbs of repeated sums from growing.
+    ///
+    fn add(self, other: &Secp256k1Point<E>) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            let sum = self.eject_value() + other.eject_value();
+            return Secp256k1Point::new(Mode::Constant, sum);
+        }
+
+        let lambda = (&other.y - &self.y) * (&other.x - &self.x).inv();
+        let x = &lambda * &lambda - &self.x - &other.x;
+        let y = lambda * (&self.x - &x) - &self.y;
+        Secp256k1Point {
+            x: x.reduce(),
+            y: y.reduce(),
+        }
+    }
+}
+
+impl<E: Environment> AddAssign<Self> for Secp256k1Point<E> {
+    fn add_assign(&mut self, other: Self) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment> AddAssign<&Self> for Secp256k1Point<E> {
+    fn add_assign(&mut self, other: &Self) {
+        *self = &*self + other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 10;
+
+    fn run_test(mode_a: Mode, mode_b: Mode, num_constraints: usize) {
+        for i in 0..ITERATIONS {
+            let first: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+            let second: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+            let a = Secp256k1Point::<Circuit>::new(mode_a, first);
+            let b = Secp256k1Point::<Circuit>::new(mode_b, second);
+
+            Circuit::scoped(&format!("Add: {:?} + {:?} {}", mode_a, mode_b, i), |scope| {
+                let candidate = &a + &b;
+                assert_eq!(first + second, candidate.eject_value());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        run_test(Mode::Constant, Mode::Constant, 0);
+        run_test(Mode::Constant, Mode::Private, 5349);
+        run_test(Mode::Public, Mode::Private, 5349);
+        run_test(Mode::Private, Mode::Private, 5349);
+    }
+
+    #[test]
+    fn test_add_exceptional() {
+        let point: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+        let a = Secp256k1Point::<Circuit>::new(Mode::Private, point);
+        let b = Secp256k1Point::<Circuit>::new(Mode::Private, -point);
+
+        // Ensure adding a point to itself or to its negation halts.
+        assert!(Circuit::catch_halt(|| &a + &a).is_err());
+        assert!(Circuit::catch_halt(|| &a + &b).is_err());
+        Circuit::reset_circuit();
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Double for Secp256k1Point<E> {
+    type Output = Self;
+
+    ///
+    /// Returns `2 * self`.
+    ///
+    /// As the order of secp256k1 is odd, no point on the curve has a zero y-coordinate,
+    /// so the tangent at `self` is never vertical and the output is never the point at infinity.
+    ///
+    /// If `self` is a constant, this method costs 0 constraints. Otherwise, the slope `3x^2 / 2y`
+    /// is computed with an inversion, and the output costs the constraints of an inversion, 4 multiplications,
+    /// and the reductions of its coordinates.
+    ///
+    fn double(self) -> Self::Output {
+        if self.is_constant() {
+            let point = self.eject_value();
+            return Secp256k1Point::new(Mode::Constant, point + point);
+        }
+
+        let x_squared = &self.x * &self.x;
+        let lambda = (&x_squared + &x_squared + &x_squared) * (&self.y + &self.y).inv();
+        let x = &lambda * &lambda - &self.x - &self.x;
+        let y = lambda * (&self.x - &x) - &self.y;
+        Secp256k1Point {
+            x: x.reduce(),
+            y: y.reduce(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 10;
+
+    fn run_test(mode: Mode, num_constraints: usize) {
+        for i in 0..ITERATIONS {
+            let point: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+            let candidate = Secp256k1Point::<Circuit>::new(mode, point);
+
+            Circuit::scoped(&format!("Double: {:?} {}", mode, i), |scope| {
+                let candidate = candidate.double();
+                assert_eq!(point + point, candidate.eject_value());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_double() {
+        run_test(Mode::Constant, 0);
+        run_test(Mode::Public, 6409);
+        run_test(Mode::Private, 6409);
+    }
+
+    #[test]
+    fn test_double_repeatedly() {
+        let point: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+        let mut expected = point;
+        let mut candidate = Secp256k1Point::<Circuit>::new(Mode::Private, point);
+
+        // Ensure the coordinates stay sound as their limbs grow and are reduced.
+        for _ in 0..ITERATIONS {
+            expected = expected + expected;
+            candidate = candidate.double();
+            assert_eq!(expected, candidate.eject_value());
+        }
+        assert!(Circuit::is_satisfied());
+    }
+}
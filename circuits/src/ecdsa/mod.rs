@@ -0,0 +1,185 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! ECDSA signature verification over secp256k1, with non-native field arithmetic.
+
+pub mod add;
+pub mod double;
+pub mod ternary;
+pub mod verify;
+
+pub use verify::*;
+
+use crate::{
+    nonnative::{NonNativeField, Secp256k1BaseFieldParameters, Secp256k1ScalarFieldParameters},
+    traits::*,
+    Boolean,
+    Environment,
+    Mode,
+};
+use snarkvm_curves::{
+    secp256k1::{Secp256k1Affine, Secp256k1Parameters},
+    AffineCurve,
+    ShortWeierstrassParameters,
+};
+use snarkvm_fields::{Field as F, Zero as Z};
+
+use num_traits::Inv;
+use std::{
+    fmt,
+    ops::{Add, AddAssign},
+};
+
+/// An element of the secp256k1 base field, in limbs of the base field of the environment.
+pub type Secp256k1BaseField<E> = NonNativeField<E, Secp256k1BaseFieldParameters>;
+
+/// An element of the secp256k1 scalar field, in limbs of the base field of the environment.
+pub type Secp256k1ScalarField<E> = NonNativeField<E, Secp256k1ScalarFieldParameters>;
+
+///
+/// A secp256k1 point in affine coordinates.
+///
+/// The point at infinity has no affine coordinates, so it is never represented. Instead, every
+/// operation that would output it halts, which makes such a circuit unsatisfiable.
+///
+#[derive(Clone)]
+pub struct Secp256k1Point<E: Environment> {
+    x: Secp256k1BaseField<E>,
+    y: Secp256k1BaseField<E>,
+}
+
+impl<E: Environment> Secp256k1Point<E> {
+    ///
+    /// Initializes a new secp256k1 point, which must not be the point at infinity.
+    ///
+    /// Unless the mode is constant, the point is enforced to be on the curve (see `Self::from`).
+    ///
+    pub fn new(mode: Mode, point: Secp256k1Affine) -> Self {
+        if point.is_zero() {
+            E::halt("The point at infinity has no affine coordinates")
+        }
+        let x = NonNativeField::new(mode, point.to_x_coordinate());
+        let y = NonNativeField::new(mode, point.to_y_coordinate());
+        Self::from(x, y)
+    }
+
+    ///
+    /// Initializes a new secp256k1 point from its coordinates.
+    ///
+    /// For safety, the point is always enforced to be on the curve, by checking that `y^2 = x^3 + 7`.
+    /// For constant coordinates this is checked natively, and costs 0 constraints. Otherwise, this costs
+    /// the constraints of 3 multiplications and an equality check.
+    ///
+    pub fn from(x: Secp256k1BaseField<E>, y: Secp256k1BaseField<E>) -> Self {
+        let b = NonNativeField::new(Mode::Constant, Secp256k1Parameters::COEFF_B);
+        match x.is_constant() && y.is_constant() {
+            true => {
+                let (x, y) = (x.eject_value(), y.eject_value());
+                if y.square() != x.square() * x + b.eject_value() {
+                    E::halt("The given coordinates are not on the secp256k1 curve")
+                }
+            }
+            false => E::assert(&(&y * &y).is_eq(&(&x * &x * &x + b))),
+        }
+        Self { x, y }
+    }
+
+    ///
+    /// Returns the x-coordinate of the point.
+    ///
+    pub fn x(&self) -> &Secp256k1BaseField<E> {
+        &self.x
+    }
+
+    ///
+    /// Returns the y-coordinate of the point.
+    ///
+    pub fn y(&self) -> &Secp256k1BaseField<E> {
+        &self.y
+    }
+
+    ///
+    /// Returns `true` if the point is a constant.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.x.is_constant() && self.y.is_constant()
+    }
+
+    ///
+    /// Ejects the point as a constant secp256k1 point.
+    ///
+    pub fn eject_value(&self) -> Secp256k1Affine {
+        Secp256k1Affine::new(self.x.eject_value(), self.y.eject_value(), false)
+    }
+}
+
+impl<E: Environment> fmt::Debug for Secp256k1Point<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x.eject_value(), self.y.eject_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_curves::secp256k1::Fq;
+    use snarkvm_fields::One as O;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_new() {
+        for (mode, num_constraints) in [(Mode::Constant, 0), (Mode::Public, 5063), (Mode::Private, 5063)] {
+            for i in 0..ITERATIONS {
+                let point: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+
+                Circuit::scoped(&format!("New: {:?} {}", mode, i), |scope| {
+                    let candidate = Secp256k1Point::<Circuit>::new(mode, point);
+                    assert_eq!(point, candidate.eject_value());
+                    let (x, y) = (point.to_x_coordinate(), point.to_y_coordinate());
+                    assert_eq!(format!("({}, {})", x, y), format!("{:?}", candidate));
+                    assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                    assert!(Circuit::is_satisfied());
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_new_not_on_curve() {
+        let point: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+        let y = point.to_y_coordinate() + Fq::one();
+
+        // Ensure a constant point that is not on the curve halts.
+        let (x_constant, y_constant) = (
+            NonNativeField::new(Mode::Constant, point.to_x_coordinate()),
+            NonNativeField::new(Mode::Constant, y),
+        );
+        assert!(Circuit::catch_halt(|| Secp256k1Point::<Circuit>::from(x_constant, y_constant)).is_err());
+        Circuit::reset_circuit();
+
+        // Ensure a variable point that is not on the curve is unsatisfiable.
+        let x = NonNativeField::new(Mode::Private, point.to_x_coordinate());
+        let y = NonNativeField::new(Mode::Private, y);
+        let _candidate = Secp256k1Point::<Circuit>::from(x, y);
+        assert!(!Circuit::is_satisfied());
+        Circuit::reset_circuit();
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Ternary for Secp256k1Point<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        let x = Ternary::ternary(condition, &first.x, &second.x);
+        let y = Ternary::ternary(condition, &first.y, &second.y);
+        Secp256k1Point { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nonnative::NonNativeParameters, Circuit};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_ternary() {
+        let num_limbs = <Secp256k1BaseFieldParameters as NonNativeParameters>::NUM_LIMBS;
+        for (mode, num_constraints) in [(Mode::Constant, 0), (Mode::Private, 2 * num_limbs)] {
+            for flag in [true, false] {
+                let first: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+                let second: Secp256k1Affine = UniformRand::rand(&mut thread_rng());
+                let condition = Boolean::<Circuit>::new(mode, flag);
+                let a = Secp256k1Point::<Circuit>::new(Mode::Private, first);
+                let b = Secp256k1Point::<Circuit>::new(Mode::Public, second);
+
+                Circuit::scoped(&format!("Ternary: {:?} {}", mode, flag), |scope| {
+                    let candidate = Secp256k1Point::ternary(&condition, &a, &b);
+                    assert_eq!(if flag { first } else { second }, candidate.eject_value());
+                    assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                    assert!(Circuit::is_satisfied());
+                });
+            }
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_curves::{secp256k1::Fq, Group, ProjectiveCurve};
+use snarkvm_fields::One as O;
+
+///
+/// Returns `true` if `(r, s)` is a valid ECDSA signature on `message_hash` under the secp256k1 `public_key`,
+/// where `message_hash` is the 32-byte message hash reduced modulo the order of secp256k1.
+///
+/// This mirrors `snarkvm_algorithms::signature::ecdsa_verify`, which accepts both high and low `s` values.
+/// The scalars `u1 = z / s` and `u2 = r / s` are decomposed into bits, and `u1 * G + u2 * Q` is computed
+/// with a single double-and-add pass over both scalars (Shamir's trick), from an auxiliary point `A`
+/// with no known discrete logarithm, which keeps every intermediate sum away from the point at infinity.
+/// The sum is then `A * 2^256` too large, and is corrected with one final addition.
+///
+/// If the sum is the point at infinity, or an intermediate sum hits an exceptional case of the
+/// incomplete addition formulas, this gadget halts. For honestly generated keys and signatures,
+/// this happens with negligible probability, and otherwise only makes the circuit unsatisfiable.
+///
+/// For a variable public key, message hash and signature, this gadget costs 3,031,329 constraints,
+/// which are dominated by the 256 doublings and 256 additions (see `test_num_constraints`).
+///
+pub fn ecdsa_verify<E: Environment>(
+    public_key: &Secp256k1Point<E>,
+    message_hash: &Secp256k1ScalarField<E>,
+    r: &Secp256k1ScalarField<E>,
+    s: &Secp256k1ScalarField<E>,
+) -> Boolean<E> {
+    let zero = NonNativeField::new(Mode::Constant, Z::zero());
+    let one = NonNativeField::new(Mode::Constant, O::one());
+
+    // Ensure r and s are nonzero. A zero s is replaced by one, so the inversion below cannot halt.
+    let is_r_zero = r.is_eq(&zero);
+    let is_s_zero = s.is_eq(&zero);
+    let s_inverse = NonNativeField::ternary(&is_s_zero, &one, s).inv();
+
+    // Compute u1 = z / s and u2 = r / s, in big-endian bits.
+    let u1 = (message_hash * &s_inverse).to_bits_be();
+    let u2 = (r * &s_inverse).to_bits_be();
+
+    // Compute A * 2^256 + u1 * G + u2 * Q, adding one of G, Q, or G + Q for every pair of bits.
+    let generator = Secp256k1Point::new(Mode::Constant, Secp256k1Affine::prime_subgroup_generator());
+    let generator_plus_public_key = &generator + public_key;
+    let (auxiliary, correction) = auxiliary_points(u1.len());
+
+    let mut sum = Secp256k1Point::new(Mode::Constant, auxiliary);
+    for (u1_bit, u2_bit) in u1.iter().zip(&u2) {
+        sum = sum.double();
+        let addend = Secp256k1Point::ternary(
+            u1_bit,
+            &Secp256k1Point::ternary(u2_bit, &generator_plus_public_key, &generator),
+            &Secp256k1Point::ternary(u2_bit, public_key, &generator),
+        );
+        sum = Secp256k1Point::ternary(&u1_bit.or(u2_bit), &(&sum + &addend), &sum);
+    }
+    let candidate = sum + Secp256k1Point::new(Mode::Constant, -correction);
+
+    // Ensure the x-coordinate of the candidate, reduced modulo the order, is r. As the x-coordinate is
+    // decomposed into its canonical bits, they encode an integer whose residue in the scalar field is x mod n.
+    let x = NonNativeField::from_bits_le(&candidate.x().to_bits_le());
+    x.is_eq(r).and(&!is_r_zero).and(&!is_s_zero)
+}
+
+///
+/// Returns the auxiliary point `A`, and the point `A * 2^num_doublings`.
+///
+/// The auxiliary point is the secp256k1 point with the smallest positive x-coordinate,
+/// and an even y-coordinate, so its discrete logarithm is unknown.
+///
+fn auxiliary_points(num_doublings: usize) -> (Secp256k1Affine, Secp256k1Affine) {
+    let mut x = Fq::one();
+    let auxiliary = loop {
+        if let Some(point) = Secp256k1Affine::from_x_coordinate(x, false) {
+            break point;
+        }
+        x += Fq::one();
+    };
+
+    let mut correction = auxiliary.into_projective();
+    for _ in 0..num_doublings {
+        correction.double_in_place();
+    }
+    (auxiliary, correction.into_affine())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_algorithms::signature::{ecdsa_public_key_from_bytes, ecdsa_verify as console_ecdsa_verify};
+    use snarkvm_curves::secp256k1::Fr;
+    use snarkvm_fields::PrimeField;
+
+    use std::convert::TryInto;
+
+    // The transaction signed in the example of EIP-155, with the secret key `0x46...46`.
+    const PUBLIC_KEY: &str = "044bc2a31265153f07e70e0bab08724e6b85e217f8cd628ceb62974247bb493382ce28cab79ad7119ee1ad3ebcdb98a16805211530ecc6cfefa1b88e6dff99232a";
+    const MESSAGE_HASH: &str = "daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53";
+    const SIGNATURE: &str = "28ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa63627667cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d8325";
+
+    fn from_hex(string: &str) -> Vec<u8> {
+        (0..string.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&string[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Ensures the circuit and the console verifier both return `expected` for the given signature.
+    fn check_ecdsa_verify(mode: Mode, public_key: &[u8], message_hash: &[u8; 32], signature: &[u8], expected: bool) {
+        assert_eq!(expected, console_ecdsa_verify(public_key, message_hash, signature));
+
+        let public_key = Secp256k1Point::<Circuit>::new(mode, ecdsa_public_key_from_bytes(public_key).unwrap());
+        let message_hash = NonNativeField::new(mode, Fr::from_bytes_be_mod_order(message_hash));
+        let r = NonNativeField::new(mode, Fr::from_bytes_be_mod_order(&signature[..32]));
+        let s = NonNativeField::new(mode, Fr::from_bytes_be_mod_order(&signature[32..64]));
+
+        let candidate = ecdsa_verify(&public_key, &message_hash, &r, &s);
+        assert_eq!(expected, candidate.eject_value());
+        assert!(Circuit::is_satisfied());
+        Circuit::reset_circuit();
+    }
+
+    #[test]
+    fn test_ecdsa_verify() {
+        let public_key = from_hex(PUBLIC_KEY);
+        let message_hash: [u8; 32] = from_hex(MESSAGE_HASH).try_into().unwrap();
+        let signature = from_hex(SIGNATURE);
+
+        // Ensure the circuit accepts the signature, with constant and variable inputs.
+        check_ecdsa_verify(Mode::Constant, &public_key, &message_hash, &signature, true);
+        check_ecdsa_verify(Mode::Private, &public_key, &message_hash, &signature, true);
+
+        // Ensure the circuit rejects a tampered message hash or s.
+        let mut bad_message_hash = message_hash;
+        bad_message_hash[31] ^= 1;
+        check_ecdsa_verify(Mode::Private, &public_key, &bad_message_hash, &signature, false);
+        let mut bad_signature = signature.clone();
+        bad_signature[63] ^= 1;
+        check_ecdsa_verify(Mode::Private, &public_key, &message_hash, &bad_signature, false);
+
+        // Ensure the circuit rejects a zero r or s.
+        for range in [0..32, 32..64] {
+            let mut bad_signature = signature.clone();
+            bad_signature[range].copy_from_slice(&[0u8; 32]);
+            check_ecdsa_verify(Mode::Private, &public_key, &message_hash, &bad_signature, false);
+        }
+    }
+
+    #[test]
+    fn test_num_constraints() {
+        let public_key = ecdsa_public_key_from_bytes(&from_hex(PUBLIC_KEY)).unwrap();
+        let message_hash = Fr::from_bytes_be_mod_order(&from_hex(MESSAGE_HASH));
+        let signature = from_hex(SIGNATURE);
+
+        let public_key = Secp256k1Point::<Circuit>::new(Mode::Public, public_key);
+        let message_hash = NonNativeField::new(Mode::Public, message_hash);
+        let r = NonNativeField::new(Mode::Private, Fr::from_bytes_be_mod_order(&signature[..32]));
+        let s = NonNativeField::new(Mode::Private, Fr::from_bytes_be_mod_order(&signature[32..64]));
+
+        Circuit::scoped("ECDSA verify", |scope| {
+            assert!(ecdsa_verify(&public_key, &message_hash, &r, &s).eject_value());
+            assert_eq!(3031329, scope.num_constraints_in_scope());
+        });
+        assert!(Circuit::is_satisfied());
+        Circuit::reset_circuit();
+    }
+
+    #[test]
+    fn test_auxiliary_points() {
+        let (auxiliary, correction) = auxiliary_points(256);
+        assert!(auxiliary.is_on_curve());
+        assert_eq!(auxiliary * Fr::from(2u64).pow([256]), correction);
+    }
+}
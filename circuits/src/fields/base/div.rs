@@ -47,6 +47,17 @@ impl<E: Environment> DivAssign<&Self> for BaseField<E> {
     }
 }
 
+impl<E: Environment> CheckedDiv<Self> for BaseField<E> {
+    type Output = Self;
+
+    fn checked_div(&self, other: &Self) -> Option<Self::Output> {
+        match other.eject_value().is_zero() {
+            true => None,
+            false => Some(self.clone() / other),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,4 +161,20 @@ mod tests {
         });
         assert!(result.is_err()); // Probe further for specific error type here, if desired
     }
+
+    #[test]
+    fn test_checked_div() {
+        let zero = <Circuit as Environment>::BaseField::zero();
+        let one = <Circuit as Environment>::BaseField::one();
+        let two = one + one;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let quotient = BaseField::<Circuit>::new(mode, two).checked_div(&BaseField::new(mode, two));
+            assert_eq!(Some(one), quotient.map(|quotient| quotient.eject_value()));
+
+            // Check division by zero returns `None` instead of halting.
+            assert!(BaseField::<Circuit>::new(mode, one).checked_div(&BaseField::new(mode, zero)).is_none());
+        }
+        assert!(Circuit::is_satisfied());
+    }
 }
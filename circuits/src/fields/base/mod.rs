@@ -22,6 +22,7 @@ pub mod inv;
 pub mod mul;
 pub mod neg;
 pub mod one;
+pub mod range_check;
 pub mod square;
 pub mod sub;
 pub mod ternary;
@@ -29,12 +30,9 @@ pub mod to_bits;
 pub mod zero;
 
 use crate::{traits::*, Boolean, Environment, LinearCombination, Mode};
-use snarkvm_fields::{Field as F, One as O};
+use snarkvm_fields::{Field as F, One as O, Zero as Z};
 use snarkvm_utilities::ToBits as TBits;
 
-#[cfg(test)]
-use snarkvm_fields::Zero as Z;
-
 use num_traits::Inv;
 use std::{
     fmt,
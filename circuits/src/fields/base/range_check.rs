@@ -0,0 +1,192 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_fields::PrimeField;
+
+impl<E: Environment> RangeCheck for BaseField<E> {
+    ///
+    /// Asserts that `self` is less than `2^num_bits`.
+    ///
+    /// For a constant, this method checks the value natively and costs 0 constraints.
+    /// Otherwise, this method costs `num_bits + 1` constraints.
+    ///
+    fn assert_bits_le(&self, num_bits: usize) {
+        Self::assert_bits_le_many(std::slice::from_ref(self), num_bits)
+    }
+
+    ///
+    /// Asserts that each of the given values is less than `2^num_bits`.
+    ///
+    /// The powers of two are computed once, and shared by the decompositions of all values.
+    /// Each constant costs 0 constraints, and every other value costs `num_bits + 1` constraints.
+    ///
+    fn assert_bits_le_many(values: &[Self], num_bits: usize) {
+        // Ensure the decomposition is unique, by ensuring `2^num_bits` is less than the modulus.
+        let capacity = E::BaseField::size_in_bits() - 1;
+        if num_bits > capacity {
+            E::halt(format!(
+                "Range checks support at most {} bits, found {}",
+                capacity, num_bits
+            ))
+        }
+
+        // Compute the powers of two, from 2^0 to 2^(num_bits - 1).
+        let mut powers_of_two = Vec::with_capacity(num_bits);
+        let mut power_of_two = E::BaseField::one();
+        for _ in 0..num_bits {
+            powers_of_two.push(power_of_two);
+            power_of_two = power_of_two.double();
+        }
+
+        for value in values {
+            let bits = value.eject_value().to_bits_le();
+
+            // Check constants natively.
+            if value.is_constant() {
+                if bits.iter().skip(num_bits).any(|bit| *bit) {
+                    E::halt(format!("{} is not less than 2^{}", value.eject_value(), num_bits))
+                }
+                continue;
+            }
+
+            // Witness the `num_bits` least significant bits of the value, which
+            // only recompose to the value if it is less than `2^num_bits`.
+            let mut accumulator = LinearCombination::zero();
+            for (bit, power_of_two) in bits.iter().zip(powers_of_two.iter()) {
+                let bit = Boolean::<E>::new(Mode::Private, *bit);
+                accumulator += (*bit).clone() * power_of_two;
+            }
+
+            // Ensure value * 1 == (2^(num_bits - 1) * b_(num_bits - 1) + ... + 2^0 * b_0)
+            E::enforce(|| (value, E::one(), accumulator));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+
+    /// Returns `2^num_bits`.
+    fn power_of_two(num_bits: usize) -> <Circuit as Environment>::BaseField {
+        (0..num_bits).fold(<Circuit as Environment>::BaseField::one(), |power, _| power.double())
+    }
+
+    fn check_assert_bits_le(
+        name: &str,
+        mode: Mode,
+        value: <Circuit as Environment>::BaseField,
+        num_bits: usize,
+        is_in_range: bool,
+    ) {
+        let candidate = BaseField::<Circuit>::new(mode, value);
+
+        match mode.is_constant() {
+            true => {
+                let result = Circuit::catch_halt(|| {
+                    Circuit::scoped(name, |scope| {
+                        candidate.assert_bits_le(num_bits);
+                        assert_eq!(0, scope.num_constraints_in_scope());
+                    })
+                });
+                assert_eq!(is_in_range, result.is_ok());
+            }
+            false => {
+                Circuit::scoped(name, |scope| {
+                    candidate.assert_bits_le(num_bits);
+                    assert_eq!(0, scope.num_constants_in_scope());
+                    assert_eq!(0, scope.num_public_in_scope());
+                    assert_eq!(num_bits, scope.num_private_in_scope());
+                    assert_eq!(num_bits + 1, scope.num_constraints_in_scope());
+                    assert_eq!(is_in_range, scope.is_satisfied());
+                });
+            }
+        }
+
+        // Reset the circuit, as an unsatisfied constraint would fail every later check.
+        Circuit::reset_circuit();
+    }
+
+    #[test]
+    fn test_assert_bits_le_boundaries() {
+        let one = <Circuit as Environment>::BaseField::one();
+        let capacity = <<Circuit as Environment>::BaseField as PrimeField>::size_in_bits() - 1;
+
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for num_bits in [0, 1, 8, 64, capacity] {
+                let name = format!("{:?} {} bits", mode, num_bits);
+                let power = power_of_two(num_bits);
+
+                check_assert_bits_le(&format!("{} zero", name), mode, Z::zero(), num_bits, true);
+                check_assert_bits_le(&format!("{} max", name), mode, power - one, num_bits, true);
+                check_assert_bits_le(&format!("{} overflow", name), mode, power, num_bits, false);
+            }
+        }
+    }
+
+    #[test]
+    fn test_assert_bits_le_random() {
+        for i in 0..ITERATIONS {
+            // Sample a random element, which is almost surely not less than 2^64.
+            let value: <Circuit as Environment>::BaseField = UniformRand::rand(&mut thread_rng());
+            check_assert_bits_le(&format!("Random {}", i), Mode::Private, value, 64, false);
+
+            // Sample a random 64-bit element.
+            let value = <Circuit as Environment>::BaseField::from(rand::random::<u64>());
+            check_assert_bits_le(&format!("Random u64 {}", i), Mode::Private, value, 64, true);
+        }
+    }
+
+    #[test]
+    fn test_assert_bits_le_many() {
+        let one = <Circuit as Environment>::BaseField::one();
+        let max = power_of_two(8) - one;
+
+        let values = [
+            BaseField::<Circuit>::new(Mode::Private, max),
+            BaseField::new(Mode::Constant, max),
+            BaseField::new(Mode::Public, one),
+        ];
+        Circuit::scoped("Many", |scope| {
+            BaseField::assert_bits_le_many(&values, 8);
+            assert_eq!(2 * 9, scope.num_constraints_in_scope());
+            assert!(scope.is_satisfied());
+        });
+
+        let values = [
+            BaseField::<Circuit>::new(Mode::Private, max),
+            BaseField::new(Mode::Private, max + one),
+        ];
+        Circuit::scoped("Many Overflow", |scope| {
+            BaseField::assert_bits_le_many(&values, 8);
+            assert!(!scope.is_satisfied());
+        });
+    }
+
+    #[test]
+    fn test_assert_bits_le_exceeds_capacity() {
+        let capacity = <<Circuit as Environment>::BaseField as PrimeField>::size_in_bits() - 1;
+        let candidate = BaseField::<Circuit>::new(Mode::Private, Z::zero());
+        assert!(Circuit::catch_halt(|| candidate.assert_bits_le(capacity + 1)).is_err());
+    }
+}
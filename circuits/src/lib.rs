@@ -23,9 +23,14 @@
 pub mod address;
 pub use address::*;
 
+pub mod array;
+
 pub mod boolean;
 pub use boolean::*;
 
+#[cfg(feature = "nonnative")]
+pub mod ecdsa;
+
 pub mod fields;
 pub use fields::*;
 
@@ -37,6 +42,9 @@ pub use group::*;
 pub mod models;
 pub use models::*;
 
+#[cfg(feature = "nonnative")]
+pub mod nonnative;
+
 // TODO (howardwu): This is temporary until the programs interface is stabilized.
 #[allow(unused)]
 pub mod programs;
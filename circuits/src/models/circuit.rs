@@ -21,7 +21,11 @@ use snarkvm_curves::{
 };
 
 use once_cell::unsync::OnceCell;
-use std::{cell::RefCell, rc::Rc};
+use std::{
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+};
 
 thread_local! {
     static CB: OnceCell<RefCell<Circuit>> = OnceCell::new();
@@ -183,9 +187,123 @@ impl Environment for Circuit {
         ))
     }
 
+    fn snapshot_witness() -> WitnessSnapshot<Self::BaseField> {
+        Self::cs().cs.borrow().snapshot_witness()
+    }
+
+    fn inject_witness(snapshot: WitnessSnapshot<Self::BaseField>) {
+        Self::cs().cs.borrow_mut().inject_witness(snapshot)
+    }
+
     fn halt<S: Into<String>, T>(message: S) -> T {
-        let error = message.into();
+        let error = HaltWithTrace::new(message.into(), Self::cs().scope().clone());
         eprintln!("{}", &error);
-        panic!("{}", &error)
+        panic::panic_any(error)
+    }
+
+    fn catch_halt<Fn, T>(logic: Fn) -> Result<T, HaltWithTrace>
+    where
+        Fn: FnOnce() -> T,
+    {
+        CB.with(|cb| {
+            // Fetch a copy of the current environment, and a checkpoint of its constraint system.
+            let current = Self::cs();
+            let checkpoint = current.cs.borrow().checkpoint();
+
+            match panic::catch_unwind(AssertUnwindSafe(logic)) {
+                Ok(output) => Ok(output),
+                Err(payload) => {
+                    // Discard the variables and constraints allocated by the logic that halted.
+                    current.cs.borrow_mut().restore(checkpoint);
+                    // Return the entire environment to the scope it was in before the halt.
+                    cb.get().unwrap().borrow_mut().0 = current;
+
+                    match payload.downcast::<HaltWithTrace>() {
+                        Ok(halt) => Err(*halt),
+                        Err(payload) => panic::resume_unwind(payload),
+                    }
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_fields::One;
+
+    #[test]
+    fn test_catch_halt_in_nested_scope() {
+        let result = Circuit::catch_halt(|| {
+            Circuit::scoped("outer", |_| {
+                Circuit::scoped("inner", |_| Circuit::halt::<_, ()>("Halted in the inner scope"));
+            })
+        });
+
+        let halt = result.unwrap_err();
+        assert_eq!("Halted in the inner scope", halt.message());
+        assert_eq!(vec!["Circuit::new", "outer", "inner"], halt.scope_path());
+
+        // Ensure the environment is returned to the scope it was in before the halt.
+        assert_eq!("Circuit::new", Circuit::cs().scope());
+
+        // Ensure logic that does not halt returns its output.
+        assert_eq!(Ok(1), Circuit::catch_halt(|| 1));
+    }
+
+    #[test]
+    fn test_catch_halt_restores_constraint_system() {
+        let one = <Circuit as Environment>::BaseField::one();
+
+        let a = Circuit::new_variable(Mode::Private, one);
+        Circuit::enforce(|| (a, Circuit::one(), a));
+
+        let (num_constants, num_public, num_private, num_constraints) = (
+            Circuit::num_constants(),
+            Circuit::num_public(),
+            Circuit::num_private(),
+            Circuit::num_constraints(),
+        );
+        assert_eq!(1, num_constraints);
+
+        let result = Circuit::catch_halt(|| {
+            Circuit::scoped("halting", |_| {
+                Circuit::new_variable(Mode::Constant, one);
+                Circuit::new_variable(Mode::Public, one);
+                let b = Circuit::new_variable(Mode::Private, one);
+                Circuit::enforce(|| (a, b, LinearCombination::zero()));
+                Circuit::halt::<_, ()>("Halted after allocating")
+            })
+        });
+        assert!(result.is_err());
+
+        // Ensure the variables and constraints allocated by the halted logic are discarded.
+        assert_eq!(num_constants, Circuit::num_constants());
+        assert_eq!(num_public, Circuit::num_public());
+        assert_eq!(num_private, Circuit::num_private());
+        assert_eq!(num_constraints, Circuit::num_constraints());
+        assert_eq!(0, Circuit::num_constraints_in_scope(&"Circuit::new/halting".to_string()));
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_inject_witness() {
+        let one = <Circuit as Environment>::BaseField::one();
+        let two = one + one;
+
+        Circuit::new_variable(Mode::Public, one);
+        Circuit::new_variable(Mode::Private, two);
+        let snapshot = Circuit::snapshot_witness();
+        assert_eq!(&[one, one], snapshot.public());
+        assert_eq!(&[two], snapshot.private());
+
+        // Ensure the recorded values replace the given values, for as long as the snapshot has them.
+        Circuit::reset_circuit();
+        Circuit::inject_witness(snapshot);
+        assert_eq!(one, Circuit::new_variable(Mode::Public, two).value());
+        assert_eq!(two, Circuit::new_variable(Mode::Private, one).value());
+        assert_eq!(one, Circuit::new_variable(Mode::Private, one).value());
+        assert_eq!(two, Circuit::new_variable(Mode::Constant, two).value());
     }
 }
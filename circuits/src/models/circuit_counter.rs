@@ -18,7 +18,7 @@ use crate::models::*;
 
 use std::collections::HashMap;
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub(super) struct CircuitCounter {
     constants: HashMap<Scope, usize>,
     public: HashMap<Scope, usize>,
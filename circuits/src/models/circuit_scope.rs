@@ -45,6 +45,11 @@ impl<F: PrimeField> CircuitScope<F> {
         }
     }
 
+    /// Returns the name of this scope, prefixed by the names of its enclosing scopes.
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
     pub(crate) fn new_constant(&mut self, value: F) -> Variable<F> {
         self.cs.borrow_mut().new_constant(value, self.scope.clone())
     }
@@ -19,6 +19,16 @@ use snarkvm_fields::traits::*;
 
 use std::collections::{HashMap, HashSet};
 
+/// The number of variables and constraints in a constraint system at some point in time.
+#[derive(Debug)]
+pub(super) struct Checkpoint {
+    num_constants: usize,
+    num_public: usize,
+    num_private: usize,
+    num_constraints: usize,
+    counter: CircuitCounter,
+}
+
 #[derive(Debug)]
 pub(super) struct ConstraintSystem<F: PrimeField> {
     constants: Vec<Variable<F>>,
@@ -28,6 +38,7 @@ pub(super) struct ConstraintSystem<F: PrimeField> {
     transcript: HashMap<Variable<F>, Scope>,
     scopes: HashSet<Scope>,
     counter: CircuitCounter,
+    injected: Option<WitnessSnapshot<F>>,
 }
 
 impl<F: PrimeField> ConstraintSystem<F> {
@@ -40,6 +51,7 @@ impl<F: PrimeField> ConstraintSystem<F> {
             transcript: Default::default(),
             scopes: Default::default(),
             counter: Default::default(),
+            injected: None,
         }
     }
 
@@ -52,7 +64,12 @@ impl<F: PrimeField> ConstraintSystem<F> {
     }
 
     pub(super) fn new_public(&mut self, value: F, scope: Scope) -> Variable<F> {
-        let variable = Variable::Public(self.public.len() as u64, value);
+        let index = self.public.len() as u64;
+        let value = match &self.injected {
+            Some(witness) => witness.public_at(index).unwrap_or(value),
+            None => value,
+        };
+        let variable = Variable::Public(index, value);
         self.public.push(variable);
         self.counter.increment_public(&scope);
         self.transcript.insert(variable, scope);
@@ -60,7 +77,12 @@ impl<F: PrimeField> ConstraintSystem<F> {
     }
 
     pub(super) fn new_private(&mut self, value: F, scope: Scope) -> Variable<F> {
-        let variable = Variable::Private(self.private.len() as u64, value);
+        let index = self.private.len() as u64;
+        let value = match &self.injected {
+            Some(witness) => witness.private_at(index).unwrap_or(value),
+            None => value,
+        };
+        let variable = Variable::Private(index, value);
         self.private.push(variable);
         self.counter.increment_private(&scope);
         self.transcript.insert(variable, scope);
@@ -128,6 +150,52 @@ impl<F: PrimeField> ConstraintSystem<F> {
         self.counter.num_constraints_in_scope(scope)
     }
 
+    /// Returns a checkpoint of the variables and constraints allocated so far.
+    pub(super) fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            num_constants: self.constants.len(),
+            num_public: self.public.len(),
+            num_private: self.private.len(),
+            num_constraints: self.constraints.len(),
+            counter: self.counter.clone(),
+        }
+    }
+
+    /// Discards the variables and constraints allocated since the given checkpoint.
+    pub(super) fn restore(&mut self, checkpoint: Checkpoint) {
+        let Checkpoint {
+            num_constants,
+            num_public,
+            num_private,
+            num_constraints,
+            counter,
+        } = checkpoint;
+
+        let constants = self.constants.drain(num_constants..);
+        let public = self.public.drain(num_public..);
+        let private = self.private.drain(num_private..);
+        for variable in constants.chain(public).chain(private) {
+            self.transcript.remove(&variable);
+        }
+
+        self.constraints.truncate(num_constraints);
+        self.counter = counter;
+    }
+
+    /// Returns the values of the public and private variables allocated so far.
+    pub(super) fn snapshot_witness(&self) -> WitnessSnapshot<F> {
+        WitnessSnapshot::new(
+            self.public.iter().map(Variable::value).collect(),
+            self.private.iter().map(Variable::value).collect(),
+        )
+    }
+
+    /// Sets the values of subsequently allocated public and private variables to the values
+    /// recorded at the same indices in the given snapshot, where they exist.
+    pub(super) fn inject_witness(&mut self, snapshot: WitnessSnapshot<F>) {
+        self.injected = Some(snapshot);
+    }
+
     pub(super) fn to_public_variables(&self) -> &Vec<Variable<F>> {
         &self.public
     }
@@ -87,7 +87,20 @@ pub trait Environment: Clone {
 
     fn affine_from_x_coordinate(x: Self::BaseField) -> Self::Affine;
 
+    /// Returns the values of the public and private variables allocated so far.
+    fn snapshot_witness() -> WitnessSnapshot<Self::BaseField>;
+
+    /// Replaces the values of subsequently allocated public and private variables
+    /// with the values recorded in the given snapshot, for as long as the snapshot has them.
+    fn inject_witness(snapshot: WitnessSnapshot<Self::BaseField>);
+
     fn halt<S: Into<String>, T>(message: S) -> T {
         panic!("{}", message.into())
     }
+
+    /// Runs the given logic, returning the message and scope of any halt that occurs in it.
+    /// Panics that are not halts of this environment are propagated.
+    fn catch_halt<Fn, T>(logic: Fn) -> Result<T, HaltWithTrace>
+    where
+        Fn: FnOnce() -> T;
 }
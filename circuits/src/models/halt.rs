@@ -0,0 +1,48 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::models::*;
+
+use thiserror::Error;
+
+/// A halt of the circuit environment, along with the scope it occurred in.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("{message} (in scope '{scope}')")]
+pub struct HaltWithTrace {
+    message: String,
+    scope: Scope,
+}
+
+impl HaltWithTrace {
+    pub(super) fn new(message: String, scope: Scope) -> Self {
+        Self { message, scope }
+    }
+
+    /// Returns the halt message.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Returns the scope the halt occurred in.
+    pub fn scope(&self) -> &Scope {
+        &self.scope
+    }
+
+    /// Returns the stack of scope names the halt occurred in, from the outermost scope.
+    pub fn scope_path(&self) -> Vec<&str> {
+        self.scope.split('/').collect()
+    }
+}
@@ -31,8 +31,14 @@ use constraint_system::*;
 pub mod environment;
 pub use environment::*;
 
+pub mod halt;
+pub use halt::*;
+
 pub mod linear_combination;
 pub use linear_combination::*;
 
 pub mod variable;
 pub use variable::*;
+
+pub mod witness_snapshot;
+pub use witness_snapshot::*;
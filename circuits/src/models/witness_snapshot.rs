@@ -0,0 +1,54 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::models::*;
+use snarkvm_fields::PrimeField;
+
+/// The values of the public and private variables of a circuit, in order of allocation.
+///
+/// A snapshot taken from a failing synthesis can be injected into a fresh circuit,
+/// to re-run the synthesis up to the point of failure with the recorded values.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WitnessSnapshot<F: PrimeField> {
+    public: Vec<F>,
+    private: Vec<F>,
+}
+
+impl<F: PrimeField> WitnessSnapshot<F> {
+    pub(super) fn new(public: Vec<F>, private: Vec<F>) -> Self {
+        Self { public, private }
+    }
+
+    /// Returns the recorded values of the public variables, including the `one` variable.
+    pub fn public(&self) -> &[F] {
+        &self.public
+    }
+
+    /// Returns the recorded values of the private variables.
+    pub fn private(&self) -> &[F] {
+        &self.private
+    }
+
+    /// Returns the recorded value of the public variable at the given index, if it exists.
+    pub(super) fn public_at(&self, index: Index) -> Option<F> {
+        self.public.get(index as usize).copied()
+    }
+
+    /// Returns the recorded value of the private variable at the given index, if it exists.
+    pub(super) fn private_at(&self, index: Index) -> Option<F> {
+        self.private.get(index as usize).copied()
+    }
+}
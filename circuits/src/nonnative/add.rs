@@ -0,0 +1,139 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Add<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn add(self, other: NonNativeField<E, P>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Add<NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn add(self, other: NonNativeField<E, P>) -> Self::Output {
+        self + &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Add<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn add(self, other: &NonNativeField<E, P>) -> Self::Output {
+        &self + other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Add<&NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn add(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut output = (*self).clone();
+        output += other;
+        output
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> AddAssign<NonNativeField<E, P>> for NonNativeField<E, P> {
+    fn add_assign(&mut self, other: NonNativeField<E, P>) {
+        *self += &other;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> AddAssign<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    ///
+    /// Adds the limbs of `other` to the limbs of `self`, without reducing.
+    ///
+    /// This method costs 0 constraints, unless the limbs of the sum would exceed `NonNativeInteger::max_limb_bound`,
+    /// in which case the operand with the larger limbs is reduced first.
+    ///
+    fn add_assign(&mut self, other: &NonNativeField<E, P>) {
+        if self.is_constant() && other.is_constant() {
+            *self = Self::new(Mode::Constant, self.eject_value() + other.eject_value());
+            return;
+        }
+
+        let max_limb_bound = NonNativeInteger::<P>::max_limb_bound(Self::host_capacity());
+        let (first, second) = Self::reduce_until(self, other, |first, second| first + second <= max_limb_bound);
+
+        self.limbs = first.limbs.iter().zip(&second.limbs).map(|(a, b)| a + b).collect();
+        self.limb_bound = first.limb_bound + second.limb_bound;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let first: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let a = NonNativeField::<Circuit, P>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, P>::new(mode_b, second);
+
+            Circuit::scoped(&format!("Add: {:?} + {:?} {}", mode_a, mode_b, i), |scope| {
+                let candidate = &a + &b;
+                assert_eq!(first + second, candidate.eject_value());
+                assert_eq!(mode_a.is_constant() && mode_b.is_constant(), candidate.is_constant());
+                assert_eq!(0, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_add() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_add_until_reduced() {
+        let max_limb_bound = NonNativeInteger::<P>::max_limb_bound(NonNativeField::<Circuit, P>::host_capacity());
+
+        let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+        let mut expected = value;
+        let mut candidate = NonNativeField::<Circuit, P>::new(Mode::Private, value);
+
+        // Double the element beyond the maximum limb bound, to ensure the sum is reduced before it overflows.
+        Circuit::scoped("Add until reduced", |scope| {
+            for _ in 0..2 * max_limb_bound.bits() {
+                candidate += candidate.clone();
+                expected = expected.double();
+
+                assert_eq!(expected, candidate.eject_value());
+                assert!(candidate.limb_bound() <= &max_limb_bound);
+            }
+            assert!(scope.num_constraints_in_scope() > 0);
+            assert!(Circuit::is_satisfied());
+        });
+    }
+}
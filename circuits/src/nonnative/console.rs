@@ -0,0 +1,383 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The native mirror of the non-native field gadgets, which computes their witnesses and limb bounds.
+
+use super::NonNativeParameters;
+use snarkvm_fields::{Field, One as O, PrimeField};
+use snarkvm_utilities::ToBytes;
+
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::{cmp, marker::PhantomData};
+
+/// Returns the given prime field element as an integer.
+pub fn to_biguint<F: PrimeField>(value: &F) -> BigUint {
+    BigUint::from_bytes_le(&value.to_repr().to_bytes_le().expect("Failed to serialize a field element"))
+}
+
+/// Returns the given integer, reduced modulo the order of the given prime field.
+pub fn from_biguint<F: PrimeField>(value: &BigUint) -> F {
+    F::from_bytes_le_mod_order(&value.to_bytes_le())
+}
+
+///
+/// An integer in the limb representation of a non-native field, which may exceed the modulus.
+///
+/// The value of each limb is at most `limb_bound`. Additions and subtractions let the limbs grow,
+/// and reductions bring them back to `P::BITS_PER_LIMB` bits.
+///
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonNativeInteger<P: NonNativeParameters> {
+    limbs: Vec<BigUint>,
+    limb_bound: BigUint,
+    _parameters: PhantomData<P>,
+}
+
+impl<P: NonNativeParameters> NonNativeInteger<P> {
+    /// Returns the reduced limbs of the given non-native field element.
+    pub fn new(value: &P::Field) -> Self {
+        Self::from_limbs(
+            to_limbs::<P>(&to_biguint(value), P::NUM_LIMBS),
+            Self::reduced_limb_bound(),
+        )
+    }
+
+    /// Returns the integer with the given limbs, each of which is at most `limb_bound`.
+    pub fn from_limbs(limbs: Vec<BigUint>, limb_bound: BigUint) -> Self {
+        debug_assert!(limbs.iter().all(|limb| *limb <= limb_bound));
+        Self {
+            limbs,
+            limb_bound,
+            _parameters: PhantomData,
+        }
+    }
+
+    /// Returns the bound on the limbs of a reduced integer, `2^P::BITS_PER_LIMB - 1`.
+    pub fn reduced_limb_bound() -> BigUint {
+        (BigUint::one() << P::BITS_PER_LIMB) - 1u32
+    }
+
+    /// Returns the modulus of the non-native field.
+    pub fn modulus() -> BigUint {
+        to_biguint(&-P::Field::one()) + 1u32
+    }
+
+    /// Returns the limbs, in little-endian order.
+    pub fn limbs(&self) -> &[BigUint] {
+        &self.limbs
+    }
+
+    /// Returns the bound on the value of every limb.
+    pub fn limb_bound(&self) -> &BigUint {
+        &self.limb_bound
+    }
+
+    /// Returns `true` if the limbs have at most `P::BITS_PER_LIMB` bits.
+    pub fn is_reduced(&self) -> bool {
+        self.limb_bound <= Self::reduced_limb_bound()
+    }
+
+    /// Returns the integer represented by the limbs.
+    pub fn to_integer(&self) -> BigUint {
+        from_limbs::<P>(&self.limbs)
+    }
+
+    /// Returns the non-native field element represented by the limbs.
+    pub fn to_field(&self) -> P::Field {
+        from_biguint(&self.to_integer())
+    }
+
+    /// Returns `self + other`, without reducing.
+    pub fn add(&self, other: &Self) -> Self {
+        let limbs = self.limbs.iter().zip(&other.limbs).map(|(a, b)| a + b).collect();
+        Self::from_limbs(limbs, &self.limb_bound + &other.limb_bound)
+    }
+
+    /// Returns `self - other`, without reducing, by adding a multiple of the modulus
+    /// whose limbs are each at least the limb bound of `other`.
+    pub fn sub(&self, other: &Self) -> Self {
+        let padding = Self::padding(&other.limb_bound);
+        let limbs = self
+            .limbs
+            .iter()
+            .zip(&padding)
+            .zip(&other.limbs)
+            .map(|((a, d), b)| a + d - b)
+            .collect();
+        Self::from_limbs(limbs, Self::sub_limb_bound(&self.limb_bound, &other.limb_bound))
+    }
+
+    /// Returns the limb bound of the difference of integers with the given limb bounds.
+    pub fn sub_limb_bound(first: &BigUint, second: &BigUint) -> BigUint {
+        first + second + Self::reduced_limb_bound()
+    }
+
+    ///
+    /// Returns the limbs of a multiple of the modulus, each of which is at least `bound`
+    /// and at most `bound + 2^P::BITS_PER_LIMB - 1`.
+    ///
+    /// The limbs are `bound + e_i`, where `e_i` are the reduced limbs of the difference
+    /// between the integer with all limbs equal to `bound`, and the next multiple of the modulus.
+    ///
+    pub fn padding(bound: &BigUint) -> Vec<BigUint> {
+        let modulus = Self::modulus();
+        let floor = from_limbs::<P>(&vec![bound.clone(); P::NUM_LIMBS]);
+        let multiple = floor.div_ceil(&modulus) * &modulus;
+        to_limbs::<P>(&(multiple - floor), P::NUM_LIMBS)
+            .into_iter()
+            .map(|e| bound + e)
+            .collect()
+    }
+
+    /// Returns the coefficients of the product of `self` and `other` as polynomials in `2^P::BITS_PER_LIMB`,
+    /// and the bound on every coefficient.
+    pub fn mul_coefficients(&self, other: &Self) -> (Vec<BigUint>, BigUint) {
+        let mut coefficients = vec![BigUint::zero(); self.limbs.len() + other.limbs.len() - 1];
+        for (i, a) in self.limbs.iter().enumerate() {
+            for (j, b) in other.limbs.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        let num_terms = cmp::min(self.limbs.len(), other.limbs.len());
+        (coefficients, num_terms * &self.limb_bound * &other.limb_bound)
+    }
+
+    /// Returns the reduced product of `self` and `other`.
+    pub fn mul(&self, other: &Self) -> Self {
+        let (coefficients, _) = self.mul_coefficients(other);
+        Self::from_integer(&(from_limbs::<P>(&coefficients) % Self::modulus()))
+    }
+
+    /// Returns the reduced inverse of `self`, or `None` if `self` is zero modulo the modulus.
+    pub fn inverse(&self) -> Option<Self> {
+        Some(Self::new(&self.to_field().inverse()?))
+    }
+
+    /// Returns an integer congruent to `self`, with reduced limbs.
+    pub fn reduce(&self) -> Self {
+        Self::from_integer(&(self.to_integer() % Self::modulus()))
+    }
+
+    /// Returns the reduced limbs of the given integer, which must be less than the modulus.
+    fn from_integer(integer: &BigUint) -> Self {
+        Self::from_limbs(to_limbs::<P>(integer, P::NUM_LIMBS), Self::reduced_limb_bound())
+    }
+
+    ///
+    /// Returns the quotient of the given coefficients minus `remainder` by the modulus,
+    /// in `num_quotient_limbs` reduced limbs, or `None` if the remainder is not congruent to the coefficients.
+    ///
+    pub fn quotient(coefficients: &[BigUint], remainder: &Self, num_quotient_limbs: usize) -> Option<Vec<BigUint>> {
+        let integer = from_limbs::<P>(coefficients);
+        let remainder = remainder.to_integer();
+        if integer < remainder {
+            return None;
+        }
+        let (quotient, rest) = (integer - remainder).div_rem(&Self::modulus());
+        match rest.is_zero() && quotient.bits() as usize <= num_quotient_limbs * P::BITS_PER_LIMB {
+            true => Some(to_limbs::<P>(&quotient, num_quotient_limbs)),
+            false => None,
+        }
+    }
+
+    /// Returns the number of limbs of the quotient by the modulus of the integer with
+    /// `num_coefficients` coefficients, each of which is at most `coefficient_bound`.
+    pub fn num_quotient_limbs(coefficient_bound: &BigUint, num_coefficients: usize) -> usize {
+        let max_integer = from_limbs::<P>(&vec![coefficient_bound.clone(); num_coefficients]);
+        let max_quotient = max_integer / Self::modulus();
+        cmp::max(1, (max_quotient.bits() as usize + P::BITS_PER_LIMB - 1) / P::BITS_PER_LIMB)
+    }
+
+    ///
+    /// Returns the bound on the coefficients of `q * p + r`, where `q` has `num_quotient_limbs`
+    /// reduced limbs, and the limbs of `r` are at most `remainder_bound`.
+    ///
+    pub fn quotient_coefficient_bound(num_quotient_limbs: usize, remainder_bound: &BigUint) -> BigUint {
+        let num_terms = cmp::min(num_quotient_limbs, P::NUM_LIMBS);
+        num_terms * Self::reduced_limb_bound() * Self::reduced_limb_bound() + remainder_bound
+    }
+
+    ///
+    /// Returns the offset and the number of bits with which to range check the carries of an
+    /// equality check between two integers with coefficients of at most `coefficient_bound`,
+    /// or `None` if the check is unsound in a host field with a capacity of `host_capacity` bits.
+    ///
+    /// Each carry is at most `K = coefficient_bound / (2^b - 1) + 1` in absolute value, so the check
+    /// is sound when `coefficient_bound + K` and `2^b * 2^(num_bits)` are less than `2^(host_capacity - 1)`.
+    ///
+    pub fn carry_parameters(coefficient_bound: &BigUint, host_capacity: usize) -> Option<(BigUint, usize)> {
+        let offset = coefficient_bound / Self::reduced_limb_bound() + 1u32;
+        let num_bits = (&offset << 1usize).bits() as usize;
+        match ((coefficient_bound + &offset).bits() as usize) < host_capacity
+            && P::BITS_PER_LIMB + num_bits < host_capacity
+        {
+            true => Some((offset, num_bits)),
+            false => None,
+        }
+    }
+
+    ///
+    /// Returns the bound on the coefficients of the check that the product of integers with the given limb bounds
+    /// is congruent to a reduced remainder, or `None` if the check is unsound in the given host field.
+    ///
+    pub fn mul_coefficient_bound(first: &BigUint, second: &BigUint, host_capacity: usize) -> Option<BigUint> {
+        let coefficient_bound = P::NUM_LIMBS * first * second;
+        let num_quotient_limbs = Self::num_quotient_limbs(&coefficient_bound, 2 * P::NUM_LIMBS - 1);
+        let bound = cmp::max(
+            coefficient_bound,
+            Self::quotient_coefficient_bound(num_quotient_limbs, &Self::reduced_limb_bound()),
+        );
+        Self::carry_parameters(&bound, host_capacity).map(|_| bound)
+    }
+
+    ///
+    /// Returns the largest limb bound of the form `2^k - 1` with which an integer can still be reduced,
+    /// in a host field with a capacity of `host_capacity` bits.
+    ///
+    pub fn max_limb_bound(host_capacity: usize) -> BigUint {
+        let mut num_bits = host_capacity;
+        while num_bits > P::BITS_PER_LIMB {
+            let limb_bound = (BigUint::one() << num_bits) - 1u32;
+            let num_quotient_limbs = Self::num_quotient_limbs(&limb_bound, P::NUM_LIMBS);
+            let bound = cmp::max(
+                limb_bound.clone(),
+                Self::quotient_coefficient_bound(num_quotient_limbs, &Self::reduced_limb_bound()),
+            );
+            if Self::carry_parameters(&bound, host_capacity).is_some() {
+                return limb_bound;
+            }
+            num_bits -= 1;
+        }
+        Self::reduced_limb_bound()
+    }
+}
+
+/// Returns the integer with the given limbs, in little-endian order.
+pub fn from_limbs<P: NonNativeParameters>(limbs: &[BigUint]) -> BigUint {
+    limbs
+        .iter()
+        .rev()
+        .fold(BigUint::zero(), |integer, limb| (integer << P::BITS_PER_LIMB) + limb)
+}
+
+/// Returns the given integer in `num_limbs` limbs of `P::BITS_PER_LIMB` bits, in little-endian order.
+pub fn to_limbs<P: NonNativeParameters>(integer: &BigUint, num_limbs: usize) -> Vec<BigUint> {
+    assert!(integer.bits() as usize <= num_limbs * P::BITS_PER_LIMB);
+    let mask = (BigUint::one() << P::BITS_PER_LIMB) - 1u32;
+    (0..num_limbs)
+        .map(|i| (integer >> (i * P::BITS_PER_LIMB)) & &mask)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nonnative::{Bls12_377BaseFieldParameters, Secp256k1BaseFieldParameters};
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+
+    fn check_arithmetic<P: NonNativeParameters>() {
+        let rng = &mut thread_rng();
+        for _ in 0..ITERATIONS {
+            let first: P::Field = UniformRand::rand(rng);
+            let second: P::Field = UniformRand::rand(rng);
+            let a = NonNativeInteger::<P>::new(&first);
+            let b = NonNativeInteger::<P>::new(&second);
+            assert_eq!(first, a.to_field());
+
+            // Ensure the unreduced results are congruent to the field operations.
+            let sum = a.add(&b);
+            assert_eq!(first + second, sum.to_field());
+            let difference = a.sub(&b);
+            assert_eq!(first - second, difference.to_field());
+            let product = sum.mul(&difference);
+            assert_eq!((first + second) * (first - second), product.to_field());
+            assert!(product.is_reduced());
+            assert_eq!(first.inverse(), a.inverse().map(|inverse| inverse.to_field()));
+
+            // Ensure the limbs never exceed their bounds.
+            for integer in [&sum, &difference, &product] {
+                assert!(integer.limbs().iter().all(|limb| limb <= integer.limb_bound()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        check_arithmetic::<Secp256k1BaseFieldParameters>();
+        check_arithmetic::<Bls12_377BaseFieldParameters>();
+    }
+
+    #[test]
+    fn test_padding() {
+        type P = Secp256k1BaseFieldParameters;
+
+        let modulus = NonNativeInteger::<P>::modulus();
+        for bound in [
+            BigUint::zero(),
+            NonNativeInteger::<P>::reduced_limb_bound(),
+            NonNativeInteger::<P>::max_limb_bound(252),
+        ] {
+            let padding = NonNativeInteger::<P>::padding(&bound);
+            assert!((from_limbs::<P>(&padding) % &modulus).is_zero());
+            for limb in &padding {
+                assert!(*limb >= bound);
+                assert!(*limb <= NonNativeInteger::<P>::sub_limb_bound(&BigUint::zero(), &bound));
+            }
+        }
+    }
+
+    #[test]
+    fn test_quotient() {
+        type P = Secp256k1BaseFieldParameters;
+
+        let rng = &mut thread_rng();
+        let a = NonNativeInteger::<P>::new(&UniformRand::rand(rng));
+        let b = NonNativeInteger::<P>::new(&UniformRand::rand(rng));
+        let (coefficients, coefficient_bound) = a.mul_coefficients(&b);
+        let num_quotient_limbs = NonNativeInteger::<P>::num_quotient_limbs(&coefficient_bound, coefficients.len());
+
+        // Ensure the quotient exists for the remainder, and for no other reduced integer.
+        let remainder = a.mul(&b);
+        assert!(NonNativeInteger::<P>::quotient(&coefficients, &remainder, num_quotient_limbs).is_some());
+        let other = remainder.add(&NonNativeInteger::new(&<P as NonNativeParameters>::Field::one()));
+        assert!(NonNativeInteger::<P>::quotient(&coefficients, &other, num_quotient_limbs).is_none());
+    }
+
+    #[test]
+    fn test_max_limb_bound() {
+        type P = Secp256k1BaseFieldParameters;
+
+        // Ensure products of reduced integers can be checked, and the maximum limb bound is as large as possible.
+        let reduced = NonNativeInteger::<P>::reduced_limb_bound();
+        assert!(NonNativeInteger::<P>::mul_coefficient_bound(&reduced, &reduced, 252).is_some());
+
+        let max_limb_bound = NonNativeInteger::<P>::max_limb_bound(252);
+        assert!(max_limb_bound > reduced);
+        let larger_limb_bound = (max_limb_bound << 1) + 1u32;
+        let num_quotient_limbs = NonNativeInteger::<P>::num_quotient_limbs(&larger_limb_bound, P::NUM_LIMBS);
+        let bound = cmp::max(
+            larger_limb_bound,
+            NonNativeInteger::<P>::quotient_coefficient_bound(num_quotient_limbs, &reduced),
+        );
+        assert!(NonNativeInteger::<P>::carry_parameters(&bound, 252).is_none());
+    }
+}
@@ -0,0 +1,127 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Equal<Self> for NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are congruent modulo the modulus.
+    ///
+    /// This method costs the constraints of `Self::is_neq`.
+    ///
+    fn is_eq(&self, other: &Self) -> Self::Output {
+        !self.is_neq(other)
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* congruent modulo the modulus.
+    ///
+    /// If both operands are constants, this method costs 0 constraints. Otherwise, the boolean and
+    /// the inverse of the difference (or one, if the difference is zero) are witnessed, and
+    ///   - `(a - b) * multiplier` is checked to be congruent to `is_neq`, and
+    ///   - `(a - b) * not(is_neq)` is checked to be congruent to zero,
+    ///
+    /// which costs 1 constraint for the boolean, the constraints of a multiplication, `P::NUM_LIMBS`
+    /// constraints to scale the difference, and the constraints of a reduction.
+    ///
+    /// For secp256k1 with the BLS12-377 scalar field as host, this is 1,345 constraints for reduced operands.
+    ///
+    fn is_neq(&self, other: &Self) -> Self::Output {
+        if self.is_constant() && other.is_constant() {
+            return Boolean::new(Mode::Constant, self.eject_value() != other.eject_value());
+        }
+
+        let difference = self - other;
+        let value = difference.eject_value();
+        let is_neq = Boolean::new(Mode::Private, !value.is_zero());
+        let multiplier = Self::new(Mode::Private, value.inverse().unwrap_or_else(P::Field::one));
+
+        // Ensure (a - b) * multiplier is congruent to is_neq.
+        let (coefficients, coefficient_bound) = Self::mul_coefficients(&difference, &multiplier);
+        let mut limbs = vec![BaseField::zero(); P::NUM_LIMBS];
+        limbs[0] = BaseField::from(&is_neq);
+        let expected = Self {
+            limbs,
+            limb_bound: NonNativeInteger::<P>::reduced_limb_bound(),
+            _parameters: PhantomData,
+        };
+        Self::enforce_congruent(&coefficients, &coefficient_bound, &expected);
+
+        // Ensure (a - b) * not(is_neq) is congruent to zero.
+        let is_eq = BaseField::from(&!&is_neq);
+        let limbs: Vec<_> = difference.limbs.iter().map(|limb| limb * &is_eq).collect();
+        let zero = Self::new(Mode::Constant, P::Field::zero());
+        Self::enforce_congruent(&limbs, &difference.limb_bound, &zero);
+
+        is_neq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_is_eq(name: &str, a: &NonNativeField<Circuit, P>, b: &NonNativeField<Circuit, P>, num_constraints: usize) {
+        Circuit::scoped(name, |scope| {
+            let expected = a.eject_value() == b.eject_value();
+            assert_eq!(expected, a.is_eq(b).eject_value());
+            assert_eq!(!expected, a.is_neq(b).eject_value());
+            assert_eq!(2 * num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    #[test]
+    fn test_is_eq() {
+        for i in 0..ITERATIONS {
+            let first: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+
+            let a = NonNativeField::<Circuit, P>::new(Mode::Constant, first);
+            let b = NonNativeField::<Circuit, P>::new(Mode::Constant, second);
+            check_is_eq(&format!("Constant {}", i), &a, &b, 0);
+            check_is_eq(&format!("Constant self {}", i), &a, &a, 0);
+
+            let a = NonNativeField::<Circuit, P>::new(Mode::Private, first);
+            let b = NonNativeField::<Circuit, P>::new(Mode::Public, second);
+            check_is_eq(&format!("Private {}", i), &a, &b, 1345);
+            check_is_eq(&format!("Private self {}", i), &a, &a, 1345);
+        }
+    }
+
+    #[test]
+    fn test_is_eq_congruent() {
+        let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+        let a = NonNativeField::<Circuit, P>::new(Mode::Private, value);
+        let b = NonNativeField::<Circuit, P>::new(Mode::Private, value + value);
+
+        // Ensure unreduced operands are compared by their values, rather than their limbs.
+        assert!((&a + &a).is_eq(&b).eject_value());
+        assert!(!(&a + &b).is_eq(&b).eject_value());
+        assert!(Circuit::is_satisfied());
+    }
+}
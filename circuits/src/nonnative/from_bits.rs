@@ -0,0 +1,119 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    ///
+    /// Initializes a non-native field element from its little-endian bits.
+    ///
+    /// The bits are packed into limbs of `P::BITS_PER_LIMB` bits, so the result is reduced and
+    /// this method costs 0 constraints. The bits may encode any integer of at most
+    /// `P::NUM_LIMBS * P::BITS_PER_LIMB` bits, which represents its value modulo the modulus.
+    ///
+    pub fn from_bits_le(bits: &[Boolean<E>]) -> Self {
+        if bits.len() > P::NUM_LIMBS * P::BITS_PER_LIMB {
+            E::halt(format!(
+                "A non-native field element has at most {} bits, found {}",
+                P::NUM_LIMBS * P::BITS_PER_LIMB,
+                bits.len()
+            ))
+        }
+
+        let mut limbs = vec![BaseField::zero(); P::NUM_LIMBS];
+        for (limb, chunk) in limbs.iter_mut().zip(bits.chunks(P::BITS_PER_LIMB)) {
+            let mut coefficient = BaseField::one();
+            for bit in chunk {
+                *limb += BaseField::from(bit) * &coefficient;
+                coefficient = coefficient.double();
+            }
+        }
+        Self {
+            limbs,
+            limb_bound: NonNativeInteger::<P>::reduced_limb_bound(),
+            _parameters: PhantomData,
+        }
+    }
+
+    ///
+    /// Initializes a non-native field element from its big-endian bits.
+    ///
+    /// This method costs 0 constraints (see `Self::from_bits_le`).
+    ///
+    pub fn from_bits_be(bits: &[Boolean<E>]) -> Self {
+        let mut bits_le = bits.to_vec();
+        bits_le.reverse();
+        Self::from_bits_le(&bits_le)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nonnative::Secp256k1ScalarFieldParameters, Circuit};
+    use snarkvm_utilities::{ToBits as TBits, UniformRand};
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    #[test]
+    fn test_from_bits() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            for i in 0..ITERATIONS {
+                let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+                let bits: Vec<_> = value
+                    .to_repr()
+                    .to_bits_le()
+                    .into_iter()
+                    .map(|bit| Boolean::<Circuit>::new(mode, bit))
+                    .collect();
+
+                Circuit::scoped(&format!("From bits: {:?} {}", mode, i), |scope| {
+                    let candidate = NonNativeField::<Circuit, P>::from_bits_le(&bits);
+                    assert_eq!(value, candidate.eject_value());
+                    assert!(candidate.is_reduced());
+                    assert_eq!(mode.is_constant(), candidate.is_constant());
+
+                    let mut bits_be = bits.clone();
+                    bits_be.reverse();
+                    let candidate = NonNativeField::<Circuit, P>::from_bits_be(&bits_be);
+                    assert_eq!(value, candidate.eject_value());
+                    assert_eq!(0, scope.num_constraints_in_scope());
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bits_of_the_base_field_modulus() {
+        // Ensure the bits of an integer beyond the modulus represent its value modulo the modulus.
+        let modulus = NonNativeInteger::<P>::modulus();
+        let bits: Vec<_> = (0..modulus.bits())
+            .map(|i| Boolean::<Circuit>::new(Mode::Private, modulus.bit(i)))
+            .collect();
+        let base = NonNativeField::<Circuit, P>::from_bits_le(&bits);
+        assert!(base.eject_value().is_zero());
+
+        // In the scalar field, the same bits represent the base field modulus minus the scalar field modulus.
+        let scalar = NonNativeField::<Circuit, Secp256k1ScalarFieldParameters>::from_bits_le(&bits);
+        let expected = modulus - NonNativeInteger::<Secp256k1ScalarFieldParameters>::modulus();
+        assert_eq!(expected, to_biguint(&scalar.eject_value()));
+        assert!(Circuit::is_satisfied());
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Inv for NonNativeField<E, P> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        (&self).inv()
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Inv for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    ///
+    /// Returns the reduced inverse of `self`.
+    ///
+    /// If `self` is a constant, this method costs 0 constraints. Otherwise, the inverse is witnessed
+    /// and range checked, and its product with `self` is checked to be congruent to one, which costs
+    /// the constraints of a multiplication, less the remainder range check.
+    ///
+    fn inv(self) -> Self::Output {
+        let inverse = match self.eject_value().inverse() {
+            Some(inverse) => inverse,
+            None => E::halt("Failed to compute the inverse for a non-native field element"),
+        };
+        if self.is_constant() {
+            return NonNativeField::new(Mode::Constant, inverse);
+        }
+
+        let inverse = NonNativeField::new(Mode::Private, inverse);
+
+        // Ensure self * self^(-1) is congruent to 1.
+        let (coefficients, coefficient_bound) = NonNativeField::mul_coefficients(self, &inverse);
+        let one = NonNativeField::<E, P>::new(Mode::Constant, P::Field::one());
+        NonNativeField::enforce_congruent(&coefficients, &coefficient_bound, &one);
+
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    fn run_test(mode: Mode, num_constraints: usize) {
+        for i in 0..ITERATIONS {
+            let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let candidate = NonNativeField::<Circuit, P>::new(mode, value);
+
+            Circuit::scoped(&format!("Inv: {:?} {}", mode, i), |scope| {
+                let inverse = candidate.clone().inv();
+                assert_eq!(value.inverse().unwrap(), inverse.eject_value());
+                assert!(inverse.is_reduced());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_inv() {
+        run_test(Mode::Constant, 0);
+        run_test(Mode::Public, 1065);
+        run_test(Mode::Private, 1065);
+    }
+
+    #[test]
+    fn test_inv_zero() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let candidate = NonNativeField::<Circuit, P>::new(mode, Z::zero());
+            assert!(Circuit::catch_halt(|| candidate.inv()).is_err());
+        }
+        Circuit::reset_circuit();
+    }
+}
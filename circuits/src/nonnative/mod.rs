@@ -0,0 +1,189 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+pub mod add;
+pub mod console;
+pub mod equal;
+pub mod from_bits;
+pub mod inv;
+pub mod mul;
+pub mod parameters;
+pub mod reduce;
+pub mod sub;
+pub mod ternary;
+pub mod to_bits;
+
+pub use console::NonNativeInteger;
+pub use parameters::*;
+
+use crate::{traits::*, BaseField, Boolean, Environment, LinearCombination, Mode};
+use console::{from_biguint, to_biguint};
+use snarkvm_fields::{Field as F, One as O, PrimeField, Zero as Z};
+
+use num_bigint::BigUint;
+use num_traits::Inv;
+use std::{
+    fmt,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+///
+/// An element of a non-native field, in limbs of the base field.
+///
+/// The limbs are little-endian, and each is at most `limb_bound`. Additions and subtractions
+/// let the limbs grow, and elements are only reduced once their limbs would be too large to
+/// multiply soundly, or to be reduced soundly themselves.
+///
+#[derive(Clone)]
+pub struct NonNativeField<E: Environment, P: NonNativeParameters> {
+    limbs: Vec<BaseField<E>>,
+    limb_bound: BigUint,
+    _parameters: PhantomData<P>,
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    ///
+    /// Initializes a new instance of a non-native field element from a non-native field value.
+    ///
+    /// Unless the mode is constant, the limbs are range checked, which costs
+    /// `P::NUM_LIMBS * (P::BITS_PER_LIMB + 1)` constraints.
+    ///
+    pub fn new(mode: Mode, value: P::Field) -> Self {
+        Self::from_integer(mode, &NonNativeInteger::new(&value))
+    }
+
+    ///
+    /// Returns `true` if the non-native field element is a constant.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.limbs.iter().all(|limb| limb.is_constant())
+    }
+
+    ///
+    /// Ejects the non-native field element as a constant non-native field value.
+    ///
+    pub fn eject_value(&self) -> P::Field {
+        self.to_console().to_field()
+    }
+
+    ///
+    /// Returns the limbs, in little-endian order.
+    ///
+    pub fn limbs(&self) -> &[BaseField<E>] {
+        &self.limbs
+    }
+
+    ///
+    /// Returns the bound on the value of every limb.
+    ///
+    pub fn limb_bound(&self) -> &BigUint {
+        &self.limb_bound
+    }
+
+    ///
+    /// Returns the native mirror of the non-native field element.
+    ///
+    pub fn to_console(&self) -> NonNativeInteger<P> {
+        let limbs = self.limbs.iter().map(|limb| to_biguint(&limb.eject_value())).collect();
+        NonNativeInteger::from_limbs(limbs, self.limb_bound.clone())
+    }
+
+    /// Initializes the limbs of the given integer, and range checks them if the mode is not constant.
+    fn from_integer(mode: Mode, integer: &NonNativeInteger<P>) -> Self {
+        let limbs: Vec<_> = integer
+            .limbs()
+            .iter()
+            .map(|limb| BaseField::new(mode, from_biguint(limb)))
+            .collect();
+        if !mode.is_constant() {
+            BaseField::assert_bits_le_many(&limbs, P::BITS_PER_LIMB);
+        }
+        Self {
+            limbs,
+            limb_bound: integer.limb_bound().clone(),
+            _parameters: PhantomData,
+        }
+    }
+
+    /// Returns the capacity of the base field, which bounds the integers the limbs may represent.
+    fn host_capacity() -> usize {
+        E::BaseField::size_in_bits() - 1
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> fmt::Debug for NonNativeField<E, P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.eject_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_new(
+        name: &str,
+        mode: Mode,
+        num_constants: usize,
+        num_public: usize,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        for i in 0..ITERATIONS {
+            let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+
+            Circuit::scoped(&format!("{} {}", name, i), |scope| {
+                let candidate = NonNativeField::<Circuit, P>::new(mode, value);
+                assert_eq!(value, candidate.eject_value());
+                assert_eq!(P::NUM_LIMBS, candidate.limbs().len());
+                assert!(candidate.is_reduced());
+                assert_eq!(value.to_string(), format!("{:?}", candidate));
+
+                assert_eq!(num_constants, scope.num_constants_in_scope());
+                assert_eq!(num_public, scope.num_public_in_scope());
+                assert_eq!(num_private, scope.num_private_in_scope());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_new_constant() {
+        check_new("Constant", Mode::Constant, P::NUM_LIMBS, 0, 0, 0);
+    }
+
+    #[test]
+    fn test_new_public() {
+        let num_bits = P::NUM_LIMBS * P::BITS_PER_LIMB;
+        check_new("Public", Mode::Public, 0, P::NUM_LIMBS, num_bits, num_bits + P::NUM_LIMBS);
+    }
+
+    #[test]
+    fn test_new_private() {
+        let num_bits = P::NUM_LIMBS * P::BITS_PER_LIMB;
+        check_new("Private", Mode::Private, 0, 0, num_bits + P::NUM_LIMBS, num_bits + P::NUM_LIMBS);
+    }
+}
@@ -0,0 +1,277 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Mul<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: NonNativeField<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Mul<NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: NonNativeField<E, P>) -> Self::Output {
+        self * &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Mul<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: &NonNativeField<E, P>) -> Self::Output {
+        &self * other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Mul<&NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn mul(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut output = (*self).clone();
+        output *= other;
+        output
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> MulAssign<NonNativeField<E, P>> for NonNativeField<E, P> {
+    fn mul_assign(&mut self, other: NonNativeField<E, P>) {
+        *self *= &other;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> MulAssign<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    ///
+    /// Multiplies `self` by `other`, and reduces the product.
+    ///
+    /// If both operands are constants, this method costs 0 constraints. Otherwise, the operands are first
+    /// reduced until their product can be checked soundly, which is never necessary for reduced operands.
+    ///
+    /// For reduced, non-constant operands with `n = P::NUM_LIMBS` limbs of `b = P::BITS_PER_LIMB` bits,
+    /// this method costs
+    ///   - `2n - 1` constraints to check the product coefficients (0 if either operand is a constant),
+    ///   - `n * (b + 1)` constraints to range check the remainder,
+    ///   - `m * (b + 1)` constraints to range check the `m` limbs of the quotient, and
+    ///   - `(L - 1) * (c + 1) + 1` constraints to check the `L` coefficients with `c`-bit carries.
+    ///
+    /// For secp256k1 with the BLS12-377 scalar field as host, this is 1,065 constraints (see `test_num_constraints`).
+    ///
+    fn mul_assign(&mut self, other: &NonNativeField<E, P>) {
+        if self.is_constant() && other.is_constant() {
+            *self = Self::new(Mode::Constant, self.eject_value() * other.eject_value());
+            return;
+        }
+
+        let (coefficients, coefficient_bound) = Self::mul_coefficients(self, other);
+        *self = Self::reduce_coefficients(&coefficients, &coefficient_bound);
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    ///
+    /// Returns the coefficients of the product of `first` and `second` as polynomials in `2^P::BITS_PER_LIMB`,
+    /// and the bound on every coefficient, reducing the operands first if the product cannot be checked soundly.
+    ///
+    /// If either operand is a constant, the coefficients are linear combinations of the other operand,
+    /// and cost 0 constraints. Otherwise, the `2n - 1` coefficients are witnessed, and the product polynomial
+    /// is checked at the `2n - 1` points `0, ..., 2n - 2`, which costs `2n - 1` constraints.
+    ///
+    pub(super) fn mul_coefficients(first: &Self, second: &Self) -> (Vec<BaseField<E>>, BigUint) {
+        let (first, second) = Self::reduce_until(first, second, |first, second| {
+            NonNativeInteger::<P>::mul_coefficient_bound(first, second, Self::host_capacity()).is_some()
+        });
+        let coefficient_bound = P::NUM_LIMBS * &first.limb_bound * &second.limb_bound;
+        let num_coefficients = 2 * P::NUM_LIMBS - 1;
+
+        if first.is_constant() || second.is_constant() {
+            let mut coefficients = vec![BaseField::zero(); num_coefficients];
+            for (i, a) in first.limbs.iter().enumerate() {
+                for (j, b) in second.limbs.iter().enumerate() {
+                    coefficients[i + j] += a * b;
+                }
+            }
+            return (coefficients, coefficient_bound);
+        }
+
+        let (values, _) = first.to_console().mul_coefficients(&second.to_console());
+        let coefficients: Vec<_> = values
+            .iter()
+            .map(|value| BaseField::new(Mode::Private, from_biguint(value)))
+            .collect();
+
+        // Ensure first(x) * second(x) == product(x) at 2n - 1 points, which determines the product polynomial.
+        let evaluate = |limbs: &[BaseField<E>], point: u64| {
+            let point = E::BaseField::from(point);
+            let mut power = E::BaseField::one();
+            let mut evaluation = E::zero();
+            for limb in limbs {
+                evaluation += LinearCombination::from(limb) * power;
+                power *= point;
+            }
+            evaluation
+        };
+        for point in 0..num_coefficients as u64 {
+            E::enforce(|| {
+                (
+                    evaluate(&first.limbs, point),
+                    evaluate(&second.limbs, point),
+                    evaluate(&coefficients, point),
+                )
+            });
+        }
+
+        (coefficients, coefficient_bound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    fn check_mul(
+        name: &str,
+        first: <P as NonNativeParameters>::Field,
+        second: <P as NonNativeParameters>::Field,
+        a: &NonNativeField<Circuit, P>,
+        b: &NonNativeField<Circuit, P>,
+        num_private: usize,
+        num_constraints: usize,
+    ) {
+        Circuit::scoped(name, |scope| {
+            let candidate = a * b;
+            assert_eq!(first * second, candidate.eject_value());
+            assert!(candidate.is_reduced());
+
+            assert_eq!(0, scope.num_public_in_scope());
+            assert_eq!(num_private, scope.num_private_in_scope());
+            assert_eq!(num_constraints, scope.num_constraints_in_scope());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    fn run_test(mode_a: Mode, mode_b: Mode, num_private: usize, num_constraints: usize) {
+        for i in 0..ITERATIONS {
+            let first: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let a = NonNativeField::<Circuit, P>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, P>::new(mode_b, second);
+
+            let name = format!("Mul: {:?} * {:?} {}", mode_a, mode_b, i);
+            check_mul(&name, first, second, &a, &b, num_private, num_constraints);
+        }
+    }
+
+    #[test]
+    fn test_constant_times_constant() {
+        run_test(Mode::Constant, Mode::Constant, 0, 0);
+    }
+
+    #[test]
+    fn test_constant_times_public() {
+        run_test(Mode::Constant, Mode::Public, 1054, 1060);
+    }
+
+    #[test]
+    fn test_public_times_constant() {
+        run_test(Mode::Public, Mode::Constant, 1054, 1060);
+    }
+
+    #[test]
+    fn test_constant_times_private() {
+        run_test(Mode::Constant, Mode::Private, 1054, 1060);
+    }
+
+    #[test]
+    fn test_public_times_private() {
+        run_test(Mode::Public, Mode::Private, 1059, 1065);
+    }
+
+    #[test]
+    fn test_private_times_private() {
+        run_test(Mode::Private, Mode::Private, 1059, 1065);
+    }
+
+    #[test]
+    fn test_num_constraints() {
+        fn num_constraints<P: NonNativeParameters>() -> usize {
+            let first: P::Field = UniformRand::rand(&mut thread_rng());
+            let second: P::Field = UniformRand::rand(&mut thread_rng());
+            let a = NonNativeField::<Circuit, P>::new(Mode::Private, first);
+            let b = NonNativeField::<Circuit, P>::new(Mode::Private, second);
+
+            let mut num_constraints = 0;
+            Circuit::scoped(&format!("{:?}", a), |scope| {
+                let candidate = a * b;
+                assert_eq!(first * second, candidate.eject_value());
+                assert!(Circuit::is_satisfied());
+                num_constraints = scope.num_constraints_in_scope();
+            });
+            num_constraints
+        }
+
+        // Ensure the cost of a single multiplication of reduced elements does not regress.
+        assert_eq!(1065, num_constraints::<Secp256k1BaseFieldParameters>());
+        assert_eq!(1065, num_constraints::<Secp256k1ScalarFieldParameters>());
+        assert_eq!(1565, num_constraints::<Bls12_377BaseFieldParameters>());
+    }
+
+    #[test]
+    fn test_mul_at_max_limb_bound() {
+        let reduced = NonNativeInteger::<P>::reduced_limb_bound();
+        let host_capacity = NonNativeField::<Circuit, P>::host_capacity();
+
+        // Find the largest limb bound whose product with a reduced element can be checked without reducing.
+        let mut max_bits = P::BITS_PER_LIMB;
+        loop {
+            let limb_bound = (BigUint::from(1u32) << (max_bits + 1)) - 1u32;
+            match NonNativeInteger::<P>::mul_coefficient_bound(&limb_bound, &reduced, host_capacity) {
+                Some(_) => max_bits += 1,
+                None => break,
+            }
+        }
+
+        // Ensure the product is correct at the bound, and just beyond it, where the first operand is reduced first.
+        for (i, num_bits) in [max_bits, max_bits + 1].iter().enumerate() {
+            // Construct an element whose limbs all equal their bound, which maximizes every coefficient and carry.
+            let limb_bound = (BigUint::from(1u32) << *num_bits) - 1u32;
+            let a = NonNativeField::<Circuit, P> {
+                limbs: vec![BaseField::new(Mode::Private, from_biguint(&limb_bound)); P::NUM_LIMBS],
+                limb_bound: limb_bound.clone(),
+                _parameters: PhantomData,
+            };
+            let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let b = NonNativeField::<Circuit, P>::new(Mode::Private, -second);
+
+            Circuit::scoped(&format!("Mul at max limb bound {}", i), |_| {
+                let candidate = &a * &b;
+                assert_eq!(a.eject_value() * -second, candidate.eject_value());
+                assert!(candidate.is_reduced());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+}
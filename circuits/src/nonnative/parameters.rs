@@ -0,0 +1,148 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_curves::{bls12_377, secp256k1};
+use snarkvm_fields::{FieldParameters, PrimeField};
+
+use std::fmt::Debug;
+
+/// The capacity of the BLS12-377 scalar field, which is the base field of the circuit environment.
+const HOST_CAPACITY: usize = <bls12_377::FrParameters as FieldParameters>::CAPACITY as usize;
+
+/// The parameters of a non-native field, which set the limb representation of its elements.
+pub trait NonNativeParameters: Clone + Debug + PartialEq + Eq + Send + Sync + 'static {
+    /// The non-native field.
+    type Field: PrimeField;
+
+    /// The number of limbs of a reduced element.
+    const NUM_LIMBS: usize;
+
+    /// The number of bits of each limb of a reduced element.
+    const BITS_PER_LIMB: usize;
+}
+
+/// The secp256k1 base field, with limbs chosen for the BLS12-377 scalar field as host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1BaseFieldParameters;
+
+impl NonNativeParameters for Secp256k1BaseFieldParameters {
+    type Field = secp256k1::Fq;
+
+    const BITS_PER_LIMB: usize = find_limb_parameters(HOST_CAPACITY, 256).1;
+    const NUM_LIMBS: usize = find_limb_parameters(HOST_CAPACITY, 256).0;
+}
+
+/// The secp256k1 scalar field, with limbs chosen for the BLS12-377 scalar field as host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1ScalarFieldParameters;
+
+impl NonNativeParameters for Secp256k1ScalarFieldParameters {
+    type Field = secp256k1::Fr;
+
+    const BITS_PER_LIMB: usize = find_limb_parameters(HOST_CAPACITY, 256).1;
+    const NUM_LIMBS: usize = find_limb_parameters(HOST_CAPACITY, 256).0;
+}
+
+/// The BLS12-377 base field, with limbs chosen for the BLS12-377 scalar field as host.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bls12_377BaseFieldParameters;
+
+impl NonNativeParameters for Bls12_377BaseFieldParameters {
+    type Field = bls12_377::Fq;
+
+    const BITS_PER_LIMB: usize = find_limb_parameters(HOST_CAPACITY, 377).1;
+    const NUM_LIMBS: usize = find_limb_parameters(HOST_CAPACITY, 377).0;
+}
+
+///
+/// Returns the `(num_limbs, bits_per_limb)` that minimize the number of constraints of a
+/// multiplication of two reduced elements of a `target_bits`-bit field, in a host field
+/// with a capacity of `host_capacity` bits.
+///
+/// The estimate follows `NonNativeField::mul`, which witnesses the `2n - 1` product coefficients
+/// and checks them with `2n - 1` constraints, witnesses and range checks the `n` limbs of the remainder
+/// and the `m` limbs of the quotient, and range checks the `L - 1` carries of the final equality check.
+///
+pub const fn find_limb_parameters(host_capacity: usize, target_bits: usize) -> (usize, usize) {
+    let mut best = (0, 0);
+    let mut best_cost = usize::MAX;
+
+    let mut bits_per_limb = 1;
+    while bits_per_limb <= target_bits {
+        let num_limbs = (target_bits + bits_per_limb - 1) / bits_per_limb;
+
+        // The product of two reduced elements is less than 2^(2bn), and the modulus is at least 2^(t - 1).
+        let quotient_bits = 2 * bits_per_limb * num_limbs + 1 - target_bits;
+        let num_quotient_limbs = (quotient_bits + bits_per_limb - 1) / bits_per_limb;
+
+        // Each product coefficient is less than n * 2^(2b), and each coefficient of `q * p + r`
+        // is less than min(m, n) * 2^(2b) + 2^b.
+        let min_limbs = if num_quotient_limbs < num_limbs { num_quotient_limbs } else { num_limbs };
+        let product_bits = 2 * bits_per_limb + ceil_log2(num_limbs);
+        let quotient_product_bits = 2 * bits_per_limb + ceil_log2(min_limbs) + 1;
+        let coefficient_bits = if product_bits > quotient_product_bits { product_bits } else { quotient_product_bits };
+
+        // Each carry is at most `coefficient_bound / (2^b - 1) + 1` in absolute value,
+        // and is range checked with an offset.
+        let carry_bits = coefficient_bits + 2 - bits_per_limb + 1;
+
+        if bits_per_limb + carry_bits < host_capacity && coefficient_bits + 1 < host_capacity {
+            let num_coefficients = if 2 * num_limbs > num_quotient_limbs + num_limbs {
+                2 * num_limbs - 1
+            } else {
+                num_quotient_limbs + num_limbs - 1
+            };
+            let cost = (2 * num_limbs - 1)
+                + (num_limbs + num_quotient_limbs) * (bits_per_limb + 1)
+                + (num_coefficients - 1) * (carry_bits + 1)
+                + 1;
+            if cost < best_cost {
+                best = (num_limbs, bits_per_limb);
+                best_cost = cost;
+            }
+        }
+
+        bits_per_limb += 1;
+    }
+
+    best
+}
+
+/// Returns the smallest `k` such that `2^k >= n`.
+const fn ceil_log2(n: usize) -> usize {
+    let mut k = 0;
+    while (1 << k) < n {
+        k += 1;
+    }
+    k
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_limb_parameters() {
+        for (host_capacity, target_bits) in [(252, 256), (252, 377), (252, 64), (376, 253)] {
+            let (num_limbs, bits_per_limb) = find_limb_parameters(host_capacity, target_bits);
+            // Ensure the limbs cover the target field, without a redundant limb.
+            assert!(num_limbs * bits_per_limb >= target_bits);
+            assert!((num_limbs - 1) * bits_per_limb < target_bits);
+            // Ensure the product of two limbs fits in the host field.
+            assert!(2 * bits_per_limb < host_capacity);
+        }
+    }
+}
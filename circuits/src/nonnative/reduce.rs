@@ -0,0 +1,276 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> NonNativeField<E, P> {
+    ///
+    /// Returns a non-native field element congruent to `self`, with limbs of at most `P::BITS_PER_LIMB` bits.
+    ///
+    /// If `self` is a constant or is already reduced, this method costs 0 constraints.
+    /// Otherwise, this method witnesses the remainder and quotient of `self` by the modulus,
+    /// and costs the constraints of `Self::enforce_congruent`.
+    ///
+    pub fn reduce(&self) -> Self {
+        if self.is_constant() {
+            return Self::new(Mode::Constant, self.eject_value());
+        }
+        if self.is_reduced() {
+            return self.clone();
+        }
+
+        let remainder = Self::from_integer(Mode::Private, &self.to_console().reduce());
+        Self::enforce_congruent(&self.limbs, &self.limb_bound, &remainder);
+        remainder
+    }
+
+    ///
+    /// Returns `true` if the limbs have at most `P::BITS_PER_LIMB` bits.
+    ///
+    pub fn is_reduced(&self) -> bool {
+        self.limb_bound <= NonNativeInteger::<P>::reduced_limb_bound()
+    }
+
+    ///
+    /// Reduces the operand with the larger limbs until `is_sound` holds for the limb bounds of the operands.
+    ///
+    pub(super) fn reduce_until<Fn>(first: &Self, second: &Self, is_sound: Fn) -> (Self, Self)
+    where
+        Fn: std::ops::Fn(&BigUint, &BigUint) -> bool,
+    {
+        let (mut first, mut second) = (first.clone(), second.clone());
+        while !is_sound(&first.limb_bound, &second.limb_bound) {
+            match (first.is_reduced(), second.is_reduced()) {
+                (true, true) => E::halt("The non-native field parameters are unsound for this base field"),
+                (false, true) => first = first.reduce(),
+                (true, false) => second = second.reduce(),
+                (false, false) => match first.limb_bound >= second.limb_bound {
+                    true => first = first.reduce(),
+                    false => second = second.reduce(),
+                },
+            }
+        }
+        (first, second)
+    }
+
+    ///
+    /// Witnesses the reduced remainder of the integer with the given coefficients by the modulus,
+    /// and enforces that it is congruent to the coefficients.
+    ///
+    pub(super) fn reduce_coefficients(coefficients: &[BaseField<E>], coefficient_bound: &BigUint) -> Self {
+        let values: Vec<_> = coefficients.iter().map(|c| to_biguint(&c.eject_value())).collect();
+        let remainder = console::from_limbs::<P>(&values) % NonNativeInteger::<P>::modulus();
+        let remainder = Self::new(Mode::Private, from_biguint(&remainder));
+        Self::enforce_congruent(coefficients, coefficient_bound, &remainder);
+        remainder
+    }
+
+    ///
+    /// Enforces that the integer with the given coefficients in `2^P::BITS_PER_LIMB` is congruent to `result`,
+    /// by witnessing the quotient `q` of their difference by the modulus `p`.
+    ///
+    /// The coefficients must each be at most `coefficient_bound`.
+    ///
+    pub(super) fn enforce_congruent(coefficients: &[BaseField<E>], coefficient_bound: &BigUint, result: &Self) {
+        let num_quotient_limbs = NonNativeInteger::<P>::num_quotient_limbs(coefficient_bound, coefficients.len());
+        let values: Vec<_> = coefficients.iter().map(|c| to_biguint(&c.eject_value())).collect();
+        let quotient = match NonNativeInteger::quotient(&values, &result.to_console(), num_quotient_limbs) {
+            Some(quotient) => quotient,
+            None => E::halt("The non-native integers are not congruent"),
+        };
+        Self::enforce_congruent_with_quotient(coefficients, coefficient_bound, &quotient, result)
+    }
+
+    ///
+    /// Enforces that the integer with the given coefficients equals `q * p + result`, where `q` has the given limbs.
+    ///
+    /// The `m` limbs of the quotient are witnessed and range checked,
+    /// which costs `m * (P::BITS_PER_LIMB + 1)` constraints.
+    /// The equality is then checked one coefficient at a time from the least significant, by range checking
+    /// each of the `L - 1` carries with an offset, which costs `(L - 1) * (c + 1) + 1` constraints,
+    /// where `L` is the number of coefficients of either side, and `c` is the number of bits of the carries.
+    ///
+    fn enforce_congruent_with_quotient(
+        coefficients: &[BaseField<E>],
+        coefficient_bound: &BigUint,
+        quotient: &[BigUint],
+        result: &Self,
+    ) {
+        let quotient: Vec<_> = quotient
+            .iter()
+            .map(|limb| BaseField::new(Mode::Private, from_biguint(limb)))
+            .collect();
+        BaseField::assert_bits_le_many(&quotient, P::BITS_PER_LIMB);
+
+        // Ensure the carries cannot wrap around the base field.
+        let quotient_coefficient_bound =
+            NonNativeInteger::<P>::quotient_coefficient_bound(quotient.len(), &result.limb_bound);
+        let bound = std::cmp::max(coefficient_bound, &quotient_coefficient_bound);
+        let (offset, num_carry_bits) = match NonNativeInteger::<P>::carry_parameters(bound, Self::host_capacity()) {
+            Some(parameters) => parameters,
+            None => E::halt("The non-native integers are too large to compare soundly"),
+        };
+
+        // Compute the coefficients of q * p + result.
+        let modulus = console::to_limbs::<P>(&NonNativeInteger::<P>::modulus(), P::NUM_LIMBS);
+        let num_expected = std::cmp::max(quotient.len() + P::NUM_LIMBS - 1, result.limbs.len());
+        let mut expected = vec![BaseField::zero(); num_expected];
+        for (i, q) in quotient.iter().enumerate() {
+            for (j, p) in modulus.iter().enumerate() {
+                expected[i + j] += q * BaseField::new(Mode::Constant, from_biguint(p));
+            }
+        }
+        for (expected, r) in expected.iter_mut().zip(&result.limbs) {
+            *expected += r;
+        }
+
+        // Check the coefficients from the least significant, carrying the difference divided by 2^b.
+        let num_coefficients = std::cmp::max(coefficients.len(), expected.len());
+        let shift = from_biguint::<E::BaseField>(&(BigUint::from(1u32) << P::BITS_PER_LIMB)).inverse().unwrap();
+        let shift = BaseField::new(Mode::Constant, shift);
+        let offset = BaseField::new(Mode::Constant, from_biguint(&offset));
+        let zero = BaseField::zero();
+
+        let mut carry = BaseField::zero();
+        let mut shifted_carries = Vec::with_capacity(num_coefficients - 1);
+        for i in 0..num_coefficients {
+            let difference = coefficients.get(i).unwrap_or(&zero) + &carry - expected.get(i).unwrap_or(&zero);
+            match i + 1 < num_coefficients {
+                // The carry is an integer in [-offset, offset] if and only if the difference is a multiple of 2^b.
+                true => {
+                    carry = difference * &shift;
+                    shifted_carries.push(&carry + &offset);
+                }
+                // Ensure the final carry is zero.
+                false => E::assert_eq(difference, E::zero()),
+            }
+        }
+        BaseField::assert_bits_le_many(&shifted_carries, num_carry_bits);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    /// Returns a private non-native field element whose limbs all equal their bound, which maximizes every carry.
+    fn max_limbs(limb_bound: BigUint) -> NonNativeField<Circuit, P> {
+        NonNativeField {
+            limbs: vec![BaseField::new(Mode::Private, from_biguint(&limb_bound)); P::NUM_LIMBS],
+            limb_bound,
+            _parameters: PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_reduce() {
+        for i in 0..ITERATIONS {
+            let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let candidate = NonNativeField::<Circuit, P>::new(Mode::Private, value);
+
+            // Ensure reduced elements are returned as is.
+            Circuit::scoped(&format!("Reduce {}", i), |scope| {
+                let reduced = candidate.reduce();
+                assert_eq!(value, reduced.eject_value());
+                assert_eq!(0, scope.num_constraints_in_scope());
+            });
+        }
+    }
+
+    #[test]
+    fn test_reduce_at_max_limb_bound() {
+        let max_limb_bound = NonNativeInteger::<P>::max_limb_bound(NonNativeField::<Circuit, P>::host_capacity());
+        let candidate = max_limbs(max_limb_bound);
+
+        Circuit::scoped("Reduce at max limb bound", |_| {
+            let reduced = candidate.reduce();
+            assert_eq!(candidate.eject_value(), reduced.eject_value());
+            assert!(reduced.is_reduced());
+            assert!(Circuit::is_satisfied());
+        });
+    }
+
+    #[test]
+    fn test_rejects_incongruent_result() {
+        let host_capacity = NonNativeField::<Circuit, P>::host_capacity();
+        let candidate = max_limbs(NonNativeInteger::<P>::max_limb_bound(host_capacity));
+        let values: Vec<_> = candidate.limbs.iter().map(|limb| to_biguint(&limb.eject_value())).collect();
+        let integer = console::from_limbs::<P>(&values);
+        let num_quotient_limbs = NonNativeInteger::<P>::num_quotient_limbs(&candidate.limb_bound, P::NUM_LIMBS);
+        let expected = NonNativeInteger::<P>::new(&candidate.eject_value());
+        let quotient = NonNativeInteger::quotient(&values, &expected, num_quotient_limbs).unwrap();
+        let quotient_integer = console::from_limbs::<P>(&quotient);
+
+        // Ensure the honest quotient and remainder are accepted.
+        Circuit::scoped("Congruent", |scope| {
+            let result = NonNativeField::<Circuit, P>::from_integer(Mode::Private, &expected);
+            let (limbs, limb_bound) = (&candidate.limbs, &candidate.limb_bound);
+            NonNativeField::enforce_congruent_with_quotient(limbs, limb_bound, &quotient, &result);
+            assert!(scope.is_satisfied());
+        });
+
+        // Ensure an off-by-one remainder is rejected.
+        Circuit::scoped("Incongruent", |scope| {
+            let value = expected.to_field() + <P as NonNativeParameters>::Field::one();
+            let result = NonNativeField::<Circuit, P>::new(Mode::Private, value);
+            let (limbs, limb_bound) = (&candidate.limbs, &candidate.limb_bound);
+            NonNativeField::enforce_congruent_with_quotient(limbs, limb_bound, &quotient, &result);
+            assert!(!scope.is_satisfied());
+        });
+        Circuit::reset_circuit();
+
+        // Ensure a quotient with an unreduced limb is rejected, even though it equals the honest quotient.
+        let mut unreduced = quotient.clone();
+        let (index, _) = quotient.iter().enumerate().skip(1).find(|(_, limb)| limb.bits() > 0).unwrap();
+        unreduced[index] -= 1u32;
+        unreduced[index - 1] += BigUint::from(1u32) << P::BITS_PER_LIMB;
+        assert_eq!(quotient_integer, console::from_limbs::<P>(&unreduced));
+
+        Circuit::scoped("Unreduced quotient", |scope| {
+            let result = NonNativeField::<Circuit, P>::from_integer(Mode::Private, &expected);
+            let (limbs, limb_bound) = (&candidate.limbs, &candidate.limb_bound);
+            NonNativeField::enforce_congruent_with_quotient(limbs, limb_bound, &unreduced, &result);
+            assert!(!scope.is_satisfied());
+        });
+        Circuit::reset_circuit();
+
+        // Ensure the integer is unchanged by the checks.
+        assert_eq!(integer, console::from_limbs::<P>(&values));
+    }
+
+    #[test]
+    fn test_halts_beyond_capacity() {
+        let host_capacity = NonNativeField::<Circuit, P>::host_capacity();
+        let candidate = max_limbs(NonNativeInteger::<P>::reduced_limb_bound());
+        let result = NonNativeField::<Circuit, P>::new(Mode::Private, candidate.eject_value());
+
+        // Ensure coefficients that may wrap around the base field cannot be compared.
+        let coefficient_bound = BigUint::from(1u32) << host_capacity;
+        let outcome = Circuit::catch_halt(|| {
+            NonNativeField::enforce_congruent(&candidate.limbs, &coefficient_bound, &result);
+        });
+        assert!(outcome.is_err());
+        Circuit::reset_circuit();
+    }
+}
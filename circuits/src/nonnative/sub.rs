@@ -0,0 +1,166 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Sub<NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: NonNativeField<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Sub<NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: NonNativeField<E, P>) -> Self::Output {
+        self - &other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Sub<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: &NonNativeField<E, P>) -> Self::Output {
+        &self - other
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Sub<&NonNativeField<E, P>> for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn sub(self, other: &NonNativeField<E, P>) -> Self::Output {
+        let mut output = (*self).clone();
+        output -= other;
+        output
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> SubAssign<NonNativeField<E, P>> for NonNativeField<E, P> {
+    fn sub_assign(&mut self, other: NonNativeField<E, P>) {
+        *self -= &other;
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> SubAssign<&NonNativeField<E, P>> for NonNativeField<E, P> {
+    ///
+    /// Subtracts the limbs of `other` from the limbs of `self`, without reducing.
+    ///
+    /// To keep every limb non-negative, a constant multiple of the modulus is added, whose limbs
+    /// are each at least the limb bound of `other` (see `NonNativeInteger::padding`).
+    ///
+    /// This method costs 0 constraints, unless the limbs of the difference would exceed
+    /// `NonNativeInteger::max_limb_bound`, in which case the operand with the larger limbs is reduced first.
+    ///
+    fn sub_assign(&mut self, other: &NonNativeField<E, P>) {
+        if self.is_constant() && other.is_constant() {
+            *self = Self::new(Mode::Constant, self.eject_value() - other.eject_value());
+            return;
+        }
+
+        let max_limb_bound = NonNativeInteger::<P>::max_limb_bound(Self::host_capacity());
+        let (first, second) = Self::reduce_until(self, other, |first, second| {
+            NonNativeInteger::<P>::sub_limb_bound(first, second) <= max_limb_bound
+        });
+
+        let padding = NonNativeInteger::<P>::padding(&second.limb_bound);
+        self.limbs = first
+            .limbs
+            .iter()
+            .zip(&padding)
+            .zip(&second.limbs)
+            .map(|((a, d), b)| a + BaseField::new(Mode::Constant, from_biguint(d)) - b)
+            .collect();
+        self.limb_bound = NonNativeInteger::<P>::sub_limb_bound(&first.limb_bound, &second.limb_bound);
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Neg for NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<E: Environment, P: NonNativeParameters> Neg for &NonNativeField<E, P> {
+    type Output = NonNativeField<E, P>;
+
+    ///
+    /// Returns `0 - self`, which costs 0 constraints.
+    ///
+    fn neg(self) -> Self::Output {
+        NonNativeField::new(Mode::Constant, P::Field::zero()) - self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    const ITERATIONS: usize = 10;
+
+    fn run_test(mode_a: Mode, mode_b: Mode) {
+        for i in 0..ITERATIONS {
+            let first: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let a = NonNativeField::<Circuit, P>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, P>::new(mode_b, second);
+
+            Circuit::scoped(&format!("Sub: {:?} - {:?} {}", mode_a, mode_b, i), |scope| {
+                let candidate = &a - &b;
+                assert_eq!(first - second, candidate.eject_value());
+                assert_eq!(0, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_sub() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(mode_a, mode_b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sub_self_and_neg() {
+        for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+            let value: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let candidate = NonNativeField::<Circuit, P>::new(mode, value);
+
+            Circuit::scoped(&format!("Sub self and neg: {:?}", mode), |scope| {
+                assert!((&candidate - &candidate).eject_value().is_zero());
+                assert_eq!(-value, (-candidate.clone()).eject_value());
+                assert_eq!(0, scope.num_constraints_in_scope());
+            });
+
+            // Ensure the reduced difference is zero, which requires the padding to be a multiple of the modulus.
+            let difference = (&candidate - &candidate).reduce();
+            assert!(difference.eject_value().is_zero());
+            assert!(Circuit::is_satisfied());
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment, P: NonNativeParameters> Ternary for NonNativeField<E, P> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    ///
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    ///
+    /// The limbs are selected one at a time, which costs at most `P::NUM_LIMBS` constraints,
+    /// and the limbs of the output are bounded by the larger limb bound of the operands.
+    ///
+    fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output {
+        let limbs = first
+            .limbs
+            .iter()
+            .zip(&second.limbs)
+            .map(|(first, second)| BaseField::ternary(condition, first, second))
+            .collect();
+        Self {
+            limbs,
+            limb_bound: std::cmp::max(&first.limb_bound, &second.limb_bound).clone(),
+            _parameters: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Circuit;
+    use snarkvm_utilities::UniformRand;
+
+    use rand::thread_rng;
+
+    type P = Secp256k1BaseFieldParameters;
+
+    fn run_test(mode_condition: Mode, mode_a: Mode, mode_b: Mode, num_constraints: usize) {
+        for flag in [true, false] {
+            let first: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+            let condition = Boolean::<Circuit>::new(mode_condition, flag);
+            let a = NonNativeField::<Circuit, P>::new(mode_a, first);
+            let b = NonNativeField::<Circuit, P>::new(mode_b, second);
+
+            let name = format!("Ternary: {:?} ? {:?} : {:?} {}", mode_condition, mode_a, mode_b, flag);
+            Circuit::scoped(&name, |scope| {
+                let candidate = NonNativeField::ternary(&condition, &a, &b);
+                assert_eq!(if flag { first } else { second }, candidate.eject_value());
+                assert!(candidate.is_reduced());
+                assert_eq!(num_constraints, scope.num_constraints_in_scope());
+                assert!(Circuit::is_satisfied());
+            });
+        }
+    }
+
+    #[test]
+    fn test_ternary() {
+        for mode_a in [Mode::Constant, Mode::Public, Mode::Private] {
+            for mode_b in [Mode::Constant, Mode::Public, Mode::Private] {
+                run_test(Mode::Constant, mode_a, mode_b, 0);
+            }
+        }
+        run_test(Mode::Private, Mode::Constant, Mode::Constant, 0);
+        run_test(Mode::Private, Mode::Private, Mode::Constant, P::NUM_LIMBS);
+        run_test(Mode::Private, Mode::Public, Mode::Private, P::NUM_LIMBS);
+    }
+
+    #[test]
+    fn test_ternary_unreduced() {
+        let first: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+        let second: <P as NonNativeParameters>::Field = UniformRand::rand(&mut thread_rng());
+        let a = NonNativeField::<Circuit, P>::new(Mode::Private, first);
+        let b = NonNativeField::<Circuit, P>::new(Mode::Private, second);
+        let sum = &a + &b;
+
+        // Ensure the output is bounded by the larger limb bound, whichever operand is selected.
+        for flag in [true, false] {
+            let condition = Boolean::<Circuit>::new(Mode::Private, flag);
+            let candidate = NonNativeField::ternary(&condition, &a, &sum);
+            assert_eq!(if flag { first } else { first + second }, candidate.eject_value());
+            assert_eq!(sum.limb_bound(), candidate.limb_bound());
+        }
+        assert!(Circuit::is_satisfied());
+    }
+}
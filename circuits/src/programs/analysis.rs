@@ -0,0 +1,257 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use snarkvm_fields::Zero as Z;
+
+use std::collections::BTreeSet;
+
+/// A finding of the static analysis of a function.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Lint {
+    /// The register written by the instruction is never read, and is not an output of the function.
+    UnusedRegister { instruction: usize, register: u32 },
+    /// The instruction only has constant operands, so the register it writes always holds the same constant.
+    ConstantOutput { instruction: usize, register: u32 },
+    /// The instruction, and every instruction after it, is never executed, as an earlier instruction always fails.
+    UnreachableCode { instruction: usize },
+}
+
+impl<E: Environment> Value<E> {
+    /// Returns `true` if the value is a constant, given the registers that are known to hold constants.
+    fn is_constant_in(&self, constant_registers: &BTreeSet<u32>) -> bool {
+        match self {
+            Self::Boolean(value) => value.is_constant(),
+            Self::BaseField(value) => value.is_constant(),
+            Self::Group(value) => value.is_constant(),
+            Self::Composite(members) => members.iter().all(|member| member.is_constant_in(constant_registers)),
+            Self::Register(register) => constant_registers.contains(&register.0.0),
+        }
+    }
+
+    /// Returns `true` if the value is a constant base field equal to zero.
+    fn is_constant_zero(&self) -> bool {
+        match self {
+            Self::BaseField(value) => value.is_constant() && value.eject_value().is_zero(),
+            _ => false,
+        }
+    }
+
+    /// Adds the locators of the registers the value reads to `registers`.
+    fn collect_registers(&self, registers: &mut BTreeSet<u32>) {
+        match self {
+            Self::Composite(members) => members.iter().for_each(|member| member.collect_registers(registers)),
+            Self::Register(register) => {
+                registers.insert(register.0.0);
+            }
+            _ => (),
+        }
+    }
+}
+
+impl<E: Environment> Instruction<E> {
+    /// Returns the values the instruction reads.
+    fn operands(&self) -> Vec<&Value<E>> {
+        match self {
+            Self::Store(value, _) => vec![value],
+            Self::Add(first, second, _) => vec![first, second],
+            Self::Div(first, second, _) => vec![first, second],
+            Self::Ternary(condition, first, second, _) => vec![condition, first, second],
+            Self::IsEq(first, second, _) => vec![first, second],
+            Self::AssertRange(value, _) => vec![value],
+            Self::DivEuclid(first, second, _, _) => vec![first, second],
+            Self::RemEuclid(first, second, _, _) => vec![first, second],
+        }
+    }
+
+    /// Returns `true` if evaluating the instruction fails for any values of the registers it reads.
+    fn always_fails(&self) -> bool {
+        match self {
+            Self::Div(_, second, _) => second.is_constant_zero(),
+            Self::DivEuclid(_, second, num_bits, _) | Self::RemEuclid(_, second, num_bits, _) => {
+                *num_bits == 0 || *num_bits > 64 || second.is_constant_zero()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<E: Environment> Function<E> {
+    /// Returns the lints of the function, in the order of its instructions.
+    /// The function is analyzed from the definitions and uses of its registers, without evaluating it.
+    pub fn analyze(&self) -> Vec<Lint> {
+        // An instruction that always fails halts the function, so the instructions after it are never executed.
+        let num_reachable = match self.instructions.iter().position(|instruction| instruction.always_fails()) {
+            Some(index) => index + 1,
+            None => self.instructions.len(),
+        };
+        let reachable = &self.instructions[..num_reachable];
+
+        // Collect the registers read by any reachable instruction.
+        let mut reads = BTreeSet::new();
+        for instruction in reachable {
+            for operand in instruction.operands() {
+                operand.collect_registers(&mut reads);
+            }
+        }
+        let outputs: BTreeSet<u32> = self.outputs.iter().map(|register| register.0.0).collect();
+
+        let mut constant_registers = BTreeSet::new();
+        let mut lints = Vec::new();
+        for (index, instruction) in reachable.iter().enumerate() {
+            let destination = match instruction.destination() {
+                Some(register) => register.0.0,
+                None => continue,
+            };
+
+            // Propagate constants through registers. Storing a constant input is not a lint.
+            if instruction.operands().iter().all(|operand| operand.is_constant_in(&constant_registers)) {
+                constant_registers.insert(destination);
+                if !matches!(instruction, Instruction::Store(..)) {
+                    lints.push(Lint::ConstantOutput {
+                        instruction: index,
+                        register: destination,
+                    });
+                }
+            }
+
+            if !reads.contains(&destination) && !outputs.contains(&destination) {
+                lints.push(Lint::UnusedRegister {
+                    instruction: index,
+                    register: destination,
+                });
+            }
+        }
+
+        if num_reachable < self.instructions.len() {
+            lints.push(Lint::UnreachableCode {
+                instruction: num_reachable,
+            });
+        }
+        lints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Mode};
+
+    fn field(mode: Mode, value: u64) -> Value<Circuit> {
+        Value::BaseField(BaseField::new(mode, value.into()))
+    }
+
+    #[test]
+    fn test_hello_world_has_no_lints() {
+        let function = HelloWorld::new([field(Mode::Private, 1), field(Mode::Public, 2)]);
+        assert!(function.function().analyze().is_empty());
+    }
+
+    #[test]
+    fn test_unused_register() {
+        let mut function = Function::<Circuit>::new();
+        let first = function.new_input(field(Mode::Private, 1));
+        let unused = function.new_input(field(Mode::Private, 2));
+        let output = function.new_output();
+        function.push_instruction(Instruction::Add(
+            Value::Register(first.clone()),
+            Value::Register(first),
+            output,
+        ));
+
+        assert_eq!(
+            vec![Lint::UnusedRegister {
+                instruction: 1,
+                register: unused.0.0
+            }],
+            function.analyze()
+        );
+    }
+
+    #[test]
+    fn test_constant_output() {
+        let mut function = Function::<Circuit>::new();
+        let first = function.new_input(field(Mode::Constant, 1));
+        let second = function.new_input(field(Mode::Constant, 2));
+        let private = function.new_input(field(Mode::Private, 3));
+        let sum = function.new_register();
+        let doubled = function.new_output();
+        let output = function.new_output();
+        let sum_register = sum.0.0;
+        function.push_instruction(Instruction::Add(
+            Value::Register(first),
+            Value::Register(second),
+            sum.clone(),
+        ));
+        // The constant propagates through the register of the sum.
+        function.push_instruction(Instruction::Add(
+            Value::Register(sum.clone()),
+            Value::Register(sum.clone()),
+            doubled.clone(),
+        ));
+        // A private operand is not a constant.
+        function.push_instruction(Instruction::Add(
+            Value::Register(sum),
+            Value::Register(private),
+            output,
+        ));
+
+        assert_eq!(
+            vec![
+                Lint::ConstantOutput {
+                    instruction: 3,
+                    register: sum_register
+                },
+                Lint::ConstantOutput {
+                    instruction: 4,
+                    register: doubled.0.0
+                },
+            ],
+            function.analyze()
+        );
+    }
+
+    #[test]
+    fn test_unreachable_code() {
+        // Returns a function that divides its input, and then doubles it.
+        let function = |divide: &dyn Fn(Value<Circuit>, Register<Circuit>) -> Instruction<Circuit>| {
+            let mut function = Function::<Circuit>::new();
+            let input = function.new_input(field(Mode::Private, 1));
+            let quotient = function.new_output();
+            let sum = function.new_output();
+            function.push_instruction(divide(Value::Register(input.clone()), quotient));
+            function.push_instruction(Instruction::Add(
+                Value::Register(input.clone()),
+                Value::Register(input),
+                sum,
+            ));
+            function
+        };
+
+        // Dividing by a constant zero always fails, so the addition is never executed.
+        let divide_by_zero = function(&|input, register| Instruction::Div(input, field(Mode::Constant, 0), register));
+        assert_eq!(vec![Lint::UnreachableCode { instruction: 2 }], divide_by_zero.analyze());
+
+        // A Euclidean division of more than 64 bits always fails.
+        let divide_too_wide =
+            function(&|input, register| Instruction::DivEuclid(input, field(Mode::Constant, 2), 65, register));
+        assert_eq!(vec![Lint::UnreachableCode { instruction: 2 }], divide_too_wide.analyze());
+
+        // Dividing by a private value, even zero, may succeed as far as the analysis knows.
+        let divide_by_private = function(&|input, register| Instruction::Div(input, field(Mode::Private, 0), register));
+        assert!(divide_by_private.analyze().is_empty());
+    }
+}
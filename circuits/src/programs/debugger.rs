@@ -0,0 +1,255 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The outcome of evaluating a single instruction in a debugger.
+#[derive(Clone)]
+pub struct StepResult<E: Environment> {
+    /// The index of the evaluated instruction in the function.
+    pub instruction_index: usize,
+    /// The opcode of the evaluated instruction.
+    pub opcode: u16,
+    /// The registers written by the instruction, as pairs of register locators and values.
+    pub destination_writes: Vec<(u32, Value<E>)>,
+}
+
+/// Evaluates a function one instruction at a time, exposing its registers between instructions.
+pub struct FunctionDebugger<'a, E: Environment> {
+    function: &'a Function<E>,
+    /// The index of the next instruction to evaluate.
+    next_instruction: usize,
+    /// The indices of the instructions to pause before.
+    breakpoints: BTreeSet<usize>,
+}
+
+impl<'a, E: Environment> FunctionDebugger<'a, E> {
+    /// Initializes a new debugger, paused before the first instruction of the given function.
+    /// The function must not have been evaluated already.
+    pub fn new(function: &'a Function<E>) -> Self {
+        Self {
+            function,
+            next_instruction: 0,
+            breakpoints: Default::default(),
+        }
+    }
+
+    /// Returns the index of the next instruction to evaluate, or `None` if the function is complete.
+    pub fn next_instruction_index(&self) -> Option<usize> {
+        match self.is_complete() {
+            true => None,
+            false => Some(self.next_instruction),
+        }
+    }
+
+    /// Returns `true` if every instruction of the function is evaluated.
+    pub fn is_complete(&self) -> bool {
+        self.next_instruction >= self.function.instructions.len()
+    }
+
+    /// Adds a breakpoint before the instruction at the given index.
+    pub fn add_breakpoint(&mut self, instruction_index: usize) {
+        self.breakpoints.insert(instruction_index);
+    }
+
+    /// Removes the breakpoint before the instruction at the given index, returning `true` if it existed.
+    pub fn remove_breakpoint(&mut self, instruction_index: usize) -> bool {
+        self.breakpoints.remove(&instruction_index)
+    }
+
+    /// Returns the values of the registers that are set, keyed by their register locator.
+    pub fn registers(&self) -> BTreeMap<u32, Value<E>> {
+        self.function.memory.borrow().to_set_registers()
+    }
+
+    /// Evaluates the next instruction, returning `None` if the function is complete.
+    /// If the instruction fails, the debugger remains paused before it.
+    pub fn step(&mut self) -> Result<Option<StepResult<E>>, EvaluationError> {
+        let instruction_index = match self.next_instruction_index() {
+            Some(index) => index,
+            None => return Ok(None),
+        };
+        let instruction = &self.function.instructions[instruction_index];
+        Function::evaluate_instruction(instruction_index, instruction)?;
+        self.next_instruction += 1;
+
+        Ok(Some(StepResult {
+            instruction_index,
+            opcode: instruction.opcode(),
+            destination_writes: instruction
+                .destination()
+                .map(|register| (register.0.0, register.load()))
+                .into_iter()
+                .collect(),
+        }))
+    }
+
+    /// Evaluates instructions until the next breakpoint or the end of the function,
+    /// returning the outcome of each evaluated instruction.
+    /// Evaluates at least one instruction, so a paused debugger moves past its current breakpoint.
+    pub fn resume(&mut self) -> Result<Vec<StepResult<E>>, EvaluationError> {
+        let mut steps = Vec::new();
+        while let Some(step) = self.step()? {
+            steps.push(step);
+            match self.next_instruction_index() {
+                Some(index) if self.breakpoints.contains(&index) => break,
+                _ => continue,
+            }
+        }
+        Ok(steps)
+    }
+
+    /// Evaluates every remaining instruction, ignoring breakpoints,
+    /// returning the outcome of each evaluated instruction.
+    pub fn run_to_completion(&mut self) -> Result<Vec<StepResult<E>>, EvaluationError> {
+        let mut steps = Vec::new();
+        while let Some(step) = self.step()? {
+            steps.push(step);
+        }
+        Ok(steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Circuit, Mode};
+
+    fn expect_base_field(value: &Value<Circuit>, expected: u64) {
+        match value {
+            Value::BaseField(value) => assert_eq!(
+                BaseField::<Circuit>::new(Mode::Constant, expected.into()).eject_value(),
+                value.eject_value()
+            ),
+            _ => panic!("Expected a base field"),
+        }
+    }
+
+    #[test]
+    fn test_step() {
+        let function = HelloWorld::new([
+            Value::BaseField(BaseField::<Circuit>::new(Mode::Private, 2u64.into())),
+            Value::BaseField(BaseField::new(Mode::Private, 3u64.into())),
+        ]);
+        let mut debugger = FunctionDebugger::new(function.function());
+        assert_eq!(Some(0), debugger.next_instruction_index());
+        assert!(debugger.registers().is_empty());
+
+        // Step through the stores of the inputs.
+        let step = debugger.step().unwrap().unwrap();
+        assert_eq!(0, step.instruction_index);
+        assert_eq!(0, step.opcode);
+        assert_eq!(1, step.destination_writes.len());
+        assert_eq!(0, step.destination_writes[0].0);
+        expect_base_field(&step.destination_writes[0].1, 2);
+        assert_eq!(1, debugger.registers().len());
+
+        debugger.step().unwrap().unwrap();
+        let registers = debugger.registers();
+        assert_eq!(vec![0, 1], registers.keys().copied().collect::<Vec<_>>());
+        expect_base_field(&registers[&1], 3);
+
+        // Step through the addition.
+        let step = debugger.step().unwrap().unwrap();
+        assert_eq!(2, step.instruction_index);
+        assert_eq!(1, step.opcode);
+        assert_eq!(2, step.destination_writes[0].0);
+        expect_base_field(&step.destination_writes[0].1, 5);
+        expect_base_field(&debugger.registers()[&2], 5);
+
+        assert!(debugger.is_complete());
+        assert!(debugger.step().unwrap().is_none());
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let mut function = Function::<Circuit>::new();
+        let input = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 255u64.into())));
+        let output = function.new_register();
+        function.push_instruction(Instruction::AssertRange(Value::Register(input.clone()), 8));
+        function.push_instruction(Instruction::Add(
+            Value::Register(input.clone()),
+            Value::Register(input),
+            output,
+        ));
+
+        let mut debugger = FunctionDebugger::new(&function);
+        debugger.add_breakpoint(1);
+        debugger.add_breakpoint(2);
+
+        // Check the debugger pauses before each breakpoint.
+        let steps = debugger.resume().unwrap();
+        assert_eq!(
+            vec![0],
+            steps.iter().map(|step| step.instruction_index).collect::<Vec<_>>()
+        );
+        assert_eq!(Some(1), debugger.next_instruction_index());
+        assert_eq!(1, debugger.registers().len());
+
+        // Check an instruction without a destination writes no registers.
+        let steps = debugger.resume().unwrap();
+        assert_eq!(1, steps.len());
+        assert!(steps[0].destination_writes.is_empty());
+        assert_eq!(Some(2), debugger.next_instruction_index());
+
+        // Check a removed breakpoint is ignored.
+        assert!(debugger.remove_breakpoint(2));
+        assert!(!debugger.remove_breakpoint(2));
+        let steps = debugger.resume().unwrap();
+        assert_eq!(2, steps[0].instruction_index);
+        expect_base_field(&debugger.registers()[&1], 510);
+        assert!(debugger.resume().unwrap().is_empty());
+        assert!(Circuit::is_satisfied());
+    }
+
+    #[test]
+    fn test_run_to_completion() {
+        let function = HelloWorld::new([
+            Value::BaseField(BaseField::<Circuit>::one()),
+            Value::BaseField(BaseField::one()),
+        ]);
+        let mut debugger = FunctionDebugger::new(function.function());
+        debugger.add_breakpoint(1);
+
+        // Check breakpoints are ignored.
+        let steps = debugger.run_to_completion().unwrap();
+        assert_eq!(
+            vec![0, 1, 2],
+            steps.iter().map(|step| step.instruction_index).collect::<Vec<_>>()
+        );
+        assert!(debugger.is_complete());
+        expect_base_field(&debugger.registers()[&2], 2);
+    }
+
+    #[test]
+    fn test_step_failure() {
+        let mut function = Function::<Circuit>::new();
+        let input = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 1u64.into())));
+        let zero = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 0u64.into())));
+        let output = function.new_register();
+        function.push_instruction(Instruction::Div(Value::Register(input), Value::Register(zero), output));
+
+        // Check the debugger stops with an error, paused before the failed instruction.
+        let mut debugger = FunctionDebugger::new(&function);
+        let error = debugger.run_to_completion().err().unwrap();
+        assert_eq!(2, error.instruction);
+        assert_eq!(Some(2), debugger.next_instruction_index());
+        assert_eq!(2, debugger.registers().len());
+    }
+}
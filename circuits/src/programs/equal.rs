@@ -0,0 +1,136 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Mode;
+
+impl<E: Environment> Equal<Self> for Value<E> {
+    type Boolean = Boolean<E>;
+    type Output = Boolean<E>;
+
+    ///
+    /// Returns `true` if `self` and `other` are equal.
+    ///
+    /// Composite values are compared member by member, costing the constraints
+    /// of the member comparisons, and one conjunction for each additional member.
+    ///
+    fn is_eq(&self, other: &Self) -> Self::Output {
+        match (self.to_value(), other.to_value()) {
+            (Value::Boolean(a), Value::Boolean(b)) => a.is_eq(&b),
+            (Value::BaseField(a), Value::BaseField(b)) => a.is_eq(&b),
+            (Value::Group(a), Value::Group(b)) => a.is_eq(&b),
+            (Value::Composite(a), Value::Composite(b)) if a.len() == b.len() => a
+                .iter()
+                .zip(b.iter())
+                .map(|(a, b)| a.is_eq(b))
+                .reduce(|is_eq, is_member_eq| is_eq.and(&is_member_eq))
+                .unwrap_or_else(|| Boolean::new(Mode::Constant, true)),
+            _ => E::halt("Attempted to compare values of different types"),
+        }
+    }
+
+    ///
+    /// Returns `true` if `self` and `other` are *not* equal.
+    ///
+    /// Composite values are compared member by member, costing the constraints
+    /// of the member comparisons, and one conjunction for each additional member.
+    ///
+    fn is_neq(&self, other: &Self) -> Self::Output {
+        !self.is_eq(other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{programs::test_helpers::NativeValue, Circuit};
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+    const DEPTH: usize = 3;
+
+    #[test]
+    fn test_is_eq() {
+        for i in 0..ITERATIONS {
+            let first = NativeValue::sample(DEPTH, &mut thread_rng());
+            let second = first.sample_like(&mut thread_rng());
+            let perturbed = first.with_last_leaf_changed(&mut thread_rng());
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = first.to_circuit(mode);
+                let b = first.to_circuit(mode);
+                let c = perturbed.to_circuit(mode);
+                let d = second.to_circuit(mode);
+
+                Circuit::scoped(&format!("{:?} Equals {}", mode, i), |scope| {
+                    // Ensure the circuit results match the native results.
+                    assert!(a.is_eq(&b).eject_value());
+                    assert!(!a.is_eq(&c).eject_value());
+                    assert!(a.is_neq(&c).eject_value());
+                    assert_eq!(first == second, a.is_eq(&d).eject_value());
+                    assert!(scope.is_satisfied());
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_eq_is_linear() {
+        // Composites of `n` private base fields cost 3 constraints per member, and 2 per conjunction.
+        for num_members in 1..8 {
+            let value = NativeValue::Composite(vec![NativeValue::BaseField(Default::default()); num_members]);
+            let a = value.to_circuit(Mode::Private);
+            let b = value.to_circuit(Mode::Private);
+
+            Circuit::scoped(&format!("Linear {}", num_members), |scope| {
+                assert!(a.is_eq(&b).eject_value());
+                assert_eq!(5 * num_members - 2, scope.num_constraints_in_scope());
+            });
+        }
+    }
+
+    #[test]
+    fn test_is_eq_halts_on_different_types() {
+        let a = NativeValue::Composite(vec![NativeValue::Boolean(true)]).to_circuit(Mode::Private);
+        let b = NativeValue::Composite(vec![NativeValue::Boolean(true); 2]).to_circuit(Mode::Private);
+        let c = NativeValue::Composite(vec![NativeValue::BaseField(Default::default())]).to_circuit(Mode::Private);
+
+        assert!(Circuit::catch_halt(|| a.is_eq(&b)).is_err());
+        assert!(Circuit::catch_halt(|| a.is_eq(&c)).is_err());
+    }
+
+    #[test]
+    fn test_is_eq_registers() {
+        let value = NativeValue::sample(DEPTH, &mut thread_rng());
+
+        let mut function = Function::<Circuit>::new();
+        let first = function.new_input(value.to_circuit(Mode::Private));
+        let second = function.new_input(value.to_circuit(Mode::Private));
+        let output = function.new_register();
+        function.push_instruction(Instruction::IsEq(
+            Value::Register(first),
+            Value::Register(second),
+            output.clone(),
+        ));
+        function.evaluate();
+
+        match output.load() {
+            Value::Boolean(output) => assert!(output.eject_value()),
+            _ => panic!("Failed to load output"),
+        }
+    }
+}
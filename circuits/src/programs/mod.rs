@@ -14,16 +14,32 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{traits::*, Affine, BaseField, Boolean, Environment};
+pub mod analysis;
+pub use analysis::*;
+
+pub mod debugger;
+pub use debugger::*;
+
+pub mod equal;
+pub mod ternary;
+
+#[cfg(test)]
+mod test_helpers;
+
+use crate::{traits::*, Affine, BaseField, Boolean, Environment, Mode};
+use snarkvm_utilities::ToBits as TBits;
 
 use once_cell::unsync::OnceCell;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+use thiserror::Error;
 
 #[derive(Clone)]
 pub enum Value<E: Environment> {
     Boolean(Boolean<E>),
     BaseField(BaseField<E>),
     Group(Affine<E>),
+    /// A struct or array value, as the ordered list of its members.
+    Composite(Vec<Value<E>>),
     Register(Register<E>),
 }
 
@@ -122,6 +138,15 @@ impl<E: Environment> Memory<E> {
     fn num_registers(&self) -> u32 {
         self.registers.len() as u32
     }
+
+    /// Returns the values of the registers that are set, keyed by their register locator.
+    fn to_set_registers(&self) -> BTreeMap<u32, Value<E>> {
+        self.registers
+            .iter()
+            .enumerate()
+            .filter_map(|(locator, register)| register.get().map(|value| (locator as u32, value.clone())))
+            .collect()
+    }
 }
 
 pub enum Instruction<E: Environment> {
@@ -129,6 +154,20 @@ pub enum Instruction<E: Environment> {
     Store(Value<E>, Register<E>),
     /// Adds `first` with `second`, storing the outcome in `register`.
     Add(Value<E>, Value<E>, Register<E>),
+    /// Divides `first` by `second`, storing the outcome in `register`.
+    Div(Value<E>, Value<E>, Register<E>),
+    /// Selects `first` if `condition` is `true`, otherwise `second`, storing the outcome in `register`.
+    Ternary(Value<E>, Value<E>, Value<E>, Register<E>),
+    /// Checks if `first` is equal to `second`, storing the outcome in `register`.
+    IsEq(Value<E>, Value<E>, Register<E>),
+    /// Asserts that `value` is less than `2^num_bits`.
+    AssertRange(Value<E>, usize),
+    /// Divides `first` by `second` as signed `num_bits`-bit integers, rounding towards negative
+    /// infinity for positive divisors, storing the Euclidean quotient in `register`.
+    DivEuclid(Value<E>, Value<E>, usize, Register<E>),
+    /// Divides `first` by `second` as signed `num_bits`-bit integers,
+    /// storing the non-negative Euclidean remainder in `register`.
+    RemEuclid(Value<E>, Value<E>, usize, Register<E>),
 }
 
 impl<E: Environment> Instruction<E> {
@@ -137,27 +176,59 @@ impl<E: Environment> Instruction<E> {
         match self {
             Self::Store(..) => 0,
             Self::Add(..) => 1,
+            Self::Ternary(..) => 2,
+            Self::IsEq(..) => 3,
+            Self::AssertRange(..) => 4,
+            Self::Div(..) => 5,
+            Self::DivEuclid(..) => 6,
+            Self::RemEuclid(..) => 7,
         }
     }
 
-    /// Evaluates the instruction.
-    fn evaluate(&self) {
+    /// Returns the register the instruction stores its outcome in, if any.
+    fn destination(&self) -> Option<&Register<E>> {
         match self {
+            Self::Store(_, register) => Some(register),
+            Self::Add(_, _, register) => Some(register),
+            Self::Div(_, _, register) => Some(register),
+            Self::Ternary(_, _, _, register) => Some(register),
+            Self::IsEq(_, _, register) => Some(register),
+            Self::AssertRange(..) => None,
+            Self::DivEuclid(_, _, _, register) => Some(register),
+            Self::RemEuclid(_, _, _, register) => Some(register),
+        }
+    }
+
+    /// Evaluates the instruction, returning the reason it failed, if any.
+    /// Halts of the environment in the gadgets of the instruction are returned as failures.
+    fn evaluate(&self) -> Result<(), String> {
+        let outcome = E::catch_halt(|| match self {
             Self::Store(..) => self.store(),
             Self::Add(..) => self.add(),
+            Self::Div(..) => self.div(),
+            Self::Ternary(..) => self.ternary(),
+            Self::IsEq(..) => self.is_eq(),
+            Self::AssertRange(..) => self.assert_range(),
+            Self::DivEuclid(..) | Self::RemEuclid(..) => self.div_rem_euclid(),
+        });
+
+        match outcome {
+            Ok(result) => result,
+            Err(halt) => Err(halt.message().to_string()),
         }
     }
 
     /// Stores `value` into `register`, if `register` is not already set.
-    fn store(&self) {
+    fn store(&self) -> Result<(), String> {
         match self {
             Self::Store(value, register) => register.store(value),
             _ => unreachable!(),
         };
+        Ok(())
     }
 
     /// Adds `first` with `second`, storing the outcome in `register`.
-    fn add(&self) {
+    fn add(&self) -> Result<(), String> {
         // Load the values and register.
         let (first, second, register) = match self {
             Self::Add(first, second, register) => (first, second, register),
@@ -168,14 +239,181 @@ impl<E: Environment> Instruction<E> {
         match (first.to_value(), second.to_value()) {
             (Value::BaseField(a), Value::BaseField(b)) => register.store(&Value::BaseField(a + b)),
             (Value::Group(a), Value::Group(b)) => register.store(&Value::Group(a + b)),
+            _ => return Err("The operands of an addition must both be base fields or groups".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Divides `first` by `second`, storing the outcome in `register`.
+    fn div(&self) -> Result<(), String> {
+        // Load the values and register.
+        let (first, second, register) = match self {
+            Self::Div(first, second, register) => (first, second, register),
             _ => unreachable!(),
+        };
+
+        // Perform the operation.
+        match (first.to_value(), second.to_value()) {
+            (Value::BaseField(a), Value::BaseField(b)) => match a.checked_div(&b) {
+                Some(quotient) => register.store(&Value::BaseField(quotient)),
+                None => return Err("Attempted to divide by zero".to_string()),
+            },
+            _ => return Err("The operands of a division must be base fields".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Selects `first` if `condition` is `true`, otherwise `second`, storing the outcome in `register`.
+    fn ternary(&self) -> Result<(), String> {
+        // Load the values and register.
+        let (condition, first, second, register) = match self {
+            Self::Ternary(condition, first, second, register) => (condition, first, second, register),
+            _ => unreachable!(),
+        };
+
+        // Perform the operation.
+        match condition.to_value() {
+            Value::Boolean(condition) => register.store(&Value::ternary(&condition, first, second)),
+            _ => return Err("The condition of a ternary must be a boolean".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Checks if `first` is equal to `second`, storing the outcome in `register`.
+    fn is_eq(&self) -> Result<(), String> {
+        // Load the values and register.
+        let (first, second, register) = match self {
+            Self::IsEq(first, second, register) => (first, second, register),
+            _ => unreachable!(),
+        };
+
+        // Perform the operation.
+        register.store(&Value::Boolean(first.is_eq(second)));
+        Ok(())
+    }
+
+    /// Asserts that `value` is less than `2^num_bits`.
+    fn assert_range(&self) -> Result<(), String> {
+        // Load the value.
+        let (value, num_bits) = match self {
+            Self::AssertRange(value, num_bits) => (value, *num_bits),
+            _ => unreachable!(),
+        };
+
+        // Perform the operation.
+        match value.to_value() {
+            Value::BaseField(value) => value.assert_bits_le(num_bits),
+            _ => return Err("The operand of a range check must be a base field".to_string()),
+        }
+        Ok(())
+    }
+
+    /// Divides `first` by `second` as signed `num_bits`-bit integers, storing the Euclidean
+    /// quotient or remainder in `register`. The operands are base fields, where a negative
+    /// integer `-x` is encoded as the base field `-x`.
+    fn div_rem_euclid(&self) -> Result<(), String> {
+        // Load the values and register.
+        let (first, second, num_bits, register, is_div) = match self {
+            Self::DivEuclid(first, second, num_bits, register) => (first, second, *num_bits, register, true),
+            Self::RemEuclid(first, second, num_bits, register) => (first, second, *num_bits, register, false),
+            _ => unreachable!(),
+        };
+
+        if num_bits == 0 || num_bits > 64 {
+            return Err(format!("Euclidean division supports 1 to 64 bits, found {}", num_bits));
+        }
+
+        let (a, b) = match (first.to_value(), second.to_value()) {
+            (Value::BaseField(a), Value::BaseField(b)) => (a, b),
+            _ => return Err("The operands of a Euclidean division must be base fields".to_string()),
+        };
+
+        // Compute the quotient and remainder natively.
+        let (a_value, b_value) = match (
+            Self::to_signed(a.eject_value(), num_bits),
+            Self::to_signed(b.eject_value(), num_bits),
+        ) {
+            (Some(a_value), Some(b_value)) => (a_value, b_value),
+            _ => return Err(format!("The operands of a Euclidean division must be {}-bit integers", num_bits)),
+        };
+        if b_value == 0 {
+            return Err("Attempted to divide by zero".to_string());
+        }
+        let (q_value, r_value) = (a_value.div_euclid(b_value), a_value.rem_euclid(b_value));
+        if Self::to_signed(Self::from_signed(q_value), num_bits).is_none() {
+            return Err(format!("The Euclidean quotient overflows {} bits", num_bits));
+        }
+
+        let mode = match a.is_constant() && b.is_constant() {
+            true => Mode::Constant,
+            false => Mode::Private,
+        };
+        let quotient = BaseField::new(mode, Self::from_signed(q_value));
+        let remainder = BaseField::new(mode, Self::from_signed(r_value));
+
+        // Ensure the operands and quotient are in [-2^(num_bits - 1), 2^(num_bits - 1)),
+        // and the remainder is in [0, 2^(num_bits - 1)).
+        let offset = BaseField::new(Mode::Constant, Self::from_signed(1i128 << (num_bits - 1)));
+        BaseField::assert_bits_le_many(&[&a + &offset, &b + &offset, &quotient + &offset], num_bits);
+        remainder.assert_bits_le(num_bits - 1);
+
+        // Ensure a == q * b + r, which cannot wrap around the modulus for operands of at most 64 bits.
+        E::assert_eq(&a, &quotient * &b + &remainder);
+
+        // Ensure r < |b|, which also ensures b != 0. An incorrect sign yields |b| == -|b|,
+        // which fails the range check.
+        let is_negative = Boolean::new(mode, b_value < 0);
+        let abs_b = BaseField::ternary(&is_negative, &-&b, &b);
+        (abs_b - &remainder - BaseField::one()).assert_bits_le(num_bits);
+
+        match is_div {
+            true => register.store(&Value::BaseField(quotient)),
+            false => register.store(&Value::BaseField(remainder)),
+        }
+        Ok(())
+    }
+
+    /// Returns the signed integer encoded by the given base field, if it is a `num_bits`-bit integer.
+    fn to_signed(value: E::BaseField, num_bits: usize) -> Option<i128> {
+        let magnitude = |value: E::BaseField| -> Option<i128> {
+            let bits = value.to_bits_le();
+            match bits.iter().skip(num_bits).any(|bit| *bit) {
+                true => None,
+                false => Some(bits.iter().take(num_bits).rev().fold(0i128, |acc, bit| (acc << 1) | *bit as i128)),
+            }
+        };
+        let bound = 1i128 << (num_bits - 1);
+        match (magnitude(value), magnitude(-value)) {
+            (Some(positive), _) if positive < bound => Some(positive),
+            (_, Some(negative)) if negative <= bound => Some(-negative),
+            _ => None,
+        }
+    }
+
+    /// Returns the base field encoding of the given signed integer, which is at most 64 bits.
+    fn from_signed(value: i128) -> E::BaseField {
+        let magnitude = E::BaseField::from(value.unsigned_abs() as u64);
+        match value < 0 {
+            true => -magnitude,
+            false => magnitude,
         }
     }
 }
 
+/// The failure of an instruction in the evaluation of a function.
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+#[error("Failed to evaluate instruction {instruction}: {reason}")]
+pub struct EvaluationError {
+    /// The index of the failed instruction in the function.
+    pub instruction: usize,
+    /// The reason the instruction failed.
+    pub reason: String,
+}
+
 pub struct Function<E: Environment> {
     memory: Rc<RefCell<Memory<E>>>,
     instructions: Vec<Instruction<E>>,
+    outputs: Vec<Register<E>>,
 }
 
 impl<E: Environment> Function<E> {
@@ -184,6 +422,7 @@ impl<E: Environment> Function<E> {
         Self {
             memory: Rc::new(RefCell::new(Memory::new())),
             instructions: Vec::new(),
+            outputs: Vec::new(),
         }
     }
 
@@ -200,16 +439,32 @@ impl<E: Environment> Function<E> {
         register
     }
 
+    /// Allocates a new register for an output of the function, returning the new register.
+    fn new_output(&mut self) -> Register<E> {
+        let register = self.new_register();
+        self.outputs.push(register.clone());
+        register
+    }
+
     /// Adds the given instruction.
     fn push_instruction(&mut self, instruction: Instruction<E>) {
         self.instructions.push(instruction);
     }
 
-    /// Evaluates the function.
-    fn evaluate(&self) {
-        for instruction in &self.instructions {
-            instruction.evaluate();
+    /// Evaluates the function, stopping at the first instruction that fails.
+    fn evaluate(&self) -> Result<(), EvaluationError> {
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            Self::evaluate_instruction(index, instruction)?;
         }
+        Ok(())
+    }
+
+    /// Evaluates the given instruction, which is at the given index in the function.
+    fn evaluate_instruction(index: usize, instruction: &Instruction<E>) -> Result<(), EvaluationError> {
+        instruction.evaluate().map_err(|reason| EvaluationError {
+            instruction: index,
+            reason,
+        })
     }
 
     /// Returns the number of registers allocated.
@@ -239,7 +494,7 @@ impl<E: Environment> HelloWorld<E> {
         for pair in registers.chunks(2) {
             let first = Value::Register(pair[0].clone());
             let second = Value::Register(pair[1].clone());
-            let output = function.new_register();
+            let output = function.new_output();
 
             let instruction = Instruction::Add(first, second, output.clone());
 
@@ -250,8 +505,13 @@ impl<E: Environment> HelloWorld<E> {
         Self { function, outputs }
     }
 
-    pub fn run(&self) {
-        self.function.evaluate();
+    pub fn run(&self) -> Result<(), EvaluationError> {
+        self.function.evaluate()
+    }
+
+    /// Returns the function, such as to evaluate it in a `FunctionDebugger`.
+    pub fn function(&self) -> &Function<E> {
+        &self.function
     }
 }
 
@@ -266,7 +526,7 @@ mod tests {
         let second = Value::BaseField(BaseField::one());
 
         let function = HelloWorld::new([first, second]);
-        function.run();
+        function.run().unwrap();
 
         let expected = BaseField::one() + BaseField::one();
         match function.outputs[0].load() {
@@ -274,4 +534,141 @@ mod tests {
             _ => panic!("Failed to load output"),
         }
     }
+
+    #[test]
+    fn test_assert_range() {
+        for (num_bits, is_in_range) in [(8, true), (7, false)] {
+            let mut function = Function::<Circuit>::new();
+            let input = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 255u64.into())));
+            function.push_instruction(Instruction::AssertRange(Value::Register(input), num_bits));
+            function.evaluate().unwrap();
+            assert_eq!(is_in_range, Circuit::is_satisfied());
+        }
+    }
+
+    /// Returns the base field encoding of the given signed integer.
+    fn signed(mode: Mode, value: i64) -> Value<Circuit> {
+        Value::BaseField(BaseField::new(mode, Instruction::<Circuit>::from_signed(value as i128)))
+    }
+
+    /// Evaluates the Euclidean division and remainder of `a` by `b` as `num_bits`-bit integers.
+    fn div_rem_euclid(mode: Mode, a: i64, b: i64, num_bits: usize) -> Result<(i128, i128), EvaluationError> {
+        let mut function = Function::<Circuit>::new();
+        let dividend = function.new_input(signed(mode, a));
+        let divisor = function.new_input(signed(mode, b));
+        let quotient = function.new_output();
+        let remainder = function.new_output();
+        function.push_instruction(Instruction::DivEuclid(
+            Value::Register(dividend.clone()),
+            Value::Register(divisor.clone()),
+            num_bits,
+            quotient.clone(),
+        ));
+        function.push_instruction(Instruction::RemEuclid(
+            Value::Register(dividend),
+            Value::Register(divisor),
+            num_bits,
+            remainder.clone(),
+        ));
+        function.evaluate()?;
+
+        let eject = |register: &Register<Circuit>| match register.load() {
+            Value::BaseField(value) => Instruction::<Circuit>::to_signed(value.eject_value(), num_bits).unwrap(),
+            _ => panic!("Failed to load output"),
+        };
+        Ok((eject(&quotient), eject(&remainder)))
+    }
+
+    #[test]
+    fn test_div_rem_euclid() {
+        for mode in [Mode::Constant, Mode::Private] {
+            for (a, b) in [(7, 3), (-7, 3), (7, -3), (-7, -3), (-6, 3), (-128, 1), (-128, 3), (-128, -3), (-1, -128), (127, -128)] {
+                let expected = ((a as i128).div_euclid(b as i128), (a as i128).rem_euclid(b as i128));
+                assert_eq!(expected, div_rem_euclid(mode, a, b, 8).unwrap());
+                assert!(Circuit::is_satisfied());
+                Circuit::reset_circuit();
+            }
+        }
+
+        let (a, b) = (i64::MIN + 1, 3);
+        assert_eq!(
+            ((a as i128).div_euclid(3), (a as i128).rem_euclid(3)),
+            div_rem_euclid(Mode::Private, a, b, 64).unwrap()
+        );
+        assert!(Circuit::is_satisfied());
+        Circuit::reset_circuit();
+    }
+
+    #[test]
+    fn test_div_rem_euclid_errors() {
+        for mode in [Mode::Constant, Mode::Private] {
+            let error = div_rem_euclid(mode, 7, 0, 8).unwrap_err();
+            assert_eq!(2, error.instruction);
+            assert_eq!("Attempted to divide by zero", error.reason);
+
+            let error = div_rem_euclid(mode, -128, -1, 8).unwrap_err();
+            assert_eq!(2, error.instruction);
+            assert_eq!("The Euclidean quotient overflows 8 bits", error.reason);
+
+            let error = div_rem_euclid(mode, 128, 1, 8).unwrap_err();
+            assert_eq!(2, error.instruction);
+            assert_eq!("The operands of a Euclidean division must be 8-bit integers", error.reason);
+
+            let error = div_rem_euclid(mode, 1, 1, 65).unwrap_err();
+            assert_eq!("Euclidean division supports 1 to 64 bits, found 65", error.reason);
+            Circuit::reset_circuit();
+        }
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let mut function = Function::<Circuit>::new();
+        let dividend = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 6u64.into())));
+        let divisor = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 0u64.into())));
+        let quotient = function.new_register();
+        let unreached = function.new_register();
+        function.push_instruction(Instruction::Div(
+            Value::Register(dividend.clone()),
+            Value::Register(divisor),
+            quotient.clone(),
+        ));
+        function.push_instruction(Instruction::Store(Value::Register(dividend), unreached.clone()));
+
+        // Check the division fails with an error, and evaluation stops at it.
+        let error = function.evaluate().unwrap_err();
+        assert_eq!(2, error.instruction);
+        assert_eq!("Attempted to divide by zero", error.reason);
+        assert!(!quotient.is_set());
+        assert!(!unreached.is_set());
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        let mut function = Function::<Circuit>::new();
+        let field = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 6u64.into())));
+        let boolean = function.new_input(Value::Boolean(Boolean::new(Mode::Private, true)));
+        let output = function.new_register();
+        function.push_instruction(Instruction::Div(
+            Value::Register(field.clone()),
+            Value::Register(boolean),
+            output,
+        ));
+        let error = function.evaluate().unwrap_err();
+        assert_eq!(2, error.instruction);
+        assert_eq!("The operands of a division must be base fields", error.reason);
+
+        // Check a halt in the gadgets of an instruction is returned as an error.
+        let mut function = Function::<Circuit>::new();
+        let field = function.new_input(Value::BaseField(BaseField::new(Mode::Private, 6u64.into())));
+        let boolean = function.new_input(Value::Boolean(Boolean::new(Mode::Private, true)));
+        let output = function.new_register();
+        function.push_instruction(Instruction::IsEq(
+            Value::Register(field),
+            Value::Register(boolean),
+            output,
+        ));
+        let error = function.evaluate().unwrap_err();
+        assert_eq!(2, error.instruction);
+        assert_eq!("Attempted to compare values of different types", error.reason);
+    }
 }
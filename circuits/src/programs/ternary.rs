@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+impl<E: Environment> Ternary for Value<E> {
+    type Boolean = Boolean<E>;
+    type Output = Self;
+
+    /// Returns `first` if `condition` is `true`, otherwise returns `second`.
+    /// Composite values are selected member by member.
+    fn ternary(condition: &Boolean<E>, first: &Self, second: &Self) -> Self::Output {
+        match (first.to_value(), second.to_value()) {
+            (Value::Boolean(a), Value::Boolean(b)) => Value::Boolean(Ternary::ternary(condition, &a, &b)),
+            (Value::BaseField(a), Value::BaseField(b)) => Value::BaseField(Ternary::ternary(condition, &a, &b)),
+            (Value::Group(a), Value::Group(b)) => Value::Group(Ternary::ternary(condition, &a, &b)),
+            (Value::Composite(a), Value::Composite(b)) if a.len() == b.len() => Value::Composite(
+                a.iter()
+                    .zip(b.iter())
+                    .map(|(a, b)| Ternary::ternary(condition, a, b))
+                    .collect(),
+            ),
+            _ => E::halt("Attempted to select between values of different types"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{programs::test_helpers::NativeValue, Circuit, Mode};
+
+    use rand::thread_rng;
+
+    const ITERATIONS: usize = 100;
+    const DEPTH: usize = 3;
+
+    #[test]
+    fn test_ternary() {
+        for i in 0..ITERATIONS {
+            let first = NativeValue::sample(DEPTH, &mut thread_rng());
+            let second = first.with_last_leaf_changed(&mut thread_rng());
+
+            for mode in [Mode::Constant, Mode::Public, Mode::Private] {
+                let a = first.to_circuit(mode);
+                let b = second.to_circuit(mode);
+
+                for flag in [true, false] {
+                    let condition = Boolean::<Circuit>::new(Mode::Private, flag);
+
+                    Circuit::scoped(&format!("{:?} Ternary {} {}", mode, flag, i), |scope| {
+                        // Ensure the circuit result matches the native result.
+                        let candidate = Value::ternary(&condition, &a, &b);
+                        let expected = if flag { &first } else { &second };
+                        assert_eq!(expected, &NativeValue::eject(&candidate));
+                        assert!(scope.is_satisfied());
+                    });
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_ternary_registers() {
+        let first = NativeValue::sample(DEPTH, &mut thread_rng());
+        let second = first.sample_like(&mut thread_rng());
+
+        let mut function = Function::<Circuit>::new();
+        let condition = function.new_input(Value::Boolean(Boolean::new(Mode::Private, false)));
+        let a = function.new_input(first.to_circuit(Mode::Private));
+        let b = function.new_input(second.to_circuit(Mode::Private));
+        let output = function.new_register();
+        function.push_instruction(Instruction::Ternary(
+            Value::Register(condition),
+            Value::Register(a),
+            Value::Register(b),
+            output.clone(),
+        ));
+        function.evaluate();
+
+        assert_eq!(second, NativeValue::eject(&output.load()));
+    }
+}
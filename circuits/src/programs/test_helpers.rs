@@ -0,0 +1,105 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::{Circuit, Mode};
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::One;
+use snarkvm_utilities::UniformRand;
+
+use rand::Rng;
+
+type Field = <Circuit as Environment>::BaseField;
+type Group = <Circuit as Environment>::Affine;
+
+/// The native counterpart of a circuit value, used to check circuit results.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum NativeValue {
+    Boolean(bool),
+    BaseField(Field),
+    Group(Group),
+    Composite(Vec<NativeValue>),
+}
+
+impl NativeValue {
+    /// Samples a random value, with composites nested up to the given depth.
+    pub(super) fn sample<R: Rng>(depth: usize, rng: &mut R) -> Self {
+        match rng.gen_range(0..if depth == 0 { 3 } else { 4 }) {
+            0 => Self::Boolean(rng.gen()),
+            1 => Self::BaseField(UniformRand::rand(rng)),
+            2 => Self::Group(UniformRand::rand(rng)),
+            _ => Self::Composite((0..rng.gen_range(1..4)).map(|_| Self::sample(depth - 1, rng)).collect()),
+        }
+    }
+
+    /// Samples a random value with the same structure as this value.
+    pub(super) fn sample_like<R: Rng>(&self, rng: &mut R) -> Self {
+        match self {
+            Self::Boolean(..) => Self::Boolean(rng.gen()),
+            Self::BaseField(..) => Self::BaseField(UniformRand::rand(rng)),
+            Self::Group(..) => Self::Group(UniformRand::rand(rng)),
+            Self::Composite(members) => Self::Composite(members.iter().map(|member| member.sample_like(rng)).collect()),
+        }
+    }
+
+    /// Returns a copy of this value, with only the last leaf changed.
+    pub(super) fn with_last_leaf_changed<R: Rng>(&self, rng: &mut R) -> Self {
+        match self {
+            Self::Boolean(value) => Self::Boolean(!value),
+            Self::BaseField(value) => Self::BaseField(*value + Field::one()),
+            Self::Group(value) => loop {
+                let candidate: Group = UniformRand::rand(rng);
+                if candidate != *value {
+                    break Self::Group(candidate);
+                }
+            },
+            Self::Composite(members) => {
+                let mut members = members.clone();
+                if let Some(last) = members.last_mut() {
+                    *last = last.with_last_leaf_changed(rng);
+                }
+                Self::Composite(members)
+            }
+        }
+    }
+
+    /// Returns the circuit value of this value, in the given mode.
+    pub(super) fn to_circuit(&self, mode: Mode) -> Value<Circuit> {
+        match self {
+            Self::Boolean(value) => Value::Boolean(Boolean::new(mode, *value)),
+            Self::BaseField(value) => Value::BaseField(BaseField::new(mode, *value)),
+            Self::Group(value) => Value::Group(Affine::new(
+                mode,
+                value.to_x_coordinate(),
+                Some(value.to_y_coordinate()),
+            )),
+            Self::Composite(members) => {
+                Value::Composite(members.iter().map(|member| member.to_circuit(mode)).collect())
+            }
+        }
+    }
+
+    /// Returns the native value of the given circuit value.
+    pub(super) fn eject(value: &Value<Circuit>) -> Self {
+        match value.to_value() {
+            Value::Boolean(value) => Self::Boolean(value.eject_value()),
+            Value::BaseField(value) => Self::BaseField(value.eject_value()),
+            Value::Group(value) => Self::Group(value.eject_value()),
+            Value::Composite(members) => Self::Composite(members.iter().map(Self::eject).collect()),
+            Value::Register(..) => unreachable!(),
+        }
+    }
+}
@@ -27,6 +27,7 @@ pub trait BooleanTrait: And + Clone + Debug + Equal + Nand + Nor + Not + Or + Te
 pub trait BaseFieldTrait:
     Add
     + AddAssign
+    + CheckedDiv
     + Clone
     + Debug
     + Div
@@ -38,6 +39,7 @@ pub trait BaseFieldTrait:
     + MulAssign
     + Neg
     + One
+    + RangeCheck
     + Square
     + Sub
     + SubAssign
@@ -137,6 +139,32 @@ pub trait Ternary {
     fn ternary(condition: &Self::Boolean, first: &Self, second: &Self) -> Self::Output;
 }
 
+/// Binary operator for concatenating two fixed-size arrays.
+pub trait Concat<Rhs: ?Sized = Self> {
+    type Element;
+
+    /// Returns the elements of `self`, followed by the elements of `other`.
+    /// Halts if `LEN` is not the sum of their lengths.
+    fn concat<const LEN: usize>(&self, other: &Rhs) -> [Self::Element; LEN];
+}
+
+/// Unary operator for slicing a fixed-size array.
+pub trait Slice {
+    type Element;
+
+    /// Returns the `LEN` elements of `self` starting at index `START`.
+    /// Halts if the slice is not within the bounds of `self`.
+    fn slice<const START: usize, const LEN: usize>(&self) -> [Self::Element; LEN];
+}
+
+/// Binary operator for performing `a / b`, without halting if `b` is zero.
+pub trait CheckedDiv<Rhs: ?Sized = Self> {
+    type Output;
+
+    /// Returns `a / b`, or `None` if `b` is zero.
+    fn checked_div(&self, other: &Rhs) -> Option<Self::Output>;
+}
+
 /// Unary operator for retrieving the doubled value.
 pub trait Double {
     type Output;
@@ -159,3 +187,14 @@ pub trait ToBits {
 
     fn to_bits_be(&self) -> Vec<Self::Boolean>;
 }
+
+/// Trait for asserting that values fit in a given number of bits.
+pub trait RangeCheck {
+    /// Asserts that `self` is less than `2^num_bits`.
+    fn assert_bits_le(&self, num_bits: usize);
+
+    /// Asserts that each of the given values is less than `2^num_bits`.
+    fn assert_bits_le_many(values: &[Self], num_bits: usize)
+    where
+        Self: Sized;
+}
@@ -108,14 +108,14 @@ pub(crate) mod g1 {
 
 pub(crate) mod g2 {
     use snarkvm_curves::{
-        bls12_377::{Fr, G2Affine, G2Projective as G2},
-        traits::ProjectiveCurve,
+        bls12_377::{Fq2, Fr, G2Affine, G2Projective as G2},
+        traits::{AffineCurve, ProjectiveCurve},
         Group,
     };
     use snarkvm_utilities::rand::UniformRand;
 
     use criterion::Criterion;
-    use rand::SeedableRng;
+    use rand::{Rng, SeedableRng};
     use rand_xorshift::XorShiftRng;
     use std::ops::{AddAssign, MulAssign};
 
@@ -197,4 +197,47 @@ pub(crate) mod g2 {
             })
         });
     }
+
+    /// Returns a random point on the G2 twist curve, which is not cofactor-cleared.
+    fn sample_twist_point(rng: &mut XorShiftRng) -> G2Affine {
+        loop {
+            if let Some(point) = G2Affine::from_x_coordinate(Fq2::rand(rng), rng.gen()) {
+                return point;
+            }
+        }
+    }
+
+    pub fn bench_g2_mul_by_cofactor(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<G2Affine> = (0..SAMPLES).map(|_| sample_twist_point(&mut rng)).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g2_mul_by_cofactor", |c| {
+            c.iter(|| {
+                let tmp = v[count].mul_by_cofactor_to_projective();
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
+
+    pub fn bench_g2_clear_cofactor(c: &mut Criterion) {
+        const SAMPLES: usize = 1000;
+
+        let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+        let v: Vec<G2Affine> = (0..SAMPLES).map(|_| sample_twist_point(&mut rng)).collect();
+
+        let mut count = 0;
+        c.bench_function("bls12_377: g2_clear_cofactor", |c| {
+            c.iter(|| {
+                let tmp = v[count].clear_cofactor();
+                count = (count + 1) % SAMPLES;
+                tmp
+            })
+        });
+    }
 }
@@ -14,7 +14,10 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_curves::bls12_377::Fq12;
+use snarkvm_curves::{
+    bls12_377::{Bls12_377Parameters, Fq12},
+    templates::bls12::Bls12Parameters,
+};
 use snarkvm_fields::Field;
 use snarkvm_utilities::rand::UniformRand;
 
@@ -135,3 +138,47 @@ pub(crate) fn bench_fq12_inverse(c: &mut Criterion) {
         })
     });
 }
+
+fn sample_cyclotomic(rng: &mut XorShiftRng) -> Fq12 {
+    let f = Fq12::rand(rng);
+    let mut f1 = f;
+    f1.conjugate();
+    f1.mul_assign(&f.inverse().unwrap());
+    let mut f2 = f1;
+    f2.frobenius_map(2);
+    f2 * f1
+}
+
+pub(crate) fn bench_fq12_cyclotomic_exp(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq12> = (0..SAMPLES).map(|_| sample_cyclotomic(&mut rng)).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq12_cyclotomic_exp", |c| {
+        c.iter(|| {
+            let tmp = v[count].cyclotomic_exp(Bls12_377Parameters::X);
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
+
+pub(crate) fn bench_fq12_cyclotomic_exp_compressed(c: &mut Criterion) {
+    const SAMPLES: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let v: Vec<Fq12> = (0..SAMPLES).map(|_| sample_cyclotomic(&mut rng)).collect();
+
+    let mut count = 0;
+    c.bench_function("bls12_377: fq12_cyclotomic_exp_compressed", |c| {
+        c.iter(|| {
+            let tmp = v[count].cyclotomic_exp_compressed(Bls12_377Parameters::X);
+            count = (count + 1) % SAMPLES;
+            tmp
+        })
+    });
+}
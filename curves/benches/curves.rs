@@ -31,6 +31,8 @@ criterion_group!(
     bls12_377::ec::g2::bench_g2_add_assign,
     bls12_377::ec::g2::bench_g2_add_assign_mixed,
     bls12_377::ec::g2::bench_g2_double,
+    bls12_377::ec::g2::bench_g2_mul_by_cofactor,
+    bls12_377::ec::g2::bench_g2_clear_cofactor,
 );
 
 criterion_group!(
@@ -60,6 +62,8 @@ criterion_group!(
     bls12_377::fq12::bench_fq12_double,
     bls12_377::fq12::bench_fq12_square,
     bls12_377::fq12::bench_fq12_inverse,
+    bls12_377::fq12::bench_fq12_cyclotomic_exp,
+    bls12_377::fq12::bench_fq12_cyclotomic_exp_compressed,
 );
 
 criterion_group!(
@@ -0,0 +1,178 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Conversions between snarkVM and arkworks types, by copying limbs in Montgomery form.
+//!
+//! The field types are defined in `snarkvm-fields`, so the orphan rule forbids `From` impls
+//! between them and the arkworks field types; [`ArkCompat`] is implemented for every type instead.
+//! The curve points additionally implement `From` into arkworks, and `TryFrom` from arkworks.
+
+use crate::GroupError;
+
+/// A conversion between a snarkVM type and its arkworks counterpart.
+pub trait ArkCompat: Sized {
+    /// The arkworks counterpart of this type.
+    type Ark;
+
+    /// Returns the arkworks counterpart of `self`.
+    fn to_ark(&self) -> Self::Ark;
+
+    /// Returns the snarkVM counterpart of the given arkworks value,
+    /// or an error if it is not a valid field element or group element.
+    fn from_ark(value: &Self::Ark) -> Result<Self, GroupError>;
+}
+
+/// Implements `ArkCompat` for a prime field, whose limbs are in the same Montgomery form in both libraries.
+macro_rules! impl_ark_compat_prime_field {
+    ($field: ty, $ark: ty) => {
+        impl $crate::ark_compat::ArkCompat for $field {
+            type Ark = $ark;
+
+            fn to_ark(&self) -> Self::Ark {
+                <$ark>::new_unchecked(ark_ff::BigInt::new(self.to_montgomery_limbs()))
+            }
+
+            fn from_ark(value: &Self::Ark) -> Result<Self, $crate::GroupError> {
+                Self::from_montgomery_limbs_checked((value.0).0)
+                    .ok_or_else(|| snarkvm_fields::FieldError::InvalidFieldElement.into())
+            }
+        }
+    };
+}
+
+/// Implements `ArkCompat`, `From` and `TryFrom` for the affine and projective points of a short Weierstrass curve.
+///
+/// The coordinates are copied as-is, except at infinity: snarkVM represents the affine point at infinity
+/// as `(0, 1)` with the infinity flag set, while arkworks uses `(0, 0)`, so it is mapped explicitly.
+macro_rules! impl_ark_compat_short_weierstrass {
+    ($affine: ty, $projective: ty, $ark_affine: ty, $ark_projective: ty) => {
+        impl $crate::ark_compat::ArkCompat for $affine {
+            type Ark = $ark_affine;
+
+            fn to_ark(&self) -> Self::Ark {
+                match self.infinity {
+                    true => <$ark_affine>::identity(),
+                    false => <$ark_affine>::new_unchecked(self.x.to_ark(), self.y.to_ark()),
+                }
+            }
+
+            fn from_ark(value: &Self::Ark) -> Result<Self, $crate::GroupError> {
+                if value.infinity {
+                    return Ok(<Self as snarkvm_fields::Zero>::zero());
+                }
+                let point = Self::new(
+                    ArkCompat::from_ark(&value.x)?,
+                    ArkCompat::from_ark(&value.y)?,
+                    false,
+                );
+                match point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve() {
+                    true => Ok(point),
+                    false => Err($crate::GroupError::InvalidGroupElement),
+                }
+            }
+        }
+
+        impl $crate::ark_compat::ArkCompat for $projective {
+            type Ark = $ark_projective;
+
+            fn to_ark(&self) -> Self::Ark {
+                <$ark_projective>::new_unchecked(self.x.to_ark(), self.y.to_ark(), self.z.to_ark())
+            }
+
+            fn from_ark(value: &Self::Ark) -> Result<Self, $crate::GroupError> {
+                let point = Self::new(
+                    ArkCompat::from_ark(&value.x)?,
+                    ArkCompat::from_ark(&value.y)?,
+                    ArkCompat::from_ark(&value.z)?,
+                );
+                let affine = point.into_affine();
+                match affine.is_on_curve() && affine.is_in_correct_subgroup_assuming_on_curve() {
+                    true => Ok(point),
+                    false => Err($crate::GroupError::InvalidGroupElement),
+                }
+            }
+        }
+
+        impl_ark_compat_conversions!($affine, $ark_affine);
+        impl_ark_compat_conversions!($projective, $ark_projective);
+    };
+}
+
+/// Implements `ArkCompat`, `From` and `TryFrom` for the affine and projective points of a twisted Edwards curve.
+/// Both libraries use extended coordinates and represent the point at infinity as `(0, 1)`.
+macro_rules! impl_ark_compat_twisted_edwards {
+    ($affine: ty, $projective: ty, $ark_affine: ty, $ark_projective: ty) => {
+        impl $crate::ark_compat::ArkCompat for $affine {
+            type Ark = $ark_affine;
+
+            fn to_ark(&self) -> Self::Ark {
+                <$ark_affine>::new_unchecked(self.x.to_ark(), self.y.to_ark())
+            }
+
+            fn from_ark(value: &Self::Ark) -> Result<Self, $crate::GroupError> {
+                let point = Self::new(ArkCompat::from_ark(&value.x)?, ArkCompat::from_ark(&value.y)?);
+                match point.is_on_curve() && point.is_in_correct_subgroup_assuming_on_curve() {
+                    true => Ok(point),
+                    false => Err($crate::GroupError::InvalidGroupElement),
+                }
+            }
+        }
+
+        impl $crate::ark_compat::ArkCompat for $projective {
+            type Ark = $ark_projective;
+
+            fn to_ark(&self) -> Self::Ark {
+                <$ark_projective>::new_unchecked(self.x.to_ark(), self.y.to_ark(), self.t.to_ark(), self.z.to_ark())
+            }
+
+            fn from_ark(value: &Self::Ark) -> Result<Self, $crate::GroupError> {
+                let point = Self::new(
+                    ArkCompat::from_ark(&value.x)?,
+                    ArkCompat::from_ark(&value.y)?,
+                    ArkCompat::from_ark(&value.t)?,
+                    ArkCompat::from_ark(&value.z)?,
+                );
+                let affine = point.into_affine();
+                match affine.is_on_curve() && affine.is_in_correct_subgroup_assuming_on_curve() {
+                    true => Ok(point),
+                    false => Err($crate::GroupError::InvalidGroupElement),
+                }
+            }
+        }
+
+        impl_ark_compat_conversions!($affine, $ark_affine);
+        impl_ark_compat_conversions!($projective, $ark_projective);
+    };
+}
+
+/// Implements `From` into the arkworks type, and `TryFrom` from the arkworks type, through `ArkCompat`.
+macro_rules! impl_ark_compat_conversions {
+    ($type: ty, $ark: ty) => {
+        impl From<$type> for $ark {
+            fn from(value: $type) -> Self {
+                value.to_ark()
+            }
+        }
+
+        impl std::convert::TryFrom<$ark> for $type {
+            type Error = $crate::GroupError;
+
+            fn try_from(value: $ark) -> Result<Self, Self::Error> {
+                Self::from_ark(&value)
+            }
+        }
+    };
+}
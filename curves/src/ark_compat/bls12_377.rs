@@ -0,0 +1,79 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ark_compat::ArkCompat,
+    bls12_377::{Fq, Fq12, Fq2, Fq6, Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+    AffineCurve,
+    GroupError,
+    ProjectiveCurve,
+};
+
+impl_ark_compat_prime_field!(Fr, ark_bls12_377::Fr);
+impl_ark_compat_prime_field!(Fq, ark_bls12_377::Fq);
+
+impl ArkCompat for Fq2 {
+    type Ark = ark_bls12_377::Fq2;
+
+    fn to_ark(&self) -> Self::Ark {
+        Self::Ark::new(self.c0.to_ark(), self.c1.to_ark())
+    }
+
+    fn from_ark(value: &Self::Ark) -> Result<Self, GroupError> {
+        Ok(Self::new(Fq::from_ark(&value.c0)?, Fq::from_ark(&value.c1)?))
+    }
+}
+
+impl ArkCompat for Fq6 {
+    type Ark = ark_bls12_377::Fq6;
+
+    fn to_ark(&self) -> Self::Ark {
+        Self::Ark::new(self.c0.to_ark(), self.c1.to_ark(), self.c2.to_ark())
+    }
+
+    fn from_ark(value: &Self::Ark) -> Result<Self, GroupError> {
+        Ok(Self::new(
+            Fq2::from_ark(&value.c0)?,
+            Fq2::from_ark(&value.c1)?,
+            Fq2::from_ark(&value.c2)?,
+        ))
+    }
+}
+
+impl ArkCompat for Fq12 {
+    type Ark = ark_bls12_377::Fq12;
+
+    fn to_ark(&self) -> Self::Ark {
+        Self::Ark::new(self.c0.to_ark(), self.c1.to_ark())
+    }
+
+    fn from_ark(value: &Self::Ark) -> Result<Self, GroupError> {
+        Ok(Self::new(Fq6::from_ark(&value.c0)?, Fq6::from_ark(&value.c1)?))
+    }
+}
+
+impl_ark_compat_short_weierstrass!(
+    G1Affine,
+    G1Projective,
+    ark_bls12_377::G1Affine,
+    ark_bls12_377::G1Projective
+);
+impl_ark_compat_short_weierstrass!(
+    G2Affine,
+    G2Projective,
+    ark_bls12_377::G2Affine,
+    ark_bls12_377::G2Projective
+);
@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ark_compat::ArkCompat,
+    edwards_bls12::{EdwardsAffine, EdwardsProjective, Fr},
+    AffineCurve,
+    ProjectiveCurve,
+};
+
+// The base field of Edwards-BLS12 is the scalar field of BLS12-377, which is converted in `bls12_377`.
+impl_ark_compat_prime_field!(Fr, ark_ed_on_bls12_377::Fr);
+
+impl_ark_compat_twisted_edwards!(
+    EdwardsAffine,
+    EdwardsProjective,
+    ark_ed_on_bls12_377::EdwardsAffine,
+    ark_ed_on_bls12_377::EdwardsProjective
+);
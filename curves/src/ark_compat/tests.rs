@@ -0,0 +1,176 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    ark_compat::ArkCompat,
+    bls12_377::{Bls12_377, Fq, Fq12, Fq2, Fr, G1Affine, G1Projective, G2Affine, G2Projective},
+    edwards_bls12::{EdwardsAffine, EdwardsProjective, Fr as EdwardsFr},
+    AffineCurve,
+    PairingEngine,
+    ProjectiveCurve,
+};
+use snarkvm_fields::{Field, Zero};
+use snarkvm_utilities::rand::UniformRand;
+
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::Zero as _;
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use std::{convert::TryFrom, fmt::Debug};
+
+const ITERATIONS: usize = 10;
+
+/// Checks that a snarkVM value survives a round trip through arkworks.
+fn check_round_trip<T: ArkCompat + Debug + PartialEq>(value: T) {
+    assert_eq!(value, T::from_ark(&value.to_ark()).unwrap());
+}
+
+fn check_field<F: Field + ArkCompat>(rng: &mut XorShiftRng)
+where
+    F::Ark: ark_ff::Field,
+{
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+        check_round_trip(a);
+
+        // The arithmetic agrees in both libraries.
+        assert_eq!((a * b).to_ark(), a.to_ark() * b.to_ark());
+        assert_eq!((a + b).to_ark(), a.to_ark() + b.to_ark());
+
+        // An arkworks element survives a round trip through snarkVM.
+        let c = <F::Ark as ark_ff::UniformRand>::rand(rng);
+        assert_eq!(c, F::from_ark(&c).unwrap().to_ark());
+    }
+    check_round_trip(F::zero());
+    check_round_trip(F::one());
+}
+
+#[test]
+fn test_bls12_377_fields() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_field::<Fr>(&mut rng);
+    check_field::<Fq>(&mut rng);
+    check_field::<Fq2>(&mut rng);
+    check_field::<Fq12>(&mut rng);
+}
+
+#[test]
+fn test_edwards_bls12_fields() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_field::<EdwardsFr>(&mut rng);
+}
+
+#[test]
+fn test_invalid_field_element() {
+    // An arkworks element whose limbs are not reduced is rejected.
+    let modulus = ark_bls12_377::Fr::new_unchecked(ark_ff::BigInt::new(Fr::MODULUS_LIMBS));
+    assert!(Fr::from_ark(&modulus).is_err());
+}
+
+#[test]
+fn test_bls12_377_points() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let g1 = G1Projective::rand(&mut rng);
+        let g2 = G2Projective::rand(&mut rng);
+        let scalar = Fr::rand(&mut rng);
+
+        check_round_trip(g1);
+        check_round_trip(g2);
+        check_round_trip(g1.into_affine());
+        check_round_trip(g2.into_affine());
+
+        // The affine coordinates agree in both libraries.
+        assert_eq!(g1.into_affine().to_ark(), g1.to_ark().into_affine());
+        assert_eq!(g2.into_affine().to_ark(), g2.to_ark().into_affine());
+
+        // The scalar multiplication agrees in both libraries.
+        assert_eq!((g1 * scalar).to_ark(), g1.to_ark() * scalar.to_ark());
+        assert_eq!((g2 * scalar).to_ark(), g2.to_ark() * scalar.to_ark());
+
+        // The `From` and `TryFrom` conversions agree with `ArkCompat`.
+        let g1_affine = ark_bls12_377::G1Affine::from(g1.into_affine());
+        assert_eq!(g1.into_affine(), G1Affine::try_from(g1_affine).unwrap());
+        let g2_affine = ark_bls12_377::G2Affine::from(g2.into_affine());
+        assert_eq!(g2.into_affine(), G2Affine::try_from(g2_affine).unwrap());
+    }
+
+    // The point at infinity is mapped explicitly.
+    assert!(G1Affine::zero().to_ark().is_zero());
+    assert!(G2Affine::zero().to_ark().is_zero());
+    assert!(G1Projective::zero().to_ark().is_zero());
+    assert!(G1Affine::from_ark(&ark_bls12_377::G1Affine::zero()).unwrap().is_zero());
+    assert!(G2Affine::from_ark(&ark_bls12_377::G2Affine::zero()).unwrap().is_zero());
+    assert!(G1Projective::from_ark(&ark_bls12_377::G1Projective::zero())
+        .unwrap()
+        .is_zero());
+
+    // The libraries choose different generators, but each is a valid point in the other.
+    let g1_generator = G1Affine::prime_subgroup_generator().to_ark();
+    assert!(g1_generator.is_on_curve() && g1_generator.is_in_correct_subgroup_assuming_on_curve());
+    let g2_generator = G2Affine::prime_subgroup_generator().to_ark();
+    assert!(g2_generator.is_on_curve() && g2_generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(G1Affine::from_ark(&ark_bls12_377::G1Affine::generator()).is_ok());
+    assert!(G2Affine::from_ark(&ark_bls12_377::G2Affine::generator()).is_ok());
+}
+
+#[test]
+fn test_invalid_point() {
+    // A point that is not on the curve is rejected.
+    let generator = ark_bls12_377::G1Affine::generator();
+    let invalid = ark_bls12_377::G1Affine::new_unchecked(generator.x, generator.x);
+    assert!(G1Affine::from_ark(&invalid).is_err());
+    assert!(G1Affine::try_from(invalid).is_err());
+}
+
+#[test]
+fn test_pairing_agreement() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        let q = G2Projective::rand(&mut rng).into_affine();
+
+        let expected = Bls12_377::pairing(p, q);
+        let candidate = ark_bls12_377::Bls12_377::pairing(p.to_ark(), q.to_ark()).0;
+        assert_eq!(expected.to_ark(), candidate);
+        assert_eq!(expected, Fq12::from_ark(&candidate).unwrap());
+    }
+}
+
+#[test]
+fn test_edwards_bls12_points() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let point = EdwardsProjective::rand(&mut rng);
+        let scalar = EdwardsFr::rand(&mut rng);
+
+        check_round_trip(point);
+        check_round_trip(point.into_affine());
+
+        assert_eq!(point.into_affine().to_ark(), point.to_ark().into_affine());
+        assert_eq!((point * scalar).to_ark(), point.to_ark() * scalar.to_ark());
+
+        let affine = ark_ed_on_bls12_377::EdwardsAffine::from(point.into_affine());
+        assert_eq!(point.into_affine(), EdwardsAffine::try_from(affine).unwrap());
+    }
+
+    check_round_trip(EdwardsAffine::zero());
+    check_round_trip(EdwardsProjective::zero());
+}
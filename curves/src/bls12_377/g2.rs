@@ -14,12 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{field, Zero};
-use snarkvm_utilities::biginteger::{BigInteger256, BigInteger384};
+use snarkvm_fields::{field, Field, Zero};
+use snarkvm_utilities::{
+    biginteger::{BigInteger256, BigInteger384},
+    bititerator::BitIteratorBE,
+};
 
 use crate::{
-    bls12_377::{g1::Bls12_377G1Parameters, Fq, Fq2, Fr},
-    traits::{ModelParameters, ShortWeierstrassParameters},
+    bls12_377::{g1::Bls12_377G1Parameters, Bls12_377Parameters, Fq, Fq2, Fr, G2Affine, G2Projective},
+    templates::bls12::Bls12Parameters,
+    traits::{AffineCurve, Group, ModelParameters, ProjectiveCurve, ShortWeierstrassParameters},
 };
 
 #[derive(Clone, Default, PartialEq, Eq)]
@@ -84,8 +88,91 @@ impl ShortWeierstrassParameters for Bls12_377G2Parameters {
     fn mul_by_a(_: &Self::BaseField) -> Self::BaseField {
         Self::BaseField::zero()
     }
+
+    /// Checks that the given point is in the prime order subgroup, assuming it is on the curve,
+    /// by checking that `psi(P) = [x]P`.
+    ///
+    /// As shown in Section 4 of <https://eprint.iacr.org/2021/1130>, this is equivalent to
+    /// checking that `[r]P = 0`, at the cost of a 64-bit scalar multiplication.
+    fn is_in_correct_subgroup_assuming_on_curve(p: &G2Affine) -> bool {
+        if p.is_zero() {
+            return true;
+        }
+
+        // As X_IS_NEGATIVE is false, there is no negation to apply.
+        let x_times_p = p.mul_bits(BitIteratorBE::new_without_leading_zeros(Bls12_377Parameters::X));
+        x_times_p == psi(p).into_projective()
+    }
+
+    /// Clears the cofactor of the given point using the psi endomorphism, by computing
+    /// `[x^2 - x - 1]P + [x - 1]psi(P) + psi^2(2P)`, as in Section 4.1 of <https://eprint.iacr.org/2017/419>.
+    ///
+    /// This is the multiplication by an effective cofactor `h_eff`, not by `COFACTOR`.
+    /// On the prime order subgroup, `h_eff = 4x^2 - 2x - 1 mod r`, so the result equals
+    /// `mul_by_cofactor` scaled by `h_eff * COFACTOR_INV`.
+    fn clear_cofactor(p: &G2Affine) -> G2Projective {
+        let x = || BitIteratorBE::new_without_leading_zeros(Bls12_377Parameters::X);
+
+        // [x]P
+        let x_p = p.mul_bits(x());
+        // psi(P)
+        let psi_p = psi(p);
+        // psi^2(2P)
+        let psi2_p2 = psi(&psi_p).into_projective().double();
+
+        // [x]([x]P + psi(P)) = [x^2]P + [x]psi(P)
+        let mut tmp = x_p;
+        tmp.add_assign_mixed(&psi_p);
+        let tmp = tmp.into_affine().mul_bits(x());
+
+        psi2_p2 + tmp - x_p - psi_p.into_projective() - p.into_projective()
+    }
+}
+
+/// Returns the untwist-Frobenius-twist endomorphism of the given point,
+/// `psi(x, y) = (x^p * u^((p - 1) / 3), y^p * u^((p - 1) / 2))`.
+///
+/// On the prime order subgroup, `psi` acts as multiplication by `p = x mod r`.
+pub fn psi(p: &G2Affine) -> G2Affine {
+    let mut x = p.x;
+    x.frobenius_map(1);
+    x.mul_by_fp(&PSI_COEFF_X);
+
+    let mut y = p.y;
+    y.frobenius_map(1);
+    y.mul_by_fp(&PSI_COEFF_Y);
+
+    G2Affine::new(x, y, p.infinity)
 }
 
+/// PSI_COEFF_X = u^((p - 1) / 3)
+/// = 80949648264912719408558363140637477264845294720710499478137287262712535938301461879813459410946
+pub const PSI_COEFF_X: Fq = field!(
+    Fq,
+    BigInteger384([
+        6382252053795993818,
+        1383562296554596171,
+        11197251941974877903,
+        6684509567199238270,
+        6699184357838251020,
+        19987743694136192,
+    ])
+);
+
+/// PSI_COEFF_Y = u^((p - 1) / 2)
+/// = 216465761340224619389371505802605247630151569547285782856803747159100223055385581585702401816380679166954762214499
+pub const PSI_COEFF_Y: Fq = field!(
+    Fq,
+    BigInteger384([
+        10965161018967488287,
+        18251363109856037426,
+        7036083669251591763,
+        16109345360066746489,
+        4679973768683352764,
+        96952949334633821,
+    ])
+);
+
 pub const G2_GENERATOR_X: Fq2 = field!(Fq2, G2_GENERATOR_X_C0, G2_GENERATOR_X_C1);
 pub const G2_GENERATOR_Y: Fq2 = field!(Fq2, G2_GENERATOR_Y_C0, G2_GENERATOR_Y_C1);
 
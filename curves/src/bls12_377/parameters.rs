@@ -28,10 +28,10 @@ use crate::{
         Bls12,
         Bls12Parameters,
         G1Affine as Bls12G1Affine,
-        G1Prepared,
+        G1Prepared as Bls12G1Prepared,
         G1Projective as Bls12G1Projective,
         G2Affine as Bls12G2Affine,
-        G2Prepared,
+        G2Prepared as Bls12G2Prepared,
         G2Projective as Bls12G2Projective,
         TwistType,
     },
@@ -59,12 +59,13 @@ pub type Bls12_377 = Bls12<Bls12_377Parameters>;
 
 pub type G1Affine = Bls12G1Affine<Bls12_377Parameters>;
 pub type G1Projective = Bls12G1Projective<Bls12_377Parameters>;
+pub type G1Prepared = Bls12G1Prepared<Bls12_377Parameters>;
 
 impl PairingCurve for G1Affine {
     type Engine = Bls12_377;
     type PairWith = G2Affine;
     type PairingResult = Fq12;
-    type Prepared = G1Prepared<Bls12_377Parameters>;
+    type Prepared = G1Prepared;
 
     fn prepare(&self) -> Self::Prepared {
         Self::Prepared::from_affine(*self)
@@ -77,12 +78,13 @@ impl PairingCurve for G1Affine {
 
 pub type G2Affine = Bls12G2Affine<Bls12_377Parameters>;
 pub type G2Projective = Bls12G2Projective<Bls12_377Parameters>;
+pub type G2Prepared = Bls12G2Prepared<Bls12_377Parameters>;
 
 impl PairingCurve for G2Affine {
     type Engine = Bls12_377;
     type PairWith = G1Affine;
     type PairingResult = Fq12;
-    type Prepared = G2Prepared<Bls12_377Parameters>;
+    type Prepared = G2Prepared;
 
     fn prepare(&self) -> Self::Prepared {
         Self::Prepared::from_affine(*self)
@@ -18,8 +18,9 @@
 use crate::{
     bls12_377::{
         g1::Bls12_377G1Parameters,
-        g2::Bls12_377G2Parameters,
+        g2::{psi, Bls12_377G2Parameters},
         Bls12_377,
+        Bls12_377Parameters,
         Fq,
         Fq12,
         Fq2,
@@ -29,16 +30,23 @@ use crate::{
         FqParameters,
         Fr,
         G1Affine,
+        G1Prepared,
         G1Projective,
         G2Affine,
+        G2Prepared,
         G2Projective,
     },
-    templates::{short_weierstrass_jacobian::tests::sw_tests, twisted_edwards_extended::tests::edwards_test},
+    templates::{
+        bls12::Bls12Parameters,
+        short_weierstrass_jacobian::tests::sw_tests,
+        twisted_edwards_extended::tests::edwards_test,
+    },
     traits::{
         tests_curve::curve_tests,
         tests_field::{field_serialization_test, field_test, frobenius_test, primefield_test, sqrt_field_test},
         tests_group::group_test,
         AffineCurve,
+        PairingCurve,
         PairingEngine,
         ProjectiveCurve,
         ShortWeierstrassParameters,
@@ -59,10 +67,11 @@ use snarkvm_fields::{
 };
 use snarkvm_utilities::{
     biginteger::{BigInteger, BigInteger384},
+    bititerator::BitIteratorBE,
     rand::UniformRand,
 };
 
-use rand::SeedableRng;
+use rand::{Rng, RngCore, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::{
     cmp::Ordering,
@@ -129,6 +138,125 @@ fn test_bls12_377_fq12() {
     field_serialization_test::<Fq12>();
 }
 
+#[test]
+fn test_montgomery_limbs() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = Fr::rand(&mut rng);
+        assert_eq!(a, Fr::from_montgomery_limbs_checked(a.to_montgomery_limbs()).unwrap());
+        assert_eq!(a, Fr::from_montgomery_limbs_unchecked(a.to_montgomery_limbs()));
+
+        let b = Fq::rand(&mut rng);
+        assert_eq!(b, Fq::from_montgomery_limbs_checked(b.to_montgomery_limbs()).unwrap());
+        assert_eq!(b, Fq::from_montgomery_limbs_unchecked(b.to_montgomery_limbs()));
+    }
+
+    // The modulus and anything above it are rejected.
+    assert!(Fr::from_montgomery_limbs_checked(Fr::MODULUS_LIMBS).is_none());
+    assert!(Fq::from_montgomery_limbs_checked(Fq::MODULUS_LIMBS).is_none());
+    assert!(Fr::from_montgomery_limbs_checked([u64::MAX; 4]).is_none());
+    assert!(Fq::from_montgomery_limbs_checked([u64::MAX; 6]).is_none());
+
+    // The limbs of one in `ark_bls12_377::Fr`, which uses the same Montgomery form.
+    let ark_fr_one = [
+        9015221291577245683u64,
+        8239323489949974514,
+        1646089257421115374,
+        958099254763297437,
+    ];
+    assert_eq!(ark_fr_one, Fr::one().to_montgomery_limbs());
+
+    // R2 is the Montgomery form of R.
+    assert_eq!(
+        Fr::one().to_montgomery_limbs(),
+        Fr::from_montgomery_limbs_checked(Fr::R2).unwrap().to_repr().0
+    );
+    assert_eq!(
+        Fq::one().to_montgomery_limbs(),
+        Fq::from_montgomery_limbs_checked(Fq::R2).unwrap().to_repr().0
+    );
+}
+
+/// An RNG that counts the bytes it produces.
+struct CountingRng<R: RngCore> {
+    rng: R,
+    num_bytes: usize,
+}
+
+impl<R: RngCore> RngCore for CountingRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.num_bytes += 4;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.num_bytes += 8;
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.num_bytes += dest.len();
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.num_bytes += dest.len();
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+#[test]
+fn test_rand_samplers() {
+    let new_rng = || CountingRng {
+        rng: XorShiftRng::seed_from_u64(1231275789u64),
+        num_bytes: 0,
+    };
+
+    // Check `UniformRand::rand` is the rejection sampler.
+    let (mut rng_a, mut rng_b) = (new_rng(), new_rng());
+    for _ in 0..ITERATIONS {
+        assert_eq!(Fr::rand(&mut rng_a), Fr::rand_uniform(&mut rng_b));
+        assert_eq!(Fq::rand(&mut rng_a), Fq::rand_uniform(&mut rng_b));
+    }
+    assert_eq!(rng_a.num_bytes, rng_b.num_bytes);
+
+    // Check the pinned outputs and RNG bytes of the rejection sampler.
+    let mut rng = new_rng();
+    assert_eq!(
+        "5558672140362608903770923966947453888164405357269798191793089996532857590395",
+        Fr::rand_uniform(&mut rng).to_string()
+    );
+    assert_eq!(32, rng.num_bytes);
+    assert_eq!(
+        "157609202186897093239703174735394591456221561347266121222029708161602534653754725291477742456795053495982522084507",
+        Fq::rand_uniform(&mut rng).to_string()
+    );
+    assert_eq!(80, rng.num_bytes);
+
+    // Check the pinned outputs and RNG bytes of the reduction sampler.
+    let mut rng = new_rng();
+    assert_eq!(
+        "6895682283004832062335638378389622900926919839222493200244555514871721231468",
+        Fr::rand_reduced(&mut rng).to_string()
+    );
+    assert_eq!(64, rng.num_bytes);
+    assert_eq!(
+        "247633895211576716258578559600975444883155763343840612356123482022462065326294439172639360946298798461766321842374",
+        Fq::rand_reduced(&mut rng).to_string()
+    );
+    assert_eq!(160, rng.num_bytes);
+
+    // Check the reduction sampler consumes a fixed number of RNG bytes.
+    assert_eq!(64, Fr::RAND_REDUCED_BYTES);
+    assert_eq!(96, Fq::RAND_REDUCED_BYTES);
+    let mut rng = new_rng();
+    for i in 1..=ITERATIONS {
+        Fr::rand_reduced(&mut rng);
+        assert_eq!(i * Fr::RAND_REDUCED_BYTES, rng.num_bytes);
+    }
+}
+
 #[test]
 fn test_fq_repr_from() {
     assert_eq!(BigInteger384::from(100), BigInteger384([100, 0, 0, 0, 0, 0]));
@@ -165,6 +293,76 @@ fn test_fq_repr_num_bits() {
     assert_eq!(0, a.num_bits());
 }
 
+#[cfg(feature = "target-specific")]
+fn check_backend_product<F: Field>(a: F, b: F, mul_portable: impl Fn(&mut F, &F), mul_backend: impl Fn(&mut F, &F)) {
+    let (mut expected, mut candidate) = (a, a);
+    mul_portable(&mut expected, &b);
+    mul_backend(&mut candidate, &b);
+    assert_eq!(expected, candidate, "{} * {}", a, b);
+}
+
+/// Checks that the given backend, and the selected squaring, agree with the portable multiplication,
+/// if the backend can be used for the given field.
+#[cfg(feature = "target-specific")]
+macro_rules! check_backend {
+    ($field:ident, $has_backend:ident, $mul_backend:ident, $rng:expr) => {{
+        const SAMPLES: usize = 1 << 20;
+
+        if $field::$has_backend() {
+            let mul_portable = |a: &mut $field, b: &$field| a.mul_assign_portable(b);
+            let mul_backend = |a: &mut $field, b: &$field| a.$mul_backend(b);
+            let square = |a: &mut $field, _: &$field| {
+                a.square_in_place();
+            };
+
+            // Check the edge cases.
+            let edges = [
+                $field::zero(),
+                $field::one(),
+                -$field::one(),
+                $field::from(2u64),
+                -$field::from(2u64),
+            ];
+            for a in edges.iter() {
+                for b in edges.iter() {
+                    check_backend_product(*a, *b, mul_portable, mul_backend);
+                }
+            }
+
+            // Check random products, and random squares with the selected squaring.
+            for _ in 0..SAMPLES {
+                let a = $field::rand($rng);
+                check_backend_product(a, $field::rand($rng), mul_portable, mul_backend);
+                check_backend_product(a, a, mul_portable, square);
+            }
+        }
+    }};
+}
+
+#[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+#[test]
+fn test_bmi2_adx_backend() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_backend!(Fq, has_bmi2_adx_backend, mul_assign_bmi2_adx, &mut rng);
+    check_backend!(Fr, has_bmi2_adx_backend, mul_assign_bmi2_adx, &mut rng);
+}
+
+#[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+#[test]
+fn test_avx2_backend() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_backend!(Fq, has_avx2_backend, mul_assign_avx2, &mut rng);
+    check_backend!(Fr, has_avx2_backend, mul_assign_avx2, &mut rng);
+}
+
+#[cfg(all(feature = "target-specific", target_arch = "aarch64", target_feature = "neon"))]
+#[test]
+fn test_neon_backend() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_backend!(Fq, has_neon_backend, mul_assign_neon, &mut rng);
+    check_backend!(Fr, has_neon_backend, mul_assign_neon, &mut rng);
+}
+
 #[test]
 fn test_fq_add_assign() {
     // Test associativity
@@ -563,6 +761,75 @@ fn test_fq12_mul_by_034() {
     }
 }
 
+/// Maps a random element into the cyclotomic subgroup via f^((p^6 - 1)(p^2 + 1)).
+fn sample_cyclotomic(rng: &mut XorShiftRng) -> Fq12 {
+    let f = Fq12::rand(rng);
+    let mut f1 = f;
+    f1.conjugate();
+    f1 *= &f.inverse().unwrap();
+    let mut f2 = f1;
+    f2.frobenius_map(2);
+    f2 * f1
+}
+
+#[test]
+fn test_fq12_cyclotomic_square_compressed() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..100 {
+        let a = sample_cyclotomic(&mut rng);
+        assert_eq!(a.cyclotomic_square(), a.square());
+
+        let mut compressed = a;
+        let mut expected = a;
+        for _ in 0..8 {
+            compressed = compressed.cyclotomic_square_compressed();
+            expected = expected.cyclotomic_square();
+            assert_eq!(compressed.decompress_karabina(), expected);
+        }
+    }
+
+    let one = Fq12::one().cyclotomic_square_compressed();
+    assert!(one.decompress_karabina().is_one());
+}
+
+#[test]
+fn test_fq12_batch_decompress_karabina() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let expected = (0..10)
+        .map(|_| sample_cyclotomic(&mut rng).cyclotomic_square())
+        .collect::<Vec<_>>();
+    let mut compressed = expected
+        .iter()
+        .map(|a| {
+            let mut a = *a;
+            a.c0.c0 = Fq2::zero();
+            a.c1.c1 = Fq2::zero();
+            a
+        })
+        .collect::<Vec<_>>();
+
+    Fq12::batch_decompress_karabina(&mut compressed);
+    assert_eq!(compressed, expected);
+}
+
+#[test]
+fn test_fq12_cyclotomic_exp_compressed() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = sample_cyclotomic(&mut rng);
+
+        let expected = a.pow(Bls12_377Parameters::X);
+        assert_eq!(a.cyclotomic_exp(Bls12_377Parameters::X), expected);
+        assert_eq!(a.cyclotomic_exp_compressed(Bls12_377Parameters::X), expected);
+
+        let exp = Fr::rand(&mut rng).to_repr();
+        assert_eq!(a.cyclotomic_exp_compressed(exp), a.cyclotomic_exp(exp));
+    }
+}
+
 #[test]
 fn test_g1_projective_curve() {
     curve_tests::<G1Projective>();
@@ -603,6 +870,106 @@ fn test_g2_generator() {
     assert!(generator.is_in_correct_subgroup_assuming_on_curve());
 }
 
+/// Returns a random point on the G2 twist curve, which is not cofactor-cleared.
+fn sample_twist_point(rng: &mut XorShiftRng) -> G2Affine {
+    loop {
+        if let Some(point) = G2Affine::from_x_coordinate(Fq2::rand(rng), rng.gen()) {
+            return point;
+        }
+    }
+}
+
+/// Checks subgroup membership by multiplying by the group order.
+fn is_in_subgroup_by_order(point: &G2Affine) -> bool {
+    point.mul_bits(BitIteratorBE::new(Fr::characteristic())).is_zero()
+}
+
+#[test]
+fn test_g2_psi() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let generator = G2Affine::prime_subgroup_generator();
+    let x_times_generator = generator.mul_bits(BitIteratorBE::new_without_leading_zeros(Bls12_377Parameters::X));
+    assert_eq!(psi(&generator).into_projective(), x_times_generator);
+    assert!(psi(&G2Affine::zero()).is_zero());
+
+    for _ in 0..ITERATIONS {
+        let a = sample_twist_point(&mut rng);
+        let b = sample_twist_point(&mut rng);
+        assert!(psi(&a).is_on_curve());
+        assert_eq!(
+            psi(&(a.into_projective() + b.into_projective()).into_affine()),
+            (psi(&a).into_projective() + psi(&b).into_projective()).into_affine()
+        );
+    }
+}
+
+#[test]
+fn test_g2_subgroup_check() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        // A cofactor-cleared point is in the subgroup.
+        let point = sample_twist_point(&mut rng).mul_by_cofactor();
+        assert!(is_in_subgroup_by_order(&point));
+        assert!(point.is_in_correct_subgroup_assuming_on_curve());
+
+        // A random point on the twist is (with overwhelming probability) not in the subgroup.
+        let point = sample_twist_point(&mut rng);
+        assert!(!is_in_subgroup_by_order(&point));
+        assert!(!point.is_in_correct_subgroup_assuming_on_curve());
+
+        // A nonzero point of order dividing the cofactor is not in the subgroup.
+        let torsion = sample_twist_point(&mut rng)
+            .mul_bits(BitIteratorBE::new(Fr::characteristic()))
+            .into_affine();
+        assert!(!torsion.is_zero());
+        assert!(torsion.is_on_curve());
+        assert!(!is_in_subgroup_by_order(&torsion));
+        assert!(!torsion.is_in_correct_subgroup_assuming_on_curve());
+
+        // Neither is a subgroup point offset by such a torsion point.
+        let point = (G2Projective::rand(&mut rng) + torsion.into_projective()).into_affine();
+        assert!(!is_in_subgroup_by_order(&point));
+        assert!(!point.is_in_correct_subgroup_assuming_on_curve());
+    }
+
+    assert!(G2Affine::zero().is_in_correct_subgroup_assuming_on_curve());
+}
+
+#[test]
+fn test_g2_clear_cofactor() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // On the prime order subgroup, the effective cofactor acts as h_eff = 4x^2 - 2x - 1.
+    let x = Fr::from(Bls12_377Parameters::X[0]);
+    let h_eff = x.square().double().double() - x.double() - Fr::one();
+    let factor = h_eff * Bls12_377G2Parameters::COFACTOR_INV;
+
+    for _ in 0..ITERATIONS {
+        let point = sample_twist_point(&mut rng);
+        let cleared = point.clear_cofactor();
+        assert!(!cleared.is_zero());
+        assert!(is_in_subgroup_by_order(&cleared.into_affine()));
+        assert!(cleared.into_affine().is_in_correct_subgroup_assuming_on_curve());
+
+        // The result agrees with the slow method, up to the fixed factor h_eff / h.
+        assert_eq!(cleared, point.scale_by_cofactor() * factor);
+
+        // Points in the subgroup are multiplied by h_eff.
+        let point = G2Projective::rand(&mut rng).into_affine();
+        assert_eq!(point.clear_cofactor(), point.into_projective() * h_eff);
+
+        // Points of order dividing the cofactor are mapped to zero.
+        let torsion = sample_twist_point(&mut rng)
+            .mul_bits(BitIteratorBE::new(Fr::characteristic()))
+            .into_affine();
+        assert!(torsion.clear_cofactor().is_zero());
+    }
+
+    assert!(G2Affine::zero().clear_cofactor().is_zero());
+}
+
 #[test]
 fn test_bilinearity() {
     let a: G1Projective = rand::random();
@@ -627,3 +994,65 @@ fn test_bilinearity() {
     assert_eq!(ans2.pow(Fr::characteristic()), Fq12::one());
     assert_eq!(ans3.pow(Fr::characteristic()), Fq12::one());
 }
+
+#[test]
+fn test_miller_loop_and_final_exponentiation() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let pairs: Vec<(G1Affine, G2Affine)> = (0..ITERATIONS)
+        .map(|_| {
+            (
+                G1Projective::rand(&mut rng).into_affine(),
+                G2Projective::rand(&mut rng).into_affine(),
+            )
+        })
+        .collect();
+    let prepared: Vec<(G1Prepared, G2Prepared)> = pairs
+        .iter()
+        .map(|(p, q)| (G1Prepared::from_affine(*p), G2Prepared::from_affine(*q)))
+        .collect();
+
+    // Ensure a miller loop followed by a final exponentiation is a pairing.
+    for ((p, q), (p_prepared, q_prepared)) in pairs.iter().zip(&prepared) {
+        let f = Bls12_377::miller_loop(std::iter::once((p_prepared, q_prepared)));
+        assert_eq!(Bls12_377::pairing(*p, *q), Bls12_377::final_exponentiation(&f).unwrap());
+    }
+
+    // Ensure separate miller loops may share a single final exponentiation.
+    let expected = pairs
+        .iter()
+        .map(|(p, q)| Bls12_377::pairing(*p, *q))
+        .fold(Fq12::one(), |acc, pairing| acc * pairing);
+    let f = prepared
+        .iter()
+        .map(|(p, q)| Bls12_377::miller_loop(std::iter::once((p, q))))
+        .fold(Fq12::one(), |acc, f| acc * f);
+    assert_eq!(expected, Bls12_377::final_exponentiation(&f).unwrap());
+
+    // Ensure a miller loop over all pairs agrees.
+    let f = Bls12_377::miller_loop(prepared.iter().map(|(p, q)| (p, q)));
+    assert_eq!(expected, Bls12_377::final_exponentiation(&f).unwrap());
+    assert_eq!(
+        expected,
+        Bls12_377::product_of_pairings(prepared.iter().map(|(p, q)| (p, q)))
+    );
+
+    // Ensure the final exponentiation of zero is rejected.
+    assert!(Bls12_377::final_exponentiation(&Fq12::zero()).is_none());
+}
+
+#[test]
+fn test_cached_g2_prepared() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    // Prepare the G2 element once, and reuse it across many pairings.
+    let q = G2Projective::rand(&mut rng).into_affine();
+    let q_prepared = G2Prepared::from_affine(q);
+    assert_eq!(q_prepared, q.prepare());
+
+    for _ in 0..100 {
+        let p = G1Projective::rand(&mut rng).into_affine();
+        let f = Bls12_377::miller_loop(std::iter::once((&G1Prepared::from_affine(p), &q_prepared)));
+        assert_eq!(Bls12_377::pairing(p, q), Bls12_377::final_exponentiation(&f).unwrap());
+    }
+}
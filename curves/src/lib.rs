@@ -27,6 +27,9 @@ extern crate derivative;
 #[macro_use]
 extern crate thiserror;
 
+#[cfg(feature = "ark-compat")]
+pub mod ark_compat;
+
 pub mod bls12_377;
 
 pub mod bw6_761;
@@ -38,6 +41,11 @@ pub mod edwards_bw6;
 pub mod errors;
 pub use errors::*;
 
+#[cfg(feature = "reference")]
+pub mod reference;
+
+pub mod secp256k1;
+
 pub mod templates;
 
 #[cfg_attr(test, macro_use)]
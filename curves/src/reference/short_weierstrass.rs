@@ -0,0 +1,95 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{templates::short_weierstrass_jacobian::Affine, traits::ShortWeierstrassParameters as Parameters};
+use snarkvm_fields::{Field, PrimeField, Zero};
+use snarkvm_utilities::BitIteratorBE;
+
+/// Returns the negation of the given point.
+pub fn sw_neg<P: Parameters>(a: &Affine<P>) -> Affine<P> {
+    match a.infinity {
+        true => *a,
+        false => Affine::new(a.x, -a.y, false),
+    }
+}
+
+/// Returns the sum of the given points, using the affine chord-and-tangent formulas.
+pub fn sw_add<P: Parameters>(a: &Affine<P>, b: &Affine<P>) -> Affine<P> {
+    if a.infinity {
+        return *b;
+    }
+    if b.infinity {
+        return *a;
+    }
+
+    if a.x == b.x {
+        // The points are either equal, or inverses of each other.
+        return match a.y == b.y {
+            true => sw_double(a),
+            false => Affine::zero(),
+        };
+    }
+
+    // lambda = (y2 - y1) / (x2 - x1)
+    let lambda = (b.y - a.y) * (b.x - a.x).inverse().expect("x2 - x1 is nonzero");
+    // x3 = lambda^2 - x1 - x2
+    let x = lambda.square() - a.x - b.x;
+    // y3 = lambda * (x1 - x3) - y1
+    let y = lambda * (a.x - x) - a.y;
+    Affine::new(x, y, false)
+}
+
+/// Returns the double of the given point, using the affine tangent formulas.
+pub fn sw_double<P: Parameters>(a: &Affine<P>) -> Affine<P> {
+    // The tangent at a point of order two is vertical.
+    if a.infinity || a.y.is_zero() {
+        return Affine::zero();
+    }
+
+    // lambda = (3 * x1^2 + a) / (2 * y1)
+    let x_squared = a.x.square();
+    let numerator = x_squared + x_squared + x_squared + P::COEFF_A;
+    let lambda = numerator * a.y.double().inverse().expect("2 * y1 is nonzero");
+    // x3 = lambda^2 - 2 * x1
+    let x = lambda.square() - a.x.double();
+    // y3 = lambda * (x1 - x3) - y1
+    let y = lambda * (a.x - x) - a.y;
+    Affine::new(x, y, false)
+}
+
+/// Returns the product of the given point and scalar, by double-and-add over the scalar bits.
+pub fn sw_mul<P: Parameters>(a: &Affine<P>, scalar: &P::ScalarField) -> Affine<P> {
+    sw_mul_bits(a, BitIteratorBE::new(scalar.to_repr()))
+}
+
+/// Returns the product of the given point and the big-endian bits, by double-and-add.
+pub fn sw_mul_bits<P: Parameters>(a: &Affine<P>, bits: impl Iterator<Item = bool>) -> Affine<P> {
+    let mut output = Affine::zero();
+    for bit in bits {
+        output = sw_double(&output);
+        if bit {
+            output = sw_add(&output, a);
+        }
+    }
+    output
+}
+
+/// Returns `true` if the given point is on the curve, and in the prime-order subgroup.
+pub fn sw_is_in_prime_subgroup<P: Parameters>(a: &Affine<P>) -> bool {
+    // Check that y^2 = x^3 + ax + b.
+    let is_on_curve = a.infinity || a.y.square() == a.x.square() * a.x + P::COEFF_A * a.x + P::COEFF_B;
+    is_on_curve && sw_mul_bits(a, BitIteratorBE::new(P::ScalarField::characteristic())).infinity
+}
@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bls12_377::{Bls12_377G1Parameters, Bls12_377G2Parameters},
+    bw6_761::BW6_761G1Parameters,
+    reference::*,
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::{AffineCurve, Group, ModelParameters, ProjectiveCurve, ShortWeierstrassParameters},
+};
+use snarkvm_fields::{One, Zero};
+use snarkvm_utilities::rand::UniformRand;
+
+use rand::SeedableRng;
+use rand_xorshift::XorShiftRng;
+use std::ops::Mul;
+
+const ITERATIONS: usize = 10;
+
+/// Returns a random point, or one of the edge cases.
+fn sample_point<P: ShortWeierstrassParameters>(rng: &mut XorShiftRng) -> Affine<P> {
+    use rand::Rng;
+
+    match rng.gen_range(0..4) {
+        0 => Affine::zero(),
+        1 => Affine::prime_subgroup_generator(),
+        _ => Projective::<P>::rand(rng).into_affine(),
+    }
+}
+
+/// Returns a random scalar, or one of the edge cases.
+fn sample_scalar<P: ShortWeierstrassParameters>(rng: &mut XorShiftRng) -> P::ScalarField {
+    use rand::Rng;
+
+    match rng.gen_range(0..5) {
+        0 => P::ScalarField::zero(),
+        1 => P::ScalarField::one(),
+        2 => -P::ScalarField::one(),
+        _ => P::ScalarField::rand(rng),
+    }
+}
+
+fn check_group_law<P: ShortWeierstrassParameters>(rng: &mut XorShiftRng) {
+    for _ in 0..ITERATIONS {
+        let a = sample_point::<P>(rng);
+        let b = sample_point::<P>(rng);
+
+        // Check addition, including of equal and inverse points.
+        assert_eq!(
+            (a.into_projective() + b.into_projective()).into_affine(),
+            sw_add(&a, &b)
+        );
+        assert_eq!(a.into_projective().double().into_affine(), sw_add(&a, &a));
+        assert_eq!(a.into_projective().double().into_affine(), sw_double(&a));
+        assert_eq!(Affine::zero(), sw_add(&a, &sw_neg(&a)));
+        assert_eq!(-a, sw_neg(&a));
+
+        // Check the identity.
+        assert_eq!(a, sw_add(&a, &Affine::zero()));
+        assert_eq!(a, sw_add(&Affine::zero(), &a));
+
+        // Check scalar multiplication.
+        let scalar = sample_scalar::<P>(rng);
+        assert_eq!(a.mul(scalar), sw_mul(&a, &scalar));
+        assert_eq!(Affine::zero(), sw_mul(&a, &P::ScalarField::zero()));
+        assert_eq!(a, sw_mul(&a, &P::ScalarField::one()));
+
+        // Check subgroup membership.
+        assert_eq!(
+            a.is_in_correct_subgroup_assuming_on_curve(),
+            sw_is_in_prime_subgroup(&a)
+        );
+        assert!(sw_is_in_prime_subgroup(&a));
+    }
+
+    // Check the generator has the expected order.
+    let generator = Affine::<P>::prime_subgroup_generator();
+    assert_eq!(sw_neg(&generator), sw_mul(&generator, &-P::ScalarField::one()));
+}
+
+#[test]
+fn test_bls12_377_g1_group_law() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_group_law::<Bls12_377G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_bls12_377_g2_group_law() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_group_law::<Bls12_377G2Parameters>(&mut rng);
+}
+
+#[test]
+fn test_bw6_761_g1_group_law() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    check_group_law::<BW6_761G1Parameters>(&mut rng);
+}
+
+#[test]
+fn test_point_of_order_two() {
+    // On BLS12-377 G1, y^2 = x^3 + 1 has the point (-1, 0) of order two, outside the prime subgroup.
+    let point = Affine::<Bls12_377G1Parameters>::new(
+        -<Bls12_377G1Parameters as ModelParameters>::BaseField::one(),
+        Zero::zero(),
+        false,
+    );
+    assert!(point.is_on_curve());
+    assert_eq!(Affine::zero(), sw_double(&point));
+    assert_eq!(Affine::zero(), sw_add(&point, &point));
+    assert_eq!(point.into_projective().double().into_affine(), sw_double(&point));
+    assert!(!sw_is_in_prime_subgroup(&point));
+    assert!(!point.is_in_correct_subgroup_assuming_on_curve());
+}
@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// secp256k1 base field.
+///
+/// The modulus occupies all 256 bits of its representation, so elements have no spare bits for flags.
+pub type Fq = Fp256<FqParameters>;
+
+pub struct FqParameters;
+
+impl Fp256Parameters for FqParameters {}
+
+impl FftParameters for FqParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 1;
+    /// TWO_ADIC_ROOT_OF_UNITY = 115792089237316195423570985008687907853269984665640564039457584007908834671662
+    /// Encoded in Montgomery form, the value is
+    /// (115792089237316195423570985008687907853269984665640564039457584007908834671662 * R % p) =
+    /// 115792089237316195423570985008687907853269984665640564039457584007904539703390
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        18446744065119615070u64,
+        18446744073709551615u64,
+        18446744073709551615u64,
+        18446744073709551615u64,
+    ]);
+}
+
+impl FieldParameters for FqParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 3
+    /// Encoded in Montgomery form, so the value is
+    /// (3 * R) % p = 12884904819
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        12884904819u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 15580212934572586289u64;
+    /// MODULUS = 115792089237316195423570985008687907853269984665640564039457584007908834671663
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        18446744069414583343u64,
+        18446744073709551615u64,
+        18446744073709551615u64,
+        18446744073709551615u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 256;
+    /// (p - 1) / 2 =
+    /// 57896044618658097711785492504343953926634992332820282019728792003954417335831
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        4294968273u64,
+        0u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        8392367050913u64,
+        1u64,
+        0u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 0;
+    // T and T_MINUS_ONE_DIV_TWO, where p - 1 = 2^s * t
+
+    /// t = (p - 1) / 2^s =
+    /// 57896044618658097711785492504343953926634992332820282019728792003954417335831
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xffffffff7ffffe17,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    /// (t - 1) / 2 =
+    /// 28948022309329048855892746252171976963317496166410141009864396001977208667915
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xffffffffbfffff0b,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x3fffffffffffffff,
+    ]);
+}
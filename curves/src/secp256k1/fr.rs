@@ -0,0 +1,117 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::{FftParameters, FieldParameters, Fp256, Fp256Parameters};
+use snarkvm_utilities::biginteger::BigInteger256 as BigInteger;
+
+/// secp256k1 scalar field.
+///
+/// The modulus occupies all 256 bits of its representation, so elements have no spare bits for flags.
+pub type Fr = Fp256<FrParameters>;
+
+pub struct FrParameters;
+
+impl Fp256Parameters for FrParameters {}
+
+impl FftParameters for FrParameters {
+    type BigInteger = BigInteger;
+
+    #[rustfmt::skip]
+    const TWO_ADICITY: u32 = 6;
+    /// TWO_ADIC_ROOT_OF_UNITY = 5480320495727936603795231718619559942670027629901634955707709633242980176626
+    /// Encoded in Montgomery form, the value is
+    /// (5480320495727936603795231718619559942670027629901634955707709633242980176626 * R % n) =
+    /// 90015010938781513766484954557678634143544562072478386605334821051798268218884
+    #[rustfmt::skip]
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger = BigInteger([
+        10686182793988345348u64,
+        9321468937290222068u64,
+        6167691817532924179u64,
+        14340218580707203894u64,
+    ]);
+}
+
+impl FieldParameters for FrParameters {
+    #[rustfmt::skip]
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+    /// GENERATOR = 7
+    /// Encoded in Montgomery form, so the value is
+    /// (7 * R) % n = 3026942705959617597966946064764777019193
+    #[rustfmt::skip]
+    const GENERATOR: BigInteger = BigInteger([
+        13924965285611452217u64,
+        16516940299852029533u64,
+        8u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const INV: u64 = 5408259542528602431u64;
+    /// MODULUS = 115792089237316195423570985008687907852837564279074904382605163141518161494337
+    #[rustfmt::skip]
+    const MODULUS: BigInteger = BigInteger([
+        13822214165235122497u64,
+        13451932020343611451u64,
+        18446744073709551614u64,
+        18446744073709551615u64,
+    ]);
+    #[rustfmt::skip]
+    const MODULUS_BITS: u32 = 256;
+    /// (n - 1) / 2 =
+    /// 57896044618658097711785492504343953926418782139537452191302581570759080747168
+    #[rustfmt::skip]
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0xdfe92f46681b20a0,
+        0x5d576e7357a4501d,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+    #[rustfmt::skip]
+    const R: BigInteger = BigInteger([
+        4624529908474429119u64,
+        4994812053365940164u64,
+        1u64,
+        0u64,
+    ]);
+    #[rustfmt::skip]
+    const R2: BigInteger = BigInteger([
+        9902555850136342848u64,
+        8364476168144746616u64,
+        16616019711348246470u64,
+        11342065889886772165u64,
+    ]);
+    #[rustfmt::skip]
+    const REPR_SHAVE_BITS: u32 = 0;
+    // T and T_MINUS_ONE_DIV_TWO, where n - 1 = 2^s * t
+
+    /// t = (n - 1) / 2^s =
+    /// 1809251394333065553493296640760748560200586941860545380978205674086221273349
+    #[rustfmt::skip]
+    const T: BigInteger = BigInteger([
+        0xeeff497a3340d905,
+        0xfaeabb739abd2280,
+        0xffffffffffffffff,
+        0x3ffffffffffffff,
+    ]);
+    /// (t - 1) / 2 =
+    /// 904625697166532776746648320380374280100293470930272690489102837043110636674
+    #[rustfmt::skip]
+    const T_MINUS_ONE_DIV_TWO: BigInteger = BigInteger([
+        0x777fa4bd19a06c82,
+        0xfd755db9cd5e9140,
+        0xffffffffffffffff,
+        0x1ffffffffffffff,
+    ]);
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    secp256k1::*,
+    traits::{
+        tests_field::{field_test, primefield_test, sqrt_field_test},
+        tests_group::group_test,
+        AffineCurve,
+    },
+};
+use snarkvm_fields::{Field, One, PrimeField, Zero};
+use snarkvm_utilities::biginteger::BigInteger256;
+
+use std::str::FromStr;
+
+// The moduli occupy all 256 bits, so there are no spare bits for serialization flags,
+// and `field_serialization_test` and `sw_tests` do not apply. As the cofactor is 1, random
+// points are sampled in affine form, so `curve_tests` (which expects them not to be) does not apply.
+
+#[test]
+fn test_secp256k1_fr() {
+    let a: Fr = rand::random();
+    let b: Fr = rand::random();
+    field_test(a, b);
+    sqrt_field_test(a);
+    primefield_test::<Fr>();
+}
+
+#[test]
+fn test_secp256k1_fq() {
+    let a: Fq = rand::random();
+    let b: Fq = rand::random();
+    field_test(a, b);
+    sqrt_field_test(a);
+    primefield_test::<Fq>();
+}
+
+#[test]
+fn test_full_width_arithmetic() {
+    // p - 1 exercises the carries out of the top limb in addition, doubling and multiplication.
+    let minus_one = -Fq::one();
+    assert_eq!(-Fq::from_str("2").unwrap(), minus_one + minus_one);
+    assert_eq!(-Fq::from_str("2").unwrap(), minus_one.double());
+    assert_eq!(Fq::one(), minus_one.square());
+    assert_eq!(minus_one, minus_one.inverse().unwrap());
+    assert_eq!(
+        BigInteger256([
+            0xfffffffefffffc2e,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff
+        ]),
+        minus_one.to_repr()
+    );
+
+    let minus_one = -Fr::one();
+    assert_eq!(-Fr::from_str("2").unwrap(), minus_one + minus_one);
+    assert_eq!(-Fr::from_str("2").unwrap(), minus_one.double());
+    assert_eq!(Fr::one(), minus_one.square());
+    assert_eq!(minus_one, minus_one.inverse().unwrap());
+}
+
+#[test]
+fn test_projective_group() {
+    for _i in 0..10 {
+        let a = rand::random();
+        let b = rand::random();
+        group_test::<Secp256k1Projective>(a, b);
+    }
+}
+
+#[test]
+fn test_affine_group() {
+    for _i in 0..10 {
+        let a: Secp256k1Affine = rand::random();
+        let b: Secp256k1Affine = rand::random();
+        group_test::<Secp256k1Affine>(a, b);
+    }
+}
+
+#[test]
+fn test_generator() {
+    let generator = Secp256k1Affine::prime_subgroup_generator();
+    assert!(generator.is_on_curve());
+    assert!(generator.is_in_correct_subgroup_assuming_on_curve());
+    assert!(
+        generator
+            .mul_bits(snarkvm_utilities::BitIteratorBE::new(Fr::characteristic()))
+            .is_zero()
+    );
+}
+
+#[test]
+fn test_scalar_multiplication() {
+    // The public key of the secret key `0x46...46` from the example in EIP-155.
+    let secret_key =
+        Fr::from_str("31786063712204445802548897845522170783250584025862115618674630904133015979590").unwrap();
+    let public_key = Secp256k1Affine::prime_subgroup_generator() * secret_key;
+    assert_eq!(
+        Fq::from_str("34267357454890834202699501369117337915298038816505545536566299989069368210306").unwrap(),
+        public_key.to_x_coordinate()
+    );
+    assert_eq!(
+        Fq::from_str("93248519793482870262716478842254439831529833249880237208181267626996973249322").unwrap(),
+        public_key.to_y_coordinate()
+    );
+}
@@ -85,7 +85,7 @@ impl<P: Bls12Parameters> Bls12<P> {
     }
 
     fn exp_by_x(mut f: Fp12<P::Fp12Params>) -> Fp12<P::Fp12Params> {
-        f = f.cyclotomic_exp(P::X);
+        f = f.cyclotomic_exp_compressed(P::X);
         if P::X_IS_NEGATIVE {
             f.conjugate();
         }
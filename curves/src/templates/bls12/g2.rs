@@ -30,6 +30,10 @@ pub type G2Affine<P> = Affine<<P as Bls12Parameters>::G2Parameters>;
 pub type G2Projective<P> = Projective<<P as Bls12Parameters>::G2Parameters>;
 type CoeffTriplet<T> = (Fp2<T>, Fp2<T>, Fp2<T>);
 
+/// A G2 element with its miller loop line coefficients precomputed.
+///
+/// Preparing an element costs about as much as the G2 half of one miller loop. A prepared element
+/// may be cached and reused across any number of miller loops.
 #[derive(Derivative, CanonicalSerialize, CanonicalDeserialize)]
 #[derivative(
     Clone(bound = "P: Bls12Parameters"),
@@ -96,6 +100,7 @@ impl<P: Bls12Parameters> G2Prepared<P> {
         self.infinity
     }
 
+    /// Returns the prepared form of the given G2 element.
     pub fn from_affine(q: G2Affine<P>) -> Self {
         let two_inv = P::Fp::one().double().inverse().unwrap();
         if q.is_zero() {
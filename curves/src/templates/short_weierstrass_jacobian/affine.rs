@@ -19,7 +19,15 @@ use crate::{
     templates::short_weierstrass_jacobian::Projective,
     traits::{AffineCurve, Group, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{
+    impl_add_sub_from_field_ref,
+    ConditionallySelectable,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
@@ -64,6 +72,14 @@ impl<P: Parameters> Affine<P> {
     pub fn scale_by_cofactor(&self) -> Projective<P> {
         self.mul_bits(BitIteratorBE::new(P::COFACTOR))
     }
+
+    /// Returns a multiple of this point which lies in the prime order subgroup,
+    /// using the fastest cofactor clearing available for the curve.
+    ///
+    /// This may differ from `scale_by_cofactor`; see `ShortWeierstrassParameters::clear_cofactor`.
+    pub fn clear_cofactor(&self) -> Projective<P> {
+        P::clear_cofactor(self)
+    }
 }
 
 impl<P: Parameters> Zero for Affine<P> {
@@ -163,8 +179,7 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 
     fn is_in_correct_subgroup_assuming_on_curve(&self) -> bool {
-        self.mul_bits(BitIteratorBE::new(P::ScalarField::characteristic()))
-            .is_zero()
+        P::is_in_correct_subgroup_assuming_on_curve(self)
     }
 
     fn to_x_coordinate(&self) -> Self::BaseField {
@@ -188,6 +203,20 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.x, &b.x, choice),
+            ConditionallySelectable::conditional_select(&a.y, &b.y, choice),
+            (a.infinity & !choice) | (b.infinity & choice),
+        )
+    }
+}
+
 impl<P: Parameters> ToMinimalBits for Affine<P> {
     fn to_minimal_bits(&self) -> Vec<bool> {
         let mut res_bits = self.x.to_bits_le();
@@ -19,7 +19,15 @@ use crate::{
     templates::short_weierstrass_projective::Projective,
     traits::{AffineCurve, Group, ProjectiveCurve, ShortWeierstrassParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{
+    impl_add_sub_from_field_ref,
+    ConditionallySelectable,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
@@ -175,6 +183,20 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.x, &b.x, choice),
+            ConditionallySelectable::conditional_select(&a.y, &b.y, choice),
+            (a.infinity & !choice) | (b.infinity & choice),
+        )
+    }
+}
+
 impl<P: Parameters> ToMinimalBits for Affine<P> {
     fn to_minimal_bits(&self) -> Vec<bool> {
         let mut res_bits = self.x.to_bits_le();
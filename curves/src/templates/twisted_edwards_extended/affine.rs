@@ -19,7 +19,15 @@ use crate::{
     templates::twisted_edwards_extended::Projective,
     traits::{AffineCurve, Group, MontgomeryParameters, ProjectiveCurve, TwistedEdwardsParameters as Parameters},
 };
-use snarkvm_fields::{impl_add_sub_from_field_ref, Field, One, PrimeField, SquareRootField, Zero};
+use snarkvm_fields::{
+    impl_add_sub_from_field_ref,
+    ConditionallySelectable,
+    Field,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{
     bititerator::BitIteratorBE,
     rand::UniformRand,
@@ -40,6 +48,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use zeroize::Zeroize;
 
 #[derive(Derivative)]
 #[derivative(
@@ -102,6 +111,16 @@ impl<P: Parameters> Zero for Affine<P> {
     }
 }
 
+impl<P: Parameters> Zeroize for Affine<P>
+where
+    P::BaseField: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
 impl<P: Parameters> Display for Affine<P> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "Affine(x={}, y={})", self.x, self.y)
@@ -217,6 +236,19 @@ impl<P: Parameters> AffineCurve for Affine<P> {
     }
 }
 
+impl<P: Parameters> ConditionallySelectable for Affine<P>
+where
+    P::BaseField: ConditionallySelectable,
+{
+    #[inline]
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.x, &b.x, choice),
+            ConditionallySelectable::conditional_select(&a.y, &b.y, choice),
+        )
+    }
+}
+
 impl<P: Parameters> ToMinimalBits for Affine<P> {
     fn to_minimal_bits(&self) -> Vec<bool> {
         self.x.to_bits_le()
@@ -30,6 +30,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use zeroize::Zeroize;
 
 #[derive(Derivative)]
 #[derivative(
@@ -130,6 +131,18 @@ impl<P: Parameters> Zero for Projective<P> {
     }
 }
 
+impl<P: Parameters> Zeroize for Projective<P>
+where
+    P::BaseField: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.t.zeroize();
+        self.z.zeroize();
+    }
+}
+
 impl<P: Parameters> ProjectiveCurve for Projective<P> {
     type Affine = Affine<P>;
     type BaseField = P::BaseField;
@@ -14,9 +14,12 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::traits::Group;
-use snarkvm_fields::{Field, PrimeField, SquareRootField, ToConstraintField};
-use snarkvm_utilities::{biginteger::BigInteger, serialize::*, ToBytes, ToMinimalBits};
+use crate::{
+    templates::short_weierstrass_jacobian::{Affine, Projective},
+    traits::Group,
+};
+use snarkvm_fields::{Field, PrimeField, SquareRootField, ToConstraintField, Zero};
+use snarkvm_utilities::{biginteger::BigInteger, bititerator::BitIteratorBE, serialize::*, ToBytes, ToMinimalBits};
 
 use serde::{de::DeserializeOwned, Serialize};
 use std::{fmt::Debug, iter};
@@ -55,6 +58,11 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Sync
     type Fqk: Field;
 
     /// Perform a miller loop with some number of (G1, G2) pairs.
+    ///
+    /// The loop squares the accumulator once per bit of the curve parameter, shared by all pairs,
+    /// and evaluates one line per step for each pair. Pairs with an identity element are skipped.
+    /// The output is not a pairing until it is passed to [`Self::final_exponentiation`], so
+    /// the outputs of several miller loops may be multiplied together and exponentiated once.
     #[must_use]
     fn miller_loop<'a, I>(i: I) -> Self::Fqk
     where
@@ -66,10 +74,13 @@ pub trait PairingEngine: Sized + 'static + Copy + Debug + PartialEq + Eq + Sync
         >;
 
     /// Perform final exponentiation of the result of a miller loop.
+    ///
+    /// This costs roughly as much as a miller loop over a single pair, and is independent of the
+    /// number of pairs. Returns `None` if the given element is zero.
     #[must_use]
     fn final_exponentiation(_: &Self::Fqk) -> Option<Self::Fqk>;
 
-    /// Computes a product of pairings.
+    /// Computes a product of pairings, with a single final exponentiation.
     #[must_use]
     fn product_of_pairings<'a, I>(i: I) -> Self::Fqk
     where
@@ -281,6 +292,31 @@ pub trait ShortWeierstrassParameters: ModelParameters {
         copy
     }
 
+    /// Checks that the given point is in the prime order subgroup, assuming it is on the curve.
+    ///
+    /// By default, this multiplies the point by the order of the subgroup.
+    #[inline]
+    fn is_in_correct_subgroup_assuming_on_curve(p: &Affine<Self>) -> bool
+    where
+        Self: Sized,
+    {
+        p.mul_bits(BitIteratorBE::new(Self::ScalarField::characteristic()))
+            .is_zero()
+    }
+
+    /// Returns a multiple of the given point which lies in the prime order subgroup.
+    ///
+    /// By default, this multiplies the point by the cofactor, matching `mul_by_cofactor`.
+    /// Curves may override this with a faster map to a different multiple of the point,
+    /// so callers whose outputs must be reproducible should use `mul_by_cofactor` instead.
+    #[inline]
+    fn clear_cofactor(p: &Affine<Self>) -> Projective<Self>
+    where
+        Self: Sized,
+    {
+        p.scale_by_cofactor()
+    }
+
     #[inline(always)]
     fn empirical_recommended_wnaf_for_scalar(scalar: <Self::ScalarField as PrimeField>::BigInteger) -> usize {
         let num_bits = scalar.num_bits() as usize;
@@ -15,7 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::traits::{AffineCurve, ProjectiveCurve};
-use snarkvm_fields::Zero;
+use snarkvm_fields::{ConditionallySelectable, Zero};
 use snarkvm_utilities::rand::UniformRand;
 
 use rand::SeedableRng;
@@ -228,7 +228,31 @@ fn random_transformation_test<G: ProjectiveCurve>() {
     }
 }
 
-pub fn curve_tests<G: ProjectiveCurve>() {
+fn random_conditional_select_test<G: ProjectiveCurve>()
+where
+    G::Affine: ConditionallySelectable,
+{
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = G::rand(&mut rng).into_affine();
+        let b = G::rand(&mut rng).into_affine();
+
+        assert_eq!(a, G::Affine::conditional_select(&a, &b, false));
+        assert_eq!(b, G::Affine::conditional_select(&a, &b, true));
+
+        // Selecting to and from the point at infinity.
+        let zero = G::Affine::zero();
+        assert_eq!(zero, G::Affine::conditional_select(&zero, &a, false));
+        assert_eq!(a, G::Affine::conditional_select(&zero, &a, true));
+        assert_eq!(zero, G::Affine::conditional_select(&a, &zero, true));
+    }
+}
+
+pub fn curve_tests<G: ProjectiveCurve>()
+where
+    G::Affine: ConditionallySelectable,
+{
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
     // Negation edge case with zero.
@@ -282,4 +306,5 @@ pub fn curve_tests<G: ProjectiveCurve>() {
     random_doubling_test::<G>();
     random_negation_test::<G>();
     random_transformation_test::<G>();
+    random_conditional_select_test::<G>();
 }
@@ -14,14 +14,27 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkvm_fields::{traits::FftParameters, FftField, Field, LegendreSymbol, PrimeField, SquareRootField};
+use snarkvm_fields::{
+    traits::FftParameters,
+    ConditionallyNegatable,
+    ConditionallySelectable,
+    FftField,
+    Field,
+    FieldParameters,
+    LegendreSymbol,
+    PrimeField,
+    SquareRootField,
+};
 use snarkvm_utilities::{
+    biginteger::BigInteger,
     io::Cursor,
     serialize::{CanonicalDeserialize, CanonicalSerialize, Flags, SWFlags},
+    ToBits,
 };
 
 use rand::{Rng, SeedableRng};
 use rand_xorshift::XorShiftRng;
+use zeroize::Zeroize;
 
 pub const ITERATIONS: u32 = 10;
 
@@ -126,6 +139,35 @@ fn random_squaring_tests<F: Field, R: Rng>(rng: &mut R) {
     }
 }
 
+fn random_conditional_tests<F: Field + ConditionallySelectable + ConditionallyNegatable, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let a = F::rand(rng);
+        let b = F::rand(rng);
+
+        assert_eq!(a, F::conditional_select(&a, &b, false));
+        assert_eq!(b, F::conditional_select(&a, &b, true));
+
+        let mut c = a;
+        c.conditional_negate_in_place(false);
+        assert_eq!(a, c);
+        c.conditional_negate_in_place(true);
+        assert_eq!(-a, c);
+    }
+
+    let mut zero = F::zero();
+    zero.conditional_negate_in_place(true);
+    assert!(zero.is_zero());
+}
+
+fn random_zeroize_tests<F: Field + Zeroize, R: Rng>(rng: &mut R) {
+    for _ in 0..ITERATIONS {
+        let mut a = F::rand(rng);
+        a.zeroize();
+        assert!(a.is_zero());
+        assert_eq!(F::zero().to_bytes_le().unwrap(), a.to_bytes_le().unwrap());
+    }
+}
+
 fn random_expansion_tests<F: Field, R: Rng>(rng: &mut R) {
     for _ in 0..ITERATIONS {
         // Compare (a + b)(c + d) and (a*c + b*c + a*d + b*d)
@@ -211,6 +253,27 @@ fn random_string_tests<F: PrimeField>() {
     }
 }
 
+fn random_bits_tests<F: PrimeField>() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..ITERATIONS {
+        let a = F::rand(&mut rng);
+        let repr = a.to_repr();
+        assert_eq!(a, F::from_bits_le_checked(&repr.to_bits_le()).unwrap());
+        assert_eq!(a, F::from_bits_be_checked(&repr.to_bits_be()).unwrap());
+    }
+
+    // The modulus is not a valid field element.
+    let modulus = F::Parameters::MODULUS;
+    assert!(F::from_bits_le_checked(&modulus.to_bits_le()).is_none());
+    assert!(F::from_bits_be_checked(&modulus.to_bits_be()).is_none());
+
+    // Inputs wider than the representation are rejected.
+    let num_bits = <F::BigInteger as BigInteger>::NUM_LIMBS * 64;
+    assert!(F::from_bits_le_checked(&vec![false; num_bits + 1]).is_none());
+    assert!(F::from_bits_be_checked(&vec![false; num_bits + 1]).is_none());
+}
+
 fn random_sqrt_tests<F: SquareRootField>() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
 
@@ -241,7 +304,7 @@ fn random_sqrt_tests<F: SquareRootField>() {
 }
 
 #[allow(clippy::eq_op)]
-pub fn field_test<F: Field>(a: F, b: F) {
+pub fn field_test<F: Field + ConditionallySelectable + ConditionallyNegatable + Zeroize>(a: F, b: F) {
     let zero = F::zero();
     assert!(zero == zero);
     assert!(zero.is_zero()); // true
@@ -310,6 +373,8 @@ pub fn field_test<F: Field>(a: F, b: F) {
     random_inversion_tests::<F, _>(&mut rng);
     random_doubling_tests::<F, _>(&mut rng);
     random_squaring_tests::<F, _>(&mut rng);
+    random_conditional_tests::<F, _>(&mut rng);
+    random_zeroize_tests::<F, _>(&mut rng);
     random_expansion_tests::<F, _>(&mut rng);
 
     assert!(F::zero().is_zero());
@@ -374,6 +439,7 @@ pub fn primefield_test<F: PrimeField>() {
     assert_eq!(F::from_str(&two.to_string()).ok().unwrap(), two);
 
     random_string_tests::<F>();
+    random_bits_tests::<F>();
     fft_field_test::<F>();
 }
 
@@ -158,27 +158,37 @@ pub fn derive_canonical_deserialize(input: proc_macro::TokenStream) -> proc_macr
 }
 
 /// Returns two TokenStreams, one for the compressed deserialize, one for the
-/// uncompressed.
-fn impl_deserialize_field(ty: &Type) -> (TokenStream, TokenStream) {
+/// uncompressed. Errors are wrapped with the given type and field name.
+fn impl_deserialize_field(ty: &Type, type_name: &str, field_name: &str) -> (TokenStream, TokenStream) {
     // Check if type is a tuple.
     match ty {
         Type::Tuple(tuple) => {
-            let (compressed_fields, uncompressed_fields): (Vec<_>, Vec<_>) =
-                tuple.elems.iter().map(impl_deserialize_field).unzip();
+            let (compressed_fields, uncompressed_fields): (Vec<_>, Vec<_>) = tuple
+                .elems
+                .iter()
+                .map(|elem_ty| impl_deserialize_field(elem_ty, type_name, field_name))
+                .unzip();
             (
                 quote! { (#(#compressed_fields)*), },
                 quote! { (#(#uncompressed_fields)*), },
             )
         }
         _ => (
-            quote! { CanonicalDeserialize::deserialize(reader)?, },
-            quote! { CanonicalDeserialize::deserialize_uncompressed(reader)?, },
+            quote! {
+                CanonicalDeserialize::deserialize(reader)
+                    .map_err(|error| SerializationError::in_field(error, #type_name, #field_name))?,
+            },
+            quote! {
+                CanonicalDeserialize::deserialize_uncompressed(reader)
+                    .map_err(|error| SerializationError::in_field(error, #type_name, #field_name))?,
+            },
         ),
     }
 }
 
 fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
     let name = &ast.ident;
+    let type_name = name.to_string();
 
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
 
@@ -190,17 +200,18 @@ fn impl_canonical_deserialize(ast: &syn::DeriveInput) -> TokenStream {
             let mut tuple = false;
             let mut compressed_field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
             let mut uncompressed_field_cases = Vec::<TokenStream>::with_capacity(data_struct.fields.len());
-            for field in data_struct.fields.iter() {
+            for (i, field) in data_struct.fields.iter().enumerate() {
                 match &field.ident {
                     None => {
                         tuple = true;
-                        let (compressed, uncompressed) = impl_deserialize_field(&field.ty);
+                        let (compressed, uncompressed) = impl_deserialize_field(&field.ty, &type_name, &i.to_string());
                         compressed_field_cases.push(compressed);
                         uncompressed_field_cases.push(uncompressed);
                     }
                     // struct field without len_type
                     Some(ident) => {
-                        let (compressed_field, uncompressed_field) = impl_deserialize_field(&field.ty);
+                        let (compressed_field, uncompressed_field) =
+                            impl_deserialize_field(&field.ty, &type_name, &ident.to_string());
                         compressed_field_cases.push(quote! { #ident: #compressed_field });
                         uncompressed_field_cases.push(quote! { #ident: #uncompressed_field });
                     }
@@ -20,6 +20,7 @@ extern crate criterion;
 use snarkvm_dpc::{prelude::*, testnet2::Testnet2};
 
 use criterion::Criterion;
+use rand::thread_rng;
 use serde::{de::DeserializeOwned, Serialize};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
@@ -97,6 +98,37 @@ fn block_transactions_serialization(c: &mut Criterion) {
     bench_serialization(c, "BlockTransactions", transactions);
 }
 
+fn block_transactions_verification(c: &mut Criterion) {
+    let rng = &mut thread_rng();
+
+    let ledger = Ledger::<Testnet2>::new().unwrap();
+    let account = Account::<Testnet2>::new(rng);
+    let recipient = Account::<Testnet2>::new(rng).address();
+
+    // Craft a block of 1 coinbase and 49 transfers.
+    let (coinbase, _) = Transaction::new_coinbase(account.address(), AleoAmount::from_aleo(100), true, rng).unwrap();
+    let mut transactions = vec![coinbase];
+    for _ in 1..50 {
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![],
+            vec![LedgerProof::default(); Testnet2::NUM_INPUT_RECORDS],
+            recipient,
+            AleoAmount::ZERO,
+            AleoAmount::ZERO,
+            true,
+            rng,
+        )
+        .unwrap();
+        transactions.push(Transaction::new(ledger.to_ledger_tree().clone(), &request, rng).unwrap());
+    }
+    let transactions = Transactions::from(&transactions).unwrap();
+
+    c.bench_function("BlockTransactions::is_valid (50 transfers)", move |b| {
+        b.iter(|| assert!(transactions.is_valid()))
+    });
+}
+
 fn transaction_serialization(c: &mut Criterion) {
     let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
     bench_serialization(c, "Transaction", transaction);
@@ -114,7 +146,7 @@ fn transition_serialization(c: &mut Criterion) {
 criterion_group! {
     name = block;
     config = Criterion::default().sample_size(10);
-    targets = block_serialization, block_header_serialization, block_transactions_serialization, transaction_serialization, transition_serialization
+    targets = block_serialization, block_header_serialization, block_transactions_serialization, block_transactions_verification, transaction_serialization, transition_serialization
 }
 
 criterion_main!(block);
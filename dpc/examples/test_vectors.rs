@@ -0,0 +1,31 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_dpc::{
+    testnet2::Testnet2,
+    test_vectors::{TestVectors, SEED},
+};
+
+/// Prints the Testnet2 test vectors as JSON, optionally from the seed given as the first argument.
+fn main() {
+    let seed = match std::env::args().nth(1) {
+        Some(seed) => seed.parse().expect("The seed must be a u64"),
+        None => SEED,
+    };
+
+    let vectors = TestVectors::generate::<Testnet2>(seed).expect("Failed to generate the test vectors");
+    println!("{}", serde_json::to_string_pretty(&vectors).unwrap());
+}
@@ -67,6 +67,10 @@ impl<N: Network> From<PrivateKey<N>> for Account<N> {
     }
 }
 
+/// The private key and view key of an account are zeroized when the account is dropped.
+#[cfg(feature = "zeroize-on-drop")]
+impl<N: Network> zeroize::ZeroizeOnDrop for Account<N> {}
+
 impl<N: Network> fmt::Display for Account<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
@@ -14,7 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{account_format, AccountError, ComputeKey, Network, PrivateKey, ViewKey};
+use crate::{
+    account_format,
+    to_signed_message_bytes,
+    AccountError,
+    AddressParseError,
+    ComputeKey,
+    Network,
+    PrivateKey,
+    ViewKey,
+};
 use snarkvm_algorithms::{EncryptionScheme, SignatureScheme};
 use snarkvm_curves::AffineCurve;
 use snarkvm_utilities::{
@@ -28,7 +37,7 @@ use snarkvm_utilities::{
     ToBytesSerializer,
 };
 
-use bech32::{self, FromBase32, ToBase32};
+use bech32::{self, u5, FromBase32, ToBase32};
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Derivative)]
@@ -65,6 +74,99 @@ impl<N: Network> Address<N> {
     pub fn verify_signature(&self, message: &[u8], signature: &N::AccountSignature) -> Result<bool, AccountError> {
         Ok(N::account_signature_scheme().verify(&self.0, message, signature)?)
     }
+
+    /// Verifies a signature on an arbitrary message signed with `PrivateKey::sign_bytes`.
+    /// Returns `true` if the signature is valid. Otherwise, returns `false`.
+    pub fn verify_bytes(&self, message: &[u8], signature: &N::AccountSignature) -> Result<bool, AccountError> {
+        self.verify_signature(&to_signed_message_bytes(message), signature)
+    }
+
+    /// Checks that the given string is a valid account address, and returns
+    /// the location of the first error otherwise, for wallets to highlight.
+    pub fn validate_str(address: &str) -> Result<(), AddressParseError> {
+        let characters: Vec<char> = address.chars().collect();
+        if characters.len() != 63 {
+            return Err(AddressParseError::WrongLength {
+                found: characters.len(),
+            });
+        }
+
+        // Ensure the prefix is correct, ignoring the case.
+        let separator = characters.iter().rposition(|c| *c == '1').unwrap_or(characters.len());
+        let hrp: String = characters[..separator].iter().collect();
+        if !hrp.eq_ignore_ascii_case(account_format::ADDRESS_PREFIX) {
+            return Err(AddressParseError::WrongHrp { found: hrp });
+        }
+
+        // Ensure the characters are in the bech32 character set, and are not of mixed case.
+        let charset = bech32_charset();
+        let is_uppercase = characters[0].is_ascii_uppercase();
+        for (index, &found) in characters.iter().enumerate() {
+            let is_in_charset = index <= separator || charset.contains(&found.to_ascii_lowercase());
+            let is_mixed_case = match is_uppercase {
+                true => found.is_ascii_lowercase(),
+                false => found.is_ascii_uppercase(),
+            };
+            if !is_in_charset || is_mixed_case {
+                return Err(AddressParseError::InvalidCharacter { index, found });
+            }
+        }
+
+        // Ensure the checksum is correct, and the data encodes an address.
+        match bech32::decode(address) {
+            Ok((_, data, _)) => match Vec::from_base32(&data).map(|buffer| Self::read_le(&buffer[..])) {
+                Ok(Ok(_)) => Ok(()),
+                _ => Err(AddressParseError::InvalidAddress),
+            },
+            Err(bech32::Error::InvalidChecksum) => Err(AddressParseError::InvalidChecksum {
+                suggestion: Self::suggest_correction(&characters, separator),
+            }),
+            Err(_) => Err(AddressParseError::InvalidAddress),
+        }
+    }
+
+    /// Returns the address string in uppercase, which encodes more compactly in QR codes.
+    pub fn to_qr_string(&self) -> String {
+        self.to_string().to_uppercase()
+    }
+
+    /// Returns the only valid address that differs from the given address in one data character,
+    /// or `None` if there is no such address or if the correction is ambiguous.
+    fn suggest_correction(characters: &[char], separator: usize) -> Option<String> {
+        let is_uppercase = characters[0].is_ascii_uppercase();
+        let mut characters: Vec<char> = characters.iter().map(|c| c.to_ascii_lowercase()).collect();
+
+        let charset = bech32_charset();
+        let mut suggestions = Vec::new();
+        for index in (separator + 1)..characters.len() {
+            let original = characters[index];
+            for &candidate in charset.iter().filter(|c| **c != original) {
+                characters[index] = candidate;
+                let candidate: String = characters.iter().collect();
+                if let Ok((_, _, bech32::Variant::Bech32m)) = bech32::decode(&candidate) {
+                    if Self::from_str(&candidate).is_ok() {
+                        suggestions.push(candidate);
+                    }
+                }
+            }
+            characters[index] = original;
+        }
+
+        match suggestions.len() {
+            1 => suggestions.pop().map(|suggestion| match is_uppercase {
+                true => suggestion.to_uppercase(),
+                false => suggestion,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the bech32 character set, in order of the 5-bit values it encodes.
+fn bech32_charset() -> Vec<char> {
+    (0..32u8)
+        .map(|value| u5::try_from_u8(value).expect("Failed to convert to u5").to_char())
+        .collect()
 }
 
 impl<N: Network> From<PrivateKey<N>> for Address<N> {
@@ -260,4 +362,79 @@ mod tests {
         assert_eq!(expected_address, Address::read_le(&expected_bytes[..]).unwrap());
         assert_eq!(expected_address, bincode::deserialize(&expected_bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_validate_str() {
+        let address: Address<Testnet2> = PrivateKey::new(&mut thread_rng()).into();
+        let address = address.to_string();
+        assert_eq!(Ok(()), Address::<Testnet2>::validate_str(&address));
+
+        // Check the length and prefix.
+        assert_eq!(
+            Err(AddressParseError::WrongLength { found: 62 }),
+            Address::<Testnet2>::validate_str(&address[..62])
+        );
+        assert_eq!(
+            Err(AddressParseError::WrongHrp {
+                found: "aleb".to_string()
+            }),
+            Address::<Testnet2>::validate_str(&address.replacen("aleo", "aleb", 1))
+        );
+
+        // Check an invalid character is located.
+        let mut invalid = address.clone();
+        invalid.replace_range(20..21, "b");
+        assert_eq!(
+            Err(AddressParseError::InvalidCharacter { index: 20, found: 'b' }),
+            Address::<Testnet2>::validate_str(&invalid)
+        );
+        let index = address[5..].find(|c: char| c.is_ascii_alphabetic()).unwrap() + 5;
+        let mut mixed_case = address.clone();
+        mixed_case.replace_range(index..index + 1, &address[index..index + 1].to_uppercase());
+        assert_eq!(
+            Err(AddressParseError::InvalidCharacter {
+                index,
+                found: mixed_case[index..].chars().next().unwrap()
+            }),
+            Address::<Testnet2>::validate_str(&mixed_case)
+        );
+    }
+
+    #[test]
+    fn test_validate_str_suggestion() {
+        let address: Address<Testnet2> = PrivateKey::new(&mut thread_rng()).into();
+        let address = address.to_string();
+
+        // Introduce a one-character typo in the data.
+        let index = 40;
+        let typo = if &address[index..index + 1] == "q" { "p" } else { "q" };
+        let mut candidate = address.clone();
+        candidate.replace_range(index..index + 1, typo);
+        assert!(Address::<Testnet2>::from_str(&candidate).is_err());
+
+        // Ensure the suggestion fixes the typo.
+        assert_eq!(
+            Err(AddressParseError::InvalidChecksum {
+                suggestion: Some(address.clone())
+            }),
+            Address::<Testnet2>::validate_str(&candidate)
+        );
+        assert_eq!(
+            Err(AddressParseError::InvalidChecksum {
+                suggestion: Some(address.to_uppercase())
+            }),
+            Address::<Testnet2>::validate_str(&candidate.to_uppercase())
+        );
+    }
+
+    #[test]
+    fn test_qr_string() {
+        let expected_address: Address<Testnet2> = PrivateKey::new(&mut thread_rng()).into();
+
+        let qr_string = expected_address.to_qr_string();
+        assert_eq!(expected_address.to_string().to_uppercase(), qr_string);
+        assert!(qr_string.starts_with("ALEO1"));
+        assert_eq!(Ok(()), Address::<Testnet2>::validate_str(&qr_string));
+        assert_eq!(expected_address, Address::from_str(&qr_string).unwrap());
+    }
 }
@@ -15,9 +15,10 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{AccountError, Network, PrivateKey};
-use snarkvm_algorithms::SignatureSchemeOperations;
+use snarkvm_algorithms::{SignatureSchemeOperations, PRF};
 use snarkvm_curves::AffineCurve;
-use snarkvm_utilities::{FromBytes, ToBytes};
+use snarkvm_fields::PrimeField;
+use snarkvm_utilities::{FromBytes, ToBits, ToBytes};
 
 use rand::thread_rng;
 use std::{
@@ -99,6 +100,25 @@ impl<N: Network> ComputeKey<N> {
         &self.sk_prf
     }
 
+    /// Returns the serial number of the record with the given commitment, if this compute key
+    /// belongs to the record owner, as `SN := PRF(sk_prf, commitment)`. Here, `sk_prf` is reinterpreted
+    /// as an element of the inner scalar field, which is larger than the program scalar field.
+    ///
+    /// As the compute key can not decrypt records, this allows watch-only services to detect spends.
+    pub fn to_serial_number(&self, commitment: &N::Commitment) -> Result<N::SerialNumber, AccountError> {
+        let seed = N::InnerScalarField::from_bits_le_checked(&self.sk_prf.to_bits_le())
+            .ok_or_else(|| AccountError::Message("Failed to convert sk_prf into a serial number PRF seed".into()))?;
+        Ok(N::SerialNumberPRF::evaluate(&seed, &(*commitment).into())?.into())
+    }
+
+    /// Returns the serial numbers of the records with the given commitments.
+    pub fn to_serial_numbers(&self, commitments: &[N::Commitment]) -> Result<Vec<N::SerialNumber>, AccountError> {
+        commitments
+            .iter()
+            .map(|commitment| self.to_serial_number(commitment))
+            .collect()
+    }
+
     /// Returns the encryption key.
     pub fn to_encryption_key(&self) -> N::ProgramAffineCurve {
         // Compute G^sk_prf.
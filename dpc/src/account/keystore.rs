@@ -0,0 +1,429 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AccountError, Address, Network, PrivateKey};
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{
+    aead::{Aead, KeyInit, Payload},
+    ChaCha20Poly1305,
+    Key,
+    Nonce,
+};
+use rand::{CryptoRng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{convert::TryInto, fmt, str::FromStr};
+use zeroize::Zeroize;
+
+/// The version of the keystore format.
+pub const KEYSTORE_VERSION: u8 = 1;
+
+/// The name of the key derivation function of the keystore format.
+const KEYSTORE_KDF: &str = "argon2id";
+
+/// The name of the cipher of the keystore format.
+const KEYSTORE_CIPHER: &str = "chacha20poly1305";
+
+/// The size of the KDF salt, in bytes.
+const KEYSTORE_SALT_SIZE: usize = 16;
+
+/// The size of the cipher nonce, in bytes.
+const KEYSTORE_NONCE_SIZE: usize = 12;
+
+/// The maximum memory cost accepted by the KDF, in KiB.
+/// The maxima bound the work a malformed keystore may cause the KDF to perform.
+const KEYSTORE_MAXIMUM_MEMORY_COST: u32 = 1024 * 1024;
+
+/// The maximum number of iterations accepted by the KDF.
+const KEYSTORE_MAXIMUM_TIME_COST: u32 = 16;
+
+/// The maximum degree of parallelism accepted by the KDF.
+const KEYSTORE_MAXIMUM_PARALLELISM: u32 = 16;
+
+/// The parameters of the Argon2id key derivation function.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreKdfParams {
+    /// The memory cost, in KiB.
+    pub memory_cost: u32,
+    /// The number of iterations.
+    pub time_cost: u32,
+    /// The degree of parallelism.
+    pub parallelism: u32,
+}
+
+impl KeystoreKdfParams {
+    /// Returns an error if any of the parameters exceeds its maximum.
+    fn check_maxima(&self) -> Result<(), AccountError> {
+        for (name, value, maximum) in [
+            ("memory cost", self.memory_cost, KEYSTORE_MAXIMUM_MEMORY_COST),
+            ("time cost", self.time_cost, KEYSTORE_MAXIMUM_TIME_COST),
+            ("parallelism", self.parallelism, KEYSTORE_MAXIMUM_PARALLELISM),
+        ] {
+            if value > maximum {
+                return Err(AccountError::InvalidKeystore(format!(
+                    "{} {} exceeds the maximum of {}",
+                    name, value, maximum
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the little-endian bytes of the parameters.
+    fn to_bytes_le(self) -> Vec<u8> {
+        [self.memory_cost, self.time_cost, self.parallelism]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+}
+
+impl Default for KeystoreKdfParams {
+    /// Returns the recommended parameters, using 64 MiB of memory.
+    fn default() -> Self {
+        Self {
+            memory_cost: 64 * 1024,
+            time_cost: 3,
+            parallelism: 1,
+        }
+    }
+}
+
+/// The cryptographic parameters and ciphertext of a keystore.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreCrypto {
+    /// The key derivation function, which is always `argon2id`.
+    pub kdf: String,
+    /// The parameters of the key derivation function.
+    pub kdfparams: KeystoreKdfParams,
+    /// The hex-encoded KDF salt.
+    pub salt: String,
+    /// The cipher, which is always `chacha20poly1305`.
+    pub cipher: String,
+    /// The hex-encoded cipher nonce.
+    pub nonce: String,
+    /// The hex-encoded ciphertext of the account seed, including the authentication tag.
+    pub ciphertext: String,
+}
+
+/// An account private key encrypted under a passphrase, for storage at rest.
+///
+/// The account seed is encrypted with ChaCha20-Poly1305, under a key derived from the passphrase with Argon2id.
+/// The version, KDF parameters, and address are authenticated as associated data.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeystoreJson {
+    /// The version of the keystore format.
+    pub version: u8,
+    /// The address of the account, used to check the decrypted private key.
+    pub address: String,
+    /// The cryptographic parameters and ciphertext.
+    pub crypto: KeystoreCrypto,
+}
+
+impl KeystoreJson {
+    /// Returns a new keystore, encrypting the given private key under the given passphrase.
+    pub fn new<N: Network, R: Rng + CryptoRng>(
+        private_key: &PrivateKey<N>,
+        passphrase: &str,
+        kdfparams: KeystoreKdfParams,
+        rng: &mut R,
+    ) -> Result<Self, AccountError> {
+        let salt: [u8; KEYSTORE_SALT_SIZE] = rng.gen();
+        let nonce: [u8; KEYSTORE_NONCE_SIZE] = rng.gen();
+        let address = Address::from_private_key(private_key).to_string();
+
+        let mut key = derive_key(passphrase, &salt, &kdfparams)?;
+        let mut seed = private_key.seed_bytes()?;
+        let ciphertext = ChaCha20Poly1305::new(&Key::from(key)).encrypt(&Nonce::from(nonce), Payload {
+            msg: &seed,
+            aad: &associated_data(KEYSTORE_VERSION, &kdfparams, &address),
+        });
+        key.zeroize();
+        seed.zeroize();
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address,
+            crypto: KeystoreCrypto {
+                kdf: KEYSTORE_KDF.to_string(),
+                kdfparams,
+                salt: hex::encode(salt),
+                cipher: KEYSTORE_CIPHER.to_string(),
+                nonce: hex::encode(nonce),
+                ciphertext: hex::encode(
+                    ciphertext.map_err(|_| AccountError::Message("Failed to encrypt the keystore".into()))?,
+                ),
+            },
+        })
+    }
+
+    /// Returns the private key in the keystore, decrypting it with the given passphrase.
+    pub fn decrypt<N: Network>(&self, passphrase: &str) -> Result<PrivateKey<N>, AccountError> {
+        if self.version != KEYSTORE_VERSION {
+            return Err(AccountError::InvalidKeystore(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        if self.crypto.kdf != KEYSTORE_KDF {
+            return Err(AccountError::InvalidKeystore(format!(
+                "unsupported KDF {:?}",
+                self.crypto.kdf
+            )));
+        }
+        if self.crypto.cipher != KEYSTORE_CIPHER {
+            return Err(AccountError::InvalidKeystore(format!(
+                "unsupported cipher {:?}",
+                self.crypto.cipher
+            )));
+        }
+        self.crypto.kdfparams.check_maxima()?;
+
+        let salt = decode_hex("salt", &self.crypto.salt)?;
+        let nonce = decode_hex("nonce", &self.crypto.nonce)?;
+        let nonce: [u8; KEYSTORE_NONCE_SIZE] = nonce.as_slice().try_into().map_err(|_| {
+            AccountError::InvalidKeystore(format!(
+                "nonce: expected {} bytes, found {}",
+                KEYSTORE_NONCE_SIZE,
+                nonce.len()
+            ))
+        })?;
+        let ciphertext = decode_hex("ciphertext", &self.crypto.ciphertext)?;
+
+        // The AEAD tag comparison runs in constant time, so a wrong passphrase is only distinguishable by the result.
+        let mut key = derive_key(passphrase, &salt, &self.crypto.kdfparams)?;
+        let seed = ChaCha20Poly1305::new(&Key::from(key)).decrypt(&Nonce::from(nonce), Payload {
+            msg: &ciphertext,
+            aad: &associated_data(self.version, &self.crypto.kdfparams, &self.address),
+        });
+        key.zeroize();
+
+        let mut seed = seed.map_err(|_| AccountError::IncorrectPassphrase)?;
+        let private_key = PrivateKey::<N>::from_seed_bytes(&seed);
+        seed.zeroize();
+        let private_key = private_key?;
+
+        // Ensure the decrypted private key matches the address.
+        if Address::from_private_key(&private_key).to_string() != self.address {
+            return Err(AccountError::InvalidKeystore(
+                "the address does not match the private key".into(),
+            ));
+        }
+        Ok(private_key)
+    }
+}
+
+impl FromStr for KeystoreJson {
+    type Err = AccountError;
+
+    fn from_str(keystore: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(keystore).map_err(|error| AccountError::InvalidKeystore(error.to_string()))
+    }
+}
+
+impl fmt::Display for KeystoreJson {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            serde_json::to_string(self).map_err::<fmt::Error, _>(serde::ser::Error::custom)?
+        )
+    }
+}
+
+/// Returns the 32-byte cipher key derived from the given passphrase.
+fn derive_key(passphrase: &str, salt: &[u8], kdfparams: &KeystoreKdfParams) -> Result<[u8; 32], AccountError> {
+    kdfparams.check_maxima()?;
+    let params = Params::new(
+        kdfparams.memory_cost,
+        kdfparams.time_cost,
+        kdfparams.parallelism,
+        Some(32),
+    )
+    .map_err(|error| AccountError::InvalidKeystore(error.to_string()))?;
+
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|error| AccountError::InvalidKeystore(error.to_string()))?;
+    Ok(key)
+}
+
+/// Returns the associated data authenticated by the cipher.
+fn associated_data(version: u8, kdfparams: &KeystoreKdfParams, address: &str) -> Vec<u8> {
+    [&[version], kdfparams.to_bytes_le().as_slice(), address.as_bytes()].concat()
+}
+
+/// Returns the bytes of the given hex-encoded field.
+fn decode_hex(field: &str, value: &str) -> Result<Vec<u8>, AccountError> {
+    hex::decode(value).map_err(|error| AccountError::InvalidKeystore(format!("{}: {}", field, error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::thread_rng;
+
+    const PASSPHRASE: &str = "correct horse battery staple";
+
+    /// Returns light parameters, so the tests run quickly.
+    fn test_params() -> KeystoreKdfParams {
+        KeystoreKdfParams {
+            memory_cost: 256,
+            time_cost: 1,
+            parallelism: 1,
+        }
+    }
+
+    #[test]
+    fn test_keystore_round_trip() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+
+        let keystore = private_key.to_keystore(PASSPHRASE, rng).unwrap();
+        assert_eq!(KeystoreKdfParams::default(), keystore.crypto.kdfparams);
+        assert_eq!(Address::from(&private_key).to_string(), keystore.address);
+
+        let candidate = KeystoreJson::from_str(&keystore.to_string()).unwrap();
+        assert_eq!(keystore, candidate);
+        assert_eq!(private_key, PrivateKey::from_keystore(&candidate, PASSPHRASE).unwrap());
+
+        // Check the salt and nonce are sampled each time.
+        let keystore = KeystoreJson::new(&private_key, PASSPHRASE, test_params(), rng).unwrap();
+        let other = KeystoreJson::new(&private_key, PASSPHRASE, test_params(), rng).unwrap();
+        assert_ne!(keystore.crypto.salt, other.crypto.salt);
+        assert_ne!(keystore.crypto.ciphertext, other.crypto.ciphertext);
+        assert_eq!(private_key, PrivateKey::from_keystore(&other, PASSPHRASE).unwrap());
+    }
+
+    #[test]
+    fn test_keystore_wrong_passphrase() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let keystore = KeystoreJson::new(&private_key, PASSPHRASE, test_params(), rng).unwrap();
+
+        for passphrase in ["", "correct horse battery stapler", "Correct horse battery staple"] {
+            assert!(matches!(
+                PrivateKey::<Testnet2>::from_keystore(&keystore, passphrase),
+                Err(AccountError::IncorrectPassphrase)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_keystore_tamper() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let other_address = Address::from(&PrivateKey::<Testnet2>::new(rng)).to_string();
+        let keystore = KeystoreJson::new(&private_key, PASSPHRASE, test_params(), rng).unwrap();
+
+        let decrypt = |keystore: KeystoreJson| PrivateKey::<Testnet2>::from_keystore(&keystore, PASSPHRASE);
+
+        // Flip a bit in the ciphertext.
+        let mut tampered = keystore.clone();
+        let mut ciphertext = hex::decode(&tampered.crypto.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        tampered.crypto.ciphertext = hex::encode(ciphertext);
+        assert!(matches!(decrypt(tampered), Err(AccountError::IncorrectPassphrase)));
+
+        // Flip a bit in the nonce and the salt.
+        let mut tampered = keystore.clone();
+        let mut nonce = hex::decode(&tampered.crypto.nonce).unwrap();
+        nonce[0] ^= 1;
+        tampered.crypto.nonce = hex::encode(nonce);
+        assert!(matches!(decrypt(tampered), Err(AccountError::IncorrectPassphrase)));
+
+        let mut tampered = keystore.clone();
+        let mut salt = hex::decode(&tampered.crypto.salt).unwrap();
+        salt[0] ^= 1;
+        tampered.crypto.salt = hex::encode(salt);
+        assert!(matches!(decrypt(tampered), Err(AccountError::IncorrectPassphrase)));
+
+        // Change the KDF parameters and the address.
+        let mut tampered = keystore.clone();
+        tampered.crypto.kdfparams.time_cost += 1;
+        assert!(matches!(decrypt(tampered), Err(AccountError::IncorrectPassphrase)));
+
+        let mut tampered = keystore.clone();
+        tampered.address = other_address;
+        assert!(matches!(decrypt(tampered), Err(AccountError::IncorrectPassphrase)));
+
+        // Check malformed keystores are rejected before decryption.
+        let mut tampered = keystore.clone();
+        tampered.version = 2;
+        assert!(matches!(decrypt(tampered), Err(AccountError::InvalidKeystore(_))));
+
+        let mut tampered = keystore.clone();
+        tampered.crypto.kdf = "scrypt".to_string();
+        assert!(matches!(decrypt(tampered), Err(AccountError::InvalidKeystore(_))));
+
+        let mut tampered = keystore.clone();
+        tampered.crypto.nonce = "00".to_string();
+        assert!(matches!(decrypt(tampered), Err(AccountError::InvalidKeystore(_))));
+
+        let mut tampered = keystore;
+        tampered.crypto.ciphertext = "zz".to_string();
+        assert!(matches!(decrypt(tampered), Err(AccountError::InvalidKeystore(_))));
+
+        assert!(matches!(
+            KeystoreJson::from_str("{\"version\":1}"),
+            Err(AccountError::InvalidKeystore(_))
+        ));
+    }
+
+    #[test]
+    fn test_keystore_kdf_maxima() {
+        let rng = &mut thread_rng();
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let keystore = KeystoreJson::new(&private_key, PASSPHRASE, test_params(), rng).unwrap();
+
+        let oversized = [
+            KeystoreKdfParams {
+                memory_cost: KEYSTORE_MAXIMUM_MEMORY_COST + 1,
+                ..test_params()
+            },
+            KeystoreKdfParams {
+                time_cost: KEYSTORE_MAXIMUM_TIME_COST + 1,
+                ..test_params()
+            },
+            KeystoreKdfParams {
+                parallelism: KEYSTORE_MAXIMUM_PARALLELISM + 1,
+                ..test_params()
+            },
+            KeystoreKdfParams {
+                memory_cost: u32::MAX,
+                time_cost: u32::MAX,
+                parallelism: u32::MAX,
+            },
+        ];
+
+        // Check oversized parameters are rejected before running the KDF, both when reading and writing.
+        for kdfparams in oversized {
+            let mut tampered = keystore.clone();
+            tampered.crypto.kdfparams = kdfparams;
+            assert!(matches!(
+                PrivateKey::<Testnet2>::from_keystore(&tampered, PASSPHRASE),
+                Err(AccountError::InvalidKeystore(_))
+            ));
+            assert!(matches!(
+                KeystoreJson::new(&private_key, PASSPHRASE, kdfparams, rng),
+                Err(AccountError::InvalidKeystore(_))
+            ));
+        }
+    }
+}
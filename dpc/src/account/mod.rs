@@ -28,9 +28,18 @@ pub use address::*;
 pub mod compute_key;
 pub use compute_key::*;
 
+pub mod keystore;
+pub use keystore::*;
+
 pub mod private_key;
 pub use private_key::*;
 
+pub mod seed_phrase;
+pub use seed_phrase::*;
+
+pub mod signed_message;
+pub use signed_message::*;
+
 pub mod view_key;
 pub use view_key::*;
 
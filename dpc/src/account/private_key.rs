@@ -16,10 +16,16 @@
 
 use crate::{
     account_format,
+    mnemonic_to_seed,
+    to_signed_message_bytes,
     AccountError,
     Address,
     ComputeKey,
+    ExtendedKey,
+    KeystoreJson,
+    KeystoreKdfParams,
     Network,
+    ACCOUNT_DERIVATION_PATH,
     ACCOUNT_SEED_R_SIG_DOMAIN,
     ACCOUNT_SEED_SK_SIG_DOMAIN,
 };
@@ -30,6 +36,7 @@ use snarkvm_utilities::{FromBytes, ToBytes, UniformRand};
 use base58::{FromBase58, ToBase58};
 use rand::{CryptoRng, Rng};
 use std::{fmt, str::FromStr};
+use zeroize::Zeroize;
 
 #[derive(Derivative)]
 #[derivative(
@@ -47,7 +54,62 @@ impl<N: Network> PrivateKey<N> {
     /// Creates a new account private key.
     pub fn new<R: Rng + CryptoRng>(rng: &mut R) -> Self {
         // Sample a random account seed.
-        Self::from(&N::AccountSeed::rand(rng))
+        let mut seed = N::AccountSeed::rand(rng);
+        let private_key = Self::from(&seed);
+        seed.zeroize();
+        private_key
+    }
+
+    /// Derives the account private key at the given index from a BIP-39 mnemonic and passphrase,
+    /// using hardened derivation along the path `m/44'/683'/index'`.
+    /// Returns an error if the mnemonic is not a valid English BIP-39 mnemonic.
+    pub fn from_seed_phrase(mnemonic: &str, passphrase: &str, index: u32) -> Result<Self, AccountError> {
+        let mut seed = mnemonic_to_seed(mnemonic, passphrase)?;
+        let master = ExtendedKey::master(&seed);
+        seed.zeroize();
+
+        let path = [ACCOUNT_DERIVATION_PATH[0], ACCOUNT_DERIVATION_PATH[1], index];
+        let mut account_seed = master.derive_path(&path)?.to_account_seed::<N>()?;
+        let private_key = Self::from(&account_seed);
+        account_seed.zeroize();
+        Ok(private_key)
+    }
+
+    /// Derives the child account private key at the given index, which must be less than 2^31.
+    /// Note that this scheme is specific to Aleo, and is not compatible with BIP-32.
+    pub fn derive_child(&self, index: u32) -> Result<Self, AccountError> {
+        let mut seed = self.seed.to_bytes_le()?;
+        let parent = ExtendedKey::from_account_seed(&seed);
+        seed.zeroize();
+
+        let mut account_seed = parent.hardened_child(index)?.to_account_seed::<N>()?;
+        let private_key = Self::from(&account_seed);
+        account_seed.zeroize();
+        Ok(private_key)
+    }
+
+    /// Returns the private key in the given keystore, decrypting it with the given passphrase.
+    pub fn from_keystore(keystore: &KeystoreJson, passphrase: &str) -> Result<Self, AccountError> {
+        keystore.decrypt(passphrase)
+    }
+
+    /// Returns a keystore of the private key, encrypted under the given passphrase
+    /// with the default key derivation parameters.
+    pub fn to_keystore<R: Rng + CryptoRng>(&self, passphrase: &str, rng: &mut R) -> Result<KeystoreJson, AccountError> {
+        KeystoreJson::new(self, passphrase, KeystoreKdfParams::default(), rng)
+    }
+
+    /// Returns the private key from the given account seed bytes.
+    pub(crate) fn from_seed_bytes(bytes: &[u8]) -> Result<Self, AccountError> {
+        let mut seed = N::AccountSeed::read_le(bytes)?;
+        let private_key = Self::from(&seed);
+        seed.zeroize();
+        Ok(private_key)
+    }
+
+    /// Returns the bytes of the account seed.
+    pub(crate) fn seed_bytes(&self) -> Result<Vec<u8>, AccountError> {
+        Ok(self.seed.to_bytes_le()?)
     }
 
     /// Returns `true` if the private key is well-formed. Otherwise, returns `false`.
@@ -57,9 +119,21 @@ impl<N: Network> PrivateKey<N> {
 
     /// Signs a message using the account private key.
     pub fn sign<R: Rng + CryptoRng>(&self, message: &[u8], rng: &mut R) -> Result<N::AccountSignature, AccountError> {
-        Ok(N::account_signature_scheme()
-            .sign(&(self.sk_sig, self.r_sig), message, rng)?
-            .into())
+        let mut signature_private_key = (self.sk_sig, self.r_sig);
+        let signature = N::account_signature_scheme().sign(&signature_private_key, message, rng);
+        signature_private_key.zeroize();
+        Ok(signature?.into())
+    }
+
+    /// Signs an arbitrary message, such as a human-readable message from an application.
+    /// The signature is over the domain-separated bytes given by `to_signed_message_bytes`,
+    /// so it is never valid as a request signature.
+    pub fn sign_bytes<R: Rng + CryptoRng>(
+        &self,
+        message: &[u8],
+        rng: &mut R,
+    ) -> Result<N::AccountSignature, AccountError> {
+        self.sign(&to_signed_message_bytes(message), rng)
     }
 
     /// Returns the address from the private key.
@@ -104,7 +178,7 @@ impl<N: Network> FromStr for PrivateKey<N> {
 
     /// Reads in an account private key string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data = s.from_base58()?;
+        let mut data = s.from_base58()?;
         if data.len() != 43 {
             return Err(AccountError::InvalidByteLength(data.len()));
         }
@@ -113,7 +187,13 @@ impl<N: Network> FromStr for PrivateKey<N> {
             return Err(AccountError::InvalidPrefixBytes(data[0..11].to_vec()));
         }
 
-        Ok(Self::from(&FromBytes::read_le(&data[11..43])?))
+        let seed = FromBytes::read_le(&data[11..43]);
+        data.zeroize();
+
+        let mut seed = seed?;
+        let private_key = Self::from(&seed);
+        seed.zeroize();
+        Ok(private_key)
     }
 }
 
@@ -125,10 +205,31 @@ impl<N: Network> fmt::Display for PrivateKey<N> {
             .write_le(&mut private_key[11..43])
             .expect("seed formatting failed");
 
-        write!(f, "{}", private_key.to_base58())
+        let result = write!(f, "{}", private_key.to_base58());
+        private_key.zeroize();
+        result
     }
 }
 
+impl<N: Network> Zeroize for PrivateKey<N> {
+    fn zeroize(&mut self) {
+        self.seed.zeroize();
+        self.sk_sig.zeroize();
+        self.r_sig.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize-on-drop")]
+impl<N: Network> Drop for PrivateKey<N> {
+    /// Zeroizes the private key when it goes out of scope.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize-on-drop")]
+impl<N: Network> zeroize::ZeroizeOnDrop for PrivateKey<N> {}
+
 impl<N: Network> fmt::Debug for PrivateKey<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "PrivateKey {{ seed: {:?} }}", self.seed)
@@ -0,0 +1,273 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Hierarchical deterministic derivation of account seeds.
+//!
+//! The scheme follows BIP-39 and SLIP-10, restricted to hardened derivation:
+//!
+//! 1. The 64-byte seed is `PBKDF2-HMAC-SHA512(NFKD(mnemonic), "mnemonic" || NFKD(passphrase), 2048)`,
+//!    where the mnemonic is checked against the English wordlist and its checksum.
+//! 2. The master node is `I := HMAC-SHA512(ACCOUNT_HD_MASTER_KEY_DOMAIN, seed)`,
+//!    with key `I[0..32]` and chain code `I[32..64]`.
+//! 3. The hardened child `i` of a node is `I := HMAC-SHA512(chain_code, 0x00 || key || ser32(i + 2^31))`.
+//! 4. The account at index `i` is the node at path `m/44'/683'/i'`.
+//! 5. The account seed of a node is `SHA-512(ACCOUNT_HD_SEED_DOMAIN || key || counter)` reduced
+//!    modulo the scalar field order, where `counter` starts at zero and is incremented on a zero result.
+
+use crate::{account_format, AccountError, Network};
+use snarkvm_fields::{PrimeField, Zero};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use bip39::{Language, Mnemonic};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha512};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroize;
+
+/// The hardened path prefix of account keys, as `m/44'/683'`, where 683 is the SLIP-44 coin type of Aleo.
+pub const ACCOUNT_DERIVATION_PATH: [u32; 2] = [44, 683];
+
+/// The number of PBKDF2 rounds used to derive a seed from a mnemonic, as specified by BIP-39.
+const BIP39_PBKDF2_ROUNDS: u32 = 2048;
+
+/// The offset of hardened child indices.
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Returns the 64-byte BIP-39 seed for the given mnemonic and passphrase.
+/// Returns an error if the mnemonic has an unknown word, an invalid length, or an invalid checksum.
+pub fn mnemonic_to_seed(mnemonic: &str, passphrase: &str) -> Result<[u8; 64], AccountError> {
+    let mut password: String = mnemonic.nfkd().collect();
+    if let Err(error) = Mnemonic::parse_in_normalized(Language::English, &password) {
+        password.zeroize();
+        return Err(AccountError::InvalidMnemonic(error.to_string()));
+    }
+
+    let mut salt: String = format!("mnemonic{}", passphrase).nfkd().collect();
+    let mut seed = [0u8; 64];
+    pbkdf2::pbkdf2::<HmacSha512>(password.as_bytes(), salt.as_bytes(), BIP39_PBKDF2_ROUNDS, &mut seed);
+    password.zeroize();
+    salt.zeroize();
+    Ok(seed)
+}
+
+/// A node in the hardened derivation tree.
+pub(crate) struct ExtendedKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedKey {
+    /// Returns the master node for the given seed.
+    pub(crate) fn master(seed: &[u8]) -> Self {
+        Self::new(account_format::ACCOUNT_HD_MASTER_KEY_DOMAIN.as_bytes(), seed)
+    }
+
+    /// Returns the node derived from the given account seed bytes, used as the parent of child accounts.
+    pub(crate) fn from_account_seed(seed: &[u8]) -> Self {
+        Self::new(account_format::ACCOUNT_HD_CHILD_KEY_DOMAIN.as_bytes(), seed)
+    }
+
+    /// Returns the node at the given hardened path from this node.
+    pub(crate) fn derive_path(&self, path: &[u32]) -> Result<Self, AccountError> {
+        let mut node = Self {
+            key: self.key,
+            chain_code: self.chain_code,
+        };
+        for index in path {
+            node = node.hardened_child(*index)?;
+        }
+        Ok(node)
+    }
+
+    /// Returns the hardened child at the given index, which must be less than 2^31.
+    pub(crate) fn hardened_child(&self, index: u32) -> Result<Self, AccountError> {
+        if index >= HARDENED_OFFSET {
+            return Err(AccountError::InvalidDerivationIndex(index));
+        }
+
+        let mut data = [0u8; 37];
+        data[1..33].copy_from_slice(&self.key);
+        data[33..37].copy_from_slice(&(index + HARDENED_OFFSET).to_be_bytes());
+        let child = Self::new(&self.chain_code, &data);
+        data.zeroize();
+        Ok(child)
+    }
+
+    /// Returns the account seed of this node.
+    pub(crate) fn to_account_seed<N: Network>(&self) -> Result<N::AccountSeed, AccountError> {
+        for counter in 0..=u8::MAX {
+            let mut digest: [u8; 64] = Sha512::new()
+                .chain_update(account_format::ACCOUNT_HD_SEED_DOMAIN.as_bytes())
+                .chain_update(self.key)
+                .chain_update([counter])
+                .finalize()
+                .into();
+            let mut scalar = N::ProgramScalarField::from_bytes_le_mod_order(&digest);
+            digest.zeroize();
+
+            if !scalar.is_zero() {
+                let mut bytes = scalar.to_bytes_le()?;
+                let seed = N::AccountSeed::read_le(&bytes[..]);
+                bytes.zeroize();
+                scalar.zeroize();
+                return Ok(seed?);
+            }
+        }
+        Err(AccountError::Message("Failed to derive a non-zero account seed".into()))
+    }
+
+    fn new(key: &[u8], data: &[u8]) -> Self {
+        let mut output = hmac_sha512(key, data);
+        let mut node = Self {
+            key: [0u8; 32],
+            chain_code: [0u8; 32],
+        };
+        node.key.copy_from_slice(&output[..32]);
+        node.chain_code.copy_from_slice(&output[32..]);
+        output.zeroize();
+        node
+    }
+}
+
+impl Drop for ExtendedKey {
+    fn drop(&mut self) {
+        self.key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+/// Returns HMAC-SHA512 of the given message under the given key.
+fn hmac_sha512(key: &[u8], message: &[u8]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(message);
+    mac.finalize().into_bytes().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Address, PrivateKey};
+
+    const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn test_hmac_sha512() {
+        // RFC 4231, test case 2.
+        let expected = "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737";
+        assert_eq!(
+            expected,
+            hex::encode(hmac_sha512(b"Jefe", b"what do ya want for nothing?"))
+        );
+    }
+
+    #[test]
+    fn test_mnemonic_to_seed() {
+        // BIP-39, English test vector 1.
+        let expected = "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04";
+        assert_eq!(expected, hex::encode(mnemonic_to_seed(MNEMONIC, "TREZOR").unwrap()));
+    }
+
+    #[test]
+    fn test_invalid_mnemonic() {
+        let invalid_mnemonics = [
+            // An invalid checksum.
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon",
+            // A word that is not in the wordlist.
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon aleo",
+            // An invalid number of words.
+            "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+            "",
+        ];
+        for mnemonic in invalid_mnemonics {
+            assert!(matches!(
+                mnemonic_to_seed(mnemonic, ""),
+                Err(AccountError::InvalidMnemonic(_))
+            ));
+            assert!(matches!(
+                PrivateKey::<Testnet2>::from_seed_phrase(mnemonic, "", 0),
+                Err(AccountError::InvalidMnemonic(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_invalid_derivation_index() {
+        let master = ExtendedKey::master(&mnemonic_to_seed(MNEMONIC, "").unwrap());
+        assert!(master.hardened_child(HARDENED_OFFSET - 1).is_ok());
+        assert!(matches!(
+            master.hardened_child(HARDENED_OFFSET),
+            Err(AccountError::InvalidDerivationIndex(HARDENED_OFFSET))
+        ));
+    }
+
+    #[test]
+    fn test_from_seed_phrase() {
+        let expected = [
+            (
+                "APrivateKey1zkpHW4CBDWxEZVRctpp6hCVaW5kYmHgZTkJUzfhV3tQAKKq",
+                "aleo19zku53lxq4yjec694e88zcdrm5kzrs3wfrn9ul92jkavapyz85pqej6g2x",
+            ),
+            (
+                "APrivateKey1zkp7fb3Uoisvk5awUgnfKSuYGmdHhAN1h3S87SyCHReh6rd",
+                "aleo1qy54nf02nvcshjprmxjnswezfyyzymvcm4qlkm6r7zqa3a2f9uqss29u6j",
+            ),
+            (
+                "APrivateKey1zkpHBRuwfPoUFEHc3rXaGx3UUPXDqpK7DnjuUxUtCUQhh3V",
+                "aleo1ll0uzs4vm2c3yfq07l7spkawn7kd9rgw6f4aed94wh22rxf9gsyqsw4s2e",
+            ),
+            (
+                "APrivateKey1zkpFUjYEjeB47JkivxZwFhnkguUqCmPsgrX4wi5pTAuLANc",
+                "aleo1slwfuzf3hvumkxl6muh6annyatjtn4z4zj3hqyq2m6g8jptxgugs4nkah8",
+            ),
+        ];
+
+        for (index, (expected_private_key, expected_address)) in expected.iter().enumerate() {
+            let private_key = PrivateKey::<Testnet2>::from_seed_phrase(MNEMONIC, "", index as u32).unwrap();
+            assert_eq!(*expected_private_key, private_key.to_string());
+            assert_eq!(*expected_address, Address::from(&private_key).to_string());
+        }
+
+        // Check the passphrase is used.
+        let private_key = PrivateKey::<Testnet2>::from_seed_phrase(MNEMONIC, "TREZOR", 0).unwrap();
+        assert_eq!(
+            "aleo1pxync2qvaxq00h2xkzg7449h9h4ty4pg0fcxpwd2pk0xj63rycxqrpnwna",
+            Address::from(&private_key).to_string()
+        );
+    }
+
+    #[test]
+    fn test_derive_child() {
+        let private_key = PrivateKey::<Testnet2>::from_seed_phrase(MNEMONIC, "", 0).unwrap();
+        let child = private_key.derive_child(0).unwrap();
+        assert_eq!(
+            "APrivateKey1zkp44WBgaCCLF9e2t8SGCytjmihFJe9mpsTX77YLNnKggGA",
+            child.to_string()
+        );
+        assert_eq!(
+            "aleo189tn54nms5r9a4uprklphj7w4cjyzquqqplrt0zh8uhgypx6ggysx5jgnq",
+            Address::from(&child).to_string()
+        );
+
+        assert_ne!(child, private_key.derive_child(1).unwrap());
+        assert!(matches!(
+            private_key.derive_child(HARDENED_OFFSET),
+            Err(AccountError::InvalidDerivationIndex(HARDENED_OFFSET))
+        ));
+    }
+}
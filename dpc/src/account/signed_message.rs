@@ -0,0 +1,126 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::account_format;
+
+/// Returns the canonical bytes signed by `PrivateKey::sign_bytes` for the given message,
+/// as `"Aleo Signed Message:\n" || decimal(message.len()) || message`.
+///
+/// The signature scheme packs these bytes into field elements, prepending their length in bytes.
+/// Request signatures are over record commitments, which never begin with the prefix,
+/// so a signed message can not be replayed as a request signature.
+pub fn to_signed_message_bytes(message: &[u8]) -> Vec<u8> {
+    let length = message.len().to_string();
+    let prefix = account_format::SIGNED_MESSAGE_PREFIX.as_bytes();
+
+    let mut bytes = Vec::with_capacity(prefix.len() + length.len() + message.len());
+    bytes.extend_from_slice(prefix);
+    bytes.extend_from_slice(length.as_bytes());
+    bytes.extend_from_slice(message);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet2::Testnet2, Address, AleoAmount, LedgerProof, Network, Operation, PrivateKey, Request};
+    use snarkvm_utilities::{to_bytes_le, ToBytes};
+
+    use rand::{thread_rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use std::str::FromStr;
+
+    const PRIVATE_KEY: &str = "APrivateKey1zkpHW4CBDWxEZVRctpp6hCVaW5kYmHgZTkJUzfhV3tQAKKq";
+    const MESSAGE: &[u8] = b"Hello, Aleo!";
+    const SIGNATURE: &str = "sign1lxtushmhls90unnta7kx740aktt5wu2ccwgf5lrrlqfrml2h0sp5r5w73qg44kzj748aeevl7mths49phwlmk4knhrxefjadft38sqvc4ksh7aq2ez48y50mdtg2sp6wnh2rxu98dvscrzpvnhpq7jyxq05vewjsqfzg73q4xrvjyzt06mxgnh3n8xwv7mnsh9alwqjtmgsskjrkk2x";
+
+    #[test]
+    fn test_to_signed_message_bytes() {
+        assert_eq!(b"Aleo Signed Message:\n0".to_vec(), to_signed_message_bytes(b""));
+        assert_eq!(
+            "416c656f205369676e6564204d6573736167653a0a313248656c6c6f2c20416c656f21",
+            hex::encode(to_signed_message_bytes(MESSAGE))
+        );
+    }
+
+    #[test]
+    fn test_sign_bytes() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let private_key = PrivateKey::<Testnet2>::from_str(PRIVATE_KEY).unwrap();
+        let address = Address::from(&private_key);
+
+        let signature = private_key.sign_bytes(MESSAGE, rng).unwrap();
+        assert_eq!(SIGNATURE, signature.to_string());
+
+        assert!(address.verify_bytes(MESSAGE, &signature).unwrap());
+        assert!(!address.verify_bytes(b"Hello, Aleo?", &signature).unwrap());
+        assert!(!Address::<Testnet2>::from(&PrivateKey::new(rng))
+            .verify_bytes(MESSAGE, &signature)
+            .unwrap());
+
+        // Check the fixed signature verifies.
+        let signature = <Testnet2 as Network>::AccountSignature::from_str(SIGNATURE).unwrap();
+        assert!(address.verify_bytes(MESSAGE, &signature).unwrap());
+
+        // Check the signature is not valid over the raw message.
+        assert!(!address.verify_signature(MESSAGE, &signature).unwrap());
+    }
+
+    #[test]
+    fn test_sign_bytes_is_not_a_request_signature() {
+        let rng = &mut thread_rng();
+
+        let caller = PrivateKey::<Testnet2>::new(rng);
+        let request = Request::new(
+            &caller,
+            vec![],
+            vec![LedgerProof::default(); Testnet2::NUM_INPUT_RECORDS],
+            Operation::Noop,
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+
+        // Sign the request message as a personal message, and check it is rejected.
+        let commitments: Vec<_> = request.records().iter().map(|record| record.commitment()).collect();
+        let message = to_bytes_le![commitments].unwrap();
+        let signature = caller.sign_bytes(&message, rng).unwrap();
+        assert!(Address::from(&caller).verify_bytes(&message, &signature).unwrap());
+        assert!(Request::from(
+            request.records().clone(),
+            request.ledger_proofs().clone(),
+            request.operation().clone(),
+            request.fee(),
+            signature,
+            request.is_public(),
+        )
+        .is_err());
+
+        // Check the request signature is accepted in its place.
+        let signature = caller.sign(&message, rng).unwrap();
+        assert!(Request::from(
+            request.records().clone(),
+            request.ledger_proofs().clone(),
+            request.operation().clone(),
+            request.fee(),
+            signature,
+            request.is_public(),
+        )
+        .is_ok());
+    }
+}
@@ -290,14 +290,29 @@ mod testnet1 {
 
 #[cfg(test)]
 mod testnet2 {
-    use crate::{testnet2::Testnet2, Account, AccountScheme, Address, Network, PrivateKey, ViewKey};
+    use crate::{
+        testnet2::Testnet2,
+        Account,
+        AccountScheme,
+        Address,
+        AleoAmount,
+        ComputeKey,
+        LedgerProof,
+        Network,
+        Operation,
+        PrivateKey,
+        Request,
+        ViewKey,
+    };
     use snarkvm_algorithms::prelude::*;
     use snarkvm_curves::AffineCurve;
+    use snarkvm_fields::Zero;
     use snarkvm_utilities::{FromBytes, ToBytes};
 
     use rand::{thread_rng, Rng, SeedableRng};
     use rand_chacha::ChaChaRng;
     use std::str::FromStr;
+    use zeroize::Zeroize;
 
     const ALEO_TESTNET2_PRIVATE_KEY: &str = "APrivateKey1zkp8cC4jgHEBnbtu3xxs1Ndja2EMizcvTRDq5Nikdkukg1p";
     const ALEO_TESTNET2_VIEW_KEY: &str = "AViewKey1iAf6a7fv6ELA4ECwAth1hDNUJJNNoWNThmREjpybqder";
@@ -394,6 +409,34 @@ mod testnet2 {
         assert_eq!(ALEO_TESTNET2_ADDRESS, address.to_string());
     }
 
+    #[test]
+    fn test_compute_key_serial_numbers() {
+        let rng = &mut thread_rng();
+
+        let private_key = PrivateKey::<Testnet2>::new(rng);
+        let request = Request::new(
+            &private_key,
+            vec![],
+            vec![LedgerProof::default(); Testnet2::NUM_INPUT_RECORDS],
+            Operation::Noop,
+            AleoAmount::ZERO,
+            false,
+            rng,
+        )
+        .unwrap();
+
+        // Check the compute key, recovered from the request signature alone, derives the same serial numbers.
+        let compute_key = ComputeKey::<Testnet2>::from_signature(request.signature()).unwrap();
+        let serial_numbers = compute_key.to_serial_numbers(&request.to_input_commitments()).unwrap();
+        assert_eq!(request.to_serial_numbers().unwrap(), serial_numbers);
+        assert_eq!(
+            serial_numbers[0],
+            compute_key
+                .to_serial_number(&request.records()[0].commitment())
+                .unwrap()
+        );
+    }
+
     #[test]
     fn test_view_key_into_address() {
         let view_key = ViewKey::<Testnet2>::from_str(ALEO_TESTNET2_VIEW_KEY).unwrap();
@@ -561,4 +604,44 @@ mod testnet2 {
             );
         }
     }
+
+    #[test]
+    fn test_private_key_zeroize() {
+        let private_key = PrivateKey::<Testnet2>::new(&mut thread_rng());
+
+        // Zeroize a copy of the private key.
+        let mut candidate = private_key.clone();
+        candidate.zeroize();
+        assert!(candidate.sk_sig.is_zero());
+        assert!(candidate.r_sig.is_zero());
+        let zero_seed = <Testnet2 as Network>::AccountSeed::default();
+        assert_eq!(
+            PrivateKey::<Testnet2>::from(&zero_seed).to_string(),
+            candidate.to_string()
+        );
+
+        // Ensure the original private key is untouched, and still serializes.
+        assert_ne!(private_key, candidate);
+        assert!(private_key.is_valid());
+        assert_eq!(private_key, PrivateKey::from_str(&private_key.to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_view_key_zeroize() {
+        let view_key = ViewKey::from(PrivateKey::<Testnet2>::new(&mut thread_rng()));
+
+        // Zeroize a copy of the view key.
+        let mut candidate = view_key.clone();
+        candidate.zeroize();
+        assert!(candidate.is_zero());
+        assert_eq!(vec![0u8; 32], candidate.to_bytes_le().unwrap());
+
+        // Ensure the original view key is untouched, and still serializes.
+        assert_ne!(view_key, candidate);
+        assert_eq!(view_key, ViewKey::from_str(&view_key.to_string()).unwrap());
+        assert_eq!(
+            view_key,
+            ViewKey::read_le(&view_key.to_bytes_le().unwrap()[..]).unwrap()
+        );
+    }
 }
@@ -25,6 +25,7 @@ use std::{
     ops::Deref,
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 #[derive(Derivative)]
 #[derivative(
@@ -61,7 +62,7 @@ impl<N: Network> FromStr for ViewKey<N> {
 
     /// Reads in an account view key string.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let data = s.from_base58()?;
+        let mut data = s.from_base58()?;
         if data.len() != 39 {
             return Err(AccountError::InvalidByteLength(data.len()));
         }
@@ -70,7 +71,9 @@ impl<N: Network> FromStr for ViewKey<N> {
             return Err(AccountError::InvalidPrefixBytes(data[0..7].to_vec()));
         }
 
-        Ok(Self(FromBytes::read_le(&data[7..])?))
+        let decryption_key = FromBytes::read_le(&data[7..]);
+        data.zeroize();
+        Ok(Self(decryption_key?))
     }
 }
 
@@ -95,10 +98,29 @@ impl<N: Network> fmt::Display for ViewKey<N> {
             .write_le(&mut view_key[7..39])
             .expect("view key formatting failed");
 
-        write!(f, "{}", view_key.to_base58())
+        let result = write!(f, "{}", view_key.to_base58());
+        view_key.zeroize();
+        result
     }
 }
 
+impl<N: Network> Zeroize for ViewKey<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize-on-drop")]
+impl<N: Network> Drop for ViewKey<N> {
+    /// Zeroizes the view key when it goes out of scope.
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize-on-drop")]
+impl<N: Network> zeroize::ZeroizeOnDrop for ViewKey<N> {}
+
 impl<N: Network> fmt::Debug for ViewKey<N> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "ViewKey {{ decryption_key: {:?} }}", self.0)
@@ -20,6 +20,7 @@ use crate::{
     BlockError,
     BlockHeader,
     BlockTemplate,
+    ConsensusConstants,
     LedgerProof,
     LedgerTree,
     LedgerTreeScheme,
@@ -32,6 +33,7 @@ use snarkvm_utilities::{to_bytes_le, FromBytes, FromBytesDeserializer, ToBytes,
 
 use anyhow::{anyhow, Result};
 use rand::{CryptoRng, Rng};
+use rayon::ThreadPool;
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
@@ -136,67 +138,65 @@ impl<N: Network> Block<N> {
 
     /// Returns `true` if the block is well-formed.
     pub fn is_valid(&self) -> bool {
+        match self.check_valid(None) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("{}", error);
+                false
+            }
+        }
+    }
+
+    /// Ensures the block is well-formed, verifying its transactions in parallel on the given thread pool,
+    /// or on the global thread pool if `None`. Returns the first check the block fails.
+    pub fn check_valid(&self, verification_pool: Option<&ThreadPool>) -> Result<(), BlockError> {
         // Ensure the previous block hash is well-formed.
         let genesis_previous_block_hash = LedgerProof::<N>::default().block_hash();
         if self.height() == 0u32 {
             if self.previous_block_hash != genesis_previous_block_hash {
-                eprintln!("Genesis block must have the default ledger proof block hash");
-                return false;
+                return Err(anyhow!("Genesis block must have the default ledger proof block hash").into());
             }
         } else if self.previous_block_hash == genesis_previous_block_hash {
-            eprintln!("Block cannot have genesis previous block hash");
-            return false;
+            return Err(anyhow!("Block cannot have genesis previous block hash").into());
         } else if self.previous_block_hash == Default::default() {
-            eprintln!("Block must have a non-empty previous block hash");
-            return false;
+            return Err(anyhow!("Block must have a non-empty previous block hash").into());
         }
 
         // Ensure the header are valid.
         if !self.header.is_valid() {
-            eprintln!("Invalid block header");
-            return false;
+            return Err(anyhow!("Invalid block header").into());
         }
 
         // Ensure the transactions are valid.
-        if !self.transactions.is_valid() {
-            eprintln!("Invalid block transactions");
-            return false;
-        }
+        self.transactions.check_valid(verification_pool)?;
 
         // Ensure the transactions root matches the computed root from the transactions list.
         if self.header.transactions_root() != self.transactions.transactions_root() {
-            eprintln!("Invalid block transactions does not match transactions root in header");
-            return false;
+            return Err(anyhow!("Invalid block transactions does not match transactions root in header").into());
         }
 
         // Retrieve the coinbase transaction.
-        let coinbase_transaction = match self.to_coinbase_transaction() {
-            Ok(coinbase_transaction) => coinbase_transaction,
-            Err(error) => {
-                eprintln!("{}", error);
-                return false;
-            }
-        };
+        let coinbase_transaction = self.to_coinbase_transaction()?;
 
         // Ensure the coinbase reward is equal to or greater than the expected block reward.
         let coinbase_reward = AleoAmount::ZERO.sub(coinbase_transaction.value_balance()); // Make it a positive number.
         let block_reward = Self::block_reward(self.height());
         if coinbase_reward < block_reward {
-            eprintln!("Coinbase reward must be >= {}, found {}", block_reward, coinbase_reward);
-            return false;
+            return Err(anyhow!("Coinbase reward must be >= {}, found {}", block_reward, coinbase_reward).into());
         }
 
         // Ensure the coinbase reward less transaction fees is less than or equal to the block reward.
         let candidate_block_reward = AleoAmount::ZERO.sub(self.transactions.net_value_balance()); // Make it a positive number.
         if candidate_block_reward > block_reward {
-            eprintln!(
+            return Err(anyhow!(
                 "Block reward must be <= {}, found {}",
-                block_reward, candidate_block_reward
-            );
-            return false;
+                block_reward,
+                candidate_block_reward
+            )
+            .into());
         }
 
-        true
+        Ok(())
     }
 
     /// Returns `true` if the block is a genesis block.
@@ -275,20 +275,38 @@ impl<N: Network> Block<N> {
         self.transactions.to_coinbase_transaction()
     }
 
+    ///
+    /// Returns the canonical JSON encoding of the block.
+    ///
+    /// The canonical encoding is compact, lists the fields of every object in the order
+    /// declared by its `Serialize` implementation (never sorted), and renders each hash,
+    /// root, and proof in its lowercase bech32 form. The output is byte-stable for a given block.
+    ///
+    pub fn to_json_canonical(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
     ///
     /// Returns the block reward for the given block height.
     ///
     pub fn block_reward(height: u32) -> AleoAmount {
+        Self::block_reward_with(&N::consensus_constants(), height)
+    }
+
+    ///
+    /// Returns the block reward for the given block height, under the given consensus constants.
+    ///
+    pub fn block_reward_with(constants: &ConsensusConstants, height: u32) -> AleoAmount {
         match height == 0 {
             true => {
                 // Output the starting supply as the genesis block reward.
-                AleoAmount::from_i64(N::ALEO_STARTING_SUPPLY_IN_CREDITS * AleoAmount::ONE_CREDIT.0)
+                AleoAmount::from_i64(constants.starting_supply_in_credits * AleoAmount::ONE_CREDIT.0)
             }
             false => {
                 // The initial blocks that aren't taken into account with the halving calculation.
                 // The time it takes before the halving - 4,730,400 blocks (approximately 3 years).
-                let expected_blocks_per_hour: u32 = 3600 / (N::ALEO_BLOCK_TIME_IN_SECS as u32);
-                let num_years = 3;
+                let expected_blocks_per_hour: u32 = 3600 / (constants.block_time_in_secs as u32);
+                let num_years = constants.block_reward_halving_interval_in_years;
                 let block_segments = num_years * 365 * 24 * expected_blocks_per_hour;
 
                 // The block reward halves at most 2 times - minimum is 25 ALEO.
@@ -296,8 +314,11 @@ impl<N: Network> Block<N> {
                 // Blocks 1 to 4,730,400         - 100 CREDITS
                 // Blocks 4,730,401 to 9,460,800 - 50 CREDITS
                 // Blocks 9,460,801+             - 25 CREDITS
-                let initial_reward = 100i64 * AleoAmount::ONE_CREDIT.0;
-                let num_halves = u32::min(height.saturating_sub(1) / block_segments, 2);
+                let initial_reward = constants.initial_block_reward_in_credits * AleoAmount::ONE_CREDIT.0;
+                let num_halves = u32::min(
+                    height.saturating_sub(1) / block_segments,
+                    constants.maximum_block_reward_halvings,
+                );
                 let reward = initial_reward / (2_u64.pow(num_halves)) as i64;
 
                 AleoAmount::from_i64(reward)
@@ -594,66 +615,33 @@ mod tests {
 
     #[test]
     fn test_testnet2_v12_compatibility() {
-        let block_string = r#"{
-    "block_hash": "ab18xgef6l9gfdnynd9z998688emp4elpwmmzjmed4xg5h3z0zazurqggx44u",
-    "header": {
-        "metadata": {
-            "cumulative_weight": 32498702396,
-            "difficulty_target": 53534725689126,
-            "height": 111135,
-            "timestamp": 1641075192
-        },
-        "nonce": "hn148tj58tt0unjqc9ff2jckkthmm0kul9tdvarcr0mex0ww6agccgqwsqmg3",
-        "previous_ledger_root": "al1qh3mjtza7lzrdpxvxazzkl6cmr3wxr3qy4ctm9r6x497aqdt4qxsz9jzek",
-        "proof": {
-            "non_hiding": "hzkp1qvqqqqqqqqqqqqcqqqqqqqqqqz2ag87aga0uqmn288dgfqw48k4e0v4yaz08fz502qezsemw2y3ncn67s9ktm7sxvrnky8xn2x8x8qzy4n3nae3xch78egrwd3z626zsufge5tppk3csj8rv898q5c8u8vj43vqqspuzawutn5z76ly0qcqqd8jk9mnn6klekk2swsy286atyuylefc2yxxthfyyngcry2ar2n4h6lavmyrevj50w832cm7vzlvqqvqqqqqqqqqqql33969pt7hn43q5kjxrlqv295rlm0753ng78d3gp02n5rn2kywr2rmqrnvc9svre2lc5w6dlkygqztp6z2djwgsqzselscxpph9jw4d8ycr7pkp5avvgu70e9vuq3nuwghu3hg4x99n9e0q53dex8vfqq0rr9qujpjq0zq5x5hflmr7jhj8v2wh30ghef56cfc56e8e4337zrlxun2l5vcad3ffpkpq7tp8tkqsyqqqqqqqqqqqwnn3cf96l2pmfnjgf8dfk3hwy7avtgkrgplg79gvx4yz9j6gs6as3pzcmrfw6j20zp9d0pyknajczjn5l40wu2mtg48zfuxm5l8mz9phywtxh96kuwluky7ntj9jjvx0dzgcf5572l6jdyyh4gkwvpfkqqzqqqqqqqqqqqz9n8kvtd6mezv6u5e9xl7vrctnprw2m7glc0320cy33galudh5ql7ptq8c9ut6vxmvexmykg2wj6w48p6dpmr9n44rnw2plvpm9vzs8slmgae6ty8y25atq4x5nn9uwy77pr5fp7utn52wvfum7yuawwsrz5wl7tqs9x446m8kszx800g9ttuhwvf37yxa77sa6grc4cemvq8sxqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqzqqqqqqqqqqqvx20dprmst6w5g8cr2s4qnpjm4050lyk6u820vn9w2uzydlljw69nlatn272qvyc8x9tlv2rfhzqpqzc27e3dmvuext5vk7zrt7v786kg0qgtd9f40ly2myzmuz2xzpk2x43pfzl77hgwyu433g32pdxzrqgqqqmwax43"
-        },
-        "transactions_root": "ht1xg2n66hxkssc4wmfx5x37vcwm0q45yml7gldwc5u0a6vq0q44grqr0a973"
-    },
-    "previous_block_hash": "ab1c5s8ddqmhhgthe3tqg3n9gy38v3frvv3e7822r88qwlxhxn8qyqqkahhgh",
-    "transactions": {
-        "transactions": [
-            {
-                "inner_circuit_id": "ic13cstkmt5j4qqzfu5am8jx2rhxm0hqplyzcgzyueefz7n32xl4h53n4xmxvhjyzaq2c0f7l70a4xszau2ryc",
-                "ledger_root": "al1enk2kwh9nuzcj2q9kdutekavlf8ayjqcuszgezsfax8qxn9k0yxqfr9fr2",
-                "transaction_id": "at10g0c3lqfjcdtnfs0f3lsxlwaaygfuz4ejr8h2wyvj6le93sumcgsnm00dx",
-                "transitions": [
-                    {
-                        "ciphertexts": [
-                            "recd12wfaxwl7srwykgs9mge8fhje58vm5frzsmvy0xs7jewv0syrhvxp8w7xa9c5xvye5wvxze9a9fs34ff3u34jzxgnfe7apquak23rkp9r254776qjvp65g8xhszg4l8elhs9v6qau2620w6w92crpm62yp8fwhr3lstynjsm8pzuawnktgtryhdklfr57fyt2llxlrl6uuvvsksp2d9tzxazqx4y6508g3sy2j3kwmx2q7aj78wfpjs0jjcafeaqwk4axuyv08qyldazx2ynj9xwamylljxpv62vua7ed2sjqe9c8fu86ladqm49ny4xwp5vmzf4n5rkn8zlupte2fvzq25eps7s7sq0yjp5ej4rvv5rg8nxa6484ydw0l5zhupskqp3tgv537d4xfls32nyaqxjrdkqltf83ggdeugy5lkjlpuc0kjhae9zyg8qsr6etandqlk8s58nukav",
-                            "recd1x9fnkethz7asd7kgghwumepp7qt3wahmqcjknwd4cm5n3xw38sp3satx3ju0298f4973r6uur43jk8r952rj353fv3llt9z5akpd2qz978rhgql60s3dyv2qpprjd90u8fhz96wwx4jamahqtkc7k44qpt5zrwrtcc9e47xp4j0e2kt840697dmvk4aspcduh606rhjfgrusv8cyfk39hj77dfkfmf48gt3k7c0ysy8usp06g5dnwapvtqq4r7gzg3gvv06yrq5yzycr3wf4ee22szvsa8gjhy2ddyjnspzcd6u2jv8wx5zcu6gtyd3rzptug0sh3ezvgml35aghj0y9ut640mw83d7jjqztayt83dgsnzfl9zesjajee7pf2fg83uzsdgnhsa2fe0vu8mxepwc6zencxr2tznlgyrx7x0mfmn2mkegauvprpqhhtcqrcae9slsqun9886h"
-                        ],
-                        "commitments": [
-                            "cm1j6emu0yy3q8cq4ema9rmvm95uj50rs3w4d29lfs9j7c0yaap5v9s2303sv",
-                            "cm1zxwtj279444cdu7mls45e44jfdmm77xrt8kwxfgatq23mx35zu9sj488j8"
-                        ],
-                        "events": [
-                            {
-                                "id": 1,
-                                "index": 0,
-                                "record_view_key": "rcvk1nxa0lm34cder6mfkn5g9teqlh7svyd30eserjxl94k05x7yl3sxszkxheg"
-                            },
-                            {
-                                "id": 1,
-                                "index": 1,
-                                "record_view_key": "rcvk16ag2e3l8wy6tuxzhfvda85shwndctlct6s5eusegvc8um7wt3czs7zx3sz"
-                            }
-                        ],
-                        "proof": "ozkp184vqqwalphghhu5l2yyqzc899gna5nvnw4e8a6xnef60cue2ax3yc7m2kqu32zg8e0g7g9s7lp0yyhafphfsn9vzw20w3l5yxmzlp2nllp4w9e4xnhg6ms3vzqxhk57w3wmrx773chak3h93mq73dvh5squwc4uftpq30ryu0zcfg3vamkgrq9xdwru7w79x5grd20qc43zk52l74yh9gj3q5yf3hsdqe9dlvy3cmusft4e8zesnrwtnwp7kvcungcc5t4fln6paa9kmt759nz5zf2pz3ugjt3frq4guu4m5u7l7gzqfpftrwpkge9hs5ye86utgezvlh9y09hkkvmtwx6dvdc20jrxflk8cke0wt6428jafqvmv6v24k8pze8tu4m2qq3sse6pft3saz6eupjn79l83fnkycmwgctc9sjmexj5ce686nhrh0npsqzaattrqsz0sqqg2vpcr7",
-                        "serial_numbers": [
-                            "sn1lnezey2ur9vhdewckn6g58vq3sx37emlxudf9v563d6pg5afcy8s6whpc5",
-                            "sn1ey3vm0fszgk7kzmnceh9c0cqjl8lhhpkkenpcuj6yjxqmnp33gyseshehs"
-                        ],
-                        "transition_id": "as15spwcnvq0stkq30drla8fvl5krzhx74mj5s88aas6lq2e8c26gzq3a7v45",
-                        "value_balance": -100000000
-                    }
-                ]
-            }
-        ]
-    }
-}"#;
+        let block_string = include_str!("./resources/block.v12.json");
         let result = Block::<Testnet2>::from_str(block_string);
         println!("{:?}", result);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_block_json_canonical() {
+        let expected_string = include_str!("./resources/block.v12.canonical.json").trim_end();
+
+        // Deserialize the block from the pretty-printed, sorted form.
+        let expected_block = Block::<Testnet2>::from_str(include_str!("./resources/block.v12.json")).unwrap();
+
+        // Serialize
+        let candidate_string = expected_block.to_json_canonical().unwrap();
+        assert_eq!(4067, candidate_string.len(), "Update me if serialization has changed");
+        assert_eq!(expected_string, candidate_string);
+        assert_eq!(expected_block.to_string(), candidate_string);
+
+        // Deserialize
+        assert_eq!(expected_block, Block::<Testnet2>::from_str(&candidate_string).unwrap());
+        assert_eq!(
+            candidate_string,
+            Block::<Testnet2>::from_str(&candidate_string)
+                .unwrap()
+                .to_json_canonical()
+                .unwrap()
+        );
+    }
 }
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{BlockError, BlockTemplate, Network, PoSWCircuit, PoSWProof, PoSWScheme};
+use crate::{BlockError, BlockTemplate, HeaderLeaf, HeaderPath, Network, PoSWCircuit, PoSWProof, PoSWScheme};
 use snarkvm_algorithms::merkle_tree::{MerklePath, MerkleTree};
 use snarkvm_utilities::{
     fmt,
@@ -69,6 +69,16 @@ impl BlockHeaderMetadata {
         }
     }
 
+    /// Initializes a new instance of a block header metadata from the given fields.
+    pub(crate) fn from(height: u32, timestamp: i64, difficulty_target: u64, cumulative_weight: u128) -> Self {
+        Self {
+            height,
+            timestamp,
+            difficulty_target,
+            cumulative_weight,
+        }
+    }
+
     /// Returns the size (in bytes) of a block header's metadata.
     pub fn size() -> usize {
         size_of::<u32>() + size_of::<i64>() + size_of::<u64>() + size_of::<u128>()
@@ -278,6 +288,23 @@ impl<N: Network> BlockHeader<N> {
     pub fn to_header_root(&self) -> Result<N::BlockHeaderRoot> {
         Ok((*self.to_header_tree()?.root()).into())
     }
+
+    /// Returns the bytes of the given leaf in the block header tree.
+    pub fn to_leaf(&self, leaf: HeaderLeaf) -> Result<Vec<u8>> {
+        Ok(match leaf {
+            HeaderLeaf::PreviousLedgerRoot => self.previous_ledger_root.to_bytes_le()?,
+            HeaderLeaf::TransactionsRoot => self.transactions_root.to_bytes_le()?,
+            HeaderLeaf::Reserved => vec![0u8; 32],
+            HeaderLeaf::Metadata => self.metadata.to_bytes_le()?,
+        })
+    }
+
+    /// Returns a proof of inclusion for the given leaf in the block header tree.
+    pub fn prove_leaf(&self, leaf: HeaderLeaf) -> Result<HeaderPath<N>> {
+        let leaf_bytes = self.to_leaf(leaf)?;
+        let path = self.to_header_tree()?.generate_proof(leaf.index(), &leaf_bytes)?;
+        HeaderPath::new(leaf_bytes, path)
+    }
 }
 
 impl<N: Network> FromBytes for BlockHeader<N> {
@@ -292,12 +319,12 @@ impl<N: Network> FromBytes for BlockHeader<N> {
         let timestamp = <[u8; 8]>::read_le(&mut reader)?;
         let difficulty_target = <[u8; 8]>::read_le(&mut reader)?;
         let cumulative_weight = <[u8; 16]>::read_le(&mut reader)?;
-        let metadata = BlockHeaderMetadata {
-            height: u32::from_le_bytes(height),
-            timestamp: i64::from_le_bytes(timestamp),
-            difficulty_target: u64::from_le_bytes(difficulty_target),
-            cumulative_weight: u128::from_le_bytes(cumulative_weight),
-        };
+        let metadata = BlockHeaderMetadata::from(
+            u32::from_le_bytes(height),
+            i64::from_le_bytes(timestamp),
+            u64::from_le_bytes(difficulty_target),
+            u128::from_le_bytes(cumulative_weight),
+        );
 
         // Read the header nonce.
         let nonce = FromBytes::read_le(&mut reader)?;
@@ -357,7 +384,7 @@ impl<N: Network> Serialize for BlockHeader<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match serializer.is_human_readable() {
             true => {
-                let mut header = serializer.serialize_struct("BlockHeader", 4)?;
+                let mut header = serializer.serialize_struct("BlockHeader", 5)?;
                 header.serialize_field("previous_ledger_root", &self.previous_ledger_root)?;
                 header.serialize_field("transactions_root", &self.transactions_root)?;
                 header.serialize_field("metadata", &self.metadata)?;
@@ -393,6 +420,9 @@ impl<'de, N: Network> Deserialize<'de> for BlockHeader<N> {
 mod tests {
     use super::*;
     use crate::{testnet1::Testnet1, testnet2::Testnet2, PoSWScheme};
+    use snarkvm_algorithms::CRH;
+    use snarkvm_parameters::{testnet2::GenesisBlock, Genesis};
+    use snarkvm_utilities::to_bytes_le;
 
     use rand::thread_rng;
 
@@ -406,6 +436,27 @@ mod tests {
             + N::HEADER_PROOF_SIZE_IN_BYTES
     }
 
+    /// Returns the genesis block header, read without verifying its PoSW proof.
+    fn genesis_header_unchecked() -> BlockHeader<Testnet2> {
+        // Skip the block hash and previous block hash.
+        let bytes = GenesisBlock::load_bytes();
+        let mut reader = &bytes[64..];
+
+        let previous_ledger_root = FromBytes::read_le(&mut reader).unwrap();
+        let transactions_root = FromBytes::read_le(&mut reader).unwrap();
+        let _metadata = <[u8; 36]>::read_le(&mut reader).unwrap();
+        let nonce = FromBytes::read_le(&mut reader).unwrap();
+        let proof = FromBytes::read_le(&mut reader).unwrap();
+
+        BlockHeader {
+            previous_ledger_root,
+            transactions_root,
+            metadata: BlockHeaderMetadata::genesis(),
+            nonce,
+            proof,
+        }
+    }
+
     #[test]
     fn test_block_header_size() {
         assert_eq!(get_expected_size::<Testnet1>(), Testnet1::HEADER_SIZE_IN_BYTES);
@@ -510,4 +561,67 @@ mod tests {
         block_header.metadata.difficulty_target = 0u64;
         assert!(!Testnet2::posw().verify_from_block_header(&block_header));
     }
+
+    #[test]
+    fn test_block_header_root_is_pinned() {
+        // Changing the block header tree changes the genesis block hash, so it must not happen silently.
+        let block_header = genesis_header_unchecked();
+        let block_header_root = block_header.to_header_root().unwrap();
+        assert_eq!(
+            "hr1djduswvvgdzuk36xcar0zwl5jr4mmwg09tkehj60drfdy37pdy8sh8rncc",
+            block_header_root.to_string()
+        );
+
+        // Ensure the block header root reproduces the genesis block hash.
+        let bytes = GenesisBlock::load_bytes();
+        let block_hash: <Testnet2 as Network>::BlockHash = FromBytes::read_le(&bytes[..32]).unwrap();
+        let previous_block_hash: <Testnet2 as Network>::BlockHash = FromBytes::read_le(&bytes[32..64]).unwrap();
+        let candidate_block_hash: <Testnet2 as Network>::BlockHash = Testnet2::block_hash_crh()
+            .hash(&to_bytes_le![previous_block_hash, block_header_root].unwrap())
+            .unwrap()
+            .into();
+        assert_eq!(block_hash, candidate_block_hash);
+    }
+
+    #[test]
+    fn test_prove_leaf() {
+        let block_header = genesis_header_unchecked();
+        let block_header_root = block_header.to_header_root().unwrap();
+
+        for leaf in HeaderLeaf::ALL {
+            let header_path = block_header.prove_leaf(leaf).unwrap();
+            assert_eq!(leaf, header_path.leaf_type());
+            assert_eq!(block_header.to_leaf(leaf).unwrap(), header_path.leaf());
+            assert!(header_path.verify(&block_header_root));
+
+            // Ensure the header path round trips.
+            let bytes = header_path.to_bytes_le().unwrap();
+            let candidate = HeaderPath::<Testnet2>::read_le(&bytes[..]).unwrap();
+            assert_eq!(leaf, candidate.leaf_type());
+            assert!(candidate.verify(&block_header_root));
+        }
+
+        // Ensure the transactions root path matches the one used in record proofs.
+        let expected = block_header
+            .to_header_inclusion_proof(1, block_header.transactions_root())
+            .unwrap();
+        let candidate = block_header.prove_leaf(HeaderLeaf::TransactionsRoot).unwrap();
+        assert_eq!(expected.to_bytes_le().unwrap(), candidate.path().to_bytes_le().unwrap());
+    }
+
+    #[test]
+    fn test_prove_leaf_rejects_mismatches() {
+        let block_header = genesis_header_unchecked();
+        let block_header_root = block_header.to_header_root().unwrap();
+
+        let header_path = block_header.prove_leaf(HeaderLeaf::TransactionsRoot).unwrap();
+
+        // Ensure the path does not verify against a different leaf.
+        let previous_ledger_root = block_header.to_leaf(HeaderLeaf::PreviousLedgerRoot).unwrap();
+        let candidate = HeaderPath::<Testnet2>::new(previous_ledger_root, header_path.path().clone()).unwrap();
+        assert!(!candidate.verify(&block_header_root));
+
+        // Ensure the path does not verify against a different root.
+        assert!(!header_path.verify(&Default::default()));
+    }
 }
@@ -0,0 +1,151 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{DeserializationError, Network};
+use snarkvm_algorithms::merkle_tree::MerklePath;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::io::{Read, Result as IoResult, Write};
+
+/// The leaves of the block header tree, in the order they are committed to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HeaderLeaf {
+    /// The ledger root of the previous block.
+    PreviousLedgerRoot,
+    /// The root of the transactions in the block.
+    TransactionsRoot,
+    /// A reserved leaf, which is always zero.
+    Reserved,
+    /// The block header metadata.
+    Metadata,
+}
+
+impl HeaderLeaf {
+    /// The header leaves, ordered by their index in the block header tree.
+    pub const ALL: [HeaderLeaf; 4] = [
+        Self::PreviousLedgerRoot,
+        Self::TransactionsRoot,
+        Self::Reserved,
+        Self::Metadata,
+    ];
+
+    /// Returns the index of the leaf in the block header tree.
+    pub fn index(&self) -> usize {
+        match self {
+            Self::PreviousLedgerRoot => 0,
+            Self::TransactionsRoot => 1,
+            Self::Reserved => 2,
+            Self::Metadata => 3,
+        }
+    }
+
+    /// Returns the header leaf at the given index in the block header tree.
+    pub fn from_index(index: usize) -> Result<Self> {
+        Self::ALL
+            .get(index)
+            .copied()
+            .ok_or_else(|| anyhow!("Invalid block header leaf index {}", index))
+    }
+}
+
+/// A proof of inclusion for a leaf in a block header.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct HeaderPath<N: Network> {
+    leaf: Vec<u8>,
+    path: MerklePath<N::BlockHeaderRootParameters>,
+}
+
+impl<N: Network> HeaderPath<N> {
+    ///
+    /// Initializes a new instance of `HeaderPath`.
+    ///
+    pub fn new(leaf: Vec<u8>, path: MerklePath<N::BlockHeaderRootParameters>) -> Result<Self> {
+        // Ensure the path is for a block header leaf.
+        HeaderLeaf::from_index(path.leaf_index as usize)?;
+
+        Ok(Self { leaf, path })
+    }
+
+    /// Returns the type of the proven leaf.
+    pub fn leaf_type(&self) -> HeaderLeaf {
+        HeaderLeaf::from_index(self.path.leaf_index as usize).expect("Header path has an invalid leaf index")
+    }
+
+    /// Returns the bytes of the proven leaf.
+    pub fn leaf(&self) -> &[u8] {
+        &self.leaf
+    }
+
+    /// Returns the Merkle path from the leaf to the block header root.
+    pub fn path(&self) -> &MerklePath<N::BlockHeaderRootParameters> {
+        &self.path
+    }
+
+    /// Returns `true` if the leaf belongs to the given block header root.
+    pub fn verify(&self, block_header_root: &N::BlockHeaderRoot) -> bool {
+        match self.path.verify(block_header_root, &self.leaf) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify block header path: {}", error);
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for HeaderPath<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        let num_bytes: u16 = FromBytes::read_le(&mut reader)?;
+        let mut leaf = vec![0u8; num_bytes as usize];
+        reader.read_exact(&mut leaf)?;
+        let path = FromBytes::read_le(&mut reader)?;
+
+        Ok(Self::new(leaf, path)
+            .map_err(|error| DeserializationError::InvalidObject("block header path", error.to_string()))?)
+    }
+}
+
+impl<N: Network> ToBytes for HeaderPath<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        (self.leaf.len() as u16).write_le(&mut writer)?;
+        self.leaf.write_le(&mut writer)?;
+        self.path.write_le(&mut writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_leaf_ordering() {
+        // Changing the leaf ordering changes every block header root, so it must not happen silently.
+        assert_eq!(0, HeaderLeaf::PreviousLedgerRoot.index());
+        assert_eq!(1, HeaderLeaf::TransactionsRoot.index());
+        assert_eq!(2, HeaderLeaf::Reserved.index());
+        assert_eq!(3, HeaderLeaf::Metadata.index());
+
+        for (index, leaf) in HeaderLeaf::ALL.iter().enumerate() {
+            assert_eq!(index, leaf.index());
+            assert_eq!(*leaf, HeaderLeaf::from_index(index).unwrap());
+        }
+        assert!(HeaderLeaf::from_index(HeaderLeaf::ALL.len()).is_err());
+    }
+}
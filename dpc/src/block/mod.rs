@@ -20,6 +20,9 @@ pub use block::*;
 pub mod header;
 pub use header::*;
 
+pub mod header_path;
+pub use header_path::*;
+
 pub mod template;
 pub use template::*;
 
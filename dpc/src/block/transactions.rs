@@ -14,12 +14,21 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, BlockError, DecryptionKey, Network, Record, Transaction};
+use crate::{
+    AleoAmount,
+    BlockError,
+    CompositionError,
+    DecryptionKey,
+    DeserializationError,
+    Network,
+    Record,
+    Transaction,
+};
 use snarkvm_algorithms::merkle_tree::*;
-use snarkvm_utilities::{has_duplicates, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
-use rayon::prelude::*;
+use rayon::{prelude::*, ThreadPool};
 use serde::{
     de,
     ser::{self, SerializeStruct},
@@ -29,13 +38,36 @@ use serde::{
     Serializer,
 };
 use std::{
+    collections::HashSet,
     fmt,
+    hash::Hash,
     io::{Read, Result as IoResult, Write},
     ops::Deref,
     str::FromStr,
     sync::Arc,
 };
 
+/// Returns the index of the first invalid item in the given order, verifying the items in parallel
+/// on the given thread pool, or on the global thread pool if `None`.
+/// The result does not depend on the scheduling of the threads.
+fn find_first_invalid<T: Sync>(
+    items: &[T],
+    is_valid: impl Fn(&T) -> bool + Send + Sync,
+    verification_pool: Option<&ThreadPool>,
+) -> Option<usize> {
+    let find = || items.par_iter().position_first(|item| !is_valid(item));
+    match verification_pool {
+        Some(pool) => pool.install(find),
+        None => find(),
+    }
+}
+
+/// Returns the first item that is equal to an earlier item, in iteration order.
+fn find_first_duplicate<T: Clone + Eq + Hash>(items: impl IntoIterator<Item = T>) -> Option<T> {
+    let mut seen = HashSet::new();
+    items.into_iter().find(|item| !seen.insert(item.clone()))
+}
+
 #[derive(Derivative)]
 #[derivative(
     Clone(bound = "N: Network"),
@@ -75,47 +107,76 @@ impl<N: Network> Transactions<N> {
 
     /// Returns `true` if the transactions are well-formed.
     pub fn is_valid(&self) -> bool {
+        match self.check_valid(None) {
+            Ok(()) => true,
+            Err(error) => {
+                eprintln!("{}", error);
+                false
+            }
+        }
+    }
+
+    /// Ensures the transactions are well-formed, verifying the transactions in parallel on the given
+    /// thread pool, or on the global thread pool if `None`. Returns the composition rule violated,
+    /// or else the first invalid transaction in block order.
+    pub fn check_valid(&self, verification_pool: Option<&ThreadPool>) -> Result<(), BlockError> {
+        // Ensure the transactions are composed correctly, before verifying any proof.
+        self.validate_composition()?;
+
+        // Ensure each transaction is well-formed, reporting the first invalid transaction in block order.
+        match find_first_invalid(&self.transactions, Transaction::is_valid, verification_pool) {
+            Some(index) => Err(BlockError::InvalidTransaction(
+                index,
+                self.transactions[index].transaction_id().to_string(),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Ensures the transactions satisfy the rules for composing the transactions of a block,
+    /// which do not depend on the validity of each transaction, returning the first rule violated.
+    ///
+    /// The rules are checked in the following order:
+    /// 1. The transactions list is not empty.
+    /// 2. No transition ID appears twice.
+    /// 3. No serial number appears twice.
+    /// 4. No commitment appears twice.
+    /// 5. Exactly 1 transaction is a coinbase transaction, with a negative value balance.
+    ///
+    pub fn validate_composition(&self) -> Result<(), CompositionError> {
+        Self::check_composition(&self.transactions)
+    }
+
+    /// Ensures the given transactions satisfy the rules for composing the transactions of a block.
+    fn check_composition(transactions: &[Transaction<N>]) -> Result<(), CompositionError> {
         // Ensure the transactions list is not empty.
-        if self.transactions.is_empty() {
-            eprintln!("Cannot process validity checks on an empty transactions list");
-            return false;
+        if transactions.is_empty() {
+            return Err(CompositionError::EmptyTransactions);
         }
 
-        // Ensure each transaction is well-formed.
-        if !self
-            .transactions
-            .as_parallel_slice()
-            .par_iter()
-            .all(Transaction::is_valid)
-        {
-            eprintln!("Invalid transaction found in the transactions list");
-            return false;
+        // Ensure there are no duplicate transition IDs.
+        if let Some(transition_id) = find_first_duplicate(transactions.iter().flat_map(Transaction::transition_ids)) {
+            return Err(CompositionError::DuplicateTransitionId(transition_id.to_string()));
         }
 
         // Ensure there are no duplicate serial numbers.
-        if has_duplicates(self.transactions.iter().flat_map(Transaction::serial_numbers)) {
-            eprintln!("Found duplicate serial numbers in the transactions list");
-            return false;
+        if let Some(serial_number) = find_first_duplicate(transactions.iter().flat_map(Transaction::serial_numbers)) {
+            return Err(CompositionError::DuplicateSerialNumber(serial_number.to_string()));
         }
 
         // Ensure there are no duplicate commitments.
-        if has_duplicates(self.transactions.iter().flat_map(Transaction::commitments)) {
-            eprintln!("Found duplicate commitments in the transactions list");
-            return false;
+        if let Some(commitment) = find_first_duplicate(transactions.iter().flat_map(Transaction::commitments)) {
+            return Err(CompositionError::DuplicateCommitment(commitment.to_string()));
         }
 
         // Ensure there is 1 coinbase transaction.
-        let num_coinbase = self
-            .transactions
-            .iter()
-            .filter(|t| t.value_balance().is_negative())
-            .count();
+        let num_coinbase = transactions.iter().filter(|t| t.value_balance().is_negative()).count();
         if num_coinbase != 1 {
-            eprintln!("Block must have exactly 1 coinbase transaction, found {}", num_coinbase);
-            return false;
+            return Err(CompositionError::InvalidCoinbaseCount(num_coinbase));
         }
 
-        true
+        Ok(())
     }
 
     /// Returns the transaction IDs, by constructing a flattened list of transaction IDs from all transactions.
@@ -208,6 +269,7 @@ impl<N: Network> FromBytes for Transactions<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
         let num_transactions: u16 = FromBytes::read_le(&mut reader)?;
+        DeserializationError::check_length("transactions", num_transactions as usize, N::NUM_TRANSACTIONS as usize)?;
         let mut transactions = Vec::with_capacity(num_transactions as usize);
         for _ in 0..num_transactions {
             transactions.push(FromBytes::read_le(&mut reader)?);
@@ -288,8 +350,16 @@ impl<N: Network> Deref for Transactions<N> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{testnet2::Testnet2, Account, AccountScheme};
-    use rand::thread_rng;
+    use crate::{
+        test_helpers::sample_transaction_with_value_balance,
+        testnet2::Testnet2,
+        Account,
+        AccountScheme,
+        Transition,
+    };
+
+    use rand::{seq::SliceRandom, thread_rng, Rng};
+    use rayon::ThreadPoolBuilder;
 
     #[test]
     fn test_to_decrypted_records() {
@@ -315,6 +385,156 @@ mod tests {
         assert_eq!(expected_record.program_id(), candidate_record.program_id());
     }
 
+    /// Returns a transaction with one transition of the given contents. The transition proof is invalid.
+    fn transaction_from(
+        serial_numbers: Vec<<Testnet2 as Network>::SerialNumber>,
+        ciphertexts: Vec<<Testnet2 as Network>::RecordCiphertext>,
+        value_balance: AleoAmount,
+    ) -> Transaction<Testnet2> {
+        let rng = &mut thread_rng();
+        let commitments: Vec<_> = ciphertexts.iter().map(|ciphertext| ciphertext.commitment()).collect();
+        let transition_id = Transition::<Testnet2>::compute_transition_id(&serial_numbers, &commitments).unwrap();
+        let transition = Transition::from(
+            transition_id,
+            serial_numbers,
+            ciphertexts,
+            value_balance,
+            vec![],
+            Default::default(),
+        )
+        .unwrap();
+        Transaction::from_unchecked(rng.gen(), rng.gen(), vec![transition]).unwrap()
+    }
+
+    #[test]
+    fn test_validate_composition() {
+        let rng = &mut thread_rng();
+        let coinbase = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-1), rng).unwrap();
+        let transaction = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount::ZERO, rng).unwrap();
+        let transactions = vec![coinbase, transaction];
+        assert_eq!(Ok(()), Transactions::check_composition(&transactions));
+    }
+
+    #[test]
+    fn test_check_valid_reports_first_invalid_transaction() {
+        // The transactions are composed correctly, but their transition proofs are invalid.
+        let rng = &mut thread_rng();
+        let coinbase = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-1), rng).unwrap();
+        let transaction = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount::ZERO, rng).unwrap();
+        let transactions = vec![coinbase, transaction];
+        let tree = MerkleTree::<<Testnet2 as Network>::TransactionsRootParameters>::new(
+            Arc::new(Testnet2::transactions_root_parameters().clone()),
+            &transactions.iter().map(Transaction::transaction_id).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let transactions = Transactions {
+            transactions,
+            tree: Arc::new(tree),
+        };
+
+        // Ensure the first invalid transaction is reported, on any thread pool.
+        let pool = ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+        for pool in [None, Some(&pool)] {
+            match transactions.check_valid(pool) {
+                Err(BlockError::InvalidTransaction(index, transaction_id)) => {
+                    assert_eq!(0, index);
+                    assert_eq!(transactions[0].transaction_id().to_string(), transaction_id);
+                }
+                result => panic!("Expected an invalid transaction, found {:?}", result),
+            }
+        }
+
+        // Ensure a composition error is reported before verifying any transaction.
+        let empty = Transactions::<Testnet2> {
+            transactions: vec![],
+            tree: transactions.tree.clone(),
+        };
+        assert!(matches!(
+            empty.check_valid(None),
+            Err(BlockError::CompositionError(CompositionError::EmptyTransactions))
+        ));
+    }
+
+    #[test]
+    fn test_composition_empty_transactions() {
+        assert_eq!(
+            Err(CompositionError::EmptyTransactions),
+            Transactions::<Testnet2>::check_composition(&[])
+        );
+    }
+
+    #[test]
+    fn test_composition_duplicate_transition_id() {
+        let rng = &mut thread_rng();
+        let transaction = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-1), rng).unwrap();
+        let transition_id = transaction.transition_ids().next().unwrap();
+        assert_eq!(
+            Err(CompositionError::DuplicateTransitionId(transition_id.to_string())),
+            Transactions::check_composition(&[transaction.clone(), transaction])
+        );
+    }
+
+    #[test]
+    fn test_composition_duplicate_serial_number() {
+        let rng = &mut thread_rng();
+        let coinbase = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-1), rng).unwrap();
+        let transaction = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount::ZERO, rng).unwrap();
+
+        // Craft a transaction that spends a serial number of the coinbase transaction.
+        let mut serial_numbers: Vec<_> = transaction.serial_numbers().cloned().collect();
+        serial_numbers[0] = *coinbase.serial_numbers().next().unwrap();
+        let transaction = transaction_from(
+            serial_numbers.clone(),
+            transaction.ciphertexts().cloned().collect(),
+            AleoAmount::ZERO,
+        );
+
+        assert_eq!(
+            Err(CompositionError::DuplicateSerialNumber(serial_numbers[0].to_string())),
+            Transactions::check_composition(&[coinbase, transaction])
+        );
+    }
+
+    #[test]
+    fn test_composition_duplicate_commitment() {
+        let rng = &mut thread_rng();
+        let coinbase = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-1), rng).unwrap();
+        let transaction = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount::ZERO, rng).unwrap();
+
+        // Craft a transaction that outputs a ciphertext of the coinbase transaction.
+        let mut ciphertexts: Vec<_> = transaction.ciphertexts().cloned().collect();
+        ciphertexts[0] = coinbase.ciphertexts().next().unwrap().clone();
+        let transaction = transaction_from(
+            transaction.serial_numbers().cloned().collect(),
+            ciphertexts.clone(),
+            AleoAmount::ZERO,
+        );
+
+        assert_eq!(
+            Err(CompositionError::DuplicateCommitment(
+                ciphertexts[0].commitment().to_string()
+            )),
+            Transactions::check_composition(&[coinbase, transaction])
+        );
+    }
+
+    #[test]
+    fn test_composition_invalid_coinbase_count() {
+        let rng = &mut thread_rng();
+        let transaction = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount::ZERO, rng).unwrap();
+        assert_eq!(
+            Err(CompositionError::InvalidCoinbaseCount(0)),
+            Transactions::check_composition(&[transaction])
+        );
+
+        let first = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-1), rng).unwrap();
+        let second = sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(-2), rng).unwrap();
+        assert_eq!(
+            Err(CompositionError::InvalidCoinbaseCount(2)),
+            Transactions::check_composition(&[first, second])
+        );
+    }
+
     #[test]
     fn test_duplicate_transactions() {
         // Fetch any transaction.
@@ -362,4 +582,41 @@ mod tests {
             bincode::deserialize(&candidate_bytes[..]).unwrap()
         );
     }
+
+    #[test]
+    fn test_deserialize_too_many_transactions() {
+        for num_transactions in [Testnet2::NUM_TRANSACTIONS + 1, u16::MAX] {
+            let error = Transactions::<Testnet2>::read_le(&num_transactions.to_le_bytes()[..]).unwrap_err();
+            assert!(matches!(
+                error.get_ref().and_then(|error| error.downcast_ref()),
+                Some(DeserializationError::TooManyItems("transactions", ..))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_find_first_invalid_is_deterministic() {
+        let rng = &mut thread_rng();
+
+        // Mark a few items as invalid, with varying verification times.
+        let items = (0..50u64).collect::<Vec<_>>();
+        let invalid = [17u64, 23, 41];
+        let is_valid = |item: &u64| {
+            std::thread::sleep(std::time::Duration::from_micros((50 - item) * 10));
+            !invalid.contains(item)
+        };
+
+        let pools = (1..8usize)
+            .map(|num_threads| ThreadPoolBuilder::new().num_threads(num_threads).build().unwrap())
+            .collect::<Vec<_>>();
+        for _ in 0..100 {
+            // Verify on the global thread pool, or on a pool of 1 to 7 threads.
+            let pool = rng.gen_range(0..=pools.len()).checked_sub(1).map(|index| &pools[index]);
+            assert_eq!(Some(17), find_first_invalid(&items, is_valid, pool));
+        }
+
+        // Check all valid items are accepted.
+        let pool = pools.choose(rng);
+        assert_eq!(None, find_first_invalid(&items, |item| *item < 50, pool));
+    }
 }
@@ -0,0 +1,311 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AleoAmount, Network, Record, ViewKey};
+use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
+use snarkvm_gadgets::{
+    bits::{Boolean, ToBytesGadget},
+    integers::{int::Int64, uint::UInt8},
+    traits::{
+        algorithms::{CRHGadget, EncryptionGadget},
+        alloc::AllocGadget,
+        eq::EqGadget,
+        integers::integer::Integer,
+    },
+    EvaluateLtGadget,
+    ToConstraintFieldGadget,
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSynthesizer, ConstraintSystem};
+use snarkvm_utilities::ToBytes;
+
+/// The public inputs of a minimum balance proof.
+#[derive(Derivative)]
+#[derivative(Copy(bound = "N: Network"), Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct BalancePublicVariables<N: Network> {
+    /// The commitment of the record.
+    commitment: N::Commitment,
+    /// The minimum value the record is claimed to hold.
+    minimum: AleoAmount,
+}
+
+impl<N: Network> BalancePublicVariables<N> {
+    pub fn blank() -> Self {
+        Self {
+            commitment: Default::default(),
+            minimum: AleoAmount::ZERO,
+        }
+    }
+
+    pub fn new(commitment: N::Commitment, minimum: AleoAmount) -> Self {
+        Self { commitment, minimum }
+    }
+
+    /// Returns the record commitment.
+    pub fn commitment(&self) -> N::Commitment {
+        self.commitment
+    }
+
+    /// Returns the minimum value.
+    pub fn minimum(&self) -> AleoAmount {
+        self.minimum
+    }
+}
+
+/// Converts the public variables into field elements, in the order they are allocated.
+impl<N: Network> ToConstraintField<N::InnerScalarField> for BalancePublicVariables<N> {
+    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
+        let mut v = self.commitment.to_field_elements()?;
+        v.extend_from_slice(&self.minimum.to_bytes_le()?.to_field_elements()?);
+        Ok(v)
+    }
+}
+
+/// A circuit proving that the record behind a commitment holds at least a minimum value,
+/// without revealing the value, owner, payload, or program ID of the record.
+/// The prover must know the view key of the record owner.
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"))]
+pub struct BalanceCircuit<N: Network> {
+    public: BalancePublicVariables<N>,
+    record: Record<N>,
+    view_key: ViewKey<N>,
+}
+
+impl<N: Network> BalanceCircuit<N> {
+    pub fn blank() -> Self {
+        Self {
+            public: BalancePublicVariables::blank(),
+            record: Record::default(),
+            view_key: ViewKey::default(),
+        }
+    }
+
+    pub fn new(public: BalancePublicVariables<N>, record: Record<N>, view_key: ViewKey<N>) -> Self {
+        Self {
+            public,
+            record,
+            view_key,
+        }
+    }
+}
+
+impl<N: Network> ConstraintSynthesizer<N::InnerScalarField> for BalanceCircuit<N> {
+    fn generate_constraints<CS: ConstraintSystem<N::InnerScalarField>>(
+        &self,
+        cs: &mut CS,
+    ) -> Result<(), SynthesisError> {
+        let public = &self.public;
+        let record = &self.record;
+        let view_key = &self.view_key;
+
+        let (account_encryption_parameters, record_commitment_parameters) = {
+            let cs = &mut cs.ns(|| "Declare parameters");
+
+            let account_encryption_parameters = N::AccountEncryptionGadget::alloc_constant(
+                &mut cs.ns(|| "Declare account encryption parameters"),
+                || Ok(N::account_encryption_scheme().clone()),
+            )?;
+
+            let record_commitment_parameters =
+                N::CommitmentGadget::alloc_constant(&mut cs.ns(|| "Declare record commitment parameters"), || {
+                    Ok(N::commitment_scheme().clone())
+                })?;
+
+            (account_encryption_parameters, record_commitment_parameters)
+        };
+
+        // Declare the record commitment.
+        let given_commitment = <N::CommitmentGadget as CRHGadget<_, _>>::OutputGadget::alloc_input(
+            &mut cs.ns(|| "Declare the record commitment"),
+            || Ok(*public.commitment()),
+        )?;
+
+        // Declare the minimum value.
+        let public_minimum_bytes = UInt8::alloc_input_vec_le(
+            &mut cs.ns(|| "Declare the minimum value"),
+            &public.minimum().to_bytes_le()?,
+        )?;
+
+        // Declare the record contents.
+        let (
+            given_owner,
+            given_is_dummy,
+            given_value,
+            given_payload,
+            given_program_id,
+            given_randomizer,
+            given_record_view_key,
+        ) = {
+            let declare_cs = &mut cs.ns(|| "Declare record");
+
+            let given_owner = <N::AccountEncryptionGadget as EncryptionGadget<
+                N::AccountEncryptionScheme,
+                N::InnerScalarField,
+            >>::PublicKeyGadget::alloc(
+                &mut declare_cs.ns(|| "given_record_owner"), || Ok(*record.owner())
+            )?;
+
+            let given_is_dummy = Boolean::alloc(&mut declare_cs.ns(|| "given_is_dummy"), || Ok(record.is_dummy()))?;
+
+            let given_value = Int64::alloc(&mut declare_cs.ns(|| "given_value"), || Ok(record.value().as_i64()))?;
+
+            let given_payload =
+                UInt8::alloc_vec(&mut declare_cs.ns(|| "given_payload"), &record.payload().to_bytes_le()?)?;
+
+            let given_program_id = UInt8::alloc_vec(
+                &mut declare_cs.ns(|| "given_program_id"),
+                &record.program_id().to_bytes_le()?,
+            )?;
+
+            let given_randomizer = <N::AccountEncryptionGadget as EncryptionGadget<
+                N::AccountEncryptionScheme,
+                N::InnerScalarField,
+            >>::CiphertextRandomizer::alloc(
+                &mut declare_cs.ns(|| "given_randomizer"), || Ok(record.randomizer())
+            )?;
+
+            let given_record_view_key = <N::AccountEncryptionGadget as EncryptionGadget<
+                N::AccountEncryptionScheme,
+                N::InnerScalarField,
+            >>::SymmetricKeyGadget::alloc(
+                &mut declare_cs.ns(|| "given_record_view_key"),
+                || Ok(*record.record_view_key().clone()),
+            )?;
+
+            (
+                given_owner,
+                given_is_dummy,
+                given_value,
+                given_payload,
+                given_program_id,
+                given_randomizer,
+                given_record_view_key,
+            )
+        };
+
+        // *******************************************************************
+        // Check that the view key derives the record owner.
+        // *******************************************************************
+        {
+            let owner_cs = &mut cs.ns(|| "Check that the view key derives the record owner");
+
+            let given_view_key = <N::AccountEncryptionGadget as EncryptionGadget<
+                N::AccountEncryptionScheme,
+                N::InnerScalarField,
+            >>::PrivateKeyGadget::alloc(&mut owner_cs.ns(|| "given_view_key"), || Ok(&**view_key))?;
+
+            let candidate_owner = account_encryption_parameters
+                .check_public_key_gadget(&mut owner_cs.ns(|| "Compute the owner address"), &given_view_key)?;
+
+            candidate_owner.enforce_equal(
+                &mut owner_cs.ns(|| "Check that the derived address matches the record owner"),
+                &given_owner,
+            )?;
+        }
+
+        // *******************************************************************
+        // Compute the record commitment and check that it matches the declared commitment.
+        // *******************************************************************
+        {
+            let commitment_cs = &mut cs.ns(|| "Check that the record opens the commitment");
+
+            let given_owner_bytes = given_owner.to_bytes(&mut commitment_cs.ns(|| "Convert given_owner to bytes"))?;
+            let given_is_dummy_bytes =
+                given_is_dummy.to_bytes(&mut commitment_cs.ns(|| "Convert given_is_dummy to bytes"))?;
+            let given_value_bytes = given_value.to_bytes(&mut commitment_cs.ns(|| "Convert given_value to bytes"))?;
+
+            let mut plaintext = Vec::new();
+            plaintext.extend_from_slice(&given_owner_bytes);
+            plaintext.extend_from_slice(&given_is_dummy_bytes);
+            plaintext.extend_from_slice(&given_value_bytes);
+            plaintext.extend_from_slice(&given_payload);
+            plaintext.extend_from_slice(&given_program_id);
+
+            let ciphertext = account_encryption_parameters.check_encryption_from_symmetric_key(
+                &mut commitment_cs.ns(|| "check_encryption_gadget"),
+                &given_record_view_key,
+                &plaintext,
+            )?;
+
+            let record_view_key_commitment = account_encryption_parameters.check_symmetric_key_commitment(
+                &mut commitment_cs.ns(|| "check_symmetric_key_commitment"),
+                &given_record_view_key,
+            )?;
+
+            let given_randomizer_bytes =
+                given_randomizer.to_bytes(&mut commitment_cs.ns(|| "Convert given_randomizer to bytes"))?;
+            let record_view_key_commitment_bytes = record_view_key_commitment
+                .to_bytes(&mut commitment_cs.ns(|| "Convert record_view_key_commitment to bytes"))?;
+
+            let mut commitment_input = Vec::with_capacity(
+                given_randomizer_bytes.len() + record_view_key_commitment_bytes.len() + ciphertext.len(),
+            );
+            commitment_input.extend_from_slice(&given_randomizer_bytes);
+            commitment_input.extend_from_slice(&record_view_key_commitment_bytes);
+            commitment_input.extend_from_slice(&ciphertext);
+
+            let candidate_commitment = record_commitment_parameters
+                .check_evaluation_gadget(&mut commitment_cs.ns(|| "Compute record commitment"), commitment_input)?;
+
+            candidate_commitment.enforce_equal(
+                &mut commitment_cs.ns(|| "Check that the record commitment matches public input"),
+                &given_commitment,
+            )?;
+        }
+
+        // *******************************************************************
+        // Check that the record value is at least the minimum value.
+        // *******************************************************************
+        {
+            let value_cs = &mut cs.ns(|| "Check that the record value is at least the minimum");
+
+            // Enforce the minimum value matches the public input.
+            let given_minimum = Int64::alloc(&mut value_cs.ns(|| "given_minimum"), || Ok(public.minimum().as_i64()))?;
+            let given_minimum_bytes = given_minimum.to_bytes(&mut value_cs.ns(|| "Convert given_minimum to bytes"))?;
+            let given_minimum_field_elements = given_minimum_bytes
+                .to_constraint_field(&mut value_cs.ns(|| "convert given minimum to field elements"))?;
+            let public_minimum_field_elements = public_minimum_bytes
+                .to_constraint_field(&mut value_cs.ns(|| "convert public minimum to field elements"))?;
+            given_minimum_field_elements.enforce_equal(
+                &mut value_cs.ns(|| "Check that the minimum matches public input"),
+                &public_minimum_field_elements,
+            )?;
+
+            // Enforce the record value and the minimum are positive values less than 2^62, so the comparison cannot wrap.
+            for (i, bit) in given_value.to_bits_be()[0..2]
+                .iter()
+                .chain(&given_minimum.to_bits_be()[0..2])
+                .enumerate()
+            {
+                bit.enforce_equal(
+                    value_cs.ns(|| format!("enforce bit {} is 0", i)),
+                    &Boolean::constant(false),
+                )?;
+            }
+
+            let is_insufficient = given_value.less_than(
+                &mut value_cs.ns(|| "Check the record value against the minimum"),
+                &given_minimum,
+            )?;
+            is_insufficient.enforce_equal(
+                &mut value_cs.ns(|| "Enforce the record value is at least the minimum"),
+                &Boolean::constant(false),
+            )?;
+        }
+
+        Ok(())
+    }
+}
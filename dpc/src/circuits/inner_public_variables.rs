@@ -78,21 +78,33 @@ impl<N: Network> InnerPublicVariables<N> {
     pub(crate) fn local_transitions_root(&self) -> N::TransactionID {
         self.local_transitions_root
     }
-}
 
-impl<N: Network> ToConstraintField<N::InnerScalarField> for InnerPublicVariables<N> {
-    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
-        let mut v = Vec::new();
-        v.extend_from_slice(&self.ledger_root.to_field_elements()?);
-        v.extend_from_slice(&self.local_transitions_root.to_field_elements()?);
+    /// Returns the field elements of each public variable, in the order they are allocated.
+    pub(crate) fn to_named_field_elements(
+        self,
+    ) -> Result<Vec<(&'static str, Vec<N::InnerScalarField>)>, ConstraintFieldError> {
+        let mut v = vec![
+            ("ledger_root", self.ledger_root.to_field_elements()?),
+            ("local_transitions_root", self.local_transitions_root.to_field_elements()?),
+        ];
 
         if let Some(program_id) = &self.program_id {
-            v.extend_from_slice(&program_id.to_bytes_le()?.to_field_elements()?);
+            v.push(("program_id", program_id.to_bytes_le()?.to_field_elements()?));
         }
 
-        v.extend_from_slice(&self.value_balance.to_bytes_le()?.to_field_elements()?);
-        v.extend_from_slice(&self.transition_id.to_field_elements()?);
+        v.push(("value_balance", self.value_balance.to_bytes_le()?.to_field_elements()?));
+        v.push(("transition_id", self.transition_id.to_field_elements()?));
 
         Ok(v)
     }
 }
+
+impl<N: Network> ToConstraintField<N::InnerScalarField> for InnerPublicVariables<N> {
+    fn to_field_elements(&self) -> Result<Vec<N::InnerScalarField>, ConstraintFieldError> {
+        Ok(self
+            .to_named_field_elements()?
+            .into_iter()
+            .flat_map(|(_, field_elements)| field_elements)
+            .collect())
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod balance_circuit;
+pub use balance_circuit::*;
+
 pub mod inner_circuit;
 pub use inner_circuit::*;
 
@@ -32,5 +35,8 @@ pub(crate) use outer_private_variables::*;
 pub(crate) mod outer_public_variables;
 pub(crate) use outer_public_variables::*;
 
+pub mod public_input_descriptor;
+pub use public_input_descriptor::*;
+
 #[cfg(test)]
 mod tests;
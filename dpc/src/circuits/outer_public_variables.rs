@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, InnerPublicVariables, Network};
+use crate::{AleoAmount, InnerPublicVariables, Network, PublicInputDescriptor};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 use snarkvm_utilities::ToBits;
 
@@ -73,10 +73,11 @@ impl<N: Network> OuterPublicVariables<N> {
     pub(crate) fn inner_circuit_id(&self) -> N::InnerCircuitID {
         self.inner_circuit_id
     }
-}
 
-impl<N: Network> ToConstraintField<N::OuterScalarField> for OuterPublicVariables<N> {
-    fn to_field_elements(&self) -> Result<Vec<N::OuterScalarField>, ConstraintFieldError> {
+    /// Returns the field elements of each public variable, in the order they are allocated.
+    pub(crate) fn to_named_field_elements(
+        &self,
+    ) -> Result<Vec<(&'static str, Vec<N::OuterScalarField>)>, ConstraintFieldError> {
         // In the outer circuit, these two variables must be allocated as witness,
         // as they are not included in the transaction.
         debug_assert!(self.inner_public_variables.program_id.is_none());
@@ -90,15 +91,40 @@ impl<N: Network> ToConstraintField<N::OuterScalarField> for OuterPublicVariables
         // apply the follow a rule:
         //
         // Alloc the original inputs as bits, then pack them into the new field, in little-endian format.
-        for inner_snark_fe in &self.inner_public_variables.to_field_elements()? {
-            v.extend_from_slice(&ToConstraintField::<N::OuterScalarField>::to_field_elements(
-                inner_snark_fe.to_bits_le().as_slice(),
-            )?);
+        for (name, inner_snark_fes) in self.inner_public_variables.to_named_field_elements()? {
+            let mut outer_snark_fes = Vec::new();
+            for inner_snark_fe in &inner_snark_fes {
+                outer_snark_fes.extend_from_slice(&ToConstraintField::<N::OuterScalarField>::to_field_elements(
+                    inner_snark_fe.to_bits_le().as_slice(),
+                )?);
+            }
+            v.push((name, outer_snark_fes));
         }
 
         // Then allocate the inner circuit ID.
-        v.extend_from_slice(&self.inner_circuit_id.to_field_elements()?);
+        v.push(("inner_circuit_id", self.inner_circuit_id.to_field_elements()?));
 
         Ok(v)
     }
+
+    /// Returns the descriptors of the field elements passed to the outer circuit verifier, in order.
+    pub(crate) fn to_public_input_layout(&self) -> Result<Vec<PublicInputDescriptor>, ConstraintFieldError> {
+        Ok(self
+            .to_named_field_elements()?
+            .into_iter()
+            .flat_map(|(name, field_elements)| {
+                (0..field_elements.len()).map(move |index| PublicInputDescriptor { name, index })
+            })
+            .collect())
+    }
+}
+
+impl<N: Network> ToConstraintField<N::OuterScalarField> for OuterPublicVariables<N> {
+    fn to_field_elements(&self) -> Result<Vec<N::OuterScalarField>, ConstraintFieldError> {
+        Ok(self
+            .to_named_field_elements()?
+            .into_iter()
+            .flat_map(|(_, field_elements)| field_elements)
+            .collect())
+    }
 }
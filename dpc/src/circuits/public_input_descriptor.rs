@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use std::fmt;
+
+/// A descriptor of a field element in the public inputs of the outer circuit.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PublicInputDescriptor {
+    /// The name of the public variable that the field element belongs to.
+    pub name: &'static str,
+    /// The index of the field element within the public variable.
+    pub index: usize,
+}
+
+impl fmt::Display for PublicInputDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}[{}]", self.name, self.index)
+    }
+}
@@ -69,6 +69,7 @@ fn dpc_execute_circuits_test<N: Network>(expected_inner_num_constraints: usize,
             .execute(
                 ProgramPublicVariables::new(transition_id),
                 &NoopPrivateVariables::<N>::new_blank().unwrap(),
+                rng,
             )
             .unwrap(),
     };
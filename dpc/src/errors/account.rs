@@ -30,12 +30,24 @@ pub enum AccountError {
     #[error("{}", _0)]
     EncryptionError(#[from] EncryptionError),
 
+    #[error("incorrect passphrase, or the keystore has been modified")]
+    IncorrectPassphrase,
+
     #[error("invalid byte length: {}", _0)]
     InvalidByteLength(usize),
 
     #[error("invalid character length: {}", _0)]
     InvalidCharacterLength(usize),
 
+    #[error("invalid derivation index: {}", _0)]
+    InvalidDerivationIndex(u32),
+
+    #[error("invalid keystore: {}", _0)]
+    InvalidKeystore(String),
+
+    #[error("invalid mnemonic: {}", _0)]
+    InvalidMnemonic(String),
+
     #[error("invalid prefix: {:?}", _0)]
     InvalidPrefix(String),
 
@@ -55,6 +67,24 @@ pub enum AccountError {
     SignatureError(#[from] SignatureError),
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum AddressParseError {
+    #[error("invalid address: the encoded value is not a valid account address")]
+    InvalidAddress,
+
+    #[error("invalid character {:?} at index {}", found, index)]
+    InvalidCharacter { index: usize, found: char },
+
+    #[error("invalid checksum{}", suggestion.as_ref().map(|s| format!(", did you mean {}?", s)).unwrap_or_default())]
+    InvalidChecksum { suggestion: Option<String> },
+
+    #[error("invalid prefix: {:?}", found)]
+    WrongHrp { found: String },
+
+    #[error("invalid character length: {}", found)]
+    WrongLength { found: usize },
+}
+
 impl From<base58::FromBase58Error> for AccountError {
     fn from(error: base58::FromBase58Error) -> Self {
         AccountError::Crate("base58", format!("{:?}", error))
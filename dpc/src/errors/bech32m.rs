@@ -39,6 +39,12 @@ pub enum Bech32mError {
     #[error("invalid variant")]
     InvalidVariant,
 
+    #[error("unexpected prefix {:?}, expected {:?}", _0, _1)]
+    UnexpectedPrefix(String, String),
+
+    #[error("unknown prefix {:?}", _0)]
+    UnknownPrefix(String),
+
     #[error("{}", _0)]
     Message(String),
 }
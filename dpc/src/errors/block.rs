@@ -27,9 +27,15 @@ pub enum BlockError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}", _0)]
+    CompositionError(#[from] crate::CompositionError),
+
     #[error("{}", _0)]
     CRHError(#[from] snarkvm_algorithms::CRHError),
 
+    #[error("Invalid transaction {} found at index {} in the transactions list", _1, _0)]
+    InvalidTransaction(usize, String),
+
     #[error("{}", _0)]
     MerkleError(#[from] snarkvm_algorithms::MerkleError),
 
@@ -0,0 +1,34 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A violation of the rules for composing the transactions of a block.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum CompositionError {
+    #[error("Found duplicate commitment {} in the transactions list", _0)]
+    DuplicateCommitment(String),
+
+    #[error("Found duplicate serial number {} in the transactions list", _0)]
+    DuplicateSerialNumber(String),
+
+    #[error("Found duplicate transition ID {} in the transactions list", _0)]
+    DuplicateTransitionId(String),
+
+    #[error("Cannot process validity checks on an empty transactions list")]
+    EmptyTransactions,
+
+    #[error("Block must have exactly 1 coinbase transaction, found {}", _0)]
+    InvalidCoinbaseCount(usize),
+}
@@ -0,0 +1,43 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// An error when deserializing an object from untrusted bytes.
+#[derive(Debug, Error)]
+pub enum DeserializationError {
+    #[error("Found {} {}, exceeding the maximum of {}", _1, _0, _2)]
+    TooManyItems(&'static str, usize, usize),
+    #[error("Found an invalid {} ID {}", _0, _1)]
+    InvalidId(&'static str, u16),
+    #[error("Found an invalid {}: {}", _0, _1)]
+    InvalidObject(&'static str, String),
+}
+
+impl DeserializationError {
+    /// Returns the given length prefix, or an error if it exceeds the given maximum.
+    /// Call this before allocating for the items that follow the length prefix.
+    pub fn check_length(items: &'static str, length: usize, maximum: usize) -> Result<usize, Self> {
+        match length <= maximum {
+            true => Ok(length),
+            false => Err(Self::TooManyItems(items, length, maximum)),
+        }
+    }
+}
+
+impl From<DeserializationError> for std::io::Error {
+    fn from(error: DeserializationError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
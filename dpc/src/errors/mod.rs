@@ -23,6 +23,12 @@ pub use bech32m::*;
 pub mod block;
 pub use block::*;
 
+pub mod composition;
+pub use composition::*;
+
+pub mod deserialization;
+pub use deserialization::*;
+
 pub mod posw;
 pub use posw::*;
 
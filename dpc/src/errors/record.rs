@@ -42,6 +42,9 @@ pub enum RecordError {
     #[error("Given compute key does not correspond to the record owner")]
     IncorrectComputeKey,
 
+    #[error("Given view key does not correspond to the record owner")]
+    IncorrectViewKey,
+
     #[error("Invalid commitment. Expected {}, found {}", _0, _1)]
     InvalidCommitment(String, String),
 
@@ -14,16 +14,19 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::prelude::*;
+use crate::{prelude::*, ConsensusConstants};
 use snarkvm_algorithms::merkle_tree::*;
 
 use anyhow::{anyhow, Result};
 use chrono::Utc;
-use itertools::Itertools;
-use std::collections::HashMap;
+use itertools::{Either, Itertools};
+use rayon::ThreadPool;
+use std::{collections::HashMap, ops::Range};
 
 #[derive(Clone, Debug)]
 pub struct Blocks<N: Network> {
+    /// The genesis block.
+    genesis_block: Block<N>,
     /// The current block height.
     current_height: u32,
     /// The current block hash.
@@ -36,21 +39,41 @@ pub struct Blocks<N: Network> {
     headers: HashMap<u32, BlockHeader<N>>,
     /// The chain of block transactions.
     transactions: HashMap<u32, Transactions<N>>,
+    /// The serial numbers of the chain, mapped to the block height that spends them.
+    serial_numbers: HashMap<N::SerialNumber, u32>,
+    /// The historical ledger roots of the chain, mapped to the latest block height they include.
+    ledger_roots: HashMap<N::LedgerRoot, u32>,
 }
 
 impl<N: Network> Blocks<N> {
     /// Initializes a new instance of `Blocks` with the genesis block.
     pub fn new() -> Result<Self> {
-        let genesis_block = N::genesis_block();
+        Self::from_genesis(N::genesis_block())
+    }
+
+    /// Initializes a new instance of `Blocks` with the given genesis block, if it is valid.
+    pub fn new_with_genesis(genesis_block: &Block<N>) -> Result<Self> {
+        // Ensure the genesis block is valid.
+        if !genesis_block.is_genesis() || !genesis_block.is_valid() {
+            return Err(anyhow!("The given genesis block is invalid"));
+        }
+        Self::from_genesis(genesis_block)
+    }
+
+    /// Initializes a new instance of `Blocks` with the given genesis block, without checking its validity.
+    fn from_genesis(genesis_block: &Block<N>) -> Result<Self> {
         let height = genesis_block.height();
 
         let mut blocks = Self {
+            genesis_block: genesis_block.clone(),
             current_height: height,
             current_hash: genesis_block.hash(),
             ledger_tree: LedgerTree::<N>::new()?,
             previous_hashes: Default::default(),
             headers: Default::default(),
             transactions: Default::default(),
+            serial_numbers: Default::default(),
+            ledger_roots: Default::default(),
         };
 
         blocks.ledger_tree.add(&genesis_block.hash())?;
@@ -59,6 +82,12 @@ impl<N: Network> Blocks<N> {
             .insert(height, genesis_block.previous_block_hash());
         blocks.headers.insert(height, genesis_block.header().clone());
         blocks.transactions.insert(height, genesis_block.transactions().clone());
+        blocks.serial_numbers.extend(
+            genesis_block
+                .serial_numbers()
+                .map(|serial_number| (*serial_number, height)),
+        );
+        blocks.ledger_roots.insert(blocks.ledger_tree.root(), height);
 
         Ok(blocks)
     }
@@ -130,7 +159,7 @@ impl<N: Network> Blocks<N> {
     /// Returns the block given the block height.
     pub fn get_block(&self, height: u32) -> Result<Block<N>> {
         match height == 0 {
-            true => Ok(N::genesis_block().clone()),
+            true => Ok(self.genesis_block.clone()),
             false => Ok(Block::from(
                 self.get_previous_block_hash(height)?,
                 self.get_block_header(height)?.clone(),
@@ -139,6 +168,20 @@ impl<N: Network> Blocks<N> {
         }
     }
 
+    /// Returns an iterator over the blocks in the given range of block heights, in order.
+    /// Each block is only constructed once the iterator reaches it.
+    pub fn blocks_iter(&self, heights: Range<u32>) -> impl Iterator<Item = Result<Block<N>>> + '_ {
+        heights.map(move |height| self.get_block(height))
+    }
+
+    /// Returns an iterator over the transactions of the blocks in the given range of block heights, in order.
+    pub fn transactions_iter(&self, heights: Range<u32>) -> impl Iterator<Item = Result<Transaction<N>>> + '_ {
+        heights.flat_map(move |height| match self.get_block_transactions(height) {
+            Ok(transactions) => Either::Left(transactions.iter().cloned().map(Ok)),
+            Err(error) => Either::Right(std::iter::once(Err(error))),
+        })
+    }
+
     /// Returns the block hash given the block height.
     pub fn get_block_hash(&self, height: u32) -> Result<N::BlockHash> {
         if height > self.current_height {
@@ -163,12 +206,12 @@ impl<N: Network> Blocks<N> {
 
     /// Returns `true` if the given ledger root exists.
     pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
-        *ledger_root == self.latest_ledger_root()
-            || self
-                .headers
-                .values()
-                .map(BlockHeader::previous_ledger_root)
-                .any(|root| root == *ledger_root)
+        self.ledger_roots.contains_key(ledger_root)
+    }
+
+    /// Returns the latest block height included in the given ledger root, if it exists.
+    pub fn get_ledger_root_height(&self, ledger_root: &N::LedgerRoot) -> Option<u32> {
+        self.ledger_roots.get(ledger_root).copied()
     }
 
     /// Returns `true` if the given block hash exists.
@@ -186,10 +229,15 @@ impl<N: Network> Blocks<N> {
 
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
-        self.transactions
-            .values()
-            .flat_map(|transactions| (**transactions).iter().map(Transaction::serial_numbers))
-            .any(|mut serial_numbers| serial_numbers.contains(serial_number))
+        self.serial_numbers.contains_key(serial_number)
+    }
+
+    /// Returns `true` for each of the given serial numbers that exists, in the given order.
+    pub fn find_spent_serial_numbers(&self, serial_numbers: &[N::SerialNumber]) -> Vec<bool> {
+        serial_numbers
+            .iter()
+            .map(|serial_number| self.contains_serial_number(serial_number))
+            .collect()
     }
 
     /// Returns `true` if the given commitment exists.
@@ -200,11 +248,12 @@ impl<N: Network> Blocks<N> {
             .any(|mut commitments| commitments.contains(commitment))
     }
 
-    /// Adds the given block as the next block in the chain.
-    pub fn add_next(&mut self, block: &Block<N>) -> Result<()> {
+    /// Adds the given block as the next block in the chain, verifying its transactions
+    /// on the given thread pool, or on the global thread pool if `None`.
+    pub fn add_next(&mut self, block: &Block<N>, verification_pool: Option<&ThreadPool>) -> Result<()> {
         // Ensure the block itself is valid.
-        if !block.is_valid() {
-            return Err(anyhow!("The given block is invalid"));
+        if let Err(error) = block.check_valid(verification_pool) {
+            return Err(anyhow!("The given block is invalid: {}", error));
         }
 
         // Ensure the next block height is correct.
@@ -231,7 +280,7 @@ impl<N: Network> Blocks<N> {
 
         // Ensure the next block timestamp is within the declared time limit.
         let now = Utc::now().timestamp();
-        if block.timestamp() > (now + N::ALEO_FUTURE_TIME_LIMIT_IN_SECS) {
+        if block.timestamp() > (now + N::consensus_constants().future_time_limit_in_secs) {
             return Err(anyhow!("The given block timestamp exceeds the time limit"));
         }
 
@@ -242,15 +291,11 @@ impl<N: Network> Blocks<N> {
         }
 
         // Ensure the expected difficulty target is met.
-        let expected_difficulty_target =
-            if N::NETWORK_ID == 2 && block.height() <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-                Blocks::<N>::compute_difficulty_target(current_block.header(), block.timestamp(), block.height())
-            } else if N::NETWORK_ID == 2 {
-                let anchor_block_header = self.get_block_header(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT)?;
-                Blocks::<N>::compute_difficulty_target(anchor_block_header, block.timestamp(), block.height())
-            } else {
-                Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block.timestamp(), block.height())
-            };
+        let expected_difficulty_target = Blocks::<N>::compute_difficulty_target(
+            self.get_difficulty_anchor_header(block.height())?,
+            block.timestamp(),
+            block.height(),
+        );
         if block.difficulty_target() != expected_difficulty_target {
             return Err(anyhow!(
                 "The given block difficulty target is incorrect. Found {}, but expected {}",
@@ -309,6 +354,10 @@ impl<N: Network> Blocks<N> {
             blocks.previous_hashes.insert(height, block.previous_block_hash());
             blocks.headers.insert(height, block.header().clone());
             blocks.transactions.insert(height, block.transactions().clone());
+            blocks
+                .serial_numbers
+                .extend(block.serial_numbers().map(|serial_number| (*serial_number, height)));
+            blocks.ledger_roots.insert(blocks.ledger_tree.root(), height);
 
             *self = blocks;
         }
@@ -342,14 +391,7 @@ impl<N: Network> Blocks<N> {
             .collect::<Vec<_>>();
         assert_eq!(1, transaction.len()); // TODO (howardwu): Clean this up with a proper error handler.
         let transaction = transaction[0];
-        let local_proof = {
-            // Initialize a transitions tree.
-            let mut transitions_tree = Transitions::<N>::new()?;
-            // Add all given transition IDs to the tree.
-            transitions_tree.add_all(transaction.transitions())?;
-            // Return the local proof for the transitions tree.
-            transitions_tree.to_local_proof(commitment)?
-        };
+        let local_proof = transaction.to_local_proof(commitment)?;
         let transaction_id = local_proof.transaction_id();
 
         // TODO (howardwu): Optimize this operation.
@@ -406,27 +448,75 @@ impl<N: Network> Blocks<N> {
         LedgerProof::new(ledger_root, ledger_root_inclusion_proof, record_proof)
     }
 
+    /// Returns the header of the anchor block of the difficulty target of the next block at the given height.
+    pub fn get_difficulty_anchor_header(&self, block_height: u32) -> Result<&BlockHeader<N>> {
+        match N::consensus_constants().legacy_retarget_height {
+            Some(height) if block_height <= height => self.get_block_header(self.current_height),
+            Some(height) => self.get_block_header(height),
+            None => self.get_block_header(0),
+        }
+    }
+
     /// Returns the expected difficulty target given the previous block and expected next block details.
     pub fn compute_difficulty_target(
         anchor_block_header: &BlockHeader<N>,
         block_timestamp: i64,
         block_height: u32,
     ) -> u64 {
-        if N::NETWORK_ID == 2 && block_height <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
+        Self::retarget(
+            anchor_block_header.timestamp(),
+            anchor_block_header.difficulty_target(),
+            anchor_block_header.height(),
+            block_timestamp,
+            block_height,
+        )
+    }
+
+    /// Returns the expected difficulty target of the block at the given height and timestamp,
+    /// given the timestamp, difficulty target, and height of its anchor block.
+    /// This is the retarget rule of `Blocks::compute_difficulty_target`, as a pure function.
+    pub fn retarget(
+        anchor_timestamp: i64,
+        anchor_difficulty_target: u64,
+        anchor_block_height: u32,
+        block_timestamp: i64,
+        block_height: u32,
+    ) -> u64 {
+        Self::retarget_with(
+            &N::consensus_constants(),
+            anchor_timestamp,
+            anchor_difficulty_target,
+            anchor_block_height,
+            block_timestamp,
+            block_height,
+        )
+    }
+
+    /// Returns the expected difficulty target of the block at the given height and timestamp,
+    /// under the given consensus constants.
+    pub fn retarget_with(
+        constants: &ConsensusConstants,
+        anchor_timestamp: i64,
+        anchor_difficulty_target: u64,
+        anchor_block_height: u32,
+        block_timestamp: i64,
+        block_height: u32,
+    ) -> u64 {
+        if constants.is_legacy_retarget(block_height) {
             Self::bitcoin_retarget(
-                anchor_block_header.timestamp(),
-                anchor_block_header.difficulty_target(),
+                anchor_timestamp,
+                anchor_difficulty_target,
                 block_timestamp,
-                N::ALEO_BLOCK_TIME_IN_SECS,
+                constants.block_time_in_secs,
             )
         } else {
             Self::asert_retarget(
-                anchor_block_header.timestamp(),
-                anchor_block_header.difficulty_target(),
-                anchor_block_header.height(),
+                anchor_timestamp,
+                anchor_difficulty_target,
+                anchor_block_height,
                 block_timestamp,
                 block_height,
-                N::ALEO_BLOCK_TIME_IN_SECS,
+                constants.block_time_in_secs,
             )
         }
     }
@@ -436,7 +526,7 @@ impl<N: Network> Blocks<N> {
     ///     M = Number of blocks per retarget.
     ///     B = Expected time per block.
     ///     S = Time elapsed between the last M blocks.
-    fn bitcoin_retarget(
+    pub fn bitcoin_retarget(
         previous_timestamp: i64,
         previous_difficulty: u64,
         block_timestamp: i64,
@@ -469,7 +559,7 @@ impl<N: Network> Blocks<N> {
     ///     tau = The half life of the algorithm. For every `tau` seconds ahead of
     ///           schedule a block’s timestamp becomes, the difficulty doubles.
     /// To avoid use of floating points, we use fixed-point arithmetic.
-    fn asert_retarget(
+    pub fn asert_retarget(
         anchor_timestamp: i64,
         anchor_difficulty_target: u64,
         anchor_block_height: u32,
@@ -0,0 +1,261 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+/// The keys that a transaction claims exclusively on the ledger.
+/// Two transactions conflict if any of their keys overlap.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ConflictKeys<N: Network> {
+    /// The ID of the transaction.
+    pub transaction_id: N::TransactionID,
+    /// The serial numbers of the input records, across all transitions.
+    pub serial_numbers: Vec<N::SerialNumber>,
+    /// The commitments of the output records, across all transitions.
+    pub commitments: Vec<N::Commitment>,
+}
+
+impl<N: Network> ConflictKeys<N> {
+    /// Returns the conflict keys of the given transaction.
+    pub fn new(transaction: &Transaction<N>) -> Self {
+        Self {
+            transaction_id: transaction.transaction_id(),
+            serial_numbers: transaction.serial_numbers().copied().collect(),
+            commitments: transaction.commitments().copied().collect(),
+        }
+    }
+}
+
+/// A conflict between a candidate transaction and an indexed transaction.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub enum ConflictWith<N: Network> {
+    /// The transaction ID is already indexed.
+    Transaction(N::TransactionID),
+    /// The serial number is already spent by the given transaction ID.
+    SerialNumber(N::SerialNumber, N::TransactionID),
+    /// The commitment is already produced by the given transaction ID.
+    Commitment(N::Commitment, N::TransactionID),
+}
+
+/// An index over the conflict keys of a set of transactions, used to reject
+/// double-spends without consulting the ledger.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    Default(bound = "N: Network")
+)]
+pub struct ConflictIndex<N: Network> {
+    /// The conflict keys of each indexed transaction.
+    transactions: HashMap<N::TransactionID, ConflictKeys<N>>,
+    /// The indexed serial numbers, mapped to the transaction that spends them.
+    serial_numbers: HashMap<N::SerialNumber, N::TransactionID>,
+    /// The indexed commitments, mapped to the transaction that produces them.
+    commitments: HashMap<N::Commitment, N::TransactionID>,
+}
+
+impl<N: Network> ConflictIndex<N> {
+    /// Initializes a new instance of a conflict index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of indexed transactions.
+    pub fn len(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns `true` if there are no indexed transactions.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    /// Returns `true` if the given transaction ID is indexed.
+    pub fn contains_transaction_id(&self, transaction_id: &N::TransactionID) -> bool {
+        self.transactions.contains_key(transaction_id)
+    }
+
+    /// Returns `true` if the given serial number is indexed.
+    pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
+        self.serial_numbers.contains_key(serial_number)
+    }
+
+    /// Returns `true` if the given commitment is indexed.
+    pub fn contains_commitment(&self, commitment: &N::Commitment) -> bool {
+        self.commitments.contains_key(commitment)
+    }
+
+    /// Returns the conflicts between the given transaction and the indexed transactions,
+    /// ordered by transaction ID, then serial numbers, then commitments.
+    pub fn check(&self, transaction: &Transaction<N>) -> Vec<ConflictWith<N>> {
+        self.check_keys(&transaction.conflict_keys())
+    }
+
+    /// Returns the conflicts between the given conflict keys and the indexed transactions,
+    /// ordered by transaction ID, then serial numbers, then commitments.
+    pub fn check_keys(&self, keys: &ConflictKeys<N>) -> Vec<ConflictWith<N>> {
+        let mut conflicts = Vec::new();
+
+        if self.transactions.contains_key(&keys.transaction_id) {
+            conflicts.push(ConflictWith::Transaction(keys.transaction_id));
+        }
+        for serial_number in &keys.serial_numbers {
+            if let Some(transaction_id) = self.serial_numbers.get(serial_number) {
+                conflicts.push(ConflictWith::SerialNumber(*serial_number, *transaction_id));
+            }
+        }
+        for commitment in &keys.commitments {
+            if let Some(transaction_id) = self.commitments.get(commitment) {
+                conflicts.push(ConflictWith::Commitment(*commitment, *transaction_id));
+            }
+        }
+
+        conflicts
+    }
+
+    /// Indexes the given transaction, if it does not conflict with any indexed transaction.
+    pub fn insert(&mut self, transaction: &Transaction<N>) -> Result<()> {
+        let keys = transaction.conflict_keys();
+
+        // Ensure the transaction does not conflict with an indexed transaction.
+        if let Some(conflict) = self.check_keys(&keys).first() {
+            return Err(anyhow!(
+                "Transaction {} conflicts with {:?}",
+                keys.transaction_id,
+                conflict
+            ));
+        }
+
+        for serial_number in &keys.serial_numbers {
+            self.serial_numbers.insert(*serial_number, keys.transaction_id);
+        }
+        for commitment in &keys.commitments {
+            self.commitments.insert(*commitment, keys.transaction_id);
+        }
+        self.transactions.insert(keys.transaction_id, keys);
+
+        Ok(())
+    }
+
+    /// Removes the given transaction ID from the index, returning its conflict keys if it was indexed.
+    pub fn remove(&mut self, transaction_id: &N::TransactionID) -> Option<ConflictKeys<N>> {
+        let keys = self.transactions.remove(transaction_id)?;
+        for serial_number in &keys.serial_numbers {
+            self.serial_numbers.remove(serial_number);
+        }
+        for commitment in &keys.commitments {
+            self.commitments.remove(commitment);
+        }
+        Some(keys)
+    }
+
+    /// Removes all transactions from the index.
+    pub fn clear(&mut self) {
+        self.transactions.clear();
+        self.serial_numbers.clear();
+        self.commitments.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    #[test]
+    fn test_conflict_keys() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let keys = transaction.conflict_keys();
+
+        assert_eq!(transaction.transaction_id(), keys.transaction_id);
+        assert_eq!(transaction.serial_numbers().count(), keys.serial_numbers.len());
+        assert_eq!(transaction.commitments().count(), keys.commitments.len());
+    }
+
+    #[test]
+    fn test_double_spend() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transaction_id = transaction.transaction_id();
+
+        let mut index = ConflictIndex::<Testnet2>::new();
+        assert!(index.check(&transaction).is_empty());
+        index.insert(&transaction).unwrap();
+        assert_eq!(1, index.len());
+
+        // Every key of the transaction should now be reported as a conflict.
+        let conflicts = index.check(&transaction);
+        assert_eq!(
+            1 + transaction.serial_numbers().count() + transaction.commitments().count(),
+            conflicts.len()
+        );
+        assert_eq!(ConflictWith::Transaction(transaction_id), conflicts[0]);
+        for serial_number in transaction.serial_numbers() {
+            assert!(conflicts.contains(&ConflictWith::SerialNumber(*serial_number, transaction_id)));
+        }
+        for commitment in transaction.commitments() {
+            assert!(conflicts.contains(&ConflictWith::Commitment(*commitment, transaction_id)));
+        }
+
+        // A transaction that reuses a single serial number is a double-spend.
+        let mut keys = transaction.conflict_keys();
+        keys.transaction_id = Default::default();
+        keys.commitments.clear();
+        keys.serial_numbers.truncate(1);
+        assert_eq!(
+            vec![ConflictWith::SerialNumber(keys.serial_numbers[0], transaction_id)],
+            index.check_keys(&keys)
+        );
+
+        // Inserting the conflicting transaction again must fail.
+        assert!(index.insert(&transaction).is_err());
+        assert_eq!(1, index.len());
+    }
+
+    #[test]
+    fn test_remove() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transaction_id = transaction.transaction_id();
+
+        let mut index = ConflictIndex::<Testnet2>::new();
+        index.insert(&transaction).unwrap();
+        assert_eq!(Some(transaction.conflict_keys()), index.remove(&transaction_id));
+        assert_eq!(None, index.remove(&transaction_id));
+
+        assert!(index.is_empty());
+        assert!(index.check(&transaction).is_empty());
+        for serial_number in transaction.serial_numbers() {
+            assert!(!index.contains_serial_number(serial_number));
+        }
+        for commitment in transaction.commitments() {
+            assert!(!index.contains_commitment(commitment));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Blocks, ConsensusConstants, Network};
+
+use std::marker::PhantomData;
+
+/// The height, timestamp, and difficulty target of a simulated block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedBlock {
+    pub height: u32,
+    pub timestamp: i64,
+    pub difficulty_target: u64,
+}
+
+/// Simulates the difficulty target of consecutive blocks over a series of block timestamps,
+/// using the retarget rule of block validation.
+#[derive(Copy, Clone, Debug)]
+pub struct DifficultySimulator<N: Network> {
+    /// The anchor block of the ASERT retarget rule.
+    anchor: SimulatedBlock,
+    /// The latest simulated block.
+    latest: SimulatedBlock,
+    /// The consensus constants of the simulation.
+    constants: ConsensusConstants,
+    _network: PhantomData<N>,
+}
+
+impl<N: Network> DifficultySimulator<N> {
+    /// Initializes a new simulator, starting after the given anchor block.
+    pub fn new(anchor: SimulatedBlock) -> Self {
+        Self::new_from(anchor, anchor)
+    }
+
+    /// Initializes a new simulator, starting after the given latest block, with the given anchor block.
+    pub fn new_from(anchor: SimulatedBlock, latest: SimulatedBlock) -> Self {
+        Self {
+            anchor,
+            latest,
+            constants: N::consensus_constants(),
+            _network: PhantomData,
+        }
+    }
+
+    /// Returns the simulator, with the given consensus constants in place of those of the network.
+    pub fn with_constants(mut self, constants: ConsensusConstants) -> Self {
+        self.constants = constants;
+        self
+    }
+
+    /// Returns the latest simulated block.
+    pub fn latest(&self) -> SimulatedBlock {
+        self.latest
+    }
+
+    /// Returns the next block, found at the given timestamp.
+    pub fn next_block(&mut self, timestamp: i64) -> SimulatedBlock {
+        let height = self.latest.height + 1;
+
+        // Select the anchor block as `Ledger::mine_next_block` does.
+        let anchor = match self.constants.is_legacy_retarget(height) {
+            true => self.latest,
+            false => self.anchor,
+        };
+
+        let block = SimulatedBlock {
+            height,
+            timestamp,
+            difficulty_target: Blocks::<N>::retarget_with(
+                &self.constants,
+                anchor.timestamp,
+                anchor.difficulty_target,
+                anchor.height,
+                timestamp,
+                height,
+            ),
+        };
+
+        // The last block retargeted against its previous block is the anchor of every later block.
+        if self.constants.legacy_retarget_height == Some(height) {
+            self.anchor = block;
+        }
+        self.latest = block;
+
+        block
+    }
+
+    /// Returns the blocks found at the given timestamps, in order.
+    pub fn simulate<I: IntoIterator<Item = i64>>(&mut self, timestamps: I) -> Vec<SimulatedBlock> {
+        timestamps
+            .into_iter()
+            .map(|timestamp| self.next_block(timestamp))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, testnet2::Testnet2};
+
+    const ANCHOR: SimulatedBlock = SimulatedBlock {
+        height: 72154,
+        timestamp: 1640179531,
+        difficulty_target: 101336179232188,
+    };
+
+    #[test]
+    fn test_on_schedule() {
+        // Check blocks found exactly on schedule keep the difficulty target.
+        let mut simulator = DifficultySimulator::<Testnet1>::new(ANCHOR);
+        let timestamps = (1..=100).map(|i| ANCHOR.timestamp + i * Testnet1::ALEO_BLOCK_TIME_IN_SECS);
+        for block in simulator.simulate(timestamps) {
+            assert_eq!(ANCHOR.difficulty_target, block.difficulty_target);
+        }
+        assert_eq!(ANCHOR.height + 100, simulator.latest().height);
+    }
+
+    #[test]
+    fn test_matches_retarget() {
+        // Check each block matches the retarget rule against the anchor.
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR);
+        let mut timestamp = ANCHOR.timestamp;
+        for i in 0..100 {
+            timestamp += 5 + (i * 7) % 40;
+            let block = simulator.next_block(timestamp);
+            let expected = Blocks::<Testnet2>::asert_retarget(
+                ANCHOR.timestamp,
+                ANCHOR.difficulty_target,
+                ANCHOR.height,
+                timestamp,
+                block.height,
+                Testnet2::ALEO_BLOCK_TIME_IN_SECS,
+            );
+            assert_eq!(expected, block.difficulty_target);
+        }
+    }
+
+    #[test]
+    fn test_known_sequence() {
+        // Simulate 10 blocks found every 10 seconds, then 10 blocks found every 30 seconds.
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR);
+        let timestamps = (1..=10)
+            .map(|i| ANCHOR.timestamp + 10 * i)
+            .chain((1..=10).map(|i| ANCHOR.timestamp + 100 + 30 * i))
+            .collect::<Vec<_>>();
+        let targets = simulator
+            .simulate(timestamps)
+            .iter()
+            .map(|block| block.difficulty_target)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                101325355360504,
+                101315304622513,
+                101304480750830,
+                101293656879147,
+                101282833007464,
+                101272009135780,
+                101261185264097,
+                101251134526106,
+                101238764387039,
+                101228713649048,
+                101238764387039,
+                101251134526106,
+                101261185264097,
+                101272009135780,
+                101282833007464,
+                101293656879147,
+                101304480750830,
+                101315304622513,
+                101325355360504,
+                101336179232188,
+            ],
+            targets
+        );
+        // The last block is back on schedule, so its difficulty target is the anchor difficulty target.
+        assert_eq!(ANCHOR.difficulty_target, *targets.last().unwrap());
+    }
+
+    #[test]
+    fn test_extreme_timestamps() {
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR);
+
+        // Check a timestamp before the anchor is treated as one second after the anchor.
+        let block = simulator.next_block(ANCHOR.timestamp - 1_000_000);
+        let expected = DifficultySimulator::<Testnet2>::new(ANCHOR).next_block(ANCHOR.timestamp + 1);
+        assert_eq!(expected.difficulty_target, block.difficulty_target);
+
+        // Check blocks far ahead of schedule clamp the difficulty target to 1.
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR);
+        let timestamps = (0..200_000).map(|_| ANCHOR.timestamp);
+        assert_eq!(1, simulator.simulate(timestamps).last().unwrap().difficulty_target);
+
+        // Check a block far behind schedule clamps the difficulty target to `u64::MAX`.
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR);
+        assert_eq!(u64::MAX, simulator.next_block(i64::MAX).difficulty_target);
+        assert_eq!(u64::MAX, simulator.next_block(i64::MAX).difficulty_target);
+    }
+}
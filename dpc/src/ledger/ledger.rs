@@ -15,11 +15,27 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::prelude::*;
+use snarkvm_utilities::ToBytes;
 
 use anyhow::{anyhow, Result};
 use chrono::Utc;
 use rand::{CryptoRng, Rng};
-use std::{collections::HashMap, sync::atomic::AtomicBool};
+use rayon::{ThreadPool, ThreadPoolBuilder};
+use std::{
+    collections::HashMap,
+    io::Write,
+    ops::Range,
+    sync::{atomic::AtomicBool, Arc},
+};
+
+/// The format of the blocks written by `Ledger::export_range`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Each block is written as its canonical bytes, prefixed by their length as a little-endian `u32`.
+    Bytes,
+    /// Each block is written as a single line of JSON.
+    Json,
+}
 
 #[derive(Clone, Debug)]
 pub struct Ledger<N: Network> {
@@ -29,6 +45,8 @@ pub struct Ledger<N: Network> {
     orphan_blocks: HashMap<u32, Block<N>>,
     /// The pool of unconfirmed transactions.
     memory_pool: MemoryPool<N>,
+    /// The thread pool used to verify the transactions of a block, or `None` to use the global thread pool.
+    verification_pool: Option<Arc<ThreadPool>>,
 }
 
 impl<N: Network> Ledger<N> {
@@ -38,9 +56,30 @@ impl<N: Network> Ledger<N> {
             canon_blocks: Blocks::new()?,
             orphan_blocks: Default::default(),
             memory_pool: MemoryPool::new(),
+            verification_pool: None,
         })
     }
 
+    /// Initializes a new instance of the ledger with the given genesis block, such as for a devnet.
+    pub fn new_with_genesis(genesis_block: &Block<N>) -> Result<Self> {
+        Ok(Self {
+            canon_blocks: Blocks::new_with_genesis(genesis_block)?,
+            orphan_blocks: Default::default(),
+            memory_pool: MemoryPool::new(),
+            verification_pool: None,
+        })
+    }
+
+    /// Sets the maximum number of threads used to verify the transactions of a block,
+    /// independently of the threads used for proving. If `0`, the global thread pool is used.
+    pub fn set_max_verification_threads(&mut self, num_threads: usize) -> Result<()> {
+        self.verification_pool = match num_threads {
+            0 => None,
+            num_threads => Some(Arc::new(ThreadPoolBuilder::new().num_threads(num_threads).build()?)),
+        };
+        Ok(())
+    }
+
     /// Returns the latest block height.
     pub fn latest_block_height(&self) -> u32 {
         self.canon_blocks.latest_block_height()
@@ -81,11 +120,119 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.latest_block()
     }
 
+    /// Returns an iterator over the canon blocks in the given range of block heights, in order.
+    ///
+    /// Blocks are constructed lazily, one at a time. As the iterator borrows the ledger,
+    /// no block can be added during the iteration, so the iterator reflects a consistent snapshot.
+    pub fn blocks_iter(&self, heights: Range<u32>) -> impl Iterator<Item = Result<Block<N>>> + '_ {
+        self.canon_blocks.blocks_iter(heights)
+    }
+
+    /// Returns an iterator over the transactions of the canon blocks in the given range of block heights, in order.
+    ///
+    /// As the iterator borrows the ledger, no block can be added during the iteration.
+    pub fn transactions_iter(&self, heights: Range<u32>) -> impl Iterator<Item = Result<Transaction<N>>> + '_ {
+        self.canon_blocks.transactions_iter(heights)
+    }
+
+    ///
+    /// Writes the canon blocks in the given range of block heights to the given writer, in the given format,
+    /// and returns the number of blocks written.
+    ///
+    /// Blocks are written one at a time, so at most one block is held in memory.
+    ///
+    pub fn export_range<W: Write>(&self, heights: Range<u32>, mut writer: W, format: ExportFormat) -> Result<u32> {
+        let mut num_blocks = 0;
+        for block in self.blocks_iter(heights) {
+            let block = block?;
+            match format {
+                ExportFormat::Bytes => {
+                    let bytes = block.to_bytes_le()?;
+                    (bytes.len() as u32).write_le(&mut writer)?;
+                    writer.write_all(&bytes)?;
+                }
+                ExportFormat::Json => {
+                    serde_json::to_writer(&mut writer, &block)?;
+                    writer.write_all(b"\n")?;
+                }
+            }
+            num_blocks += 1;
+        }
+        writer.flush()?;
+
+        Ok(num_blocks)
+    }
+
+    ///
+    /// Returns a report for each ciphertext randomizer that is used by more than one output record
+    /// in the canon blocks in the given range of block heights, in the order they first appear.
+    ///
+    pub fn scan_for_randomizer_reuse(&self, heights: Range<u32>) -> Result<Vec<ReuseReport<N>>> {
+        let mut scanner = RandomizerReuseScanner::default();
+        for block in self.blocks_iter(heights) {
+            let block = block?;
+            for transaction in block.transactions().iter() {
+                for transition in transaction.transitions() {
+                    scanner.add_transition(block.height(), transition);
+                }
+            }
+        }
+
+        Ok(scanner.to_reports())
+    }
+
+    ///
+    /// Returns the data of the custom events emitted by each transaction in the canon blocks
+    /// in the given range of block heights, in order, skipping transactions without custom events.
+    ///
+    pub fn custom_events(&self, heights: Range<u32>) -> Result<Vec<(N::TransactionID, Vec<Vec<u8>>)>> {
+        let mut custom_events = Vec::new();
+        for transaction in self.transactions_iter(heights) {
+            let transaction = transaction?;
+            let events: Vec<_> = transaction.custom_events().cloned().collect();
+            if !events.is_empty() {
+                custom_events.push((transaction.transaction_id(), events));
+            }
+        }
+
+        Ok(custom_events)
+    }
+
+    ///
+    /// Returns the mining statistics of each epoch of `epoch_length` block heights, over the canon blocks
+    /// in the given range of block heights. Epochs cut by the range boundaries only cover the heights in the range.
+    ///
+    pub fn mining_statistics(&self, heights: Range<u32>, epoch_length: u32) -> Result<Vec<EpochStatistics<N>>> {
+        self.mining_statistics_iter(heights, epoch_length)?.collect()
+    }
+
+    ///
+    /// Returns an iterator over the mining statistics of each epoch of `epoch_length` block heights,
+    /// over the canon blocks in the given range of block heights.
+    ///
+    /// Blocks are read one at a time, so at most one block is held in memory.
+    ///
+    pub fn mining_statistics_iter(
+        &self,
+        heights: Range<u32>,
+        epoch_length: u32,
+    ) -> Result<impl Iterator<Item = Result<EpochStatistics<N>>> + '_> {
+        if epoch_length == 0 {
+            return Err(anyhow!("The epoch length must be positive"));
+        }
+        Ok(EpochStatisticsIter::new(self.blocks_iter(heights), epoch_length))
+    }
+
     /// Returns `true` if the given ledger root exists on the canon chain.
     pub fn contains_ledger_root(&self, ledger_root: &N::LedgerRoot) -> bool {
         self.canon_blocks.contains_ledger_root(ledger_root)
     }
 
+    /// Returns the latest block height included in the given ledger root, if it exists on the canon chain.
+    pub fn get_ledger_root_height(&self, ledger_root: &N::LedgerRoot) -> Option<u32> {
+        self.canon_blocks.get_ledger_root_height(ledger_root)
+    }
+
     /// Returns `true` if the given block hash exists on the canon chain.
     pub fn contains_block_hash(&self, block_hash: &N::BlockHash) -> bool {
         self.canon_blocks.contains_block_hash(block_hash)
@@ -96,11 +243,121 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.contains_transaction(transaction)
     }
 
+    /// Returns `true` if the given serial number exists on the canon chain.
+    pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
+        self.canon_blocks.contains_serial_number(serial_number)
+    }
+
+    /// Returns `true` for each of the given serial numbers that is spent on the canon chain, in the given order.
+    /// Combined with `ComputeKey::to_serial_numbers`, this detects spends without the view key.
+    pub fn find_spent_serial_numbers(&self, serial_numbers: &[N::SerialNumber]) -> Vec<bool> {
+        self.canon_blocks.find_spent_serial_numbers(serial_numbers)
+    }
+
+    /// Returns the latest block height included in the ledger root of the given transaction,
+    /// if the transaction is valid and its ledger root is a historical ledger root of the canon chain.
+    /// Unlike `add_unconfirmed_transaction`, this does not check whether its serial numbers are spent.
+    pub fn verify_transaction_historical(&self, transaction: &Transaction<N>) -> Result<u32> {
+        // Ensure the transaction references a ledger root from the canon chain.
+        let ledger_root = transaction.ledger_root();
+        let height = match self.canon_blocks.get_ledger_root_height(&ledger_root) {
+            Some(height) => height,
+            None => {
+                return Err(anyhow!(
+                    "Transaction references a non-existent ledger root {}",
+                    ledger_root
+                ));
+            }
+        };
+
+        // Ensure the transaction is valid.
+        if !transaction.is_valid() {
+            return Err(anyhow!("Transaction is invalid"));
+        }
+
+        Ok(height)
+    }
+
+    ///
+    /// Returns the result of each check that the network performs before accepting the given
+    /// transaction into the memory pool, without modifying the ledger.
+    ///
+    /// Every check is performed, even if an earlier check fails.
+    ///
+    pub fn preflight(&self, transaction: &Transaction<N>) -> PreflightReport<N> {
+        // Ensure the transaction is well-formed.
+        let structure = transaction
+            .check_well_formed()
+            .map_err(|error| PreflightFailure::Malformed(error.to_string()));
+
+        // Ensure every transition proof is valid.
+        let proofs = match Transaction::find_invalid_transition_proofs(std::slice::from_ref(transaction)) {
+            Ok(transition_ids) if transition_ids.is_empty() => Ok(()),
+            Ok(transition_ids) => Err(PreflightFailure::InvalidProofs(transition_ids)),
+            Err(error) => Err(PreflightFailure::Malformed(error.to_string())),
+        };
+
+        // Ensure the transaction does not attempt to mint new value.
+        let value_balance = transaction.value_balance();
+        let fee = match value_balance.is_negative() {
+            true => Err(PreflightFailure::MintsValue(AleoAmount::ZERO.sub(value_balance))),
+            false => Ok(()),
+        };
+
+        // Ensure the transaction references a ledger root from the canon chain.
+        let ledger_root = match self.canon_blocks.contains_ledger_root(&transaction.ledger_root()) {
+            true => Ok(()),
+            false => Err(PreflightFailure::UnknownLedgerRoot(transaction.ledger_root())),
+        };
+
+        // Ensure the transaction does not contain serial numbers already in the canon chain.
+        let spent_serial_numbers = transaction
+            .serial_numbers()
+            .filter(|serial_number| self.canon_blocks.contains_serial_number(serial_number))
+            .copied()
+            .collect::<Vec<_>>();
+        let double_spend = match spent_serial_numbers.is_empty() {
+            true => Ok(()),
+            false => Err(PreflightFailure::SpentSerialNumbers(spent_serial_numbers)),
+        };
+
+        // Ensure the transaction and its commitments do not already exist in the canon chain.
+        let existing_commitments = transaction
+            .commitments()
+            .filter(|commitment| self.canon_blocks.contains_commitment(commitment))
+            .copied()
+            .collect::<Vec<_>>();
+        let collision = if self.canon_blocks.contains_transaction(transaction) {
+            Err(PreflightFailure::ExistingTransaction(transaction.transaction_id()))
+        } else if !existing_commitments.is_empty() {
+            Err(PreflightFailure::ExistingCommitments(existing_commitments))
+        } else {
+            Ok(())
+        };
+
+        // Ensure the transaction does not conflict with the memory pool.
+        let conflicts = self.memory_pool.conflicts(transaction);
+        let memory_pool = match conflicts.is_empty() {
+            true => Ok(()),
+            false => Err(PreflightFailure::MemoryPoolConflicts(conflicts)),
+        };
+
+        PreflightReport {
+            structure,
+            proofs,
+            fee,
+            ledger_root,
+            double_spend,
+            collision,
+            memory_pool,
+        }
+    }
+
     /// Adds the given canon block, if it is well-formed and does not already exist.
     /// Note: This method requires blocks to be added in order of canon block height.
     pub fn add_next_block(&mut self, block: &Block<N>) -> Result<()> {
         // Attempt to insert the block into canon.
-        self.canon_blocks.add_next(block)?;
+        self.canon_blocks.add_next(block, self.verification_pool.as_deref())?;
 
         Ok(())
     }
@@ -161,16 +418,11 @@ impl<N: Network> Ledger<N> {
         let block_timestamp = std::cmp::max(Utc::now().timestamp(), self.latest_block_timestamp()?.saturating_add(1));
 
         // Compute the block difficulty target.
-        let difficulty_target = if N::NETWORK_ID == 2 && block_height <= crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT {
-            Blocks::<N>::compute_difficulty_target(self.latest_block()?.header(), block_timestamp, block_height)
-        } else if N::NETWORK_ID == 2 {
-            let anchor_block_header = self
-                .canon_blocks
-                .get_block_header(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT)?;
-            Blocks::<N>::compute_difficulty_target(anchor_block_header, block_timestamp, block_height)
-        } else {
-            Blocks::<N>::compute_difficulty_target(N::genesis_block().header(), block_timestamp, block_height)
-        };
+        let difficulty_target = Blocks::<N>::compute_difficulty_target(
+            self.canon_blocks.get_difficulty_anchor_header(block_height)?,
+            block_timestamp,
+            block_height,
+        );
 
         // Compute the cumulative weight.
         let cumulative_weight = self
@@ -215,6 +467,33 @@ impl<N: Network> Ledger<N> {
         self.canon_blocks.to_ledger_tree()
     }
 
+    ///
+    /// Returns a snapshot of the ledger tree and the ledger proofs for the given commitments,
+    /// padded with default ledger proofs to `N::NUM_INPUT_RECORDS`, all against the latest ledger root.
+    ///
+    /// The snapshot is owned, so a transaction can be proven from it without borrowing the ledger,
+    /// such as while another thread adds blocks. A transaction proven against a ledger root remains
+    /// valid after more blocks are added, as every ledger root of the canon chain is accepted,
+    /// provided its serial numbers are not spent in the meantime.
+    ///
+    pub fn to_execution_snapshot(&self, commitments: &[N::Commitment]) -> Result<(LedgerTree<N>, Vec<LedgerProof<N>>)> {
+        if commitments.len() > N::NUM_INPUT_RECORDS {
+            return Err(anyhow!(
+                "Found {} commitments, exceeding the maximum of {}",
+                commitments.len(),
+                N::NUM_INPUT_RECORDS
+            ));
+        }
+
+        let mut ledger_proofs = commitments
+            .iter()
+            .map(|commitment| self.to_ledger_proof(*commitment))
+            .collect::<Result<Vec<_>>>()?;
+        ledger_proofs.resize(N::NUM_INPUT_RECORDS, LedgerProof::default());
+
+        Ok((self.to_ledger_tree().clone(), ledger_proofs))
+    }
+
     ///
     /// Returns the ledger proof for the given commitment with the current ledger root.
     ///
@@ -227,8 +506,16 @@ impl<N: Network> Ledger<N> {
 mod tests {
     use super::*;
     use crate::{testnet1::Testnet1, testnet2::Testnet2};
+    use snarkvm_utilities::{FromBytes, UniformRand};
 
-    use rand::thread_rng;
+    use itertools::Itertools;
+    use rand::{thread_rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    use std::{
+        str::FromStr,
+        sync::{Arc, RwLock},
+    };
 
     #[test]
     fn test_new() {
@@ -263,4 +550,521 @@ mod tests {
             assert_eq!(1, ledger.latest_block_height());
         }
     }
+
+    #[test]
+    fn test_verify_transaction_historical() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Craft a transfer against the ledger root at block 1.
+        let record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![record.clone()],
+            vec![
+                ledger.to_ledger_proof(record.commitment()).unwrap(),
+                LedgerProof::default(),
+            ],
+            recipient.address(),
+            record.value(),
+            AleoAmount::ZERO,
+            true,
+            rng,
+        )
+        .unwrap();
+        let transaction = Transaction::new(ledger.to_ledger_tree().clone(), &request, rng).unwrap();
+        let ledger_root = transaction.ledger_root();
+        assert_eq!(Some(1), ledger.get_ledger_root_height(&ledger_root));
+
+        // Check the transaction verifies after several blocks have passed.
+        for _ in 0..3 {
+            ledger
+                .mine_next_block(recipient.address(), true, &terminator, rng)
+                .unwrap();
+        }
+        assert_eq!(4, ledger.latest_block_height());
+        assert_ne!(ledger_root, ledger.latest_ledger_root());
+        assert!(ledger.contains_ledger_root(&ledger_root));
+        assert_eq!(1, ledger.verify_transaction_historical(&transaction).unwrap());
+
+        // Check a ledger root from another chain is rejected.
+        let mut other_ledger = Ledger::<Testnet2>::new().unwrap();
+        let other_record = other_ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        let other_request = Request::new_transfer(
+            account.private_key(),
+            vec![other_record.clone()],
+            vec![
+                other_ledger.to_ledger_proof(other_record.commitment()).unwrap(),
+                LedgerProof::default(),
+            ],
+            recipient.address(),
+            other_record.value(),
+            AleoAmount::ZERO,
+            true,
+            rng,
+        )
+        .unwrap();
+        let other_transaction = Transaction::new(other_ledger.to_ledger_tree().clone(), &other_request, rng).unwrap();
+        assert!(!ledger.contains_ledger_root(&other_transaction.ledger_root()));
+        assert!(ledger.verify_transaction_historical(&other_transaction).is_err());
+        assert_eq!(
+            Some(1),
+            other_ledger.get_ledger_root_height(&other_transaction.ledger_root())
+        );
+    }
+
+    #[test]
+    fn test_concurrent_execution_and_block_insertion() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Mine a coinbase record for each thread to spend.
+        let records = (0..3)
+            .map(|_| {
+                ledger
+                    .mine_next_block(account.address(), true, &terminator, rng)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let ledger = Arc::new(RwLock::new(ledger));
+
+        // Prove a transfer of each record outside the lock, while blocks are added.
+        let threads = records
+            .into_iter()
+            .map(|record| {
+                let ledger = ledger.clone();
+                let private_key = account.private_key().clone();
+                let recipient = recipient.address();
+                std::thread::spawn(move || {
+                    let rng = &mut thread_rng();
+                    let (ledger_tree, ledger_proofs) = ledger
+                        .read()
+                        .unwrap()
+                        .to_execution_snapshot(&[record.commitment()])
+                        .unwrap();
+                    let request = Request::new_transfer(
+                        &private_key,
+                        vec![record.clone()],
+                        ledger_proofs,
+                        recipient,
+                        record.value(),
+                        AleoAmount::ZERO,
+                        true,
+                        rng,
+                    )
+                    .unwrap();
+                    let transaction = Transaction::new(ledger_tree, &request, rng).unwrap();
+                    ledger
+                        .write()
+                        .unwrap()
+                        .add_unconfirmed_transaction(&transaction)
+                        .unwrap();
+                    transaction
+                })
+            })
+            .collect::<Vec<_>>();
+        for _ in 0..2 {
+            ledger
+                .write()
+                .unwrap()
+                .mine_next_block(account.address(), true, &terminator, rng)
+                .unwrap();
+        }
+        let transactions = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap())
+            .collect::<Vec<_>>();
+
+        // Check every transaction is accepted in a block.
+        let mut ledger = ledger.write().unwrap();
+        ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        for transaction in &transactions {
+            assert!(ledger.contains_transaction(transaction));
+            assert!(ledger.verify_transaction_historical(transaction).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_find_spent_serial_numbers() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Mine two coinbase records for the account.
+        let record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        let unspent_record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+
+        // Spend the first record.
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![record.clone()],
+            vec![
+                ledger.to_ledger_proof(record.commitment()).unwrap(),
+                LedgerProof::default(),
+            ],
+            recipient.address(),
+            record.value(),
+            AleoAmount::ZERO,
+            true,
+            rng,
+        )
+        .unwrap();
+        let transaction = Transaction::new(ledger.to_ledger_tree().clone(), &request, rng).unwrap();
+        ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        ledger
+            .mine_next_block(recipient.address(), true, &terminator, rng)
+            .unwrap();
+
+        // Detect the spend using only the compute key.
+        let compute_key = account.private_key().to_compute_key();
+        let serial_numbers = compute_key
+            .to_serial_numbers(&[record.commitment(), unspent_record.commitment()])
+            .unwrap();
+        assert_eq!(vec![true, false], ledger.find_spent_serial_numbers(&serial_numbers));
+        assert_eq!(request.to_serial_numbers().unwrap()[0], serial_numbers[0]);
+    }
+
+    #[test]
+    fn test_preflight() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let account = Account::<Testnet2>::new(rng);
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Craft a transfer against the ledger root at block 1.
+        let record = ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        let request = Request::new_transfer(
+            account.private_key(),
+            vec![record.clone()],
+            vec![
+                ledger.to_ledger_proof(record.commitment()).unwrap(),
+                LedgerProof::default(),
+            ],
+            recipient.address(),
+            record.value(),
+            AleoAmount::ZERO,
+            true,
+            rng,
+        )
+        .unwrap();
+        let transaction = Transaction::new(ledger.to_ledger_tree().clone(), &request, rng).unwrap();
+
+        // Check the transaction passes every check, including when checked concurrently.
+        let report = ledger.preflight(&transaction);
+        assert!(report.is_ok());
+        assert_eq!(0, report.failures().count());
+        let reports = (0..4)
+            .into_par_iter()
+            .map(|_| ledger.preflight(&transaction))
+            .collect::<Vec<_>>();
+        assert!(reports.iter().all(|candidate| candidate == &report));
+
+        // Check a malformed transaction is rejected.
+        let malformed =
+            Transaction::from_unchecked(transaction.inner_circuit_id(), transaction.ledger_root(), vec![]).unwrap();
+        assert!(matches!(
+            ledger.preflight(&malformed).structure,
+            Err(PreflightFailure::Malformed(..))
+        ));
+
+        // Check a minting transaction is rejected.
+        let (coinbase, _) = Transaction::<Testnet2>::new_coinbase(account.address(), AleoAmount(1234), true, rng).unwrap();
+        let report = ledger.preflight(&coinbase);
+        assert_eq!(Err(PreflightFailure::MintsValue(AleoAmount(1234))), report.fee);
+        assert!(report.structure.is_ok());
+        assert!(report.proofs.is_ok());
+
+        // Check a transaction conflicting with the memory pool is rejected.
+        let mut pending_ledger = ledger.clone();
+        pending_ledger.add_unconfirmed_transaction(&transaction).unwrap();
+        let report = pending_ledger.preflight(&transaction);
+        assert!(matches!(report.memory_pool, Err(PreflightFailure::MemoryPoolConflicts(..))));
+        assert_eq!(1, report.failures().count());
+
+        // Check a transaction spending the record again is rejected, once it is in the canon chain.
+        pending_ledger
+            .mine_next_block(recipient.address(), true, &terminator, rng)
+            .unwrap();
+        let report = pending_ledger.preflight(&transaction);
+        assert_eq!(
+            Err(PreflightFailure::SpentSerialNumbers(
+                transaction.serial_numbers().copied().collect()
+            )),
+            report.double_spend
+        );
+        assert_eq!(
+            Err(PreflightFailure::ExistingTransaction(transaction.transaction_id())),
+            report.collision
+        );
+        assert!(report.memory_pool.is_ok());
+
+        // Check a transaction proven against a different ledger root is rejected.
+        ledger
+            .mine_next_block(recipient.address(), true, &terminator, rng)
+            .unwrap();
+        let unproven = Transaction::from_unchecked(
+            transaction.inner_circuit_id(),
+            ledger.latest_ledger_root(),
+            transaction.transitions().clone(),
+        )
+        .unwrap();
+        let report = ledger.preflight(&unproven);
+        assert!(report.structure.is_ok());
+        assert!(report.ledger_root.is_ok());
+        assert_eq!(
+            Err(PreflightFailure::InvalidProofs(transaction.transition_ids().collect())),
+            report.proofs
+        );
+
+        // Check a transaction referencing a non-existent ledger root is rejected.
+        let other = Transaction::from_unchecked(
+            transaction.inner_circuit_id(),
+            UniformRand::rand(rng),
+            transaction.transitions().clone(),
+        )
+        .unwrap();
+        assert!(matches!(
+            ledger.preflight(&other).ledger_root,
+            Err(PreflightFailure::UnknownLedgerRoot(..))
+        ));
+    }
+
+    #[test]
+    fn test_blocks_iter() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        for _ in 0..3 {
+            ledger
+                .mine_next_block(recipient.address(), true, &terminator, rng)
+                .unwrap();
+        }
+
+        // Check the iterators match the stored blocks.
+        let blocks = ledger.blocks_iter(0..4).collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(4, blocks.len());
+        for (height, block) in blocks.iter().enumerate() {
+            assert_eq!(height as u32, block.height());
+            assert_eq!(ledger.canon_blocks.get_block(height as u32).unwrap(), *block);
+        }
+        let transactions = ledger.transactions_iter(1..4).collect::<Result<Vec<_>>>().unwrap();
+        let expected_transactions = blocks[1..]
+            .iter()
+            .flat_map(|block| block.transactions().iter().cloned())
+            .collect::<Vec<_>>();
+        assert_eq!(expected_transactions, transactions);
+
+        // Check a missing block is returned as an error, after the existing blocks.
+        let mut candidates = ledger.blocks_iter(3..5);
+        assert!(candidates.next().unwrap().is_ok());
+        assert!(candidates.next().unwrap().is_err());
+        assert!(candidates.next().is_none());
+        assert!(ledger.transactions_iter(4..5).next().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_scan_for_randomizer_reuse() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        for _ in 0..2 {
+            ledger
+                .mine_next_block(recipient.address(), true, &terminator, rng)
+                .unwrap();
+        }
+
+        // Check an honest chain does not reuse randomizers.
+        assert!(ledger.scan_for_randomizer_reuse(0..3).unwrap().is_empty());
+        assert!(ledger.scan_for_randomizer_reuse(3..4).is_err());
+
+        // Check a transition scanned twice reports each of its randomizers.
+        let block = ledger.canon_blocks.get_block(2).unwrap();
+        let transaction = block.to_coinbase_transaction().unwrap();
+        let transition = transaction.transitions().first().unwrap();
+        let mut scanner = RandomizerReuseScanner::default();
+        scanner.add_transition(1, transition);
+        assert!(scanner.to_reports().is_empty());
+        scanner.add_transition(2, transition);
+
+        let reports = scanner.to_reports();
+        assert_eq!(Testnet2::NUM_OUTPUT_RECORDS, reports.len());
+        for (output_index, (report, randomizer)) in reports.iter().zip_eq(transition.output_randomizers()).enumerate() {
+            assert_eq!(randomizer, report.randomizer);
+            let expected_occurrences = (1..3)
+                .map(|block_height| RandomizerOccurrence {
+                    block_height,
+                    transition_id: transition.transition_id(),
+                    output_index: output_index as u8,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(expected_occurrences, report.occurrences);
+        }
+    }
+
+    #[test]
+    fn test_custom_events() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        ledger
+            .mine_next_block(recipient.address(), true, &terminator, rng)
+            .unwrap();
+
+        // Check the coinbase transactions emit no custom events.
+        assert!(ledger.custom_events(0..2).unwrap().is_empty());
+        assert!(ledger.custom_events(2..3).is_err());
+    }
+
+    #[test]
+    fn test_mining_statistics() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let miner = Account::<Testnet2>::new(rng);
+        let other_miner = Account::<Testnet2>::new(rng);
+        for is_public in [true, true, false, true] {
+            ledger
+                .mine_next_block(miner.address(), is_public, &terminator, rng)
+                .unwrap();
+        }
+        ledger
+            .mine_next_block(other_miner.address(), true, &terminator, rng)
+            .unwrap();
+
+        // Check the epochs cut by the range boundaries only cover the heights in the range.
+        let statistics = ledger.mining_statistics(1..6, 2).unwrap();
+        let ranges = statistics
+            .iter()
+            .map(|statistics| (statistics.epoch, statistics.start_height, statistics.end_height))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(0, 1, 1), (1, 2, 3), (2, 4, 5)], ranges);
+
+        // Check the miners of the public coinbase records are counted.
+        assert_eq!(Some(&1), statistics[0].blocks_by_miner.get(&miner.address()));
+        assert_eq!(Some(&1), statistics[1].blocks_by_miner.get(&miner.address()));
+        assert_eq!(1, statistics[1].num_private_coinbase);
+        assert_eq!(1, statistics[2].blocks_by_miner[&miner.address()]);
+        assert_eq!(1, statistics[2].blocks_by_miner[&other_miner.address()]);
+
+        // Check the totals match the blocks.
+        for statistics in &statistics {
+            let blocks = ledger
+                .blocks_iter(statistics.start_height..statistics.end_height + 1)
+                .collect::<Result<Vec<_>>>()
+                .unwrap();
+            assert_eq!(blocks.len() as u32, statistics.num_blocks);
+            let total_coinbase = blocks
+                .iter()
+                .map(|block| Block::<Testnet2>::block_reward(block.height()))
+                .sum::<AleoAmount>();
+            assert_eq!(total_coinbase, statistics.total_coinbase);
+            let total_difficulty_target = blocks
+                .iter()
+                .map(|block| block.difficulty_target() as u128)
+                .sum::<u128>();
+            assert_eq!(total_difficulty_target, statistics.total_difficulty_target);
+        }
+
+        // Check an epoch length of zero is rejected.
+        assert!(ledger.mining_statistics(0..6, 0).is_err());
+    }
+
+    #[test]
+    fn test_export_range() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        let mut ledger = Ledger::<Testnet2>::new().unwrap();
+        let recipient = Account::<Testnet2>::new(rng);
+        for _ in 0..2 {
+            ledger
+                .mine_next_block(recipient.address(), true, &terminator, rng)
+                .unwrap();
+        }
+        let expected_blocks = ledger.blocks_iter(1..3).collect::<Result<Vec<_>>>().unwrap();
+
+        // Check the length-prefixed canonical bytes decode to the blocks.
+        let mut bytes = vec![];
+        assert_eq!(2, ledger.export_range(1..3, &mut bytes, ExportFormat::Bytes).unwrap());
+        let mut reader = &bytes[..];
+        for expected_block in &expected_blocks {
+            let length: u32 = FromBytes::read_le(&mut reader).unwrap();
+            let (block_bytes, remaining) = reader.split_at(length as usize);
+            assert_eq!(*expected_block, Block::read_le(block_bytes).unwrap());
+            reader = remaining;
+        }
+        assert!(reader.is_empty());
+
+        // Check each line of JSON decodes to a block.
+        let mut json = vec![];
+        assert_eq!(2, ledger.export_range(1..3, &mut json, ExportFormat::Json).unwrap());
+        let candidate_blocks = String::from_utf8(json)
+            .unwrap()
+            .lines()
+            .map(|line| Block::from_str(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(expected_blocks, candidate_blocks);
+    }
+
+    #[test]
+    fn test_new_with_genesis() {
+        let rng = &mut thread_rng();
+        let terminator = AtomicBool::new(false);
+
+        // Generate a custom genesis block deterministically.
+        let account = Account::<Testnet2>::new(rng);
+        let genesis_block =
+            Block::<Testnet2>::new_genesis(account.address(), &mut ChaChaRng::seed_from_u64(1231275789u64)).unwrap();
+        let candidate_block =
+            Block::<Testnet2>::new_genesis(account.address(), &mut ChaChaRng::seed_from_u64(1231275789u64)).unwrap();
+        assert_eq!(genesis_block, candidate_block);
+        assert_ne!(Testnet2::genesis_block().hash(), genesis_block.hash());
+
+        // Check a ledger boots from the custom genesis block, and extends it.
+        let mut ledger = Ledger::<Testnet2>::new_with_genesis(&genesis_block).unwrap();
+        assert_eq!(0, ledger.latest_block_height());
+        assert_eq!(genesis_block.hash(), ledger.latest_block_hash());
+        assert_eq!(genesis_block, ledger.latest_block().unwrap());
+        ledger
+            .mine_next_block(account.address(), true, &terminator, rng)
+            .unwrap();
+        assert_eq!(1, ledger.latest_block_height());
+        assert_eq!(genesis_block.hash(), ledger.latest_block().unwrap().previous_block_hash());
+
+        // Check a block that is not a genesis block is rejected.
+        let block = ledger.latest_block().unwrap();
+        assert!(Ledger::<Testnet2>::new_with_genesis(&block).is_err());
+    }
 }
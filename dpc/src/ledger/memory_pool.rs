@@ -19,17 +19,55 @@ use crate::prelude::*;
 use anyhow::{anyhow, Result};
 use std::collections::{HashMap, HashSet};
 
+/// The order in which to include the transactions of the memory pool in a block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum OrderingPolicy {
+    /// The order of `MemoryPool::transactions`, which is unspecified.
+    AsGiven,
+    /// By decreasing transaction fee, then by increasing transaction ID, for a deterministic order.
+    ByFeeDescending,
+}
+
+impl Default for OrderingPolicy {
+    fn default() -> Self {
+        Self::AsGiven
+    }
+}
+
+impl OrderingPolicy {
+    /// Returns the given transactions in the order of the policy, each with its transaction fee,
+    /// which is the value balance of the transaction.
+    pub fn order<N: Network>(&self, transactions: Vec<Transaction<N>>) -> Vec<(Transaction<N>, AleoAmount)> {
+        let mut transactions: Vec<_> = transactions
+            .into_iter()
+            .map(|transaction| {
+                let fee = transaction.value_balance();
+                (transaction, fee)
+            })
+            .collect();
+
+        if let Self::ByFeeDescending = self {
+            transactions.sort_by(|(a, a_fee), (b, b_fee)| {
+                b_fee
+                    .cmp(a_fee)
+                    .then_with(|| (*a.transaction_id()).cmp(&*b.transaction_id()))
+            });
+        }
+        transactions
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct MemoryPool<N: Network> {
     /// The pool of unconfirmed transactions.
     transactions: HashMap<N::TransactionID, Transaction<N>>,
-    /// The list of unconfirmed serial numbers.
-    serial_numbers: HashSet<N::SerialNumber>,
-    /// The list of unconfirmed commitments.
-    commitments: HashSet<N::Commitment>,
+    /// The index of unconfirmed serial numbers and commitments.
+    conflicts: ConflictIndex<N>,
     /// The set of open requests.
     #[allow(dead_code)]
     requests: HashSet<Request<N>>,
+    /// The cache of transaction verification outcomes, if enabled.
+    verified_cache: Option<VerifiedCache<N>>,
 }
 
 impl<N: Network> MemoryPool<N> {
@@ -37,12 +75,26 @@ impl<N: Network> MemoryPool<N> {
     pub fn new() -> Self {
         Self {
             transactions: Default::default(),
-            serial_numbers: Default::default(),
-            commitments: Default::default(),
+            conflicts: Default::default(),
             requests: Default::default(),
+            verified_cache: None,
         }
     }
 
+    /// Initializes a new instance of a memory pool, which caches the verification outcomes
+    /// of at most `capacity` transactions, so that resubmitted transactions are not verified again.
+    pub fn with_verified_cache(capacity: usize) -> Self {
+        Self {
+            verified_cache: Some(VerifiedCache::new(capacity)),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the cache of transaction verification outcomes, if enabled.
+    pub fn verified_cache(&self) -> Option<&VerifiedCache<N>> {
+        self.verified_cache.as_ref()
+    }
+
     /// Returns `true` if the given transaction exists in the memory pool.
     pub fn contains_transaction(&self, transaction: &Transaction<N>) -> bool {
         self.transactions.contains_key(&transaction.transaction_id())
@@ -53,10 +105,24 @@ impl<N: Network> MemoryPool<N> {
         self.transactions.values().cloned().collect()
     }
 
+    /// Returns the transactions in the memory pool in the order of the given policy, each with its transaction fee.
+    pub fn to_ordered_transactions(&self, policy: OrderingPolicy) -> Vec<(Transaction<N>, AleoAmount)> {
+        policy.order(self.transactions())
+    }
+
+    /// Returns the conflicts between the given transaction and the transactions in the memory pool.
+    pub fn conflicts(&self, transaction: &Transaction<N>) -> Vec<ConflictWith<N>> {
+        self.conflicts.check(transaction)
+    }
+
     /// Adds the given unconfirmed transaction to the memory pool.
     pub fn add_transaction(&mut self, transaction: &Transaction<N>) -> Result<()> {
         // Ensure the unconfirmed transaction itself is valid.
-        if !transaction.is_valid() {
+        let is_valid = match &mut self.verified_cache {
+            Some(verified_cache) => verified_cache.is_valid(transaction),
+            None => transaction.is_valid(),
+        };
+        if !is_valid {
             return Err(anyhow!("The unconfirmed transaction is invalid"));
         }
 
@@ -65,37 +131,22 @@ impl<N: Network> MemoryPool<N> {
             return Err(anyhow!("The unconfirmed transaction is attempting to mint new value"));
         }
 
-        // Ensure the transaction does not already exist in the memory pool.
-        let transaction_id = transaction.transaction_id();
-        if self.transactions.contains_key(&transaction_id) {
-            return Err(anyhow!("Transaction already exists in memory pool"));
-        }
-
-        // Ensure the memory pool does not already contain a given serial numbers.
-        for serial_number in transaction.serial_numbers() {
-            if self.serial_numbers.contains(serial_number) {
-                return Err(anyhow!("Serial number already used in memory pool"));
-            }
-        }
-
-        // Ensure the memory pool does not already contain a given commitments.
-        for commitment in transaction.commitments() {
-            if self.commitments.contains(commitment) {
-                return Err(anyhow!("Commitment already used in memory pool"));
-            }
+        // Ensure the transaction does not conflict with the memory pool.
+        match self.conflicts.check(transaction).first() {
+            Some(ConflictWith::Transaction(..)) => return Err(anyhow!("Transaction already exists in memory pool")),
+            Some(ConflictWith::SerialNumber(..)) => return Err(anyhow!("Serial number already used in memory pool")),
+            Some(ConflictWith::Commitment(..)) => return Err(anyhow!("Commitment already used in memory pool")),
+            None => (),
         }
 
         // Add the transaction to the memory pool. This code section executes atomically.
         {
             let mut memory_pool = self.clone();
 
-            memory_pool.transactions.insert(transaction_id, transaction.clone());
-            for serial_number in transaction.serial_numbers() {
-                memory_pool.serial_numbers.insert(*serial_number);
-            }
-            for commitment in transaction.commitments() {
-                memory_pool.commitments.insert(*commitment);
-            }
+            memory_pool.conflicts.insert(transaction)?;
+            memory_pool
+                .transactions
+                .insert(transaction.transaction_id(), transaction.clone());
 
             *self = memory_pool;
         }
@@ -105,45 +156,22 @@ impl<N: Network> MemoryPool<N> {
 
     /// Clear a transaction (and associated state) from the memory pool.
     pub fn remove_transaction(&mut self, transaction: &Transaction<N>) {
-        // This code section executes atomically.
-
-        let mut memory_pool = self.clone();
-
-        memory_pool.transactions.remove(&transaction.transaction_id());
-        for serial_number in transaction.serial_numbers() {
-            memory_pool.serial_numbers.remove(serial_number);
-        }
-        for commitment in transaction.commitments() {
-            memory_pool.commitments.remove(commitment);
-        }
-
-        *self = memory_pool;
+        let transaction_id = transaction.transaction_id();
+        self.transactions.remove(&transaction_id);
+        self.conflicts.remove(&transaction_id);
     }
 
     /// Clear a list of transactions (and associated state) from the memory pool.
     pub fn remove_transactions(&mut self, transactions: &[Transaction<N>]) {
-        // This code section executes atomically.
-
-        let mut memory_pool = self.clone();
-
         for transaction in transactions {
-            memory_pool.transactions.remove(&transaction.transaction_id());
-            for serial_number in transaction.serial_numbers() {
-                memory_pool.serial_numbers.remove(serial_number);
-            }
-            for commitment in transaction.commitments() {
-                memory_pool.commitments.remove(commitment);
-            }
+            self.remove_transaction(transaction);
         }
-
-        *self = memory_pool;
     }
 
     /// Clears all transactions (and associated state) from the memory pool.
     pub fn clear_all_transactions(&mut self) {
         self.transactions = Default::default();
-        self.serial_numbers = Default::default();
-        self.commitments = Default::default();
+        self.conflicts.clear();
     }
 }
 
@@ -152,3 +180,65 @@ impl<N: Network> Default for MemoryPool<N> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::sample_transaction_with_value_balance, testnet2::Testnet2};
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_ordering_policy() {
+        let rng = &mut thread_rng();
+        let transactions: Vec<_> = [3, 1, 3, 0, 2]
+            .iter()
+            .map(|fee| sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount(*fee), rng).unwrap())
+            .collect();
+
+        // Check the default policy keeps the given order.
+        assert_eq!(OrderingPolicy::AsGiven, OrderingPolicy::default());
+        let ordered = OrderingPolicy::AsGiven.order(transactions.clone());
+        assert_eq!(
+            transactions,
+            ordered.iter().map(|(transaction, _)| transaction.clone()).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![3, 1, 3, 0, 2],
+            ordered.iter().map(|(_, fee)| fee.as_i64()).collect::<Vec<_>>()
+        );
+
+        // Check the transactions are ordered by decreasing fee.
+        let ordered = OrderingPolicy::ByFeeDescending.order(transactions.clone());
+        assert_eq!(
+            vec![3, 3, 2, 1, 0],
+            ordered.iter().map(|(_, fee)| fee.as_i64()).collect::<Vec<_>>()
+        );
+
+        // Check equal fees are ordered by transaction ID, independently of the given order.
+        assert!(*ordered[0].0.transaction_id() < *ordered[1].0.transaction_id());
+        let reversed = OrderingPolicy::ByFeeDescending.order(transactions.into_iter().rev().collect());
+        assert_eq!(ordered, reversed);
+    }
+
+    #[test]
+    fn test_resubmitted_transaction_is_cache_hit() {
+        // A transaction whose transition proof is invalid.
+        let transaction =
+            sample_transaction_with_value_balance::<Testnet2, _>(AleoAmount::ZERO, &mut thread_rng()).unwrap();
+
+        let mut memory_pool = MemoryPool::<Testnet2>::with_verified_cache(8);
+        assert!(memory_pool.add_transaction(&transaction).is_err());
+        let verified_cache = memory_pool.verified_cache().unwrap();
+        assert_eq!((0, 1), (verified_cache.hits(), verified_cache.misses()));
+
+        // Ensure the resubmitted transaction is rejected without verifying it again.
+        assert!(memory_pool.add_transaction(&transaction).is_err());
+        let verified_cache = memory_pool.verified_cache().unwrap();
+        assert_eq!((1, 1), (verified_cache.hits(), verified_cache.misses()));
+        assert!(!memory_pool.contains_transaction(&transaction));
+
+        // Ensure a memory pool without a cache has none.
+        assert!(MemoryPool::<Testnet2>::new().verified_cache().is_none());
+    }
+}
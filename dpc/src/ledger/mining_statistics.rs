@@ -0,0 +1,214 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+///
+/// The statistics of the canon blocks mined in one epoch, where an epoch is the `epoch_length`
+/// consecutive block heights that start at a multiple of `epoch_length`.
+///
+/// An epoch that is cut by the boundaries of the requested range of block heights is reported
+/// over the heights inside the range only, so `num_blocks` is less than `epoch_length`.
+///
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct EpochStatistics<N: Network> {
+    /// The index of the epoch, which is the block height divided by the epoch length.
+    pub epoch: u32,
+    /// The height of the first block of the epoch in the range.
+    pub start_height: u32,
+    /// The height of the last block of the epoch in the range.
+    pub end_height: u32,
+    /// The number of blocks of the epoch in the range.
+    pub num_blocks: u32,
+    /// The sum of the difficulty targets of the blocks.
+    pub total_difficulty_target: u128,
+    /// The sum of the weights of the blocks, where the weight of a block is `u64::MAX / difficulty_target`.
+    pub total_weight: u128,
+    /// The sum of the coinbase amounts of the blocks.
+    pub total_coinbase: AleoAmount,
+    /// The sum of the transaction fees of the blocks.
+    pub total_transaction_fees: AleoAmount,
+    /// The number of blocks mined by each address, for the blocks with a public coinbase record.
+    pub blocks_by_miner: HashMap<Address<N>, u32>,
+    /// The number of blocks with a private coinbase record, whose miner is not known.
+    pub num_private_coinbase: u32,
+}
+
+impl<N: Network> EpochStatistics<N> {
+    /// Initializes empty statistics for the epoch starting from the given block height.
+    fn new(epoch: u32, start_height: u32) -> Self {
+        Self {
+            epoch,
+            start_height,
+            end_height: start_height,
+            num_blocks: 0,
+            total_difficulty_target: 0,
+            total_weight: 0,
+            total_coinbase: AleoAmount::ZERO,
+            total_transaction_fees: AleoAmount::ZERO,
+            blocks_by_miner: Default::default(),
+            num_private_coinbase: 0,
+        }
+    }
+
+    /// Returns the average difficulty target of the blocks, or `0` if there are no blocks.
+    pub fn average_difficulty_target(&self) -> u64 {
+        match self.num_blocks {
+            0 => 0,
+            num_blocks => (self.total_difficulty_target / num_blocks as u128) as u64,
+        }
+    }
+
+    /// Adds the given block to the statistics.
+    fn add_block(&mut self, block: &Block<N>) -> Result<()> {
+        let coinbase_transaction = block.to_coinbase_transaction()?;
+        let difficulty_target = block.difficulty_target();
+
+        self.end_height = block.height();
+        self.num_blocks += 1;
+        self.total_difficulty_target += difficulty_target as u128;
+        self.total_weight += (u64::MAX / difficulty_target) as u128;
+        self.total_coinbase = self.total_coinbase.sub(coinbase_transaction.value_balance());
+        self.total_transaction_fees = self.total_transaction_fees.add(block.transactions().transaction_fees());
+
+        // The miner is the owner of the coinbase record, if the coinbase record is public.
+        match coinbase_transaction
+            .to_records()
+            .find(|record| record.value().is_positive())
+        {
+            Some(record) => *self.blocks_by_miner.entry(record.owner()).or_default() += 1,
+            None => self.num_private_coinbase += 1,
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the statistics of each epoch of the given blocks, which are ordered by block height.
+pub(crate) struct EpochStatisticsIter<N: Network, I: Iterator<Item = Result<Block<N>>>> {
+    blocks: I,
+    /// The block read past the end of the previous epoch, if any.
+    next_block: Option<Block<N>>,
+    epoch_length: u32,
+}
+
+impl<N: Network, I: Iterator<Item = Result<Block<N>>>> EpochStatisticsIter<N, I> {
+    /// Initializes a new iterator over the statistics of each epoch of the given blocks.
+    pub(crate) fn new(blocks: I, epoch_length: u32) -> Self {
+        Self {
+            blocks,
+            next_block: None,
+            epoch_length,
+        }
+    }
+}
+
+impl<N: Network, I: Iterator<Item = Result<Block<N>>>> Iterator for EpochStatisticsIter<N, I> {
+    type Item = Result<EpochStatistics<N>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let block = match self.next_block.take() {
+            Some(block) => block,
+            None => match self.blocks.next()? {
+                Ok(block) => block,
+                Err(error) => return Some(Err(error)),
+            },
+        };
+
+        let epoch = block.height() / self.epoch_length;
+        let mut statistics = EpochStatistics::new(epoch, block.height());
+        if let Err(error) = statistics.add_block(&block) {
+            return Some(Err(error));
+        }
+
+        for block in &mut self.blocks {
+            let block = match block {
+                Ok(block) => block,
+                Err(error) => return Some(Err(error)),
+            };
+
+            // Stop at the first block of the next epoch, keeping it for the next call.
+            if block.height() / self.epoch_length != epoch {
+                self.next_block = Some(block);
+                break;
+            }
+            if let Err(error) = statistics.add_block(&block) {
+                return Some(Err(error));
+            }
+        }
+
+        Some(Ok(statistics))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use anyhow::anyhow;
+
+    #[test]
+    fn test_genesis_statistics() {
+        let block = Testnet2::genesis_block().clone();
+        let coinbase_transaction = block.to_coinbase_transaction().unwrap();
+
+        let blocks = vec![Ok(block.clone())];
+        let statistics = EpochStatisticsIter::new(blocks.into_iter(), 10)
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        assert_eq!(1, statistics.len());
+
+        let statistics = &statistics[0];
+        assert_eq!(0, statistics.epoch);
+        assert_eq!((0, 0), (statistics.start_height, statistics.end_height));
+        assert_eq!(1, statistics.num_blocks);
+        assert_eq!(block.difficulty_target(), statistics.average_difficulty_target());
+        assert_eq!((u64::MAX / block.difficulty_target()) as u128, statistics.total_weight);
+        assert_eq!(
+            AleoAmount::ZERO.sub(coinbase_transaction.value_balance()),
+            statistics.total_coinbase
+        );
+        assert_eq!(AleoAmount::ZERO, statistics.total_transaction_fees);
+
+        // Check the miner is counted if, and only if, the coinbase record is public.
+        let num_public_coinbase = statistics.blocks_by_miner.values().sum::<u32>();
+        assert_eq!(1, num_public_coinbase + statistics.num_private_coinbase);
+    }
+
+    #[test]
+    fn test_statistics_error() {
+        let block = Testnet2::genesis_block().clone();
+
+        // Check an error ends the current epoch with the error.
+        let blocks = vec![Ok(block), Err(anyhow!("Missing block"))];
+        let mut statistics = EpochStatisticsIter::new(blocks.into_iter(), 10);
+        assert!(statistics.next().unwrap().is_err());
+        assert!(statistics.next().is_none());
+
+        // Check an empty range has no epochs.
+        let blocks: Vec<Result<Block<Testnet2>>> = vec![];
+        assert_eq!(0, EpochStatisticsIter::new(blocks.into_iter(), 10).count());
+    }
+}
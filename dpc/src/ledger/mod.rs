@@ -17,6 +17,12 @@
 pub mod blocks;
 pub use blocks::*;
 
+pub mod conflict_index;
+pub use conflict_index::*;
+
+pub mod difficulty_simulator;
+pub use difficulty_simulator::*;
+
 pub mod ledger;
 pub use ledger::*;
 
@@ -28,3 +34,12 @@ pub use ledger_tree::*;
 
 pub mod memory_pool;
 pub use memory_pool::*;
+
+pub mod mining_statistics;
+pub use mining_statistics::*;
+
+pub mod preflight;
+pub use preflight::*;
+
+pub mod randomizer_reuse;
+pub use randomizer_reuse::*;
@@ -0,0 +1,121 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use std::fmt;
+
+/// The reason a transaction fails a preflight check.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub enum PreflightFailure<N: Network> {
+    /// The transaction is malformed, for the given reason.
+    Malformed(String),
+    /// The transitions with the given IDs have an invalid proof.
+    InvalidProofs(Vec<N::TransitionID>),
+    /// The transaction mints the given amount of new value, which is only valid in a coinbase.
+    MintsValue(AleoAmount),
+    /// The transaction references a ledger root that does not exist on the canon chain.
+    UnknownLedgerRoot(N::LedgerRoot),
+    /// The transaction spends serial numbers that are already spent on the canon chain.
+    SpentSerialNumbers(Vec<N::SerialNumber>),
+    /// The transaction already exists on the canon chain.
+    ExistingTransaction(N::TransactionID),
+    /// The transaction produces commitments that already exist on the canon chain.
+    ExistingCommitments(Vec<N::Commitment>),
+    /// The transaction conflicts with unconfirmed transactions in the memory pool.
+    MemoryPoolConflicts(Vec<ConflictWith<N>>),
+}
+
+impl<N: Network> fmt::Display for PreflightFailure<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Malformed(reason) => write!(f, "{}", reason),
+            Self::InvalidProofs(transition_ids) => {
+                write!(f, "Transaction contains {} invalid transition proofs", transition_ids.len())
+            }
+            Self::MintsValue(amount) => write!(f, "Transaction is attempting to mint {}", amount),
+            Self::UnknownLedgerRoot(ledger_root) => {
+                write!(f, "Transaction references a non-existent ledger root {}", ledger_root)
+            }
+            Self::SpentSerialNumbers(serial_numbers) => {
+                write!(f, "Transaction contains {} spent serial numbers", serial_numbers.len())
+            }
+            Self::ExistingTransaction(transaction_id) => {
+                write!(f, "Transaction {} already exists in the canon chain", transaction_id)
+            }
+            Self::ExistingCommitments(commitments) => {
+                write!(f, "Transaction contains {} existing commitments", commitments.len())
+            }
+            Self::MemoryPoolConflicts(conflicts) => {
+                write!(f, "Transaction conflicts {} times with the memory pool", conflicts.len())
+            }
+        }
+    }
+}
+
+/// The result of each check that the network performs before accepting a transaction,
+/// as computed by `Ledger::preflight`.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct PreflightReport<N: Network> {
+    /// The check that the transaction is well-formed.
+    pub structure: Result<(), PreflightFailure<N>>,
+    /// The check that every transition proof is valid.
+    pub proofs: Result<(), PreflightFailure<N>>,
+    /// The check that the transaction pays a non-negative fee.
+    pub fee: Result<(), PreflightFailure<N>>,
+    /// The check that the ledger root of the transaction is on the canon chain.
+    pub ledger_root: Result<(), PreflightFailure<N>>,
+    /// The check that the serial numbers of the transaction are unspent on the canon chain.
+    pub double_spend: Result<(), PreflightFailure<N>>,
+    /// The check that the transaction ID and commitments are new to the canon chain.
+    pub collision: Result<(), PreflightFailure<N>>,
+    /// The check that the transaction does not conflict with the memory pool.
+    pub memory_pool: Result<(), PreflightFailure<N>>,
+}
+
+impl<N: Network> PreflightReport<N> {
+    /// Returns `true` if the transaction passes every check.
+    pub fn is_ok(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Returns the failures of the transaction, in the order of the checks.
+    pub fn failures(&self) -> impl Iterator<Item = &PreflightFailure<N>> + '_ {
+        vec![
+            &self.structure,
+            &self.proofs,
+            &self.fee,
+            &self.ledger_root,
+            &self.double_spend,
+            &self.collision,
+            &self.memory_pool,
+        ]
+        .into_iter()
+        .filter_map(|check| check.as_ref().err())
+    }
+}
@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use std::collections::HashMap;
+
+/// The location of an output record ciphertext on the canon chain.
+#[derive(Derivative)]
+#[derivative(
+    Copy(bound = "N: Network"),
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct RandomizerOccurrence<N: Network> {
+    /// The height of the block containing the transition.
+    pub block_height: u32,
+    /// The ID of the transition containing the ciphertext.
+    pub transition_id: N::TransitionID,
+    /// The index of the output record in the transition.
+    pub output_index: u8,
+}
+
+/// A ciphertext randomizer that is used by more than one output record.
+///
+/// Reusing the encryption randomness of a record leaks relations between the plaintexts of the records.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ReuseReport<N: Network> {
+    /// The reused ciphertext randomizer.
+    pub randomizer: N::RecordRandomizer,
+    /// The output records using the randomizer, in the order they appear on the chain.
+    pub occurrences: Vec<RandomizerOccurrence<N>>,
+}
+
+/// Collects the ciphertext randomizers of transitions, to detect any randomizer that is used more than once.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    Default(bound = "N: Network")
+)]
+pub(crate) struct RandomizerReuseScanner<N: Network> {
+    /// The randomizers seen so far, in the order they were first seen.
+    randomizers: Vec<N::RecordRandomizer>,
+    /// The occurrences of each randomizer.
+    occurrences: HashMap<N::RecordRandomizer, Vec<RandomizerOccurrence<N>>>,
+}
+
+impl<N: Network> RandomizerReuseScanner<N> {
+    /// Records the output randomizers of the given transition, from the block at the given height.
+    pub(crate) fn add_transition(&mut self, block_height: u32, transition: &Transition<N>) {
+        for (output_index, randomizer) in transition.output_randomizers().enumerate() {
+            let occurrence = RandomizerOccurrence {
+                block_height,
+                transition_id: transition.transition_id(),
+                output_index: output_index as u8,
+            };
+            let occurrences = self.occurrences.entry(randomizer).or_default();
+            if occurrences.is_empty() {
+                self.randomizers.push(randomizer);
+            }
+            occurrences.push(occurrence);
+        }
+    }
+
+    /// Returns a report for each randomizer that is used more than once, in the order they were first seen.
+    pub(crate) fn to_reports(&self) -> Vec<ReuseReport<N>> {
+        self.randomizers
+            .iter()
+            .filter_map(|randomizer| match &self.occurrences[randomizer] {
+                occurrences if occurrences.len() > 1 => Some(ReuseReport {
+                    randomizer: *randomizer,
+                    occurrences: occurrences.clone(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+}
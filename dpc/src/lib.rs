@@ -49,6 +49,12 @@ pub use posw::*;
 pub mod record;
 pub use record::*;
 
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_helpers;
+
+#[cfg(any(test, feature = "test-helpers"))]
+pub mod test_vectors;
+
 pub mod traits;
 pub use traits::*;
 
@@ -0,0 +1,161 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+
+/// The constants of the consensus rules of a network, which are used by block validation,
+/// difficulty retargeting, and the block reward.
+///
+/// By default, `Network::consensus_constants` assembles them from the constants of the network.
+/// A network may override it, such as to experiment with a shorter block time on a devnet.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConsensusConstants {
+    /// The targeted time between blocks.
+    pub block_time_in_secs: i64,
+    /// The number of credits issued in the genesis block.
+    pub starting_supply_in_credits: i64,
+    /// The maximum future block time.
+    pub future_time_limit_in_secs: i64,
+    /// The maximum number of blocks that a fork can be.
+    pub maximum_fork_depth: u32,
+    /// The block reward for each block after the genesis block, before any halving.
+    pub initial_block_reward_in_credits: i64,
+    /// The number of years between halvings of the block reward.
+    pub block_reward_halving_interval_in_years: u32,
+    /// The maximum number of times the block reward halves.
+    pub maximum_block_reward_halvings: u32,
+    /// The last block height that is retargeted with the Bitcoin retarget rule against the previous block,
+    /// if any. Every later block is retargeted with the ASERT retarget rule against this block, or
+    /// against the genesis block if there is no such height.
+    pub legacy_retarget_height: Option<u32>,
+}
+
+impl ConsensusConstants {
+    /// Returns the consensus constants assembled from the constants of the given network.
+    pub fn of<N: Network>() -> Self {
+        Self {
+            block_time_in_secs: N::ALEO_BLOCK_TIME_IN_SECS,
+            starting_supply_in_credits: N::ALEO_STARTING_SUPPLY_IN_CREDITS,
+            future_time_limit_in_secs: N::ALEO_FUTURE_TIME_LIMIT_IN_SECS,
+            maximum_fork_depth: N::ALEO_MAXIMUM_FORK_DEPTH,
+            initial_block_reward_in_credits: N::ALEO_INITIAL_BLOCK_REWARD_IN_CREDITS,
+            block_reward_halving_interval_in_years: N::ALEO_BLOCK_REWARD_HALVING_INTERVAL_IN_YEARS,
+            maximum_block_reward_halvings: N::ALEO_MAXIMUM_BLOCK_REWARD_HALVINGS,
+            legacy_retarget_height: match N::NETWORK_ID == 2 {
+                true => Some(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT),
+                false => None,
+            },
+        }
+    }
+
+    /// Returns `true` if the block at the given height is retargeted against the previous block.
+    pub const fn is_legacy_retarget(&self, block_height: u32) -> bool {
+        match self.legacy_retarget_height {
+            Some(height) => block_height <= height,
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{testnet1::Testnet1, testnet2::Testnet2, AleoAmount, Block, DifficultySimulator, SimulatedBlock};
+
+    /// Returns the consensus constants of a devnet with a 1-second block time.
+    fn devnet() -> ConsensusConstants {
+        ConsensusConstants {
+            block_time_in_secs: 1,
+            legacy_retarget_height: None,
+            ..Testnet2::consensus_constants()
+        }
+    }
+
+    const ANCHOR: SimulatedBlock = SimulatedBlock {
+        height: 72154,
+        timestamp: 1640179531,
+        difficulty_target: 101336179232188,
+    };
+
+    #[test]
+    fn test_consensus_constants() {
+        let expected = ConsensusConstants {
+            block_time_in_secs: 20,
+            starting_supply_in_credits: 1_000_000_000,
+            future_time_limit_in_secs: 90,
+            maximum_fork_depth: 4096,
+            initial_block_reward_in_credits: 100,
+            block_reward_halving_interval_in_years: 3,
+            maximum_block_reward_halvings: 2,
+            legacy_retarget_height: None,
+        };
+        assert_eq!(expected, Testnet1::consensus_constants());
+        assert_eq!(
+            ConsensusConstants {
+                legacy_retarget_height: Some(crate::testnet2::V12_UPGRADE_BLOCK_HEIGHT),
+                ..expected
+            },
+            Testnet2::consensus_constants()
+        );
+    }
+
+    #[test]
+    fn test_block_reward_with() {
+        let first_halving: u32 = 3 * 365 * 24 * 180; // 4,730,400
+        for (height, expected) in [
+            (0, 1_000_000_000),
+            (1, 100),
+            (first_halving, 100),
+            (first_halving + 1, 50),
+            (first_halving * 2 + 1, 25),
+            (u32::MAX, 25),
+        ] {
+            let expected = AleoAmount::from_i64(expected * AleoAmount::ONE_CREDIT.0);
+            assert_eq!(expected, Block::<Testnet2>::block_reward(height));
+            assert_eq!(
+                expected,
+                Block::<Testnet2>::block_reward_with(&Testnet2::consensus_constants(), height)
+            );
+        }
+
+        // Check a 1-second block time halves the block reward after 20 times as many blocks.
+        let devnet_first_halving = first_halving * 20;
+        let initial_reward = AleoAmount::from_i64(100 * AleoAmount::ONE_CREDIT.0);
+        assert_eq!(
+            initial_reward,
+            Block::<Testnet2>::block_reward_with(&devnet(), devnet_first_halving)
+        );
+        assert_eq!(
+            AleoAmount::from_i64(50 * AleoAmount::ONE_CREDIT.0),
+            Block::<Testnet2>::block_reward_with(&devnet(), devnet_first_halving + 1)
+        );
+    }
+
+    #[test]
+    fn test_devnet_retarget() {
+        // Check blocks found every second keep the difficulty target of the devnet.
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR).with_constants(devnet());
+        let timestamps = (1..=100).map(|i| ANCHOR.timestamp + i);
+        for block in simulator.simulate(timestamps.clone()) {
+            assert_eq!(ANCHOR.difficulty_target, block.difficulty_target);
+        }
+
+        // Check the same blocks are ahead of schedule on testnet2, which lowers the difficulty target.
+        let mut simulator = DifficultySimulator::<Testnet2>::new(ANCHOR);
+        let block = simulator.simulate(timestamps).pop().unwrap();
+        assert!(block.difficulty_target < ANCHOR.difficulty_target);
+    }
+}
@@ -0,0 +1,230 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{Bech32Locator, Bech32mError, Network};
+use snarkvm_fields::Field;
+
+///
+/// The kind of an identifier that is encoded as a Bech32m locator, such as a transaction ID.
+///
+/// Each kind of locator has a distinct human-readable prefix, given by the prefix constants of `Network`.
+/// Locators are displayed in lowercase. As in Bech32m, an uppercase locator parses to the same
+/// identifier, while a locator in mixed case is rejected.
+///
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LocatorKind {
+    BlockHash,
+    LedgerRoot,
+    ProgramID,
+    TransitionID,
+    TransactionID,
+    Commitment,
+    FunctionInputsHash,
+    FunctionID,
+    HeaderNonce,
+    HeaderRoot,
+    HeaderTransactionsRoot,
+    InnerCircuitID,
+    RecordRandomizer,
+    RecordViewKeyCommitment,
+    SerialNumber,
+}
+
+impl LocatorKind {
+    /// Every kind of locator, in the order of the prefix constants of `Network`.
+    pub const ALL: [Self; 15] = [
+        Self::BlockHash,
+        Self::LedgerRoot,
+        Self::ProgramID,
+        Self::TransitionID,
+        Self::TransactionID,
+        Self::Commitment,
+        Self::FunctionInputsHash,
+        Self::FunctionID,
+        Self::HeaderNonce,
+        Self::HeaderRoot,
+        Self::HeaderTransactionsRoot,
+        Self::InnerCircuitID,
+        Self::RecordRandomizer,
+        Self::RecordViewKeyCommitment,
+        Self::SerialNumber,
+    ];
+
+    /// Returns the human-readable prefix of this kind of locator in the given network.
+    pub fn prefix<N: Network>(&self) -> String {
+        let prefix = match self {
+            Self::BlockHash => N::BLOCK_HASH_PREFIX,
+            Self::LedgerRoot => N::LEDGER_ROOT_PREFIX,
+            Self::ProgramID => N::PROGRAM_ID_PREFIX,
+            Self::TransitionID => N::TRANSITION_ID_PREFIX,
+            Self::TransactionID => N::TRANSACTION_ID_PREFIX,
+            Self::Commitment => N::COMMITMENT_PREFIX,
+            Self::FunctionInputsHash => N::FUNCTION_INPUTS_HASH_PREFIX,
+            Self::FunctionID => N::FUNCTION_ID_PREFIX,
+            Self::HeaderNonce => N::HEADER_NONCE_PREFIX,
+            Self::HeaderRoot => N::HEADER_ROOT_PREFIX,
+            Self::HeaderTransactionsRoot => N::HEADER_TRANSACTIONS_ROOT_PREFIX,
+            Self::InnerCircuitID => N::INNER_CIRCUIT_ID_PREFIX,
+            Self::RecordRandomizer => N::RECORD_RANDOMIZER_PREFIX,
+            Self::RecordViewKeyCommitment => N::RECORD_VIEW_KEY_COMMITMENT_PREFIX,
+            Self::SerialNumber => N::SERIAL_NUMBER_PREFIX,
+        };
+        String::from_utf8(prefix.to_le_bytes().to_vec()).expect("Failed to convert prefix to string")
+    }
+
+    /// Returns the kind of locator with the given human-readable prefix in the given network, if it exists.
+    pub fn from_prefix<N: Network>(prefix: &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|kind| kind.prefix::<N>() == prefix)
+    }
+
+    ///
+    /// Returns the kind and the little-endian data bytes of the given locator of any kind,
+    /// for tools that accept any identifier of the given network.
+    ///
+    /// The locator is parsed as the kind named by its prefix, so it is checked as strictly
+    /// as when parsing the identifier type itself.
+    ///
+    pub fn parse_any<N: Network>(locator: &str) -> Result<(Self, Vec<u8>), Bech32mError> {
+        let (hrp, _, _) = bech32::decode(locator)?;
+        let kind = Self::from_prefix::<N>(&hrp).ok_or(Bech32mError::UnknownPrefix(hrp))?;
+
+        let bytes = match kind {
+            Self::BlockHash => Self::parse::<N::BlockHash, _>(locator)?,
+            Self::LedgerRoot => Self::parse::<N::LedgerRoot, _>(locator)?,
+            Self::ProgramID => Self::parse::<N::ProgramID, _>(locator)?,
+            Self::TransitionID => Self::parse::<N::TransitionID, _>(locator)?,
+            Self::TransactionID => Self::parse::<N::TransactionID, _>(locator)?,
+            Self::Commitment => Self::parse::<N::Commitment, _>(locator)?,
+            Self::FunctionInputsHash => Self::parse::<N::FunctionInputsHash, _>(locator)?,
+            Self::FunctionID => Self::parse::<N::FunctionID, _>(locator)?,
+            Self::HeaderNonce => Self::parse::<N::PoSWNonce, _>(locator)?,
+            Self::HeaderRoot => Self::parse::<N::BlockHeaderRoot, _>(locator)?,
+            Self::HeaderTransactionsRoot => Self::parse::<N::TransactionsRoot, _>(locator)?,
+            Self::InnerCircuitID => Self::parse::<N::InnerCircuitID, _>(locator)?,
+            Self::RecordRandomizer => Self::parse::<N::RecordRandomizer, _>(locator)?,
+            Self::RecordViewKeyCommitment => Self::parse::<N::RecordViewKeyCommitment, _>(locator)?,
+            Self::SerialNumber => Self::parse::<N::SerialNumber, _>(locator)?,
+        };
+        Ok((kind, bytes))
+    }
+
+    /// Returns the little-endian data bytes of the given locator, parsed as the given locator type.
+    fn parse<L: Bech32Locator<F>, F: Field>(locator: &str) -> Result<Vec<u8>, Bech32mError> {
+        Ok(L::from_str(locator)?.to_bytes_le()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::{thread_rng, Rng};
+    use std::{collections::HashSet, str::FromStr};
+
+    type N = Testnet2;
+
+    /// Returns a random locator of the given kind, and its little-endian data bytes.
+    fn sample<R: Rng>(kind: LocatorKind, rng: &mut R) -> (String, Vec<u8>) {
+        fn sample<L: Bech32Locator<F>, F: Field, R: Rng>(rng: &mut R) -> (String, Vec<u8>) {
+            let locator = L::rand(rng);
+            (locator.to_string(), locator.to_bytes_le().unwrap())
+        }
+
+        match kind {
+            LocatorKind::BlockHash => sample::<<N as Network>::BlockHash, _, _>(rng),
+            LocatorKind::LedgerRoot => sample::<<N as Network>::LedgerRoot, _, _>(rng),
+            LocatorKind::ProgramID => sample::<<N as Network>::ProgramID, _, _>(rng),
+            LocatorKind::TransitionID => sample::<<N as Network>::TransitionID, _, _>(rng),
+            LocatorKind::TransactionID => sample::<<N as Network>::TransactionID, _, _>(rng),
+            LocatorKind::Commitment => sample::<<N as Network>::Commitment, _, _>(rng),
+            LocatorKind::FunctionInputsHash => sample::<<N as Network>::FunctionInputsHash, _, _>(rng),
+            LocatorKind::FunctionID => sample::<<N as Network>::FunctionID, _, _>(rng),
+            LocatorKind::HeaderNonce => sample::<<N as Network>::PoSWNonce, _, _>(rng),
+            LocatorKind::HeaderRoot => sample::<<N as Network>::BlockHeaderRoot, _, _>(rng),
+            LocatorKind::HeaderTransactionsRoot => sample::<<N as Network>::TransactionsRoot, _, _>(rng),
+            LocatorKind::InnerCircuitID => sample::<<N as Network>::InnerCircuitID, _, _>(rng),
+            LocatorKind::RecordRandomizer => sample::<<N as Network>::RecordRandomizer, _, _>(rng),
+            LocatorKind::RecordViewKeyCommitment => sample::<<N as Network>::RecordViewKeyCommitment, _, _>(rng),
+            LocatorKind::SerialNumber => sample::<<N as Network>::SerialNumber, _, _>(rng),
+        }
+    }
+
+    #[test]
+    fn test_prefixes_are_unique() {
+        let prefixes = LocatorKind::ALL
+            .iter()
+            .map(|kind| kind.prefix::<N>())
+            .collect::<HashSet<_>>();
+        assert_eq!(LocatorKind::ALL.len(), prefixes.len());
+
+        for kind in LocatorKind::ALL {
+            assert_eq!(Some(kind), LocatorKind::from_prefix::<N>(&kind.prefix::<N>()));
+        }
+        assert_eq!(None, LocatorKind::from_prefix::<N>("aleo"));
+    }
+
+    #[test]
+    fn test_parse_any() {
+        let rng = &mut thread_rng();
+
+        for kind in LocatorKind::ALL {
+            let (locator, bytes) = sample(kind, rng);
+            assert!(locator.starts_with(&format!("{}1", kind.prefix::<N>())));
+            assert_eq!((kind, bytes.clone()), LocatorKind::parse_any::<N>(&locator).unwrap());
+
+            // Ensure an uppercase locator is accepted, and a locator in mixed case is rejected.
+            assert_eq!(
+                (kind, bytes),
+                LocatorKind::parse_any::<N>(&locator.to_uppercase()).unwrap()
+            );
+            let mixed_case = format!("{}{}", &locator[..3], locator[3..].to_uppercase());
+            assert!(LocatorKind::parse_any::<N>(&mixed_case).is_err());
+        }
+
+        // Ensure unknown prefixes and other objects are rejected.
+        let address = crate::Address::<N>::from_private_key(&crate::PrivateKey::new(rng));
+        assert!(matches!(
+            LocatorKind::parse_any::<N>(&address.to_string()),
+            Err(Bech32mError::UnknownPrefix(prefix)) if prefix == "aleo"
+        ));
+        assert!(LocatorKind::parse_any::<N>("").is_err());
+    }
+
+    #[test]
+    fn test_wrong_prefix_names_expected_prefix() {
+        let rng = &mut thread_rng();
+
+        for kind in LocatorKind::ALL {
+            let (locator, _) = sample(kind, rng);
+            let expected = LocatorKind::TransactionID.prefix::<N>();
+            match <N as Network>::TransactionID::from_str(&locator) {
+                Ok(_) => assert_eq!(LocatorKind::TransactionID, kind),
+                Err(Bech32mError::UnexpectedPrefix(found, candidate_expected)) => {
+                    assert_eq!(kind.prefix::<N>(), found);
+                    assert_eq!(expected, candidate_expected);
+                }
+                // Locators of a different data size are rejected by their length.
+                Err(Bech32mError::InvalidCharacterLength(..)) => assert_ne!(LocatorKind::TransactionID, kind),
+                Err(error) => panic!("Unexpected error for {:?}: {}", kind, error),
+            }
+        }
+
+        let (transition_id, _) = sample(LocatorKind::TransitionID, rng);
+        let error = <N as Network>::TransactionID::from_str(&transition_id).unwrap_err();
+        assert_eq!("unexpected prefix \"as\", expected \"at\"", error.to_string());
+    }
+}
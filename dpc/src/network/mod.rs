@@ -14,15 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod consensus;
+pub use consensus::*;
+
 pub mod locator;
 pub use locator::*;
 
+pub mod locator_kind;
+pub use locator_kind::*;
+
 #[macro_use]
 pub mod macros;
 
 pub mod object;
 pub use object::*;
 
+pub mod registry;
+pub use registry::*;
+
 #[cfg(feature = "testnet1")]
 pub mod testnet1;
 
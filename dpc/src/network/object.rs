@@ -107,7 +107,7 @@ impl<
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let (hrp, data, variant) = bech32::decode(string)?;
         if hrp != Self::prefix() {
-            return Err(Bech32mError::InvalidPrefix(hrp));
+            return Err(Bech32mError::UnexpectedPrefix(hrp, Self::prefix()));
         };
         if data.is_empty() {
             return Err(Bech32mError::InvalidByteLength(0));
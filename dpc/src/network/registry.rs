@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+
+use once_cell::sync::Lazy;
+use std::fmt;
+
+/// A runtime description of a network, for tools that select a network by its ID.
+#[derive(Copy, Clone)]
+pub struct NetworkParameters {
+    /// The ID of the network.
+    pub network_id: u16,
+    /// The name of the network.
+    pub network_name: &'static str,
+    /// The targeted time between blocks.
+    pub block_time_in_secs: i64,
+    /// The number of credits issued in the genesis block.
+    pub starting_supply_in_credits: i64,
+    /// The maximum future block time.
+    pub future_time_limit_in_secs: i64,
+    /// The maximum number of blocks that a fork can be.
+    pub maximum_fork_depth: u32,
+    /// The block reward for each block after the genesis block, before any halving.
+    pub initial_block_reward_in_credits: i64,
+    /// The number of years between halvings of the block reward.
+    pub block_reward_halving_interval_in_years: u32,
+    /// The maximum number of times the block reward halves.
+    pub maximum_block_reward_halvings: u32,
+    /// Loads the genesis block of the network, and returns its block hash.
+    genesis_block_hash: fn() -> String,
+}
+
+impl NetworkParameters {
+    /// Returns the parameters of the given network.
+    pub fn of<N: Network>() -> Self {
+        let constants = N::consensus_constants();
+        Self {
+            network_id: N::NETWORK_ID,
+            network_name: N::NETWORK_NAME,
+            block_time_in_secs: constants.block_time_in_secs,
+            starting_supply_in_credits: constants.starting_supply_in_credits,
+            future_time_limit_in_secs: constants.future_time_limit_in_secs,
+            maximum_fork_depth: constants.maximum_fork_depth,
+            initial_block_reward_in_credits: constants.initial_block_reward_in_credits,
+            block_reward_halving_interval_in_years: constants.block_reward_halving_interval_in_years,
+            maximum_block_reward_halvings: constants.maximum_block_reward_halvings,
+            genesis_block_hash: || N::genesis_block().hash().to_string(),
+        }
+    }
+
+    /// Returns the block hash of the genesis block, loading the genesis block if necessary.
+    pub fn genesis_block_hash(&self) -> String {
+        (self.genesis_block_hash)()
+    }
+
+    /// Returns the parameters of the registered network with the given ID, if it exists.
+    pub fn from_id(network_id: u16) -> Option<&'static Self> {
+        NETWORKS.iter().find(|network| network.network_id == network_id)
+    }
+
+    /// Returns the parameters of the registered network with the given name, if it exists.
+    pub fn from_name(network_name: &str) -> Option<&'static Self> {
+        NETWORKS.iter().find(|network| network.network_name == network_name)
+    }
+
+    /// Returns the parameters of every registered network.
+    pub fn all() -> &'static [Self] {
+        &NETWORKS
+    }
+}
+
+impl fmt::Debug for NetworkParameters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("NetworkParameters")
+            .field("network_id", &self.network_id)
+            .field("network_name", &self.network_name)
+            .finish()
+    }
+}
+
+/// The networks enabled in this build.
+static NETWORKS: Lazy<Vec<NetworkParameters>> = Lazy::new(|| {
+    vec![
+        #[cfg(feature = "testnet1")]
+        NetworkParameters::of::<crate::testnet1::Testnet1>(),
+        #[cfg(feature = "testnet2")]
+        NetworkParameters::of::<crate::testnet2::Testnet2>(),
+    ]
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_registry_is_unique() {
+        let ids = NetworkParameters::all()
+            .iter()
+            .map(|network| network.network_id)
+            .collect::<HashSet<_>>();
+        let names = NetworkParameters::all()
+            .iter()
+            .map(|network| network.network_name)
+            .collect::<HashSet<_>>();
+        assert_eq!(NetworkParameters::all().len(), ids.len());
+        assert_eq!(NetworkParameters::all().len(), names.len());
+    }
+
+    #[test]
+    fn test_from_id() {
+        let network = NetworkParameters::from_id(Testnet2::NETWORK_ID).unwrap();
+        assert_eq!(Testnet2::NETWORK_NAME, network.network_name);
+        assert_eq!(Testnet2::ALEO_BLOCK_TIME_IN_SECS, network.block_time_in_secs);
+        assert_eq!(
+            Testnet2::ALEO_STARTING_SUPPLY_IN_CREDITS,
+            network.starting_supply_in_credits
+        );
+        assert_eq!(
+            network.network_id,
+            NetworkParameters::from_name("testnet2").unwrap().network_id
+        );
+
+        assert!(NetworkParameters::from_id(u16::MAX).is_none());
+        assert!(NetworkParameters::from_name("mainnet").is_none());
+    }
+
+    #[test]
+    fn test_genesis_block_hash() {
+        for network in NetworkParameters::all() {
+            let expected = match network.network_id {
+                #[cfg(feature = "testnet1")]
+                1 => crate::testnet1::Testnet1::genesis_block().hash().to_string(),
+                2 => Testnet2::genesis_block().hash().to_string(),
+                id => panic!("Missing genesis block check for network {}", id),
+            };
+            assert_eq!(expected, network.genesis_block_hash());
+        }
+    }
+}
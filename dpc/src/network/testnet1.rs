@@ -19,6 +19,7 @@ use crate::{
     posw::PoSW,
     AleoLocator,
     AleoObject,
+    BalancePublicVariables,
     Block,
     Ciphertext,
     InnerPublicVariables,
@@ -85,6 +86,7 @@ impl Network for Testnet1 {
     const NUM_OUTPUT_RECORDS: usize = 2;
     const NUM_TRANSITIONS: u8 = u8::pow(2, Self::TRANSACTION_TREE_DEPTH as u32);
     const NUM_EVENTS: u16 = 2;
+    const NUM_TRANSACTIONS: u16 = u16::pow(2, Self::HEADER_TRANSACTIONS_TREE_DEPTH as u32);
 
     const BLOCK_HASH_PREFIX: u16 = hrp2!("ab");
     const LEDGER_ROOT_PREFIX: u16 = hrp2!("al");
@@ -134,6 +136,9 @@ impl Network for Testnet1 {
     const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
     const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
     const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const ALEO_INITIAL_BLOCK_REWARD_IN_CREDITS: i64 = 100;
+    const ALEO_BLOCK_REWARD_HALVING_INTERVAL_IN_YEARS: u32 = 3;
+    const ALEO_MAXIMUM_BLOCK_REWARD_HALVINGS: u32 = 2;
 
     type InnerCurve = Bls12_377;
     type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
@@ -162,6 +167,8 @@ impl Network for Testnet1 {
     type ProgramVerifyingKey = <Self::ProgramSNARK as SNARK>::VerifyingKey;
     type ProgramProof = AleoObject<<Self::ProgramSNARK as SNARK>::Proof, { Self::PROGRAM_PROOF_PREFIX }, { Self::PROGRAM_PROOF_SIZE_IN_BYTES }>;
 
+    type BalanceSNARK = Groth16<Self::InnerCurve, BalancePublicVariables<Self>>;
+
     type PoSWSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirAlgebraicSpongeRng<Self::InnerScalarField, Self::OuterScalarField, PoseidonSponge<Self::OuterScalarField, 6, 1>>, MarlinPoswMode, Vec<Self::InnerScalarField>>;
     type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }>;
     type PoSW = PoSW<Self>;
@@ -259,12 +266,26 @@ impl Network for Testnet1 {
     dpc_snark_setup!{Testnet1, outer_proving_key, OuterSNARK, ProvingKey, OuterProvingKeyBytes, "outer proving key"}
     dpc_snark_setup!{Testnet1, outer_verifying_key, OuterSNARK, VerifyingKey, OuterVerifyingKeyBytes, "outer verifying key"}
 
+    fn outer_prepared_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::PreparedVerifyingKey {
+        static PREPARED_VERIFYING_KEY: OnceCell<<<Testnet1 as Network>::OuterSNARK as SNARK>::PreparedVerifyingKey> = OnceCell::new();
+        PREPARED_VERIFYING_KEY.get_or_init(|| Self::outer_verifying_key().prepare())
+    }
+
     dpc_snark_setup!{Testnet1, noop_circuit_proving_key, ProgramSNARK, ProvingKey, NoopProvingKeyBytes, "noop circuit proving key"}
     dpc_snark_setup!{Testnet1, noop_circuit_verifying_key, ProgramSNARK, VerifyingKey, NoopVerifyingKeyBytes, "noop circuit verifying key"}
 
     dpc_snark_setup!{Testnet1, posw_proving_key, PoSWSNARK, ProvingKey, PoSWProvingKeyBytes, "posw proving key"}
     dpc_snark_setup!{Testnet1, posw_verifying_key, PoSWSNARK, VerifyingKey, PoSWVerifyingKeyBytes, "posw verifying key"}
 
+    /// Testnet1 has no trusted setup for the balance circuit, so it does not support minimum balance proofs.
+    fn balance_proving_key() -> Option<&'static <Self::BalanceSNARK as SNARK>::ProvingKey> {
+        None
+    }
+
+    fn balance_verifying_key() -> Option<&'static <Self::BalanceSNARK as SNARK>::VerifyingKey> {
+        None
+    }
+
     fn inner_circuit_id() -> &'static Self::InnerCircuitID {
         static INNER_CIRCUIT_ID: OnceCell<<Testnet1 as Network>::InnerCircuitID> = OnceCell::new();
         INNER_CIRCUIT_ID.get_or_init(|| Self::inner_circuit_id_crh()
@@ -19,6 +19,8 @@ use crate::{
     posw::PoSW,
     AleoLocator,
     AleoObject,
+    BalanceCircuit,
+    BalancePublicVariables,
     Block,
     Ciphertext,
     InnerPublicVariables,
@@ -71,7 +73,7 @@ use snarkvm_polycommit::sonic_pc::{sonic_kzg10::SonicKZG10Gadget, SonicKZG10};
 use snarkvm_utilities::{FromBytes, ToMinimalBits};
 
 use once_cell::sync::OnceCell;
-use rand::{CryptoRng, Rng};
+use rand::{thread_rng, CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use std::{cell::RefCell, rc::Rc};
 
@@ -106,6 +108,7 @@ impl Network for Testnet2 {
     const NUM_OUTPUT_RECORDS: usize = 2;
     const NUM_TRANSITIONS: u8 = u8::pow(2, Self::TRANSACTION_TREE_DEPTH as u32);
     const NUM_EVENTS: u16 = 2;
+    const NUM_TRANSACTIONS: u16 = u16::pow(2, Self::HEADER_TRANSACTIONS_TREE_DEPTH as u32);
 
     const BLOCK_HASH_PREFIX: u16 = hrp2!("ab");
     const LEDGER_ROOT_PREFIX: u16 = hrp2!("al");
@@ -155,6 +158,9 @@ impl Network for Testnet2 {
     const ALEO_STARTING_SUPPLY_IN_CREDITS: i64 = 1_000_000_000;
     const ALEO_FUTURE_TIME_LIMIT_IN_SECS: i64 = 90;
     const ALEO_MAXIMUM_FORK_DEPTH: u32 = 4096;
+    const ALEO_INITIAL_BLOCK_REWARD_IN_CREDITS: i64 = 100;
+    const ALEO_BLOCK_REWARD_HALVING_INTERVAL_IN_YEARS: u32 = 3;
+    const ALEO_MAXIMUM_BLOCK_REWARD_HALVINGS: u32 = 2;
 
     type InnerCurve = Bls12_377;
     type InnerScalarField = <Self::InnerCurve as PairingEngine>::Fr;
@@ -183,6 +189,8 @@ impl Network for Testnet2 {
     type ProgramVerifyingKey = <Self::ProgramSNARK as SNARK>::VerifyingKey;
     type ProgramProof = AleoObject<<Self::ProgramSNARK as SNARK>::Proof, { Self::PROGRAM_PROOF_PREFIX }, { Self::PROGRAM_PROOF_SIZE_IN_BYTES }>;
 
+    type BalanceSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirAlgebraicSpongeRng<Self::InnerScalarField, Self::OuterScalarField, PoseidonSponge<Self::OuterScalarField, 6, 1>>, MarlinTestnet2Mode, BalancePublicVariables<Self>>;
+
     type PoSWSNARK = MarlinSNARK<Self::InnerScalarField, Self::OuterScalarField, SonicKZG10<Self::InnerCurve>, FiatShamirChaChaRng<Self::InnerScalarField, Self::OuterScalarField, Blake2s>, MarlinPoswMode, Vec<Self::InnerScalarField>>;
     type PoSWProof = AleoObject<<Self::PoSWSNARK as SNARK>::Proof, { Self::HEADER_PROOF_PREFIX }, { Self::HEADER_PROOF_SIZE_IN_BYTES }>;
     type PoSW = PoSW<Self>;
@@ -280,12 +288,28 @@ impl Network for Testnet2 {
     dpc_snark_setup!{Testnet2, outer_proving_key, OuterSNARK, ProvingKey, OuterProvingKeyBytes, "outer circuit proving key"}
     dpc_snark_setup!{Testnet2, outer_verifying_key, OuterSNARK, VerifyingKey, OuterVerifyingKeyBytes, "outer circuit verifying key"}
 
+    fn outer_prepared_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::PreparedVerifyingKey {
+        static PREPARED_VERIFYING_KEY: OnceCell<<<Testnet2 as Network>::OuterSNARK as SNARK>::PreparedVerifyingKey> = OnceCell::new();
+        PREPARED_VERIFYING_KEY.get_or_init(|| Self::outer_verifying_key().prepare())
+    }
+
     dpc_snark_setup!{Testnet2, noop_circuit_proving_key, ProgramSNARK, ProvingKey, NoopProvingKeyBytes, "noop circuit proving key"}
     dpc_snark_setup!{Testnet2, noop_circuit_verifying_key, ProgramSNARK, VerifyingKey, NoopVerifyingKeyBytes, "noop circuit verifying key"}
 
     dpc_snark_setup!{Testnet2, posw_proving_key, PoSWSNARK, ProvingKey, PoSWProvingKeyBytes, "posw proving key"}
     dpc_snark_setup!{Testnet2, posw_verifying_key, PoSWSNARK, VerifyingKey, PoSWVerifyingKeyBytes, "posw verifying key"}
 
+    /// The balance circuit keys are derived from the universal SRS, so they do not depend on the given RNG.
+    fn balance_proving_key() -> Option<&'static <Self::BalanceSNARK as SNARK>::ProvingKey> {
+        static BALANCE_PROVING_KEY: OnceCell<<<Testnet2 as Network>::BalanceSNARK as SNARK>::ProvingKey> = OnceCell::new();
+        Some(BALANCE_PROVING_KEY.get_or_init(|| <Self::BalanceSNARK as SNARK>::setup(&BalanceCircuit::<Self>::blank(), &mut *Self::program_srs(&mut thread_rng()).borrow_mut())
+            .expect("Failed to derive the balance circuit keys").0))
+    }
+
+    fn balance_verifying_key() -> Option<&'static <Self::BalanceSNARK as SNARK>::VerifyingKey> {
+        Self::balance_proving_key().map(|proving_key| &proving_key.circuit_verifying_key)
+    }
+
     fn inner_circuit_id() -> &'static Self::InnerCircuitID {
         static INNER_CIRCUIT_ID: OnceCell<<Testnet2 as Network>::InnerCircuitID> = OnceCell::new();
         INNER_CIRCUIT_ID.get_or_init(|| Self::inner_circuit_id_crh()
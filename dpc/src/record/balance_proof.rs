@@ -0,0 +1,100 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{AleoAmount, BalanceCircuit, BalancePublicVariables, Network, Record, ViewKey};
+use snarkvm_algorithms::traits::SNARK;
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use rand::{CryptoRng, Rng};
+use std::io::{Read, Result as IoResult, Write};
+
+/// A proof that the record behind a commitment holds at least a minimum value.
+///
+/// The proof reveals neither the value nor the owner of the record.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct BalanceProof<N: Network> {
+    proof: <N::BalanceSNARK as SNARK>::Proof,
+}
+
+impl<N: Network> BalanceProof<N> {
+    ///
+    /// Returns a proof that the given record, owned by the given view key, holds at least the given minimum value.
+    ///
+    pub(crate) fn prove<R: Rng + CryptoRng>(
+        record: &Record<N>,
+        view_key: &ViewKey<N>,
+        minimum: AleoAmount,
+        rng: &mut R,
+    ) -> Result<Self> {
+        let proving_key = N::balance_proving_key()
+            .ok_or_else(|| anyhow!("Minimum balance proofs are not supported on {}", N::NETWORK_NAME))?;
+        if minimum.is_negative() {
+            return Err(anyhow!("The minimum value must be non-negative, found {}", minimum));
+        }
+        if record.value() < minimum {
+            return Err(anyhow!("The record value is less than the minimum of {}", minimum));
+        }
+
+        let public = BalancePublicVariables::new(record.commitment(), minimum);
+        let circuit = BalanceCircuit::new(public, record.clone(), view_key.clone());
+        let proof = <N::BalanceSNARK as SNARK>::prove(proving_key, &circuit, rng)?;
+
+        Ok(Self { proof })
+    }
+
+    ///
+    /// Returns `true` if the proof attests that the record with the given commitment
+    /// holds at least the given minimum value.
+    ///
+    pub fn verify(&self, commitment: &N::Commitment, minimum: AleoAmount) -> bool {
+        let verifying_key = match N::balance_verifying_key() {
+            Some(verifying_key) => verifying_key,
+            None => return false,
+        };
+
+        let public = BalancePublicVariables::<N>::new(*commitment, minimum);
+        match <N::BalanceSNARK as SNARK>::verify(verifying_key, &public, &self.proof) {
+            Ok(is_valid) => is_valid,
+            Err(error) => {
+                eprintln!("Failed to verify balance proof: {:?}", error);
+                false
+            }
+        }
+    }
+}
+
+impl<N: Network> FromBytes for BalanceProof<N> {
+    #[inline]
+    fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
+        Ok(Self {
+            proof: FromBytes::read_le(&mut reader)?,
+        })
+    }
+}
+
+impl<N: Network> ToBytes for BalanceProof<N> {
+    #[inline]
+    fn write_le<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        self.proof.write_le(&mut writer)
+    }
+}
@@ -26,6 +26,7 @@ use snarkvm_utilities::{
 };
 
 use anyhow::Result;
+use zeroize::Zeroize;
 
 #[derive(Derivative)]
 #[derivative(
@@ -50,9 +51,7 @@ impl<N: Network> Ciphertext<N> {
         record_bytes: Vec<u8>,
     ) -> Result<Self, RecordError> {
         // Compute the commitment.
-        let commitment = N::commitment_scheme()
-            .hash(&to_bytes_le![randomizer, record_view_key_commitment, record_bytes]?)?
-            .into();
+        let commitment = Self::compute_commitment(&randomizer, &record_view_key_commitment, &record_bytes)?;
 
         Ok(Self {
             commitment,
@@ -62,10 +61,27 @@ impl<N: Network> Ciphertext<N> {
         })
     }
 
+    ///
+    /// Returns the record commitment for the given ciphertext components, as
+    /// `CM := H(randomizer || record_view_key_commitment || record_bytes)`.
+    ///
+    /// This is the commitment the inner circuit recomputes for every input and output record,
+    /// so it can be checked against the little-endian bytes of a ciphertext without a proof.
+    ///
+    pub fn compute_commitment(
+        randomizer: &N::RecordRandomizer,
+        record_view_key_commitment: &N::RecordViewKeyCommitment,
+        record_bytes: &[u8],
+    ) -> Result<N::Commitment, RecordError> {
+        Ok(N::commitment_scheme()
+            .hash(&to_bytes_le![randomizer, record_view_key_commitment, record_bytes]?)?
+            .into())
+    }
+
     /// Returns `true` if this ciphertext belongs to the given account view key.
     pub fn is_owner(&self, account_view_key: &ViewKey<N>) -> bool {
         // Compute the record view key.
-        let candidate_record_view_key =
+        let mut candidate_record_view_key =
             match N::account_encryption_scheme().generate_symmetric_key(account_view_key, *self.randomizer) {
                 Some(symmetric_key) => symmetric_key,
                 None => return false,
@@ -74,6 +90,7 @@ impl<N: Network> Ciphertext<N> {
         // Compute the record view key commitment.
         let candidate_record_view_key_commitment =
             N::account_encryption_scheme().generate_symmetric_key_commitment(&candidate_record_view_key);
+        candidate_record_view_key.zeroize();
 
         // Check if the computed record view key commitment matches.
         *self.record_view_key_commitment == candidate_record_view_key_commitment
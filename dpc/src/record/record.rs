@@ -17,6 +17,7 @@
 use crate::{
     Address,
     AleoAmount,
+    BalanceProof,
     Bech32Locator,
     Ciphertext,
     ComputeKey,
@@ -26,9 +27,8 @@ use crate::{
     RecordError,
     ViewKey,
 };
-use snarkvm_algorithms::traits::{EncryptionScheme, PRF};
-use snarkvm_fields::PrimeField;
-use snarkvm_utilities::{to_bytes_le, FromBits, FromBytes, FromBytesDeserializer, ToBits, ToBytes, ToBytesSerializer};
+use snarkvm_algorithms::traits::EncryptionScheme;
+use snarkvm_utilities::{to_bytes_le, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::anyhow;
 use rand::{CryptoRng, Rng};
@@ -233,17 +233,27 @@ impl<N: Network> Record<N> {
             return Err(RecordError::IncorrectComputeKey);
         }
 
-        // Compute the serial number.
-        // First, convert the program scalar field element to bytes,
-        // and interpret these bytes as a program base field element
-        // For our choice of scalar field and base field (i.e., on TE curves)
-        // scalar field is always smaller than base field, so the bytes always fit without
-        // wraparound.
-        let seed = N::InnerScalarField::from_repr(FromBits::from_bits_le(&compute_key.sk_prf().to_bits_le())).unwrap();
-        let input = self.commitment();
-        let serial_number = N::SerialNumberPRF::evaluate(&seed, &input.into())?.into();
-
-        Ok(serial_number)
+        Ok(compute_key.to_serial_number(&self.commitment())?)
+    }
+
+    /// Returns a proof that the record holds at least the given minimum value, given the view key
+    /// corresponding to the record owner. A private key may be used via `ViewKey::from(&private_key)`.
+    ///
+    /// The proof is bound to the record commitment, and reveals neither the record value nor the owner.
+    /// The circuit enforces that the view key derives the record owner. Networks without balance
+    /// circuit keys return an error.
+    pub fn prove_minimum_balance<R: Rng + CryptoRng>(
+        &self,
+        view_key: &ViewKey<N>,
+        minimum: AleoAmount,
+        rng: &mut R,
+    ) -> Result<BalanceProof<N>, RecordError> {
+        // Check that the view key corresponds with the owner of the record, before proving.
+        if self.owner != Address::<N>::from_view_key(view_key) {
+            return Err(RecordError::IncorrectViewKey);
+        }
+
+        Ok(BalanceProof::prove(self, view_key, minimum, rng)?)
     }
 
     /// Encode the record contents into plaintext bytes.
@@ -14,10 +14,24 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{testnet2::*, Account, AccountScheme, AleoAmount, Network, Payload, Record, ViewKey};
+use crate::{
+    testnet2::*,
+    Account,
+    AccountScheme,
+    AleoAmount,
+    BalanceCircuit,
+    BalancePublicVariables,
+    Network,
+    Payload,
+    Record,
+    RecordError,
+    ViewKey,
+};
+use snarkvm_algorithms::SNARK;
+use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
-use rand::{Rng, SeedableRng};
+use rand::{CryptoRng, Rng, SeedableRng};
 use rand_chacha::ChaChaRng;
 
 pub(crate) const ITERATIONS: usize = 25;
@@ -55,3 +69,182 @@ fn test_record_ciphertext() {
         assert_eq!(expected_record, candidate_record);
     }
 }
+
+#[test]
+fn test_balance_proof_exact_minimum() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+    let account = Account::<Testnet2>::new(rng);
+    let view_key = ViewKey::from_private_key(account.private_key());
+    let value = AleoAmount::from_i64(1_000);
+    let record = Record::new(
+        account.address(),
+        value,
+        Default::default(),
+        *Testnet2::noop_program_id(),
+        rng,
+    )
+    .unwrap();
+
+    // Prove the record holds exactly the minimum.
+    let proof = record.prove_minimum_balance(&view_key, value, rng).unwrap();
+    assert!(proof.verify(&record.commitment(), value));
+    assert!(!proof.verify(&record.commitment(), AleoAmount::from_i64(999)));
+    assert!(!proof.verify(&record.commitment(), AleoAmount::from_i64(1_001)));
+}
+
+#[test]
+fn test_balance_proof_insufficient_value() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+    let account = Account::<Testnet2>::new(rng);
+    let view_key = ViewKey::from_private_key(account.private_key());
+    let value = AleoAmount::from_i64(1_000);
+    let record = Record::new(
+        account.address(),
+        value,
+        Default::default(),
+        *Testnet2::noop_program_id(),
+        rng,
+    )
+    .unwrap();
+
+    // The prover refuses to prove a minimum above the record value.
+    let minimum = AleoAmount::from_i64(1_001);
+    assert!(record.prove_minimum_balance(&view_key, minimum, rng).is_err());
+
+    // The circuit is unsatisfied for a minimum above the record value.
+    let public = BalancePublicVariables::new(record.commitment(), minimum);
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    BalanceCircuit::new(public, record.clone(), view_key.clone())
+        .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+        .unwrap();
+    assert!(!cs.is_satisfied());
+
+    // The circuit is satisfied for a minimum at the record value.
+    let public = BalancePublicVariables::new(record.commitment(), value);
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    BalanceCircuit::new(public, record, view_key)
+        .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+        .unwrap();
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_balance_proof_other_record() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+    let account = Account::<Testnet2>::new(rng);
+    let view_key = ViewKey::from_private_key(account.private_key());
+    let record = Record::new(
+        account.address(),
+        AleoAmount::from_i64(1_000),
+        Default::default(),
+        *Testnet2::noop_program_id(),
+        rng,
+    )
+    .unwrap();
+
+    let other_account = Account::<Testnet2>::new(rng);
+    let other_view_key = ViewKey::from_private_key(other_account.private_key());
+    let other_record = Record::new(
+        other_account.address(),
+        AleoAmount::from_i64(1_000),
+        Default::default(),
+        *Testnet2::noop_program_id(),
+        rng,
+    )
+    .unwrap();
+
+    // A view key cannot prove the balance of a record it does not own.
+    let minimum = AleoAmount::from_i64(500);
+    assert!(matches!(
+        other_record.prove_minimum_balance(&view_key, minimum, rng),
+        Err(RecordError::IncorrectViewKey)
+    ));
+
+    // A proof for one record does not verify against the commitment of another record.
+    let proof = record.prove_minimum_balance(&view_key, minimum, rng).unwrap();
+    assert!(proof.verify(&record.commitment(), minimum));
+    assert!(!proof.verify(&other_record.commitment(), minimum));
+
+    // The circuit is unsatisfied when the record does not open the given commitment.
+    let public = BalancePublicVariables::new(other_record.commitment(), minimum);
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    BalanceCircuit::new(public, record, view_key.clone())
+        .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+        .unwrap();
+    assert!(!cs.is_satisfied());
+
+    let proof = other_record
+        .prove_minimum_balance(&other_view_key, minimum, rng)
+        .unwrap();
+    assert!(proof.verify(&other_record.commitment(), minimum));
+}
+
+/// Returns a record owned by a new account, the view key of its owner, and the view key of another account.
+fn sample_record_and_view_keys<R: Rng + CryptoRng>(rng: &mut R) -> (Record<Testnet2>, ViewKey<Testnet2>, ViewKey<Testnet2>) {
+    let account = Account::<Testnet2>::new(rng);
+    let record = Record::new(
+        account.address(),
+        AleoAmount::from_i64(1_000),
+        Default::default(),
+        *Testnet2::noop_program_id(),
+        rng,
+    )
+    .unwrap();
+
+    let other_account = Account::<Testnet2>::new(rng);
+    (
+        record,
+        ViewKey::from_private_key(account.private_key()),
+        ViewKey::from_private_key(other_account.private_key()),
+    )
+}
+
+#[test]
+fn test_balance_circuit_non_owner() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+    let (record, view_key, other_view_key) = sample_record_and_view_keys(rng);
+    let public = BalancePublicVariables::new(record.commitment(), AleoAmount::from_i64(500));
+
+    // A non-owner builds the circuit directly, bypassing the ownership check of the prover.
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    BalanceCircuit::new(public, record.clone(), other_view_key)
+        .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+        .unwrap();
+    assert!(!cs.is_satisfied());
+    assert!(
+        cs.which_is_unsatisfied()
+            .unwrap()
+            .starts_with("Balance circuit/Check that the view key derives the record owner/")
+    );
+
+    // The circuit is satisfied for the owner.
+    let mut cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+    BalanceCircuit::new(public, record, view_key)
+        .generate_constraints(&mut cs.ns(|| "Balance circuit"))
+        .unwrap();
+    assert!(cs.is_satisfied());
+}
+
+#[test]
+fn test_balance_proof_non_owner() {
+    let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+    let (record, _, other_view_key) = sample_record_and_view_keys(rng);
+    let public = BalancePublicVariables::new(record.commitment(), AleoAmount::from_i64(500));
+
+    // A non-owner proves with the circuit directly, and the proof does not verify.
+    let circuit = BalanceCircuit::new(public, record, other_view_key);
+    let proving_key = Testnet2::balance_proving_key().unwrap();
+    let verifying_key = Testnet2::balance_verifying_key().unwrap();
+    if let Ok(proof) = <Testnet2 as Network>::BalanceSNARK::prove(proving_key, &circuit, rng) {
+        assert!(!<Testnet2 as Network>::BalanceSNARK::verify(verifying_key, &public, &proof).unwrap_or(false));
+    }
+}
+
+#[test]
+fn test_balance_proof_unsupported_on_testnet1() {
+    assert!(crate::testnet1::Testnet1::balance_proving_key().is_none());
+    assert!(crate::testnet1::Testnet1::balance_verifying_key().is_none());
+}
@@ -0,0 +1,471 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Generators of objects with randomized contents, for fuzzing the deserializers and the verifier.
+//! Each generator documents whether its objects parse and verify.
+
+use crate::prelude::*;
+use snarkvm_algorithms::SNARK;
+use snarkvm_utilities::UniformRand;
+
+use anyhow::Result;
+use rand::{CryptoRng, Rng};
+use std::sync::atomic::AtomicBool;
+
+/// A value that can be simplified toward a minimal case.
+pub trait Shrink: Sized {
+    /// Returns simpler candidates for `self`, in order of preference.
+    fn shrink(&self) -> Result<Vec<Self>>;
+}
+
+/// Returns a minimal value for which the given property fails, by repeatedly
+/// replacing the value with its first simpler candidate that still fails.
+pub fn minimize<T: Shrink>(mut value: T, fails: impl Fn(&T) -> bool) -> Result<T> {
+    'shrink: loop {
+        for candidate in value.shrink()? {
+            if fails(&candidate) {
+                value = candidate;
+                continue 'shrink;
+            }
+        }
+        return Ok(value);
+    }
+}
+
+/// Returns a boundary or random amount.
+pub fn sample_amount<R: Rng + CryptoRng>(rng: &mut R) -> AleoAmount {
+    match rng.gen_range(0..6) {
+        0 => AleoAmount::ZERO,
+        1 => AleoAmount(1),
+        2 => AleoAmount(-1),
+        3 => AleoAmount(i64::MIN),
+        4 => AleoAmount(i64::MAX),
+        _ => AleoAmount(rng.gen()),
+    }
+}
+
+/// Returns a random event, with boundary sizes for custom events and unchecked record indices.
+pub fn sample_event<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Event<N>> {
+    let caller = Address::from_private_key(&PrivateKey::new(rng));
+    let recipient = Address::from_private_key(&PrivateKey::new(rng));
+
+    Ok(match rng.gen_range(0..6) {
+        0 => Event::Custom(vec![]),
+        1 => Event::Custom((0..rng.gen_range(1..=u8::MAX as usize)).map(|_| rng.gen()).collect()),
+        2 => {
+            let record = Record::<N>::new_noop(recipient, rng)?;
+            Event::RecordViewKey(rng.gen(), record.record_view_key().clone())
+        }
+        3 => Event::Operation(Operation::Noop),
+        4 => match rng.gen() {
+            true => Event::Operation(Operation::Coinbase(recipient, sample_amount(rng))),
+            false => Event::Operation(Operation::Transfer(caller, recipient, sample_amount(rng))),
+        },
+        _ => Event::Operation(Operation::Evaluate(
+            *N::noop_function_id(),
+            FunctionType::from_id(rng.gen_range(0..=8))?,
+            FunctionInputs::new(&caller, &recipient, sample_amount(rng), Default::default()),
+        )),
+    })
+}
+
+/// Returns a random transition, with boundary value balances and numbers of events.
+/// The transition proof is the default proof, so the transition does not verify.
+pub fn sample_transition<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Transition<N>>
+where
+    <N::OuterSNARK as SNARK>::Proof: Default,
+{
+    let serial_numbers: Vec<N::SerialNumber> = (0..N::NUM_INPUT_RECORDS).map(|_| UniformRand::rand(rng)).collect();
+
+    let mut ciphertexts = Vec::with_capacity(N::NUM_OUTPUT_RECORDS);
+    for _ in 0..N::NUM_OUTPUT_RECORDS {
+        let owner = Address::from_private_key(&PrivateKey::new(rng));
+        let record: Record<N> = match rng.gen() {
+            true => Record::new_noop(owner, rng)?,
+            false => Record::new(
+                owner,
+                AleoAmount(rng.gen_range(0..=i64::MAX)),
+                Default::default(),
+                *N::noop_program_id(),
+                rng,
+            )?,
+        };
+        ciphertexts.push(record.ciphertext().clone());
+    }
+
+    let num_events = match rng.gen_range(0..3) {
+        0 => 0,
+        1 => N::NUM_EVENTS,
+        _ => rng.gen_range(0..=N::NUM_EVENTS),
+    };
+    let events = (0..num_events).map(|_| sample_event(rng)).collect::<Result<Vec<_>>>()?;
+
+    let commitments: Vec<_> = ciphertexts.iter().map(|ciphertext| ciphertext.commitment()).collect();
+    Transition::from(
+        Transition::<N>::compute_transition_id(&serial_numbers, &commitments)?,
+        serial_numbers,
+        ciphertexts,
+        sample_amount(rng),
+        events,
+        N::OuterProof::from(Default::default()),
+    )
+}
+
+/// Returns a transaction with one random transition that has the given value balance and no events.
+/// The transition proof is the default proof, so the transaction does not verify.
+pub fn sample_transaction_with_value_balance<N: Network, R: Rng + CryptoRng>(
+    value_balance: AleoAmount,
+    rng: &mut R,
+) -> Result<Transaction<N>>
+where
+    <N::OuterSNARK as SNARK>::Proof: Default,
+{
+    let transition = sample_transition::<N, R>(rng)?;
+    let transition = Transition::from(
+        transition.transition_id(),
+        transition.serial_numbers().cloned().collect(),
+        transition.ciphertexts().cloned().collect(),
+        value_balance,
+        vec![],
+        transition.proof().clone(),
+    )?;
+
+    Transaction::from_unchecked(UniformRand::rand(rng), UniformRand::rand(rng), vec![transition])
+}
+
+/// Returns a random transaction of one or two coinbase transitions, with boundary and random amounts.
+/// The transitions are proven, so the transaction parses and verifies.
+pub fn sample_transaction<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Transaction<N>> {
+    let mut vm = VirtualMachine::<N>::new(LedgerTree::<N>::new()?.root())?;
+    for _ in 0..rng.gen_range(1..=2) {
+        let recipient = Address::from_private_key(&PrivateKey::new(rng));
+        let amount = match rng.gen_range(0..3) {
+            0 => AleoAmount::ZERO,
+            1 => AleoAmount(1),
+            _ => AleoAmount(rng.gen_range(0..=i64::MAX / 2)),
+        };
+        let request = Request::new_coinbase(recipient, amount, rng.gen(), rng)?;
+        vm = vm.execute(&request, rng)?.0;
+    }
+    vm.finalize()
+}
+
+/// Returns a random transaction, with boundary numbers of transitions and random roots.
+/// The transaction is not checked for validity, so it does not verify. As deserialization
+/// verifies the transaction, its bytes do not parse either.
+pub fn sample_transaction_unchecked<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Transaction<N>>
+where
+    <N::OuterSNARK as SNARK>::Proof: Default,
+{
+    let num_transitions = match rng.gen_range(0..3) {
+        0 => 1,
+        1 => N::NUM_TRANSITIONS - 1,
+        _ => rng.gen_range(1..N::NUM_TRANSITIONS),
+    };
+    let transitions = (0..num_transitions)
+        .map(|_| sample_transition(rng))
+        .collect::<Result<Vec<_>>>()?;
+
+    Transaction::from_unchecked(UniformRand::rand(rng), UniformRand::rand(rng), transitions)
+}
+
+/// Returns a random block template past the genesis block, with boundary heights and timestamps.
+/// The difficulty target is the maximum, so any nonce is a solution.
+fn sample_block_template<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<BlockTemplate<N>> {
+    let block_height = match rng.gen_range(0..3) {
+        0 => 1,
+        1 => u32::MAX,
+        _ => rng.gen_range(1..u32::MAX),
+    };
+    let block_timestamp = match rng.gen_range(0..3) {
+        0 => 1,
+        1 => i64::MAX,
+        _ => rng.gen_range(1..i64::MAX),
+    };
+
+    let recipient = Address::from_private_key(&PrivateKey::new(rng));
+    let (transaction, coinbase_record) =
+        Transaction::new_coinbase(recipient, Block::<N>::block_reward(block_height), true, rng)?;
+
+    Ok(BlockTemplate::new(
+        UniformRand::rand(rng),
+        block_height,
+        block_timestamp,
+        u64::MAX,
+        rng.gen(),
+        UniformRand::rand(rng),
+        Transactions::from(&[transaction])?,
+        coinbase_record,
+    ))
+}
+
+/// Returns a random block header, mined from a random block template.
+/// The header parses and verifies.
+pub fn sample_block_header<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<BlockHeader<N>> {
+    BlockHeader::mine(&sample_block_template(rng)?, &AtomicBool::new(false), rng)
+}
+
+/// Returns a random block with a single coinbase transaction, mined from a random block template.
+/// The block parses and verifies.
+pub fn sample_block<N: Network, R: Rng + CryptoRng>(rng: &mut R) -> Result<Block<N>> {
+    Block::mine(&sample_block_template(rng)?, &AtomicBool::new(false), rng)
+}
+
+impl<N: Network> Shrink for Transition<N> {
+    fn shrink(&self) -> Result<Vec<Self>> {
+        let events: Vec<_> = self.events().cloned().collect();
+
+        // Shrink toward no events, then fewer events, then empty custom events.
+        let mut candidates_events = Vec::new();
+        if !events.is_empty() {
+            candidates_events.push(vec![]);
+        }
+        if events.len() > 1 {
+            for index in 0..events.len() {
+                let mut candidate = events.clone();
+                candidate.remove(index);
+                candidates_events.push(candidate);
+            }
+        }
+        for (index, event) in events.iter().enumerate() {
+            if let Event::Custom(bytes) = event {
+                if !bytes.is_empty() {
+                    let mut candidate = events.clone();
+                    candidate[index] = Event::Custom(vec![]);
+                    candidates_events.push(candidate);
+                }
+            }
+        }
+
+        let with = |value_balance: AleoAmount, events: Vec<Event<N>>| {
+            Transition::from(
+                self.transition_id(),
+                self.serial_numbers().cloned().collect(),
+                self.ciphertexts().cloned().collect(),
+                value_balance,
+                events,
+                self.proof().clone(),
+            )
+        };
+
+        let mut candidates = Vec::new();
+        for events in candidates_events {
+            candidates.push(with(*self.value_balance(), events)?);
+        }
+        // Shrink toward a zero value balance.
+        if *self.value_balance() != AleoAmount::ZERO {
+            candidates.push(with(AleoAmount::ZERO, events)?);
+        }
+        Ok(candidates)
+    }
+}
+
+impl<N: Network> Shrink for Transaction<N> {
+    fn shrink(&self) -> Result<Vec<Self>> {
+        let transitions = self.transitions();
+        let with = |transitions: Vec<Transition<N>>| {
+            Transaction::from_unchecked(self.inner_circuit_id(), self.ledger_root(), transitions)
+        };
+
+        let mut candidates = Vec::new();
+        // Shrink toward fewer transitions.
+        if transitions.len() > 1 {
+            for index in 0..transitions.len() {
+                let mut candidate_transitions = transitions.clone();
+                candidate_transitions.remove(index);
+                candidates.push(with(candidate_transitions)?);
+            }
+        }
+        // Shrink each transition in turn.
+        for (index, transition) in transitions.iter().enumerate() {
+            for candidate in transition.shrink()? {
+                let mut candidate_transitions = transitions.clone();
+                candidate_transitions[index] = candidate;
+                candidates.push(with(candidate_transitions)?);
+            }
+        }
+        Ok(candidates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+    use snarkvm_utilities::{FromBytes, ToBytes};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+    use std::{
+        fmt::{Debug, Display},
+        panic::{self, AssertUnwindSafe},
+        str::FromStr,
+    };
+
+    const ITERATIONS: usize = 25;
+
+    /// Returns an RNG seeded from `SNARKVM_TEST_SEED`, or from a random seed otherwise.
+    /// The seed is logged, so a failing run can be reproduced.
+    fn test_rng() -> ChaChaRng {
+        let seed: u64 = match std::env::var("SNARKVM_TEST_SEED") {
+            Ok(seed) => seed.parse().expect("Invalid SNARKVM_TEST_SEED"),
+            Err(_) => rand::random(),
+        };
+        println!("Using the test seed {} (set SNARKVM_TEST_SEED to reproduce)", seed);
+        ChaChaRng::seed_from_u64(seed)
+    }
+
+    /// Checks that the given object round-trips through its byte and string representations.
+    fn check_round_trip<T>(expected: &T)
+    where
+        T: FromBytes + ToBytes + FromStr + Display + Debug + PartialEq,
+        <T as FromStr>::Err: Debug,
+    {
+        let bytes = expected.to_bytes_le().unwrap();
+        assert_eq!(*expected, T::read_le(&bytes[..]).unwrap());
+        assert_eq!(bytes, T::read_le(&bytes[..]).unwrap().to_bytes_le().unwrap());
+
+        let string = expected.to_string();
+        assert_eq!(*expected, T::from_str(&string).unwrap());
+    }
+
+    #[test]
+    fn test_transition_round_trip() {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            check_round_trip(&sample_transition::<Testnet2, _>(rng).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_transaction_round_trip() {
+        let rng = &mut test_rng();
+
+        for _ in 0..2 {
+            let transaction = sample_transaction::<Testnet2, _>(rng).unwrap();
+            assert!(transaction.is_valid());
+            check_round_trip(&transaction);
+        }
+    }
+
+    #[test]
+    fn test_block_header_round_trip() {
+        let rng = &mut test_rng();
+
+        let block_header = sample_block_header::<Testnet2, _>(rng).unwrap();
+        assert!(block_header.is_valid());
+        check_round_trip(&block_header);
+    }
+
+    #[test]
+    fn test_block_round_trip() {
+        let rng = &mut test_rng();
+
+        let block = sample_block::<Testnet2, _>(rng).unwrap();
+        assert!(block.is_valid());
+        check_round_trip(&block);
+    }
+
+    #[test]
+    fn test_transition_read_le_never_panics() {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let bytes = sample_transition::<Testnet2, _>(rng).unwrap().to_bytes_le().unwrap();
+
+            // Corrupt a random byte, and truncate the bytes at a random length.
+            let mut corrupted = bytes.clone();
+            let index = rng.gen_range(0..corrupted.len());
+            corrupted[index] ^= rng.gen_range(1..=u8::MAX);
+            let truncated = &bytes[..rng.gen_range(0..bytes.len())];
+
+            for candidate in [&corrupted[..], truncated] {
+                let result = panic::catch_unwind(|| Transition::<Testnet2>::read_le(candidate).is_ok());
+                assert!(
+                    result.is_ok(),
+                    "Transition::read_le panicked on {}",
+                    hex::encode(candidate)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_transaction_is_valid_never_panics() {
+        let rng = &mut test_rng();
+
+        for _ in 0..ITERATIONS {
+            let transaction = sample_transaction_unchecked::<Testnet2, _>(rng).unwrap();
+            let bytes = transaction.to_bytes_le().unwrap();
+
+            // The generated transactions do not verify, and must be rejected without panicking.
+            let is_valid = panic::catch_unwind(AssertUnwindSafe(|| transaction.is_valid()));
+            assert_eq!(
+                Ok(false),
+                is_valid.map_err(|_| ()),
+                "Transaction::is_valid panicked on {}",
+                hex::encode(&bytes)
+            );
+
+            let is_ok = panic::catch_unwind(|| Transaction::<Testnet2>::read_le(&bytes[..]).is_ok());
+            assert_eq!(
+                Ok(false),
+                is_ok.map_err(|_| ()),
+                "Transaction::read_le panicked on {}",
+                hex::encode(&bytes)
+            );
+        }
+    }
+
+    #[test]
+    fn test_minimize() {
+        let rng = &mut test_rng();
+
+        let has_custom_event =
+            |transition: &Transition<Testnet2>| transition.events().any(|event| matches!(event, Event::Custom(..)));
+
+        // Sample a transition with a custom event.
+        let transition = loop {
+            let transition = sample_transition::<Testnet2, _>(rng).unwrap();
+            if has_custom_event(&transition) {
+                break transition;
+            }
+        };
+
+        let minimal = minimize(transition.clone(), has_custom_event).unwrap();
+        assert_eq!(transition.transition_id(), minimal.transition_id());
+        assert_eq!(AleoAmount::ZERO, *minimal.value_balance());
+        assert_eq!(vec![&Event::Custom(vec![])], minimal.events().collect::<Vec<_>>());
+
+        // Check a transaction shrinks toward its failing transition.
+        let transaction =
+            Transaction::<Testnet2>::from_unchecked(UniformRand::rand(rng), UniformRand::rand(rng), vec![
+                sample_transition(rng).unwrap(),
+                transition,
+            ])
+            .unwrap();
+        let minimal = minimize(transaction, |transaction| {
+            transaction.transitions().iter().any(has_custom_event)
+        })
+        .unwrap();
+        assert_eq!(1, minimal.transitions().len());
+        assert_eq!(
+            vec![&Event::Custom(vec![])],
+            minimal.transitions()[0].events().collect::<Vec<_>>()
+        );
+    }
+}
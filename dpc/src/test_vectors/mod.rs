@@ -0,0 +1,403 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Golden vectors for the text, byte, bit, and field encodings of the network types,
+//! so that implementations in other languages can be checked against this library.
+//!
+//! The vectors are generated deterministically from a seed. The committed vectors in
+//! `resources` are regenerated with:
+//! `cargo run -p snarkvm-dpc --example test_vectors --features snarkvm-dpc/test-helpers > dpc/src/test_vectors/resources/testnet2.json`
+
+use crate::prelude::*;
+use snarkvm_curves::AffineCurve;
+use snarkvm_fields::Zero;
+use snarkvm_utilities::{FromBytes, ToBits, ToBytes, UniformRand};
+
+use anyhow::{anyhow, ensure, Result};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaChaRng;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{fmt::Display, str::FromStr};
+
+/// The seed of the committed vectors.
+pub const SEED: u64 = 1231275789;
+
+const FIELD: &str = "field";
+const SCALAR: &str = "scalar";
+const GROUP: &str = "group";
+const PRIVATE_KEY: &str = "private_key";
+const VIEW_KEY: &str = "view_key";
+const ADDRESS: &str = "address";
+const SIGNATURE: &str = "signature";
+const AMOUNT: &str = "amount";
+const PAYLOAD: &str = "payload";
+const RECORD: &str = "record";
+const RECORD_CIPHERTEXT: &str = "record_ciphertext";
+const COMMITMENT: &str = "commitment";
+
+/// A valid value of a type, in each of the encodings the type has.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVector {
+    /// The name of the type.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// The string representation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// The little-endian byte representation, hex encoded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<String>,
+    /// The little-endian bit representation, as a string of `0` and `1`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bits: Option<String>,
+    /// The field elements that represent the value, in decimal.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<String>>,
+}
+
+impl TestVector {
+    fn new(type_name: &str) -> Self {
+        Self {
+            type_name: type_name.to_string(),
+            text: None,
+            bytes: None,
+            bits: None,
+            fields: None,
+        }
+    }
+
+    fn with_text<T: Display>(mut self, value: &T) -> Self {
+        self.text = Some(value.to_string());
+        self
+    }
+
+    fn with_bytes<T: ToBytes>(mut self, value: &T) -> Result<Self> {
+        self.bytes = Some(hex::encode(value.to_bytes_le()?));
+        Ok(self)
+    }
+
+    fn with_bits<T: ToBits>(mut self, value: &T) -> Self {
+        self.bits = Some(value.to_bits_le().iter().map(|bit| if *bit { '1' } else { '0' }).collect());
+        self
+    }
+
+    fn with_fields<F: Display>(mut self, fields: &[F]) -> Self {
+        self.fields = Some(fields.iter().map(|field| field.to_string()).collect());
+        self
+    }
+}
+
+/// A string that must fail to parse as the given type.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvalidTestVector {
+    /// The name of the type.
+    #[serde(rename = "type")]
+    pub type_name: String,
+    /// The string that must fail to parse.
+    pub text: String,
+    /// The reason the string is invalid.
+    pub reason: String,
+}
+
+impl InvalidTestVector {
+    fn new(type_name: &str, text: &str, reason: &str) -> Self {
+        Self {
+            type_name: type_name.to_string(),
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+/// The golden vectors of a network.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestVectors {
+    /// The name of the network.
+    pub network: String,
+    /// The seed the vectors are generated from.
+    pub seed: u64,
+    /// The valid values, in each of their encodings.
+    pub valid: Vec<TestVector>,
+    /// The strings that must fail to parse.
+    pub invalid: Vec<InvalidTestVector>,
+}
+
+impl TestVectors {
+    /// Returns the vectors of the given network, generated from the given seed.
+    pub fn generate<N: Network>(seed: u64) -> Result<Self> {
+        let rng = &mut ChaChaRng::seed_from_u64(seed);
+
+        let mut valid = Vec::new();
+        let mut invalid = Vec::new();
+
+        // Fields and scalars.
+        for field in [N::InnerScalarField::from(0u64), N::InnerScalarField::from(1u64), UniformRand::rand(rng)] {
+            valid.push(field_vector(FIELD, &field)?);
+        }
+        for scalar in [N::ProgramScalarField::from(1u64), UniformRand::rand(rng)] {
+            valid.push(field_vector(SCALAR, &scalar)?);
+        }
+        for (text, reason) in [
+            ("", "The string is empty"),
+            ("01", "The number has a leading zero"),
+            ("-1", "The number is negative"),
+            ("0x1", "The number is not decimal"),
+        ] {
+            invalid.push(InvalidTestVector::new(FIELD, text, reason));
+            invalid.push(InvalidTestVector::new(SCALAR, text, reason));
+        }
+
+        // Accounts.
+        let private_key = PrivateKey::<N>::new(rng);
+        let view_key = ViewKey::from_private_key(&private_key);
+        let address = Address::from_private_key(&private_key);
+
+        // Groups, including the group element of the address.
+        for group in [N::ProgramAffineCurve::zero(), N::ProgramAffineCurve::prime_subgroup_generator(), *address] {
+            valid.push(
+                TestVector::new(GROUP)
+                    .with_bytes(&group)?
+                    .with_fields(&[group.to_x_coordinate(), group.to_y_coordinate()]),
+            );
+        }
+
+        valid.push(TestVector::new(PRIVATE_KEY).with_text(&private_key));
+        valid.push(TestVector::new(VIEW_KEY).with_text(&view_key).with_bytes(&view_key)?);
+        valid.push(
+            TestVector::new(ADDRESS)
+                .with_text(&address)
+                .with_bytes(&address)?
+                .with_fields(&[address.to_x_coordinate()]),
+        );
+        invalid.push(InvalidTestVector::new(
+            PRIVATE_KEY,
+            &truncate(&private_key),
+            "The string is truncated",
+        ));
+        invalid.push(InvalidTestVector::new(VIEW_KEY, &truncate(&view_key), "The string is truncated"));
+        invalid.push(InvalidTestVector::new(ADDRESS, &truncate(&address), "The string is truncated"));
+        invalid.push(InvalidTestVector::new(
+            ADDRESS,
+            &replace_last_char(&address),
+            "The checksum is invalid",
+        ));
+        invalid.push(InvalidTestVector::new(
+            ADDRESS,
+            &address.to_string().to_uppercase().replacen("ALEO", "aleo", 1),
+            "The string mixes cases",
+        ));
+
+        // Signatures.
+        let signature = private_key.sign(b"Hello, Aleo!", rng)?;
+        valid.push(TestVector::new(SIGNATURE).with_text(&signature).with_bytes(&signature)?);
+        invalid.push(InvalidTestVector::new(SIGNATURE, &truncate(&signature), "The string is truncated"));
+
+        // Amounts.
+        for amount in [0, 1, -1, i64::MAX, i64::MIN, rng.gen()] {
+            let amount = AleoAmount(amount);
+            valid.push(TestVector::new(AMOUNT).with_text(&amount).with_bytes(&amount)?);
+        }
+        for (text, reason) in [
+            ("1.5", "Bytes have no decimal places"),
+            ("1.0000001 ALEO", "The amount has too many decimal places"),
+            ("9223372036854775808", "The amount overflows"),
+            ("1 XYZ", "The denomination is unknown"),
+        ] {
+            invalid.push(InvalidTestVector::new(AMOUNT, text, reason));
+        }
+
+        // Records.
+        let payload = Payload::<N>::from(&rng.gen::<[u8; 32]>());
+        let record = Record::new(address, AleoAmount(rng.gen_range(0..i64::MAX)), payload.clone(), UniformRand::rand(rng), rng)?;
+        valid.push(TestVector::new(PAYLOAD).with_text(&payload).with_bytes(&payload)?);
+        valid.push(TestVector::new(RECORD).with_text(&record).with_bytes(&record)?);
+        valid.push(
+            TestVector::new(RECORD_CIPHERTEXT)
+                .with_text(record.ciphertext())
+                .with_bytes(record.ciphertext())?,
+        );
+        valid.push(
+            TestVector::new(COMMITMENT)
+                .with_text(&record.commitment())
+                .with_bytes(&record.commitment())?,
+        );
+        invalid.push(InvalidTestVector::new(PAYLOAD, "zz", "The string is not hex"));
+        invalid.push(InvalidTestVector::new(RECORD, "{}", "The record is missing its fields"));
+        invalid.push(InvalidTestVector::new(
+            RECORD_CIPHERTEXT,
+            &truncate(record.ciphertext()),
+            "The string is truncated",
+        ));
+        invalid.push(InvalidTestVector::new(
+            COMMITMENT,
+            &truncate(&record.commitment()),
+            "The string is truncated",
+        ));
+
+        Ok(Self {
+            network: N::NETWORK_NAME.to_string(),
+            seed,
+            valid,
+            invalid,
+        })
+    }
+
+    /// Checks that every valid vector parses from its text and bytes, back into the same encodings,
+    /// and that every invalid vector fails to parse.
+    pub fn verify<N: Network>(&self) -> Result<()> {
+        ensure!(self.network == N::NETWORK_NAME, "The vectors are for the {} network", self.network);
+
+        for vector in &self.valid {
+            match vector.type_name.as_str() {
+                FIELD => check_bytes::<N::InnerScalarField>(vector, from_text)?,
+                SCALAR => check_bytes::<N::ProgramScalarField>(vector, from_text)?,
+                GROUP => check_bytes::<N::ProgramAffineCurve>(vector, |_| Err(anyhow!("Groups have no text")))?,
+                PRIVATE_KEY => check_text::<PrivateKey<N>>(vector, from_text)?,
+                VIEW_KEY => check_bytes::<ViewKey<N>>(vector, from_text)?,
+                ADDRESS => check_bytes::<Address<N>>(vector, from_text)?,
+                SIGNATURE => check_bytes::<N::AccountSignature>(vector, from_json_text)?,
+                AMOUNT => check_bytes::<AleoAmount>(vector, from_text)?,
+                PAYLOAD => check_bytes::<Payload<N>>(vector, from_text)?,
+                RECORD => check_bytes::<Record<N>>(vector, from_text)?,
+                RECORD_CIPHERTEXT => check_bytes::<N::RecordCiphertext>(vector, from_json_text)?,
+                COMMITMENT => check_bytes::<N::Commitment>(vector, from_text)?,
+                type_name => return Err(anyhow!("Unknown type {} in the vectors", type_name)),
+            }
+        }
+
+        for vector in &self.invalid {
+            let is_parsed = match vector.type_name.as_str() {
+                FIELD => from_text::<N::InnerScalarField>(&vector.text).is_ok(),
+                SCALAR => from_text::<N::ProgramScalarField>(&vector.text).is_ok(),
+                PRIVATE_KEY => from_text::<PrivateKey<N>>(&vector.text).is_ok(),
+                VIEW_KEY => from_text::<ViewKey<N>>(&vector.text).is_ok(),
+                ADDRESS => from_text::<Address<N>>(&vector.text).is_ok(),
+                SIGNATURE => from_json_text::<N::AccountSignature>(&vector.text).is_ok(),
+                AMOUNT => from_text::<AleoAmount>(&vector.text).is_ok(),
+                PAYLOAD => from_text::<Payload<N>>(&vector.text).is_ok(),
+                RECORD => from_text::<Record<N>>(&vector.text).is_ok(),
+                RECORD_CIPHERTEXT => from_json_text::<N::RecordCiphertext>(&vector.text).is_ok(),
+                COMMITMENT => from_text::<N::Commitment>(&vector.text).is_ok(),
+                type_name => return Err(anyhow!("Unknown type {} in the vectors", type_name)),
+            };
+            ensure!(
+                !is_parsed,
+                "Parsed the invalid {} '{}': {}",
+                vector.type_name,
+                vector.text,
+                vector.reason
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the vector of a field element, which is its own field representation.
+fn field_vector<F: Display + ToBytes + ToBits>(type_name: &str, field: &F) -> Result<TestVector> {
+    Ok(TestVector::new(type_name)
+        .with_text(field)
+        .with_bytes(field)?
+        .with_bits(field)
+        .with_fields(&[field]))
+}
+
+/// Returns the string representation of the value, without its last character.
+fn truncate<T: Display>(value: &T) -> String {
+    let mut text = value.to_string();
+    text.pop();
+    text
+}
+
+/// Returns the string representation of the value, with a different last character.
+fn replace_last_char<T: Display>(value: &T) -> String {
+    let mut text = value.to_string();
+    let last = match text.pop() {
+        Some('q') => 'p',
+        _ => 'q',
+    };
+    text.push(last);
+    text
+}
+
+/// Parses a value from its string representation.
+fn from_text<T: FromStr>(text: &str) -> Result<T> {
+    T::from_str(text).map_err(|_| anyhow!("Failed to parse '{}'", text))
+}
+
+/// Parses a value from its string representation, for types that only parse through serde.
+fn from_json_text<T: DeserializeOwned>(text: &str) -> Result<T> {
+    Ok(serde_json::from_value(serde_json::Value::String(text.to_string()))?)
+}
+
+/// Checks the text of the vector parses into a value with the same text.
+fn check_text<T: Display>(vector: &TestVector, parse_text: impl Fn(&str) -> Result<T>) -> Result<()> {
+    if let Some(text) = &vector.text {
+        let candidate = parse_text(text)?.to_string();
+        ensure!(*text == candidate, "The {} '{}' reencoded as '{}'", vector.type_name, text, candidate);
+    }
+    Ok(())
+}
+
+/// Checks the text and bytes of the vector parse into values with the same text and bytes.
+fn check_bytes<T: Display + ToBytes + FromBytes>(
+    vector: &TestVector,
+    parse_text: impl Fn(&str) -> Result<T>,
+) -> Result<()> {
+    check_text(vector, &parse_text)?;
+
+    if let Some(bytes) = &vector.bytes {
+        let value = T::read_le(&hex::decode(bytes)?[..])?;
+        let candidate = hex::encode(value.to_bytes_le()?);
+        ensure!(*bytes == candidate, "The {} bytes {} reencoded as {}", vector.type_name, bytes, candidate);
+
+        if let Some(text) = &vector.text {
+            ensure!(*text == value.to_string(), "The {} bytes {} have a different text", vector.type_name, bytes);
+            let candidate = hex::encode(parse_text(text)?.to_bytes_le()?);
+            ensure!(*bytes == candidate, "The {} '{}' has different bytes", vector.type_name, text);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    const TESTNET2_VECTORS: &str = include_str!("./resources/testnet2.json");
+
+    #[test]
+    fn test_generated_vectors_match_committed_vectors() {
+        let expected: TestVectors = serde_json::from_str(TESTNET2_VECTORS).unwrap();
+        let candidate = TestVectors::generate::<Testnet2>(SEED).unwrap();
+        assert_eq!(expected, candidate);
+    }
+
+    #[test]
+    fn test_committed_vectors() {
+        let vectors: TestVectors = serde_json::from_str(TESTNET2_VECTORS).unwrap();
+        vectors.verify::<Testnet2>().unwrap();
+        assert!(!vectors.invalid.is_empty());
+    }
+
+    #[test]
+    fn test_verify_rejects_parsed_invalid_vector() {
+        let mut vectors = TestVectors::generate::<Testnet2>(SEED).unwrap();
+        vectors.invalid.push(InvalidTestVector::new(AMOUNT, "1", "Not invalid"));
+        assert!(vectors.verify::<Testnet2>().is_err());
+    }
+}
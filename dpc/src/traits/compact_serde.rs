@@ -0,0 +1,387 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! A compact CBOR encoding of ledger objects, for light clients.
+//!
+//! Each object is encoded as a definite-length CBOR map, whose keys are the indices of the fields
+//! of the object, from `0` in ascending order. Nested objects are encoded as nested maps, lists
+//! as definite-length arrays, integers as CBOR integers, and every other field as a byte string
+//! of its canonical little-endian bytes, as produced by `ToBytes`. All lengths and integers are
+//! encoded in their shortest form, which makes the encoding deterministic (RFC 8949, 4.2.1).
+//!
+//! This encoding is a transport format only. Consensus paths read the canonical bytes with
+//! `FromBytes`, and do not accept the CBOR encoding.
+
+use crate::{Block, BlockHeader, BlockHeaderMetadata, Execution, Network, Transaction, Transactions, Transition};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use ciborium::value::{Integer, Value};
+use std::convert::TryFrom;
+
+/// An object with a compact CBOR encoding, for light clients.
+pub trait CompactSerde: Sized {
+    /// The name of the object, used in deserialization errors.
+    const NAME: &'static str;
+
+    /// Returns the fields of the object, in the order of their keys.
+    fn to_cbor_fields(&self) -> Result<Vec<Value>>;
+
+    /// Returns the object for the given fields, in the order of their keys.
+    fn from_cbor_fields(fields: Vec<Value>) -> Result<Self>;
+
+    /// Returns the CBOR map of the object.
+    fn to_cbor_value(&self) -> Result<Value> {
+        let fields = self.to_cbor_fields()?;
+        Ok(Value::Map(
+            fields
+                .into_iter()
+                .enumerate()
+                .map(|(key, field)| (Value::Integer(Integer::from(key as u64)), field))
+                .collect(),
+        ))
+    }
+
+    /// Returns the object for the given CBOR map, which must have the keys `0..n` in ascending order.
+    fn from_cbor_value(value: Value) -> Result<Self> {
+        let entries = match value {
+            Value::Map(entries) => entries,
+            _ => return Err(anyhow!("Expected a CBOR map for {}", Self::NAME)),
+        };
+        let mut fields = Vec::with_capacity(entries.len());
+        for (index, (key, field)) in entries.into_iter().enumerate() {
+            match key {
+                Value::Integer(key) if u64::try_from(key).ok() == Some(index as u64) => fields.push(field),
+                _ => return Err(anyhow!("Unexpected key in the CBOR map of {}", Self::NAME)),
+            }
+        }
+        Self::from_cbor_fields(fields)
+    }
+
+    /// Returns the compact CBOR encoding of the object.
+    fn to_cbor(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(&self.to_cbor_value()?, &mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the object for the given compact CBOR encoding.
+    ///
+    /// This method only accepts the exact encoding produced by `to_cbor`, and rejects
+    /// trailing bytes, non-minimal length encodings, and indefinite-length items.
+    fn from_cbor(bytes: &[u8]) -> Result<Self> {
+        let value: Value = ciborium::de::from_reader(bytes)?;
+        let object = Self::from_cbor_value(value)?;
+
+        // Ensure the encoding is canonical.
+        match object.to_cbor()? == bytes {
+            true => Ok(object),
+            false => Err(anyhow!("Non-canonical CBOR encoding of {}", Self::NAME)),
+        }
+    }
+}
+
+/// Returns the byte string of the canonical bytes of the given object.
+fn to_byte_string<T: ToBytes>(object: &T) -> Result<Value> {
+    Ok(Value::Bytes(object.to_bytes_le()?))
+}
+
+/// Returns the object for the given byte string of its canonical bytes.
+fn from_byte_string<T: FromBytes>(value: Value) -> Result<T> {
+    match value {
+        Value::Bytes(bytes) => T::from_bytes_le(&bytes),
+        _ => Err(anyhow!("Expected a CBOR byte string")),
+    }
+}
+
+/// Returns the array of byte strings of the given objects.
+fn to_byte_strings<'a, T: ToBytes + 'a>(objects: impl Iterator<Item = &'a T>) -> Result<Value> {
+    Ok(Value::Array(objects.map(to_byte_string).collect::<Result<_>>()?))
+}
+
+/// Returns the objects for the given array of byte strings.
+fn from_byte_strings<T: FromBytes>(value: Value) -> Result<Vec<T>> {
+    from_array(value)?.into_iter().map(from_byte_string).collect()
+}
+
+/// Returns the members of the given array.
+fn from_array(value: Value) -> Result<Vec<Value>> {
+    match value {
+        Value::Array(members) => Ok(members),
+        _ => Err(anyhow!("Expected a CBOR array")),
+    }
+}
+
+/// Returns the integer for the given CBOR integer.
+fn from_integer<T: TryFrom<Integer>>(value: Value) -> Result<T> {
+    match value {
+        Value::Integer(integer) => T::try_from(integer).map_err(|_| anyhow!("CBOR integer is out of range")),
+        _ => Err(anyhow!("Expected a CBOR integer")),
+    }
+}
+
+/// Returns the `N` fields of an object, in the order of their keys.
+fn to_fields<const N: usize>(fields: Vec<Value>, name: &str) -> Result<[Value; N]> {
+    <[Value; N]>::try_from(fields).map_err(|fields| anyhow!("Expected {} fields for {}, found {}", N, name, fields.len()))
+}
+
+impl<N: Network> CompactSerde for Block<N> {
+    const NAME: &'static str = "block";
+
+    fn to_cbor_fields(&self) -> Result<Vec<Value>> {
+        Ok(vec![
+            to_byte_string(&self.hash())?,
+            to_byte_string(&self.previous_block_hash())?,
+            self.header().to_cbor_value()?,
+            Value::Array(
+                self.transactions()
+                    .iter()
+                    .map(|transaction| transaction.to_cbor_value())
+                    .collect::<Result<_>>()?,
+            ),
+        ])
+    }
+
+    fn from_cbor_fields(fields: Vec<Value>) -> Result<Self> {
+        let [block_hash, previous_block_hash, header, transactions] = to_fields(fields, Self::NAME)?;
+        let block_hash: N::BlockHash = from_byte_string(block_hash)?;
+        let transactions = from_array(transactions)?
+            .into_iter()
+            .map(Transaction::from_cbor_value)
+            .collect::<Result<Vec<_>>>()?;
+        let block = Self::from(
+            from_byte_string(previous_block_hash)?,
+            BlockHeader::from_cbor_value(header)?,
+            Transactions::from(&transactions)?,
+        )?;
+
+        match block_hash == block.hash() {
+            true => Ok(block),
+            false => Err(anyhow!("Mismatching block hash, possible data corruption")),
+        }
+    }
+}
+
+impl<N: Network> CompactSerde for BlockHeader<N> {
+    const NAME: &'static str = "block header";
+
+    fn to_cbor_fields(&self) -> Result<Vec<Value>> {
+        Ok(vec![
+            to_byte_string(&self.previous_ledger_root())?,
+            to_byte_string(&self.transactions_root())?,
+            Value::Integer(self.height().into()),
+            Value::Integer(self.timestamp().into()),
+            Value::Integer(self.difficulty_target().into()),
+            Value::serialized(&self.cumulative_weight())?,
+            to_byte_string(&self.nonce())?,
+            to_byte_string(self.proof())?,
+        ])
+    }
+
+    fn from_cbor_fields(fields: Vec<Value>) -> Result<Self> {
+        let [previous_ledger_root, transactions_root, height, timestamp, difficulty_target, cumulative_weight, nonce, proof] =
+            to_fields(fields, Self::NAME)?;
+        let metadata = BlockHeaderMetadata::from(
+            from_integer(height)?,
+            from_integer(timestamp)?,
+            from_integer(difficulty_target)?,
+            cumulative_weight.deserialized()?,
+        );
+        Ok(Self::from(
+            from_byte_string(previous_ledger_root)?,
+            from_byte_string(transactions_root)?,
+            metadata,
+            from_byte_string(nonce)?,
+            from_byte_string(proof)?,
+        )?)
+    }
+}
+
+impl<N: Network> CompactSerde for Transaction<N> {
+    const NAME: &'static str = "transaction";
+
+    fn to_cbor_fields(&self) -> Result<Vec<Value>> {
+        Ok(vec![
+            to_byte_string(&self.transaction_id())?,
+            to_byte_string(&self.inner_circuit_id())?,
+            to_byte_string(&self.ledger_root())?,
+            Value::Array(
+                self.transitions()
+                    .iter()
+                    .map(|transition| transition.to_cbor_value())
+                    .collect::<Result<_>>()?,
+            ),
+        ])
+    }
+
+    fn from_cbor_fields(fields: Vec<Value>) -> Result<Self> {
+        let [transaction_id, inner_circuit_id, ledger_root, transitions] = to_fields(fields, Self::NAME)?;
+        let transaction_id: N::TransactionID = from_byte_string(transaction_id)?;
+        let transitions = from_array(transitions)?
+            .into_iter()
+            .map(Transition::from_cbor_value)
+            .collect::<Result<Vec<_>>>()?;
+        let transaction = Self::from(
+            from_byte_string(inner_circuit_id)?,
+            from_byte_string(ledger_root)?,
+            transitions,
+        )?;
+
+        match transaction_id == transaction.transaction_id() {
+            true => Ok(transaction),
+            false => Err(anyhow!("Mismatching transaction ID, possible data corruption")),
+        }
+    }
+}
+
+impl<N: Network> CompactSerde for Transition<N> {
+    const NAME: &'static str = "transition";
+
+    fn to_cbor_fields(&self) -> Result<Vec<Value>> {
+        Ok(vec![
+            to_byte_string(&self.transition_id())?,
+            to_byte_strings(self.serial_numbers())?,
+            to_byte_strings(self.ciphertexts())?,
+            Value::Integer(self.value_balance().as_i64().into()),
+            to_byte_strings(self.events())?,
+            to_byte_string(self.proof())?,
+        ])
+    }
+
+    fn from_cbor_fields(fields: Vec<Value>) -> Result<Self> {
+        let [transition_id, serial_numbers, ciphertexts, value_balance, events, proof] = to_fields(fields, Self::NAME)?;
+        Self::from(
+            from_byte_string(transition_id)?,
+            from_byte_strings(serial_numbers)?,
+            from_byte_strings(ciphertexts)?,
+            crate::AleoAmount(from_integer(value_balance)?),
+            from_byte_strings(events)?,
+            from_byte_string(proof)?,
+        )
+    }
+}
+
+impl<N: Network> CompactSerde for Execution<N> {
+    const NAME: &'static str = "execution";
+
+    fn to_cbor_fields(&self) -> Result<Vec<Value>> {
+        Ok(vec![
+            to_byte_string(&self.program_id)?,
+            to_byte_string(&self.program_path)?,
+            to_byte_string(&self.verifying_key)?,
+            to_byte_string(&self.proof)?,
+        ])
+    }
+
+    fn from_cbor_fields(fields: Vec<Value>) -> Result<Self> {
+        let [program_id, program_path, verifying_key, proof] = to_fields(fields, Self::NAME)?;
+        Ok(Self {
+            program_id: from_byte_string(program_id)?,
+            program_path: from_byte_string(program_path)?,
+            verifying_key: from_byte_string(verifying_key)?,
+            proof: from_byte_string(proof)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    /// Returns the sizes of the CBOR and JSON encodings of the given object,
+    /// after checking the CBOR encoding round-trips exactly.
+    fn check_compact_serde<T: CompactSerde + PartialEq + std::fmt::Debug + serde::Serialize>(
+        expected: &T,
+    ) -> (usize, usize) {
+        let candidate_cbor = expected.to_cbor().unwrap();
+
+        // Ensure the encoding is a map keyed by the field indices, in ascending order.
+        match ciborium::de::from_reader::<Value, _>(&candidate_cbor[..]).unwrap() {
+            Value::Map(entries) => {
+                for (index, (key, _)) in entries.iter().enumerate() {
+                    assert_eq!(&Value::Integer((index as u64).into()), key);
+                }
+            }
+            _ => panic!("Expected a CBOR map"),
+        }
+
+        // Ensure the encoding round-trips exactly.
+        let candidate = T::from_cbor(&candidate_cbor).unwrap();
+        assert_eq!(expected, &candidate);
+        assert_eq!(candidate_cbor, candidate.to_cbor().unwrap());
+
+        let json_size = serde_json::to_string(expected).unwrap().len();
+        (candidate_cbor.len(), json_size)
+    }
+
+    #[test]
+    fn test_transaction_cbor() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+
+        let (cbor_size, json_size) = check_compact_serde(&transaction);
+        assert!(cbor_size < json_size);
+    }
+
+    #[test]
+    fn test_transition_cbor() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transition = transaction.transitions().first().unwrap();
+
+        let (cbor_size, json_size) = check_compact_serde(transition);
+        assert!(cbor_size < json_size);
+    }
+
+    #[test]
+    fn test_block_cbor() {
+        let block = Testnet2::genesis_block();
+
+        let (cbor_size, json_size) = check_compact_serde(block);
+        assert!(cbor_size < json_size);
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_cbor() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let bytes = transaction.to_bytes_le().unwrap();
+        let cbor = transaction.to_cbor().unwrap();
+
+        // Ensure trailing bytes are rejected.
+        let mut trailing = cbor.clone();
+        trailing.push(0);
+        assert!(Transaction::<Testnet2>::from_cbor(&trailing).is_err());
+
+        // Ensure a non-minimal length encoding of the map is rejected.
+        assert_eq!(0xa4, cbor[0]);
+        let mut non_minimal = vec![0xb8, 4];
+        non_minimal.extend_from_slice(&cbor[1..]);
+        assert!(Transaction::<Testnet2>::from_cbor(&non_minimal).is_err());
+
+        // Ensure fields out of order are rejected.
+        let mut entries = match transaction.to_cbor_value().unwrap() {
+            Value::Map(entries) => entries,
+            _ => panic!("Expected a CBOR map"),
+        };
+        entries.swap(1, 2);
+        let mut swapped = Vec::new();
+        ciborium::ser::into_writer(&Value::Map(entries), &mut swapped).unwrap();
+        assert!(Transaction::<Testnet2>::from_cbor(&swapped).is_err());
+
+        // Ensure the canonical bytes are not accepted as CBOR, and vice versa.
+        assert!(Transaction::<Testnet2>::from_cbor(&bytes).is_err());
+        assert!(Transaction::<Testnet2>::read_le(&cbor[..]).is_err());
+    }
+}
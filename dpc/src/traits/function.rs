@@ -19,8 +19,14 @@ use snarkvm_r1cs::{ConstraintSystem, SynthesisError, ToConstraintField};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
+use rand::{CryptoRng, RngCore};
 use std::fmt::Debug;
 
+/// A cryptographically secure random number generator that can be passed as a trait object.
+pub trait CryptoRngCore: RngCore + CryptoRng {}
+
+impl<R: RngCore + CryptoRng> CryptoRngCore for R {}
+
 pub trait Function<N: Network>: Send + Sync {
     /// Returns the function ID.
     fn function_id(&self) -> N::FunctionID;
@@ -33,6 +39,7 @@ pub trait Function<N: Network>: Send + Sync {
         &self,
         public: ProgramPublicVariables<N>,
         private: &dyn ProgramPrivateVariables<N>,
+        rng: &mut dyn CryptoRngCore,
     ) -> Result<N::ProgramProof>;
 
     /// Returns true if the execution of the function is valid.
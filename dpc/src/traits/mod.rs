@@ -28,3 +28,8 @@ pub use network::*;
 
 pub mod posw;
 pub use posw::*;
+
+#[cfg(feature = "compact-serde")]
+pub mod compact_serde;
+#[cfg(feature = "compact-serde")]
+pub use compact_serde::*;
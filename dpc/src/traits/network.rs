@@ -15,8 +15,11 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{
+    BalancePublicVariables,
+    Bech32mError,
     Block,
     Ciphertext,
+    ConsensusConstants,
     InnerPublicVariables,
     OuterPublicVariables,
     PoSWScheme,
@@ -46,6 +49,7 @@ use anyhow::Result;
 use rand::{CryptoRng, Rng};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{borrow::Borrow, cell::RefCell, ops::Deref, rc::Rc, str::FromStr};
+use zeroize::Zeroize;
 
 pub trait Bech32Locator<F: Field>:
     From<F>
@@ -59,7 +63,7 @@ pub trait Bech32Locator<F: Field>:
     + Default
     + Debug
     + Display
-    + FromStr
+    + FromStr<Err = Bech32mError>
     + ToBytes
     + FromBytes
     + Serialize
@@ -105,6 +109,7 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     const NUM_TOTAL_RECORDS: usize = Self::NUM_INPUT_RECORDS + Self::NUM_OUTPUT_RECORDS;
     const NUM_TRANSITIONS: u8;
     const NUM_EVENTS: u16;
+    const NUM_TRANSACTIONS: u16;
 
     const BLOCK_HASH_PREFIX: u16;
     const LEDGER_ROOT_PREFIX: u16;
@@ -159,6 +164,14 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     /// The maximum number of blocks that a fork can be.
     const ALEO_MAXIMUM_FORK_DEPTH: u32;
 
+    /// The block reward for each block after the genesis block, before any halving.
+    const ALEO_INITIAL_BLOCK_REWARD_IN_CREDITS: i64;
+    /// The number of years between halvings of the block reward.
+    const ALEO_BLOCK_REWARD_HALVING_INTERVAL_IN_YEARS: u32;
+    /// The maximum number of times the block reward halves.
+    const ALEO_MAXIMUM_BLOCK_REWARD_HALVINGS: u32;
+
+
     /// Inner curve type declarations.
     type InnerCurve: PairingEngine<Fr = Self::InnerScalarField, Fq = Self::OuterScalarField>;
     type InnerScalarField: PrimeField + PoseidonDefaultParametersField;
@@ -174,7 +187,7 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type ProgramProjectiveCurve: ProjectiveCurve<BaseField = Self::ProgramBaseField>;
     type ProgramCurveParameters: TwistedEdwardsParameters;
     type ProgramBaseField: PrimeField;
-    type ProgramScalarField: PrimeField;
+    type ProgramScalarField: PrimeField + Zeroize;
 
     /// SNARK for inner circuit proof generation.
     type InnerSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = InnerPublicVariables<Self>>;
@@ -192,6 +205,9 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type ProgramVerifyingKey: ToConstraintField<Self::OuterScalarField> + Clone + ToBytes + FromBytes + ToMinimalBits + Send + Sync;
     type ProgramProof: Bech32Object<<Self::ProgramSNARK as SNARK>::Proof>;
 
+    /// SNARK for minimum balance proofs of records.
+    type BalanceSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = BalancePublicVariables<Self>>;
+
     /// SNARK for PoSW.
     type PoSWSNARK: SNARK<ScalarField = Self::InnerScalarField, BaseField = Self::OuterScalarField, VerifierInput = Vec<Self::InnerScalarField>, UniversalSetupConfig = usize>;
     type PoSWProof: Bech32Object<<Self::PoSWSNARK as SNARK>::Proof>;
@@ -203,7 +219,7 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
 
     /// PRF for deriving the account private key from a seed.
     type AccountSeedPRF: PRF<Input = Vec<Self::ProgramScalarField>, Seed = Self::AccountSeed, Output = Self::ProgramScalarField>;
-    type AccountSeed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug + UniformRand;
+    type AccountSeed: FromBytes + ToBytes + PartialEq + Eq + Clone + Default + Debug + UniformRand + Zeroize;
 
     /// Signature scheme for transaction authorizations. Invoked only over `Self::InnerScalarField`.
     type AccountSignatureScheme: SignatureScheme<PrivateKey = (Self::ProgramScalarField, Self::ProgramScalarField), PublicKey = Self::ProgramAffineCurve>
@@ -294,6 +310,11 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
     type TransitionIDParameters: MerkleParameters<H = Self::TransitionIDCRH>;
     type TransitionID: Bech32Locator<<Self::TransitionIDCRH as CRH>::Output>;
 
+    /// Returns the constants of the consensus rules.
+    fn consensus_constants() -> ConsensusConstants {
+        ConsensusConstants::of::<Self>()
+    }
+
     fn account_encryption_scheme() -> &'static Self::AccountEncryptionScheme;
     fn account_signature_scheme() -> &'static Self::AccountSignatureScheme;
     fn block_hash_crh() -> &'static Self::BlockHashCRH;
@@ -320,6 +341,12 @@ pub trait Network: 'static + Copy + Clone + Debug + Default + PartialEq + Eq + S
 
     fn outer_proving_key() -> &'static <Self::OuterSNARK as SNARK>::ProvingKey;
     fn outer_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::VerifyingKey;
+    /// Returns the outer circuit verifying key, prepared once for the verification of transition proofs.
+    fn outer_prepared_verifying_key() -> &'static <Self::OuterSNARK as SNARK>::PreparedVerifyingKey;
+
+    /// Returns the balance circuit keys, or `None` if the network does not support minimum balance proofs.
+    fn balance_proving_key() -> Option<&'static <Self::BalanceSNARK as SNARK>::ProvingKey>;
+    fn balance_verifying_key() -> Option<&'static <Self::BalanceSNARK as SNARK>::VerifyingKey>;
 
     fn posw_proving_key() -> &'static <Self::PoSWSNARK as SNARK>::ProvingKey;
     fn posw_verifying_key() -> &'static <Self::PoSWSNARK as SNARK>::VerifyingKey;
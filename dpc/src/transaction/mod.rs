@@ -17,8 +17,17 @@
 pub(crate) mod local_proof;
 pub(crate) use local_proof::*;
 
+pub mod size_breakdown;
+pub use size_breakdown::*;
+
+pub mod summary;
+pub use summary::*;
+
 pub mod transaction;
 pub use transaction::*;
 
 pub(crate) mod transitions;
 pub(crate) use transitions::*;
+
+pub mod verified_cache;
+pub use verified_cache::*;
@@ -0,0 +1,90 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The size in bytes of each part of a transition, as encoded by `ToBytes`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransitionSize {
+    /// The size of the transition ID.
+    pub transition_id: usize,
+    /// The size of the serial numbers of the input records.
+    pub inputs: usize,
+    /// The size of the ciphertexts of the output records.
+    pub outputs: usize,
+    /// The size of the value balance.
+    pub value_balance: usize,
+    /// The size of the events, including their length prefix.
+    pub events: usize,
+    /// The size of the transition proof.
+    pub proof: usize,
+}
+
+impl TransitionSize {
+    /// Returns the size of the encoded transition.
+    pub fn total(&self) -> usize {
+        self.transition_id + self.inputs + self.outputs + self.value_balance + self.events + self.proof
+    }
+}
+
+/// The size in bytes of each part of a transaction, as encoded by `ToBytes`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeBreakdown {
+    /// The size of the inner circuit ID, the ledger root, and the number of transitions.
+    pub header: usize,
+    /// The size of each transition, in order.
+    pub transitions: Vec<TransitionSize>,
+}
+
+impl SizeBreakdown {
+    /// Returns the total size of the transition proofs.
+    pub fn proofs(&self) -> usize {
+        self.transitions.iter().map(|transition| transition.proof).sum()
+    }
+
+    /// Returns the size of the encoded transaction.
+    pub fn total(&self) -> usize {
+        self.header + self.transitions.iter().map(TransitionSize::total).sum::<usize>()
+    }
+}
+
+impl fmt::Display for SizeBreakdown {
+    /// Renders the sizes as a table with one row per transition, in bytes.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+            "transition", "id", "inputs", "outputs", "balance", "events", "proof", "total"
+        )?;
+        for (index, transition) in self.transitions.iter().enumerate() {
+            writeln!(
+                f,
+                "{:<12} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+                index,
+                transition.transition_id,
+                transition.inputs,
+                transition.outputs,
+                transition.value_balance,
+                transition.events,
+                transition.proof,
+                transition.total(),
+            )?;
+        }
+        writeln!(f, "header: {} bytes", self.header)?;
+        write!(f, "total: {} bytes", self.total())
+    }
+}
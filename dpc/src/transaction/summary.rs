@@ -0,0 +1,465 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+
+use std::fmt;
+
+/// An output record of a transition, as seen by the summarizing account.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct RecordSummary<N: Network> {
+    /// The index of the record in the outputs of the transition.
+    pub index: u8,
+    /// The owner of the record.
+    pub owner: Address<N>,
+    /// The value of the record.
+    pub value: AleoAmount,
+    /// `true` if the record is owned by the summarizing account.
+    pub is_owned: bool,
+}
+
+/// A summary of a transition, listing its public operation and the output records
+/// that are public or owned by the summarizing account.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct TransitionSummary<N: Network> {
+    /// The ID of the transition.
+    pub transition_id: N::TransitionID,
+    /// The operation of the transition, if it is public.
+    pub operation: Option<Operation<N>>,
+    /// The non-dummy output records that are public or owned by the summarizing account.
+    pub records: Vec<RecordSummary<N>>,
+    /// The number of custom events emitted by the transition.
+    pub num_custom_events: usize,
+    /// The value balance of the transition, which is its fee.
+    pub value_balance: AleoAmount,
+}
+
+impl<N: Network> TransitionSummary<N> {
+    /// Returns the summary of the given transition, decrypting its records with the view key, if given.
+    pub fn new(transition: &Transition<N>, view_key: Option<&ViewKey<N>>) -> Self {
+        let ciphertexts: Vec<_> = transition.ciphertexts().cloned().collect();
+        let events: Vec<_> = transition.events().cloned().collect();
+        Self::from_parts(
+            transition.transition_id(),
+            &ciphertexts,
+            *transition.value_balance(),
+            &events,
+            view_key,
+        )
+    }
+
+    /// Returns the summary of a transition from its parts.
+    pub(crate) fn from_parts(
+        transition_id: N::TransitionID,
+        ciphertexts: &[N::RecordCiphertext],
+        value_balance: AleoAmount,
+        events: &[Event<N>],
+        view_key: Option<&ViewKey<N>>,
+    ) -> Self {
+        let mut operation = None;
+        let mut num_custom_events = 0;
+        let mut record_view_keys = vec![None; ciphertexts.len()];
+        for event in events {
+            match event {
+                Event::Custom(..) => num_custom_events += 1,
+                Event::RecordViewKey(index, record_view_key) => {
+                    if let Some(entry) = record_view_keys.get_mut(*index as usize) {
+                        *entry = Some(record_view_key);
+                    }
+                }
+                Event::Operation(candidate) => operation = Some(candidate.clone()),
+            }
+        }
+
+        // Decrypt the owned records, falling back to the record view keys of public records.
+        let mut records = Vec::new();
+        for (index, (ciphertext, record_view_key)) in ciphertexts.iter().zip(record_view_keys).enumerate() {
+            let (record, is_owned) =
+                match view_key.and_then(|view_key| Record::from_account_view_key(view_key, ciphertext).ok()) {
+                    Some(record) => (record, true),
+                    None => match record_view_key.and_then(|key| Record::from_record_view_key(key, ciphertext).ok()) {
+                        Some(record) => (record, false),
+                        None => continue,
+                    },
+                };
+            if !record.is_dummy() {
+                records.push(RecordSummary {
+                    index: index as u8,
+                    owner: record.owner(),
+                    value: record.value(),
+                    is_owned,
+                });
+            }
+        }
+
+        Self {
+            transition_id,
+            operation,
+            records,
+            num_custom_events,
+            value_balance,
+        }
+    }
+
+    /// Returns the caller of the transition, who pays its fee, if the operation is public.
+    pub fn caller(&self) -> Option<Address<N>> {
+        match &self.operation {
+            Some(Operation::Transfer(caller, ..)) => Some(*caller),
+            Some(Operation::Evaluate(_, _, function_inputs)) => Some(function_inputs.caller),
+            _ => None,
+        }
+    }
+
+    /// Returns the total value of the records owned by the summarizing account.
+    pub fn received(&self) -> AleoAmount {
+        self.records
+            .iter()
+            .filter(|record| record.is_owned)
+            .map(|record| record.value)
+            .sum()
+    }
+
+    /// Returns the total value of the input records, if the operation is public.
+    /// As public transitions reveal every output record, this is the sum of the outputs and the fee.
+    pub fn spent(&self) -> Option<AleoAmount> {
+        self.caller().map(|_| {
+            self.records
+                .iter()
+                .map(|record| record.value)
+                .sum::<AleoAmount>()
+                .add(self.value_balance)
+        })
+    }
+}
+
+impl<N: Network> fmt::Display for TransitionSummary<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Transition {}", self.transition_id)?;
+        match &self.operation {
+            Some(Operation::Noop) => writeln!(f, "  Operation: noop")?,
+            Some(Operation::Coinbase(recipient, amount)) => {
+                writeln!(f, "  Operation: coinbase of {} AB to {}", amount, recipient)?
+            }
+            Some(Operation::Transfer(caller, recipient, amount)) => writeln!(
+                f,
+                "  Operation: transfer of {} AB from {} to {}",
+                amount, caller, recipient
+            )?,
+            Some(Operation::Evaluate(function_id, function_type, function_inputs)) => writeln!(
+                f,
+                "  Operation: call to function {} ({:?}) of {} AB from {} to {}",
+                function_id, function_type, function_inputs.amount, function_inputs.caller, function_inputs.recipient
+            )?,
+            None => writeln!(f, "  Operation: private")?,
+        }
+        for record in &self.records {
+            writeln!(
+                f,
+                "  Output {}: {} AB to {}{}",
+                record.index,
+                record.value,
+                record.owner,
+                if record.is_owned { " (owned)" } else { "" }
+            )?;
+        }
+        if self.num_custom_events > 0 {
+            writeln!(f, "  Custom events: {}", self.num_custom_events)?;
+        }
+        writeln!(f, "  Fee: {} AB", self.value_balance)
+    }
+}
+
+/// A summary of a transaction for display in wallets, listing each transition and,
+/// when summarized with a view key, the balance change of the account.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct TransactionSummary<N: Network> {
+    /// The ID of the transaction.
+    pub transaction_id: N::TransactionID,
+    /// The summaries of the transitions, in order.
+    pub transitions: Vec<TransitionSummary<N>>,
+    /// The address of the view key used to summarize the transaction, if given.
+    pub account: Option<Address<N>>,
+}
+
+impl<N: Network> TransactionSummary<N> {
+    /// Returns the summary of the given transaction, decrypting its records with the view key, if given.
+    pub fn new(transaction: &Transaction<N>, view_key: Option<&ViewKey<N>>) -> Self {
+        Self::from_transitions(
+            transaction.transaction_id(),
+            transaction
+                .transitions()
+                .iter()
+                .map(|transition| TransitionSummary::new(transition, view_key))
+                .collect(),
+            view_key,
+        )
+    }
+
+    /// Returns the summary of a transaction from the summaries of its transitions.
+    pub(crate) fn from_transitions(
+        transaction_id: N::TransactionID,
+        transitions: Vec<TransitionSummary<N>>,
+        view_key: Option<&ViewKey<N>>,
+    ) -> Self {
+        Self {
+            transaction_id,
+            transitions,
+            account: view_key.map(Address::from_view_key),
+        }
+    }
+
+    /// Returns the fee of the transaction. A coinbase transaction has a negative fee.
+    pub fn fee(&self) -> AleoAmount {
+        self.transitions.iter().map(|transition| transition.value_balance).sum()
+    }
+
+    /// Returns the net balance change of the summarizing account, if a view key was given.
+    ///
+    /// This counts the records received by the account, less the inputs of the public transitions
+    /// called by the account. The inputs of private transitions are not visible to a view key,
+    /// so spends in private transitions are not counted.
+    pub fn balance_change(&self) -> Option<AleoAmount> {
+        let account = self.account?;
+        Some(
+            self.transitions
+                .iter()
+                .map(|transition| match transition.caller() == Some(account) {
+                    true => transition
+                        .received()
+                        .sub(transition.spent().unwrap_or(AleoAmount::ZERO)),
+                    false => transition.received(),
+                })
+                .sum(),
+        )
+    }
+}
+
+impl<N: Network> fmt::Display for TransactionSummary<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Transaction {}", self.transaction_id)?;
+        for transition in &self.transitions {
+            for line in transition.to_string().lines() {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        write!(f, "Fee: {} AB", self.fee())?;
+        if let (Some(account), Some(balance_change)) = (self.account, self.balance_change()) {
+            write!(f, "\nBalance change of {}: {} AB", account, balance_change)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testnet2::Testnet2;
+
+    use rand::{CryptoRng, Rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use std::str::FromStr;
+
+    const SENDER: &str = "APrivateKey1zkpHW4CBDWxEZVRctpp6hCVaW5kYmHgZTkJUzfhV3tQAKKq";
+    const RECIPIENT: &str = "APrivateKey1zkp7fb3Uoisvk5awUgnfKSuYGmdHhAN1h3S87SyCHReh6rd";
+
+    /// Returns the record ciphertexts and events of a transition with the given outputs.
+    fn outputs<R: Rng + CryptoRng>(
+        outputs: &[(Address<Testnet2>, i64)],
+        is_public: bool,
+        rng: &mut R,
+    ) -> (Vec<<Testnet2 as Network>::RecordCiphertext>, Vec<Event<Testnet2>>) {
+        let mut ciphertexts = Vec::new();
+        let mut events = Vec::new();
+        for (index, (owner, value)) in outputs.iter().enumerate() {
+            let record = Record::new(
+                *owner,
+                AleoAmount(*value),
+                Default::default(),
+                *Testnet2::noop_program_id(),
+                rng,
+            )
+            .unwrap();
+            if is_public {
+                events.push(Event::RecordViewKey(index as u8, record.record_view_key().clone()));
+            }
+            ciphertexts.push(record.ciphertext().clone());
+        }
+        (ciphertexts, events)
+    }
+
+    #[test]
+    fn test_public_transfer_summary() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let sender = ViewKey::from(&PrivateKey::<Testnet2>::from_str(SENDER).unwrap());
+        let recipient = ViewKey::from(&PrivateKey::<Testnet2>::from_str(RECIPIENT).unwrap());
+        let (sender_address, recipient_address) = (Address::from_view_key(&sender), Address::from_view_key(&recipient));
+
+        // Transfer 300 from an input of 1000, with a fee of 10.
+        let (ciphertexts, mut events) = outputs(&[(sender_address, 690), (recipient_address, 300)], true, rng);
+        events.push(Event::Custom(vec![1, 2, 3]));
+        events.push(Event::Operation(Operation::Transfer(
+            sender_address,
+            recipient_address,
+            AleoAmount(300),
+        )));
+
+        let summarize = |view_key| {
+            TransactionSummary::from_transitions(
+                Default::default(),
+                vec![TransitionSummary::from_parts(
+                    Default::default(),
+                    &ciphertexts,
+                    AleoAmount(10),
+                    &events,
+                    view_key,
+                )],
+                view_key,
+            )
+        };
+
+        let summary = summarize(Some(&sender));
+        assert_eq!(Some(sender_address), summary.transitions[0].caller());
+        assert_eq!(Some(AleoAmount(1000)), summary.transitions[0].spent());
+        assert_eq!(AleoAmount(10), summary.fee());
+        assert_eq!(Some(AleoAmount(-310)), summary.balance_change());
+        assert_eq!(
+            format!(
+                "Transaction {}\n  Transition {}\n    Operation: transfer of 300 AB from {} to {}\n    Output 0: 690 AB to {} (owned)\n    Output 1: 300 AB to {}\n    Custom events: 1\n    Fee: 10 AB\nFee: 10 AB\nBalance change of {}: -310 AB",
+                <Testnet2 as Network>::TransactionID::default(),
+                <Testnet2 as Network>::TransitionID::default(),
+                sender_address,
+                recipient_address,
+                sender_address,
+                recipient_address,
+                sender_address,
+            ),
+            summary.to_string()
+        );
+
+        let summary = summarize(Some(&recipient));
+        assert!(summary.transitions[0].records[1].is_owned);
+        assert_eq!(Some(AleoAmount(300)), summary.balance_change());
+
+        // Check the public records are listed without a view key.
+        let summary = summarize(None);
+        assert_eq!(2, summary.transitions[0].records.len());
+        assert!(summary.transitions[0].records.iter().all(|record| !record.is_owned));
+        assert_eq!(None, summary.balance_change());
+    }
+
+    #[test]
+    fn test_private_transfer_summary() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let sender = ViewKey::from(&PrivateKey::<Testnet2>::from_str(SENDER).unwrap());
+        let recipient = ViewKey::from(&PrivateKey::<Testnet2>::from_str(RECIPIENT).unwrap());
+        let (sender_address, recipient_address) = (Address::from_view_key(&sender), Address::from_view_key(&recipient));
+
+        let (ciphertexts, events) = outputs(&[(sender_address, 690), (recipient_address, 300)], false, rng);
+        let summarize = |view_key| {
+            TransactionSummary::from_transitions(
+                Default::default(),
+                vec![TransitionSummary::from_parts(
+                    Default::default(),
+                    &ciphertexts,
+                    AleoAmount(10),
+                    &events,
+                    view_key,
+                )],
+                view_key,
+            )
+        };
+
+        // Check only the owned record is visible.
+        let summary = summarize(Some(&recipient));
+        assert_eq!(None, summary.transitions[0].operation);
+        assert_eq!(
+            vec![RecordSummary {
+                index: 1,
+                owner: recipient_address,
+                value: AleoAmount(300),
+                is_owned: true,
+            }],
+            summary.transitions[0].records
+        );
+        assert_eq!(Some(AleoAmount(300)), summary.balance_change());
+        assert_eq!(
+            format!(
+                "Transaction {}\n  Transition {}\n    Operation: private\n    Output 1: 300 AB to {} (owned)\n    Fee: 10 AB\nFee: 10 AB\nBalance change of {}: 300 AB",
+                <Testnet2 as Network>::TransactionID::default(),
+                <Testnet2 as Network>::TransitionID::default(),
+                recipient_address,
+                recipient_address,
+            ),
+            summary.to_string()
+        );
+
+        // Check nothing is visible without a view key.
+        let summary = summarize(None);
+        assert!(summary.transitions[0].records.is_empty());
+    }
+
+    #[test]
+    fn test_coinbase_summary() {
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let recipient = ViewKey::from(&PrivateKey::<Testnet2>::from_str(RECIPIENT).unwrap());
+        let recipient_address = Address::from_view_key(&recipient);
+
+        let (ciphertexts, mut events) = outputs(&[(recipient_address, 1000)], true, rng);
+        events.push(Event::Operation(Operation::Coinbase(
+            recipient_address,
+            AleoAmount(1000),
+        )));
+
+        let summary = TransactionSummary::from_transitions(
+            Default::default(),
+            vec![TransitionSummary::from_parts(
+                Default::default(),
+                &ciphertexts,
+                AleoAmount(-1000),
+                &events,
+                Some(&recipient),
+            )],
+            Some(&recipient),
+        );
+        assert_eq!(None, summary.transitions[0].caller());
+        assert_eq!(AleoAmount(-1000), summary.fee());
+        assert_eq!(Some(AleoAmount(1000)), summary.balance_change());
+        assert!(summary
+            .to_string()
+            .contains(&format!("Operation: coinbase of 1000 AB to {}", recipient_address)));
+    }
+}
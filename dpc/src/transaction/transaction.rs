@@ -18,19 +18,27 @@ use crate::{
     record::*,
     Address,
     AleoAmount,
+    ConflictKeys,
+    DeserializationError,
     Event,
     LedgerTree,
     LedgerTreeScheme,
     LocalProof,
     Network,
+    OuterPublicVariables,
     Request,
+    SizeBreakdown,
+    TransactionSummary,
     Transition,
     Transitions,
+    ViewKey,
     VirtualMachine,
 };
+use snarkvm_algorithms::SNARK;
 use snarkvm_utilities::{
     has_duplicates,
     io::{Read, Result as IoResult, Write},
+    FieldContext,
     FromBytes,
     FromBytesDeserializer,
     ToBytes,
@@ -39,7 +47,7 @@ use snarkvm_utilities::{
 
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
-use rand::{CryptoRng, Rng};
+use rand::{thread_rng, CryptoRng, Rng};
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt,
@@ -108,108 +116,198 @@ impl<N: Network> Transaction<N> {
         }
     }
 
+    /// Initializes an instance of `Transaction` from the given inputs, without checking its validity.
+    #[cfg(any(test, feature = "test-helpers"))]
+    pub(crate) fn from_unchecked(
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        transitions: Vec<Transition<N>>,
+    ) -> Result<Self> {
+        Ok(Self {
+            transaction_id: Self::compute_transaction_id(&transitions)?,
+            inner_circuit_id,
+            ledger_root,
+            transitions,
+        })
+    }
+
     /// Returns `true` if the transaction is well-formed, meaning it contains
     /// the correct network ID, unique serial numbers, unique commitments,
     /// correct ciphertext IDs, and a valid proof.
     #[inline]
     pub fn is_valid(&self) -> bool {
+        // Returns `false` if the transaction is malformed.
+        let public_variables = match self.to_well_formed_public_variables() {
+            Ok(public_variables) => public_variables,
+            Err(error) => {
+                eprintln!("{}", error);
+                return false;
+            }
+        };
+
+        // Returns `false` if any transition proof is invalid, verifying multiple proofs in a single batch.
+        let verifying_key = N::outer_prepared_verifying_key();
+        let inputs_and_proofs = public_variables
+            .iter()
+            .zip(&self.transitions)
+            .map(|(public_variables, transition)| (public_variables, &**transition.proof()))
+            .collect::<Vec<_>>();
+        let result = match &inputs_and_proofs[..] {
+            [(public_variables, proof)] => N::OuterSNARK::verify_prepared(verifying_key, public_variables, proof),
+            _ => N::OuterSNARK::verify_batch_prepared(verifying_key, &inputs_and_proofs, &mut thread_rng()),
+        };
+        match result {
+            Ok(true) => true,
+            Ok(false) => {
+                eprintln!("Transaction contains an invalid transition proof");
+                false
+            }
+            Err(error) => {
+                eprintln!("Failed to validate transition proofs: {:?}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns an error if the transaction is malformed, performing every check of
+    /// `Transaction::is_valid` except for the verification of the transition proofs.
+    pub fn check_well_formed(&self) -> Result<()> {
+        self.to_well_formed_public_variables().map(|_| ())
+    }
+
+    /// Returns the outer public variables of each transition, if the transaction is well-formed.
+    fn to_well_formed_public_variables(&self) -> Result<Vec<OuterPublicVariables<N>>> {
         // Ensure the number of transitions is between 1 and N::NUM_TRANSITIONS.
         let num_transitions = self.transitions.len();
         if num_transitions < 1 || num_transitions > N::NUM_TRANSITIONS as usize {
-            eprintln!("Transaction contains invalid number of transitions");
-            return false;
+            return Err(anyhow!("Transaction contains invalid number of transitions"));
         }
 
         // Ensure the number of events is less than `N::NUM_EVENTS`.
         if self.events().count() > num_transitions * N::NUM_EVENTS as usize {
-            eprintln!("Transaction contains an invalid number of events");
-            return false;
+            return Err(anyhow!("Transaction contains an invalid number of events"));
         }
 
-        // Returns `false` if the number of serial numbers in the transaction is incorrect.
+        // Ensure the number of serial numbers in the transaction is correct.
         if self.serial_numbers().count() != num_transitions * N::NUM_INPUT_RECORDS {
-            eprintln!("Transaction contains incorrect number of serial numbers");
-            return false;
+            return Err(anyhow!("Transaction contains incorrect number of serial numbers"));
         }
 
-        // Returns `false` if there are duplicate serial numbers in the transaction.
+        // Ensure there are no duplicate serial numbers in the transaction.
         if has_duplicates(self.serial_numbers()) {
-            eprintln!("Transaction contains duplicate serial numbers");
-            return false;
+            return Err(anyhow!("Transaction contains duplicate serial numbers"));
         }
 
-        // Returns `false` if the number of commitments in the transaction is incorrect.
+        // Ensure the number of commitments in the transaction is correct.
         if self.commitments().count() != num_transitions * N::NUM_OUTPUT_RECORDS {
-            eprintln!("Transaction contains incorrect number of commitments");
-            return false;
+            return Err(anyhow!("Transaction contains incorrect number of commitments"));
         }
 
-        // Returns `false` if there are duplicate commitments numbers in the transaction.
+        // Ensure there are no duplicate commitments in the transaction.
         if has_duplicates(self.commitments()) {
-            eprintln!("Transaction contains duplicate commitments");
-            return false;
+            return Err(anyhow!("Transaction contains duplicate commitments"));
         }
 
-        // Returns `false` if the number of record ciphertexts in the transaction is incorrect.
+        // Ensure the number of record ciphertexts in the transaction is correct.
         if self.ciphertexts().count() != num_transitions * N::NUM_OUTPUT_RECORDS {
-            eprintln!("Transaction contains incorrect number of record ciphertexts");
-            return false;
+            return Err(anyhow!("Transaction contains incorrect number of record ciphertexts"));
         }
 
-        // Returns `false` if there are duplicate ciphertexts in the transition.
+        // Ensure there are no duplicate ciphertexts in the transaction.
         if has_duplicates(self.ciphertexts()) {
-            eprintln!("Transaction contains duplicate ciphertexts");
-            return false;
+            return Err(anyhow!("Transaction contains duplicate ciphertexts"));
         }
 
-        // Returns `false` if the transaction is not a coinbase, and has a transition with a negative value balance.
+        // Ensure that, unless the transaction is a coinbase, no transition has a negative value balance.
         if self.transitions.len() > 1
             && self
                 .transitions
                 .iter()
                 .any(|transition| transition.value_balance().is_negative())
         {
-            eprintln!("Transaction contains a transition with a negative value balance");
-            return false;
+            return Err(anyhow!("Transaction contains a transition with a negative value balance"));
         }
 
         // Initialize a local transitions tree.
-        let mut transitions = match Transitions::<N>::new() {
-            Ok(transitions) => transitions,
-            Err(error) => {
-                eprintln!("Transaction failed to initialize a local transitions tree: {}", error);
-                return false;
-            }
-        };
+        let mut transitions = Transitions::<N>::new()
+            .map_err(|error| anyhow!("Transaction failed to initialize a local transitions tree: {}", error))?;
 
-        // Returns `false` if any transition is invalid.
+        let mut public_variables = Vec::with_capacity(num_transitions);
         for transition in &self.transitions {
-            // Returns `false` if the transition is invalid.
-            if !transition.verify(self.inner_circuit_id, self.ledger_root, transitions.root()) {
-                eprintln!("Transaction contains an invalid transition");
-                return false;
+            // Ensure the transition is well-formed.
+            if !transition.is_well_formed() {
+                return Err(anyhow!("Transaction contains an invalid transition"));
             }
 
+            // Each transition is proven against the local transitions root of the transitions before it.
+            public_variables.push(transition.to_outer_public_variables(
+                self.inner_circuit_id,
+                self.ledger_root,
+                transitions.root(),
+            ));
+
             // Update the local transitions tree.
-            if let Err(error) = transitions.add(transition) {
-                eprintln!("Transaction failed to update local transitions tree: {}", error);
-                return false;
-            }
+            transitions
+                .add(transition)
+                .map_err(|error| anyhow!("Transaction failed to update local transitions tree: {}", error))?;
         }
 
-        // Returns `false` if the size of the local transitions tree does not match the number of transitions.
+        // Ensure the size of the local transitions tree matches the number of transitions.
         if transitions.len() != num_transitions {
-            eprintln!("Transaction contains invalid local transitions tree state");
-            return false;
+            return Err(anyhow!("Transaction contains invalid local transitions tree state"));
         }
 
-        // Returns `false` if the final transitions root does not match the transaction ID.
+        // Ensure the final transitions root matches the transaction ID.
         if transitions.root() != self.transaction_id {
-            eprintln!("Transaction contains an invalid transaction ID");
-            return false;
+            return Err(anyhow!("Transaction contains an invalid transaction ID"));
+        }
+
+        Ok(public_variables)
+    }
+
+    /// Returns the IDs of the transitions in `transactions` with an invalid proof.
+    ///
+    /// Every transition proof is verified against the outer verifying key, so the proofs are
+    /// first verified together in a single batch. If the batch fails, each proof is verified
+    /// individually to identify the invalid transitions. A transition proof is accepted here
+    /// if and only if it is accepted by `Transaction::is_valid`.
+    pub fn find_invalid_transition_proofs(transactions: &[Self]) -> Result<Vec<N::TransitionID>> {
+        // Compute the outer public variables of each transition.
+        let mut statements = Vec::with_capacity(transactions.iter().map(|t| t.transitions.len()).sum());
+        for transaction in transactions {
+            let mut transitions = Transitions::<N>::new()?;
+            for transition in &transaction.transitions {
+                let public_variables = transition.to_outer_public_variables(
+                    transaction.inner_circuit_id,
+                    transaction.ledger_root,
+                    transitions.root(),
+                );
+                statements.push((transition, public_variables));
+                transitions.add(transition)?;
+            }
         }
 
-        true
+        let verifying_key = N::outer_prepared_verifying_key();
+        let inputs_and_proofs = statements
+            .iter()
+            .map(|(transition, public_variables)| (public_variables, &**transition.proof()))
+            .collect::<Vec<_>>();
+        if let Ok(true) = N::OuterSNARK::verify_batch_prepared(verifying_key, &inputs_and_proofs, &mut thread_rng()) {
+            return Ok(vec![]);
+        }
+
+        // Fall back to verifying each proof individually.
+        Ok(statements
+            .iter()
+            .zip(&inputs_and_proofs)
+            .filter(|(_, (public_variables, proof))| {
+                !matches!(
+                    N::OuterSNARK::verify_prepared(verifying_key, public_variables, proof),
+                    Ok(true)
+                )
+            })
+            .map(|((transition, _), _)| transition.transition_id())
+            .collect())
     }
 
     /// Returns `true` if the given transition ID exists.
@@ -289,12 +387,34 @@ impl<N: Network> Transaction<N> {
         self.transitions.iter().flat_map(Transition::events)
     }
 
+    /// Returns the data of the custom events, in the order they are emitted.
+    pub fn custom_events(&self) -> impl Iterator<Item = &Vec<u8>> + fmt::Debug + '_ {
+        self.events().filter_map(|event| match event {
+            Event::Custom(data) => Some(data),
+            _ => None,
+        })
+    }
+
     /// Returns a reference to the state transitions.
     #[inline]
     pub fn transitions(&self) -> &Vec<Transition<N>> {
         &self.transitions
     }
 
+    /// Returns the size in bytes of each part of the transaction, as encoded by `ToBytes`.
+    pub fn size_breakdown(&self) -> Result<SizeBreakdown> {
+        Ok(SizeBreakdown {
+            header: self.inner_circuit_id.to_bytes_le()?.len()
+                + self.ledger_root.to_bytes_le()?.len()
+                + (self.transitions.len() as u16).to_bytes_le()?.len(),
+            transitions: self
+                .transitions
+                .iter()
+                .map(Transition::size_breakdown)
+                .collect::<Result<Vec<_>>>()?,
+        })
+    }
+
     /// Returns records from the transaction belonging to the given account view key.
     #[inline]
     pub fn to_decrypted_records<'a>(
@@ -312,6 +432,60 @@ impl<N: Network> Transaction<N> {
         self.transitions.iter().flat_map(Transition::to_records)
     }
 
+    /// Returns a summary of the transaction for display, decrypting the records owned by the view key, if given.
+    #[inline]
+    pub fn summarize(&self, view_key: Option<&ViewKey<N>>) -> TransactionSummary<N> {
+        TransactionSummary::new(self, view_key)
+    }
+
+    ///
+    /// Returns the ordered leaves of the transactions tree, whose root is the transaction ID.
+    ///
+    /// The transactions tree has a depth of `N::TRANSACTION_TREE_DEPTH`, and its leaves are the IDs of
+    /// the transitions in order, with the remaining leaves up to `N::NUM_TRANSITIONS` left empty.
+    /// Each leaf is hashed with `N::transaction_id_parameters()`. See `Transition::id_preimage`
+    /// for the leaves of each transition ID.
+    ///
+    #[inline]
+    pub fn id_preimage(&self) -> Vec<N::TransitionID> {
+        self.transition_ids().collect()
+    }
+
+    /// Returns `true` if the transaction ID and the ID of each transition match the roots of their trees.
+    #[inline]
+    pub fn verify_id(&self) -> bool {
+        // Returns `false` if any transition ID is invalid.
+        if !self.transitions.iter().all(Transition::verify_id) {
+            return false;
+        }
+
+        match Self::compute_transaction_id(&self.transitions) {
+            Ok(transaction_id) => transaction_id == self.transaction_id,
+            Err(error) => {
+                eprintln!("Failed to compute the transaction ID: {}", error);
+                false
+            }
+        }
+    }
+
+    /// Returns the field elements passed to the outer circuit verifier for each transition, in order.
+    /// Each transition is verified against the local transitions root of the transitions before it.
+    pub fn to_public_inputs(&self) -> Result<Vec<Vec<N::OuterScalarField>>> {
+        let mut transitions = Transitions::<N>::new()?;
+        let mut public_inputs = Vec::with_capacity(self.transitions.len());
+        for transition in &self.transitions {
+            public_inputs.push(transition.to_public_inputs(self.inner_circuit_id, self.ledger_root, transitions.root())?);
+            transitions.add(transition)?;
+        }
+        Ok(public_inputs)
+    }
+
+    /// Returns the keys this transaction claims exclusively on the ledger.
+    #[inline]
+    pub fn conflict_keys(&self) -> ConflictKeys<N> {
+        ConflictKeys::new(self)
+    }
+
     /// Returns the local proof for a given commitment.
     #[inline]
     pub fn to_local_proof(&self, record_commitment: N::Commitment) -> Result<LocalProof<N>> {
@@ -338,16 +512,18 @@ impl<N: Network> Transaction<N> {
 impl<N: Network> FromBytes for Transaction<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let inner_circuit_id = FromBytes::read_le(&mut reader)?;
-        let ledger_root = FromBytes::read_le(&mut reader)?;
+        let inner_circuit_id = FromBytes::read_le(&mut reader).in_field("Transaction", "inner_circuit_id")?;
+        let ledger_root = FromBytes::read_le(&mut reader).in_field("Transaction", "ledger_root")?;
 
-        let num_transitions: u16 = FromBytes::read_le(&mut reader)?;
+        let num_transitions: u16 = FromBytes::read_le(&mut reader).in_field("Transaction", "transitions")?;
+        DeserializationError::check_length("transitions", num_transitions as usize, N::NUM_TRANSITIONS as usize)?;
         let mut transitions = Vec::with_capacity(num_transitions as usize);
         for _ in 0..num_transitions {
-            transitions.push(FromBytes::read_le(&mut reader)?);
+            transitions.push(FromBytes::read_le(&mut reader).in_field("Transaction", "transitions")?);
         }
 
-        Ok(Self::from(inner_circuit_id, ledger_root, transitions).expect("Failed to deserialize a transaction"))
+        Ok(Self::from(inner_circuit_id, ledger_root, transitions)
+            .map_err(|error| DeserializationError::InvalidObject("transaction", error.to_string()))?)
     }
 }
 
@@ -438,8 +614,12 @@ impl<N: Network> Hash for Transaction<N> {
 mod tests {
     use super::*;
     use crate::{testnet2::Testnet2, Account, AccountScheme};
+    use snarkvm_algorithms::merkle_tree::MerkleTree;
+    use snarkvm_utilities::{to_bytes_le, UniformRand};
 
-    use rand::thread_rng;
+    use rand::{thread_rng, SeedableRng};
+    use rand_chacha::ChaChaRng;
+    use std::sync::Arc;
 
     #[test]
     fn test_decrypt_records() {
@@ -482,6 +662,157 @@ mod tests {
         assert_eq!(expected_record.program_id(), candidate_record.program_id());
     }
 
+    #[test]
+    fn test_deterministic_coinbase() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+
+        // Craft the same coinbase transaction twice, using the same seed.
+        let (expected_transaction, expected_record) = Transaction::<Testnet2>::new_coinbase(
+            account.address(),
+            AleoAmount(1234),
+            true,
+            &mut ChaChaRng::seed_from_u64(1231275789u64),
+        )
+        .unwrap();
+        let (candidate_transaction, candidate_record) = Transaction::<Testnet2>::new_coinbase(
+            account.address(),
+            AleoAmount(1234),
+            true,
+            &mut ChaChaRng::seed_from_u64(1231275789u64),
+        )
+        .unwrap();
+
+        assert_eq!(expected_transaction, candidate_transaction);
+        assert_eq!(
+            expected_transaction.to_bytes_le().unwrap(),
+            candidate_transaction.to_bytes_le().unwrap()
+        );
+        assert_eq!(expected_record, candidate_record);
+    }
+
+    #[test]
+    fn test_seeded_transaction() {
+        let account = Account::<Testnet2>::new(&mut thread_rng());
+        let request =
+            Request::new_coinbase(account.address(), AleoAmount(1234), true, &mut thread_rng()).unwrap();
+        let ledger = LedgerTree::<Testnet2>::new().unwrap();
+
+        // Craft the same transaction twice, using the same seed.
+        let expected_transaction =
+            Transaction::new(ledger.clone(), &request, &mut ChaChaRng::seed_from_u64(1231275789u64)).unwrap();
+        let candidate_transaction =
+            Transaction::new(ledger.clone(), &request, &mut ChaChaRng::seed_from_u64(1231275789u64)).unwrap();
+        assert_eq!(
+            expected_transaction.to_bytes_le().unwrap(),
+            candidate_transaction.to_bytes_le().unwrap()
+        );
+
+        // Ensure a different seed crafts a different transaction.
+        let other_transaction =
+            Transaction::new(ledger, &request, &mut ChaChaRng::seed_from_u64(1231275790u64)).unwrap();
+        assert_ne!(
+            expected_transaction.to_bytes_le().unwrap(),
+            other_transaction.to_bytes_le().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_size_breakdown() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        let (coinbase, _) = Transaction::new_coinbase(account.address(), AleoAmount(1234), true, rng).unwrap();
+        for transaction in &[Testnet2::genesis_block().to_coinbase_transaction().unwrap(), coinbase] {
+            let breakdown = transaction.size_breakdown().unwrap();
+
+            // Check the parts sum to the encoded size of the transaction and of each transition.
+            assert_eq!(transaction.to_bytes_le().unwrap().len(), breakdown.total());
+            assert_eq!(transaction.transitions().len(), breakdown.transitions.len());
+            for (transition, size) in transaction.transitions().iter().zip(&breakdown.transitions) {
+                assert_eq!(transition.to_bytes_le().unwrap().len(), size.total());
+                assert_eq!(Testnet2::OUTER_PROOF_SIZE_IN_BYTES, size.proof);
+                assert_eq!(
+                    Testnet2::NUM_OUTPUT_RECORDS * Testnet2::RECORD_CIPHERTEXT_SIZE_IN_BYTES,
+                    size.outputs
+                );
+            }
+            assert_eq!(
+                transaction.transitions().len() * Testnet2::OUTER_PROOF_SIZE_IN_BYTES,
+                breakdown.proofs()
+            );
+
+            // Check the breakdown round-trips through JSON.
+            let candidate = serde_json::from_str(&serde_json::to_string(&breakdown).unwrap()).unwrap();
+            assert_eq!(breakdown, candidate);
+            let expected_total = format!("total: {} bytes", breakdown.total());
+            assert!(breakdown.to_string().ends_with(&expected_total));
+        }
+    }
+
+    #[test]
+    fn test_id_preimage() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        assert!(transaction.verify_id());
+
+        // Recompute the transaction ID from the preimage.
+        let leaves = transaction.id_preimage();
+        assert_eq!(transaction.transition_ids().collect::<Vec<_>>(), leaves);
+        let parameters = Arc::new(Testnet2::transaction_id_parameters().clone());
+        let tree =
+            MerkleTree::<<Testnet2 as Network>::TransactionIDParameters>::new(parameters.clone(), &leaves).unwrap();
+        assert_eq!(transaction.transaction_id(), (*tree.root()).into());
+
+        // Check a single-bit corruption in a leaf changes the transaction ID.
+        let mut leaf_bytes = leaves[0].to_bytes_le().unwrap();
+        leaf_bytes[0] ^= 1;
+        let corrupted_leaves = vec![<Testnet2 as Network>::TransitionID::read_le(&leaf_bytes[..]).unwrap()];
+        let tree =
+            MerkleTree::<<Testnet2 as Network>::TransactionIDParameters>::new(parameters, &corrupted_leaves).unwrap();
+        assert_ne!(transaction.transaction_id(), (*tree.root()).into());
+
+        // Check a corrupted transaction ID is detected.
+        let mut corrupted_transaction = transaction;
+        let mut transaction_id_bytes = corrupted_transaction.transaction_id.to_bytes_le().unwrap();
+        transaction_id_bytes[0] ^= 1;
+        corrupted_transaction.transaction_id = FromBytes::read_le(&transaction_id_bytes[..]).unwrap();
+        assert!(!corrupted_transaction.verify_id());
+    }
+
+    #[test]
+    fn test_to_public_inputs() {
+        use snarkvm_algorithms::{snark::groth16, Prepare};
+        use snarkvm_fields::One;
+
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let public_inputs = transaction.to_public_inputs().unwrap();
+        assert_eq!(transaction.transitions().len(), public_inputs.len());
+
+        let layout = Transition::<Testnet2>::public_input_layout().unwrap();
+        let verifying_key = Testnet2::outer_verifying_key().prepare();
+        let local_transitions_root = Transitions::<Testnet2>::new().unwrap().root();
+        for (transition, inputs) in transaction.transitions().iter().zip_eq(&public_inputs) {
+            assert_eq!(layout.len(), inputs.len());
+
+            // Check the extracted inputs are accepted by the verifier, as in `Transaction::is_valid`.
+            assert!(transaction.is_valid());
+            assert!(groth16::verify_proof(&verifying_key, transition.proof(), inputs).unwrap());
+
+            // Check the extracted inputs for an incorrect ledger root are rejected, as in `Transition::verify`.
+            let ledger_root = Default::default();
+            let inner_circuit_id = transaction.inner_circuit_id();
+            let incorrect_inputs = transition
+                .to_public_inputs(inner_circuit_id, ledger_root, local_transitions_root)
+                .unwrap();
+            assert!(!transition.verify(inner_circuit_id, ledger_root, local_transitions_root));
+            assert!(!groth16::verify_proof(&verifying_key, transition.proof(), &incorrect_inputs).unwrap());
+
+            // Check the verifier rejects a corrupted input.
+            let mut corrupted_inputs = inputs.clone();
+            corrupted_inputs[0] += <Testnet2 as Network>::OuterScalarField::one();
+            assert!(!groth16::verify_proof(&verifying_key, transition.proof(), &corrupted_inputs).unwrap());
+        }
+    }
+
     #[test]
     fn test_transaction_serde_json() {
         let rng = &mut thread_rng();
@@ -525,4 +856,144 @@ mod tests {
             bincode::deserialize(&candidate_bytes[..]).unwrap()
         );
     }
+
+    #[test]
+    fn test_deserialize_too_many_transitions() {
+        let rng = &mut thread_rng();
+        let inner_circuit_id = <Testnet2 as Network>::InnerCircuitID::rand(rng);
+        let ledger_root = <Testnet2 as Network>::LedgerRoot::rand(rng);
+
+        for num_transitions in [Testnet2::NUM_TRANSITIONS as u16 + 1, 1000, u16::MAX] {
+            let bytes = to_bytes_le![inner_circuit_id, ledger_root, num_transitions].unwrap();
+            let error = Transaction::<Testnet2>::read_le(&bytes[..]).unwrap_err();
+            assert!(matches!(
+                error.get_ref().and_then(|error| error.downcast_ref()),
+                Some(DeserializationError::TooManyItems("transitions", ..))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_find_invalid_transition_proofs() {
+        let rng = &mut thread_rng();
+        let transaction = Testnet2::genesis_block().transactions()[0].clone();
+
+        // Ensure a batch of valid transactions has no invalid transition proofs.
+        let transactions = vec![transaction.clone(); 5];
+        assert!(
+            Transaction::find_invalid_transition_proofs(&transactions)
+                .unwrap()
+                .is_empty()
+        );
+
+        // Craft a transaction whose transition proofs do not match its ledger root.
+        let invalid_transaction = Transaction::<Testnet2>::from_unchecked(
+            transaction.inner_circuit_id(),
+            UniformRand::rand(rng),
+            transaction.transitions().clone(),
+        )
+        .unwrap();
+        assert!(!invalid_transaction.is_valid());
+
+        // Ensure the invalid transition proof is identified.
+        let mut transactions = vec![transaction; 5];
+        transactions.insert(2, invalid_transaction.clone());
+        assert_eq!(
+            invalid_transaction.transition_ids().collect::<Vec<_>>(),
+            Transaction::find_invalid_transition_proofs(&transactions).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_custom_events() {
+        let rng = &mut thread_rng();
+
+        // Craft a transaction whose transitions emit custom events among other events.
+        let transitions = [
+            vec![
+                Event::Custom(b"first".to_vec()),
+                Event::Operation(crate::Operation::Noop),
+                Event::Custom(vec![]),
+            ],
+            vec![],
+            vec![Event::Custom(b"last".to_vec())],
+        ]
+        .iter()
+        .map(|events| {
+            let transition = crate::test_helpers::sample_transition::<Testnet2, _>(rng).unwrap();
+            Transition::from(
+                transition.transition_id(),
+                transition.serial_numbers().cloned().collect(),
+                transition.ciphertexts().cloned().collect(),
+                AleoAmount::ZERO,
+                events.clone(),
+                transition.proof().clone(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<_>>();
+        let transaction =
+            Transaction::<Testnet2>::from_unchecked(UniformRand::rand(rng), UniformRand::rand(rng), transitions).unwrap();
+
+        // Ensure the custom events are read back in order, without the other events.
+        assert_eq!(
+            vec![b"first".to_vec(), vec![], b"last".to_vec()],
+            transaction.custom_events().cloned().collect::<Vec<_>>()
+        );
+        assert_eq!(4, transaction.events().count());
+    }
+
+    #[test]
+    fn test_is_valid_with_multiple_transitions() {
+        let rng = &mut thread_rng();
+
+        // Craft a transaction with three well-formed transitions, whose proofs are invalid.
+        let transitions = (0..3)
+            .map(|_| {
+                let transition = crate::test_helpers::sample_transition::<Testnet2, _>(rng).unwrap();
+                Transition::from(
+                    transition.transition_id(),
+                    transition.serial_numbers().cloned().collect(),
+                    transition.ciphertexts().cloned().collect(),
+                    AleoAmount::ZERO,
+                    vec![],
+                    transition.proof().clone(),
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let transaction =
+            Transaction::<Testnet2>::from_unchecked(UniformRand::rand(rng), UniformRand::rand(rng), transitions).unwrap();
+        assert!(transaction.transitions().iter().all(Transition::is_well_formed));
+
+        // Ensure the batched verification rejects the transaction, and identifies every invalid proof.
+        assert!(!transaction.is_valid());
+        assert_eq!(
+            transaction.transition_ids().collect::<Vec<_>>(),
+            Transaction::find_invalid_transition_proofs(&[transaction]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_valid_with_multiple_valid_transitions() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        // Craft a transaction with two coinbase transitions.
+        let mut vm = VirtualMachine::<Testnet2>::new(LedgerTree::<Testnet2>::new().unwrap().root()).unwrap();
+        for amount in [1234, 5678] {
+            let request = Request::new_coinbase(account.address(), AleoAmount(amount), true, rng).unwrap();
+            vm = vm.execute(&request, rng).unwrap().0;
+        }
+        let transaction = vm.finalize().unwrap();
+        assert_eq!(2, transaction.transitions().len());
+
+        // Ensure the batched verification accepts the transaction.
+        assert!(transaction.is_valid());
+        assert!(
+            Transaction::find_invalid_transition_proofs(&[transaction])
+                .unwrap()
+                .is_empty()
+        );
+    }
 }
@@ -0,0 +1,238 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::prelude::*;
+use snarkvm_algorithms::crh::sha256::sha256;
+use snarkvm_utilities::ToBytes;
+
+use std::collections::{HashMap, VecDeque};
+
+///
+/// A bounded cache of transaction verification outcomes, to avoid verifying the transition proofs
+/// of a transaction again when it is received more than once.
+///
+/// Outcomes are keyed by transaction ID, and stored with the checksum of the transaction bytes.
+/// As the transaction ID does not commit to the ledger root, the inner circuit ID, or the transition
+/// proofs, an outcome is only reused for a transaction whose bytes match its checksum.
+/// Whether the ledger root is on the canon chain depends on the ledger, and is never cached.
+///
+/// When the cache is full, the oldest outcome is evicted first.
+///
+#[derive(Derivative)]
+#[derivative(Clone(bound = "N: Network"), Debug(bound = "N: Network"))]
+pub struct VerifiedCache<N: Network> {
+    /// The maximum number of outcomes in the cache.
+    capacity: usize,
+    /// The checksum of the transaction bytes and the verification outcome, for each transaction ID.
+    outcomes: HashMap<N::TransactionID, ([u8; 32], bool)>,
+    /// The transaction IDs in the cache, from oldest to newest.
+    insertion_order: VecDeque<N::TransactionID>,
+    /// The number of lookups answered from the cache.
+    hits: u64,
+    /// The number of lookups that required verification.
+    misses: u64,
+}
+
+impl<N: Network> VerifiedCache<N> {
+    /// Initializes a new cache that holds at most `capacity` verification outcomes.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            outcomes: Default::default(),
+            insertion_order: Default::default(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Returns `true` if the given transaction is valid, verifying it only if its outcome is not cached.
+    pub fn is_valid(&mut self, transaction: &Transaction<N>) -> bool {
+        self.get_or_verify(transaction, Transaction::is_valid)
+    }
+
+    /// Returns the cached outcome for the given transaction, or the outcome of `verify` if it is not cached.
+    fn get_or_verify(
+        &mut self,
+        transaction: &Transaction<N>,
+        verify: impl FnOnce(&Transaction<N>) -> bool,
+    ) -> bool {
+        let transaction_id = transaction.transaction_id();
+
+        // A transaction that can not be serialized is verified without caching its outcome.
+        let checksum = match transaction.to_bytes_le() {
+            Ok(bytes) => sha256(&bytes),
+            Err(_) => {
+                self.misses += 1;
+                return verify(transaction);
+            }
+        };
+
+        if let Some((cached_checksum, is_valid)) = self.outcomes.get(&transaction_id) {
+            if *cached_checksum == checksum {
+                self.hits += 1;
+                return *is_valid;
+            }
+        }
+
+        self.misses += 1;
+        let is_valid = verify(transaction);
+        self.insert(transaction_id, checksum, is_valid);
+        is_valid
+    }
+
+    /// Returns the number of lookups answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of lookups that required verification.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Returns the number of outcomes in the cache.
+    pub fn len(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    /// Returns `true` if the cache holds no outcomes.
+    pub fn is_empty(&self) -> bool {
+        self.outcomes.is_empty()
+    }
+
+    /// Removes every outcome from the cache, keeping the hit and miss counts.
+    pub fn clear(&mut self) {
+        self.outcomes.clear();
+        self.insertion_order.clear();
+    }
+
+    /// Stores the given outcome, replacing any outcome for the same transaction ID, and evicting the oldest outcomes.
+    fn insert(&mut self, transaction_id: N::TransactionID, checksum: [u8; 32], is_valid: bool) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if self.outcomes.insert(transaction_id, (checksum, is_valid)).is_none() {
+            self.insertion_order.push_back(transaction_id);
+        }
+
+        while self.outcomes.len() > self.capacity {
+            match self.insertion_order.pop_front() {
+                Some(oldest) => {
+                    self.outcomes.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{test_helpers::sample_transition, testnet2::Testnet2};
+
+    use rand::{thread_rng, Rng};
+    use std::cell::Cell;
+
+    /// Returns a transaction with a random ledger root, and a copy of it under another ledger root.
+    fn sample_transactions() -> (Transaction<Testnet2>, Transaction<Testnet2>) {
+        let rng = &mut thread_rng();
+        let transition = sample_transition::<Testnet2, _>(rng).unwrap();
+        let inner_circuit_id = rng.gen();
+        let first = Transaction::from_unchecked(inner_circuit_id, rng.gen(), vec![transition.clone()]).unwrap();
+        let second = Transaction::from_unchecked(inner_circuit_id, rng.gen(), vec![transition]).unwrap();
+        (first, second)
+    }
+
+    #[test]
+    fn test_hits_and_misses() {
+        let (transaction, _) = sample_transactions();
+        let mut cache = VerifiedCache::<Testnet2>::new(8);
+        let num_verifications = Cell::new(0);
+        let verify = |_: &Transaction<Testnet2>| {
+            num_verifications.set(num_verifications.get() + 1);
+            true
+        };
+
+        assert!(cache.get_or_verify(&transaction, verify));
+        assert!(cache.get_or_verify(&transaction, verify));
+        assert!(cache.get_or_verify(&transaction, verify));
+        assert_eq!(1, num_verifications.get());
+        assert_eq!((2, 1), (cache.hits(), cache.misses()));
+        assert_eq!(1, cache.len());
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert!(cache.get_or_verify(&transaction, verify));
+        assert_eq!(2, num_verifications.get());
+        assert_eq!((2, 2), (cache.hits(), cache.misses()));
+    }
+
+    #[test]
+    fn test_outcome_is_not_reused_across_ledger_roots() {
+        let (valid_under_first_root, invalid_under_second_root) = sample_transactions();
+        assert_eq!(
+            valid_under_first_root.transaction_id(),
+            invalid_under_second_root.transaction_id()
+        );
+        assert_ne!(
+            valid_under_first_root.ledger_root(),
+            invalid_under_second_root.ledger_root()
+        );
+
+        // Only the transaction under the first ledger root verifies.
+        let first_root = valid_under_first_root.ledger_root();
+        let verify = |transaction: &Transaction<Testnet2>| transaction.ledger_root() == first_root;
+
+        let mut cache = VerifiedCache::<Testnet2>::new(8);
+        assert!(cache.get_or_verify(&valid_under_first_root, verify));
+        assert!(!cache.get_or_verify(&invalid_under_second_root, verify));
+        assert!(!cache.get_or_verify(&invalid_under_second_root, verify));
+        assert!(cache.get_or_verify(&valid_under_first_root, verify));
+        assert_eq!((1, 3), (cache.hits(), cache.misses()));
+    }
+
+    #[test]
+    fn test_capacity() {
+        let transactions: Vec<_> = (0..4).map(|_| sample_transactions().0).collect();
+        let num_verifications = Cell::new(0);
+        let verify = |_: &Transaction<Testnet2>| {
+            num_verifications.set(num_verifications.get() + 1);
+            true
+        };
+
+        // Ensure the oldest outcomes are evicted first.
+        let mut cache = VerifiedCache::<Testnet2>::new(2);
+        for transaction in &transactions {
+            cache.get_or_verify(transaction, verify);
+        }
+        assert_eq!(2, cache.len());
+        cache.get_or_verify(&transactions[3], verify);
+        cache.get_or_verify(&transactions[2], verify);
+        assert_eq!(4, num_verifications.get());
+        cache.get_or_verify(&transactions[0], verify);
+        assert_eq!(5, num_verifications.get());
+        assert_eq!(2, cache.len());
+
+        // Ensure a cache without capacity stores nothing.
+        let mut cache = VerifiedCache::<Testnet2>::new(0);
+        cache.get_or_verify(&transactions[0], verify);
+        cache.get_or_verify(&transactions[0], verify);
+        assert_eq!(7, num_verifications.get());
+        assert!(cache.is_empty());
+    }
+}
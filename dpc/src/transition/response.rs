@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{AleoAmount, Event, Network, Record};
+use crate::{AleoAmount, DeserializationError, Event, Network, Record};
 use snarkvm_utilities::{FromBytes, ToBytes};
 
 use anyhow::Result;
@@ -127,6 +127,7 @@ impl<N: Network> FromBytes for Response<N> {
         let value_balance = FromBytes::read_le(&mut reader)?;
 
         let num_events: u16 = FromBytes::read_le(&mut reader)?;
+        DeserializationError::check_length("events", num_events as usize, N::NUM_EVENTS as usize)?;
         let mut events = Vec::with_capacity(num_events as usize);
         for _ in 0..num_events {
             events.push(FromBytes::read_le(&mut reader)?);
@@ -19,7 +19,8 @@ use snarkvm_algorithms::{
     merkle_tree::{MerklePath, MerkleTree},
     traits::SNARK,
 };
-use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
+use snarkvm_fields::ToConstraintField;
+use snarkvm_utilities::{FieldContext, FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use anyhow::{anyhow, Result};
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
@@ -112,25 +113,15 @@ impl<N: Network> Transition<N> {
         ledger_root: N::LedgerRoot,
         local_transitions_root: N::TransactionID,
     ) -> bool {
-        // Ensure the number of events is less than `N::NUM_EVENTS`.
-        if self.events.len() > N::NUM_EVENTS as usize {
-            eprintln!("Transition contains an invalid number of events");
+        // Returns `false` if the transition is malformed.
+        if !self.is_well_formed() {
             return false;
         }
 
         // Returns `false` if the transition proof is invalid.
-        match N::OuterSNARK::verify(
-            N::outer_verifying_key(),
-            &OuterPublicVariables::new(
-                InnerPublicVariables::new(
-                    self.transition_id,
-                    self.value_balance,
-                    ledger_root,
-                    local_transitions_root,
-                    None,
-                ),
-                &inner_circuit_id,
-            ),
+        match N::OuterSNARK::verify_prepared(
+            N::outer_prepared_verifying_key(),
+            &self.to_outer_public_variables(inner_circuit_id, ledger_root, local_transitions_root),
             &self.proof,
         ) {
             Ok(is_valid) => match is_valid {
@@ -147,6 +138,42 @@ impl<N: Network> Transition<N> {
         }
     }
 
+    /// Returns `true` if the transition has a valid number of events and a well-formed transition ID.
+    /// This performs every check in `Transition::verify`, except for verifying the transition proof.
+    pub(crate) fn is_well_formed(&self) -> bool {
+        // Ensure the number of events is less than `N::NUM_EVENTS`.
+        if self.events.len() > N::NUM_EVENTS as usize {
+            eprintln!("Transition contains an invalid number of events");
+            return false;
+        }
+
+        // Returns `false` if the transition ID is not the root of the transition tree.
+        if !self.verify_id() {
+            eprintln!("Transition contains an invalid transition ID");
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns the descriptors of the field elements passed to the outer circuit verifier, in order.
+    pub fn public_input_layout() -> Result<Vec<PublicInputDescriptor>> {
+        Ok(OuterPublicVariables::<N>::blank().to_public_input_layout()?)
+    }
+
+    /// Returns the field elements passed to the outer circuit verifier for this transition,
+    /// in the order described by `Transition::public_input_layout`.
+    pub fn to_public_inputs(
+        &self,
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> Result<Vec<N::OuterScalarField>> {
+        Ok(self
+            .to_outer_public_variables(inner_circuit_id, ledger_root, local_transitions_root)
+            .to_field_elements()?)
+    }
+
     /// Returns `true` if the given serial number exists.
     pub fn contains_serial_number(&self, serial_number: &N::SerialNumber) -> bool {
         self.serial_numbers.contains(serial_number)
@@ -181,6 +208,27 @@ impl<N: Network> Transition<N> {
         self.ciphertexts.iter()
     }
 
+    /// Returns the ciphertext randomizers of the output records, in order.
+    ///
+    /// The randomizer is the group element of the encryption randomness of each output record.
+    /// As it is part of the public ciphertext, verifiers can recompute it without a view key.
+    #[inline]
+    pub fn output_randomizers(&self) -> impl Iterator<Item = N::RecordRandomizer> + fmt::Debug + '_ {
+        self.ciphertexts.iter().map(|ciphertext| ciphertext.randomizer())
+    }
+
+    /// Returns the size in bytes of each part of the transition, as encoded by `ToBytes`.
+    pub fn size_breakdown(&self) -> Result<TransitionSize> {
+        Ok(TransitionSize {
+            transition_id: self.transition_id.to_bytes_le()?.len(),
+            inputs: self.serial_numbers.to_bytes_le()?.len(),
+            outputs: self.ciphertexts.to_bytes_le()?.len(),
+            value_balance: self.value_balance.to_bytes_le()?.len(),
+            events: (self.events.len() as u16).to_bytes_le()?.len() + self.events.to_bytes_le()?.len(),
+            proof: self.proof.to_bytes_le()?.len(),
+        })
+    }
+
     /// Returns a reference to the value balance.
     #[inline]
     pub fn value_balance(&self) -> &AleoAmount {
@@ -227,6 +275,49 @@ impl<N: Network> Transition<N> {
             .filter(|record| !record.is_dummy())
     }
 
+    /// Returns the public variables of the outer circuit for this transition.
+    pub(crate) fn to_outer_public_variables(
+        &self,
+        inner_circuit_id: N::InnerCircuitID,
+        ledger_root: N::LedgerRoot,
+        local_transitions_root: N::TransactionID,
+    ) -> OuterPublicVariables<N> {
+        OuterPublicVariables::new(
+            InnerPublicVariables::new(
+                self.transition_id,
+                self.value_balance,
+                ledger_root,
+                local_transitions_root,
+                None,
+            ),
+            &inner_circuit_id,
+        )
+    }
+
+    ///
+    /// Returns the ordered leaves of the transition tree, whose root is the transition ID.
+    ///
+    /// The transition tree has a depth of `N::TRANSITION_TREE_DEPTH`, and its leaves are the
+    /// little-endian bytes of the `N::NUM_INPUT_RECORDS` serial numbers, followed by the
+    /// `N::NUM_OUTPUT_RECORDS` commitments. Each leaf is hashed with `N::transition_id_parameters()`.
+    ///
+    #[inline]
+    pub fn id_preimage(&self) -> Result<Vec<Vec<u8>>> {
+        Self::compute_transition_leaves(&self.serial_numbers, &self.commitments)
+    }
+
+    /// Returns `true` if the transition ID matches the root of the transition tree.
+    #[inline]
+    pub fn verify_id(&self) -> bool {
+        match Self::compute_transition_id(&self.serial_numbers, &self.commitments) {
+            Ok(transition_id) => transition_id == self.transition_id,
+            Err(error) => {
+                eprintln!("Failed to compute the transition ID: {}", error);
+                false
+            }
+        }
+    }
+
     /// Returns an inclusion proof for the transition tree.
     #[inline]
     pub fn to_transition_inclusion_proof(&self, leaf: impl ToBytes) -> Result<MerklePath<N::TransitionIDParameters>> {
@@ -253,8 +344,11 @@ impl<N: Network> Transition<N> {
     ///
     /// Returns the transition ID, which is the root of transition tree.
     ///
+    /// This matches the transition ID the inner circuit enforces as a public input, so it can be
+    /// recomputed from the serial numbers of the request and the commitments of the response.
+    ///
     #[inline]
-    pub(crate) fn compute_transition_id(
+    pub fn compute_transition_id(
         serial_numbers: &[N::SerialNumber],
         commitments: &[N::Commitment],
     ) -> Result<N::TransitionID> {
@@ -270,7 +364,7 @@ impl<N: Network> Transition<N> {
     /// Transition Tree := MerkleTree(serial numbers || commitments)
     ///
     #[inline]
-    pub(crate) fn compute_transition_leaves(
+    pub fn compute_transition_leaves(
         serial_numbers: &[N::SerialNumber],
         commitments: &[N::Commitment],
     ) -> Result<Vec<Vec<u8>>> {
@@ -301,31 +395,33 @@ impl<N: Network> Transition<N> {
 impl<N: Network> FromBytes for Transition<N> {
     #[inline]
     fn read_le<R: Read>(mut reader: R) -> IoResult<Self> {
-        let transition_id: N::TransitionID = FromBytes::read_le(&mut reader)?;
+        let transition_id: N::TransitionID =
+            FromBytes::read_le(&mut reader).in_field("Transition", "transition_id")?;
 
         let mut serial_numbers = Vec::<N::SerialNumber>::with_capacity(N::NUM_INPUT_RECORDS);
         for _ in 0..N::NUM_INPUT_RECORDS {
-            serial_numbers.push(FromBytes::read_le(&mut reader)?);
+            serial_numbers.push(FromBytes::read_le(&mut reader).in_field("Transition", "serial_numbers")?);
         }
 
         let mut ciphertexts = Vec::<N::RecordCiphertext>::with_capacity(N::NUM_OUTPUT_RECORDS);
         for _ in 0..N::NUM_OUTPUT_RECORDS {
-            ciphertexts.push(FromBytes::read_le(&mut reader)?);
+            ciphertexts.push(FromBytes::read_le(&mut reader).in_field("Transition", "ciphertexts")?);
         }
 
-        let value_balance: AleoAmount = FromBytes::read_le(&mut reader)?;
+        let value_balance: AleoAmount = FromBytes::read_le(&mut reader).in_field("Transition", "value_balance")?;
 
-        let num_events: u16 = FromBytes::read_le(&mut reader)?;
+        let num_events: u16 = FromBytes::read_le(&mut reader).in_field("Transition", "events")?;
+        DeserializationError::check_length("events", num_events as usize, N::NUM_EVENTS as usize)?;
         let mut events = Vec::with_capacity(num_events as usize);
         for _ in 0..num_events {
-            events.push(FromBytes::read_le(&mut reader)?);
+            events.push(FromBytes::read_le(&mut reader).in_field("Transition", "events")?);
         }
 
-        let proof: N::OuterProof = FromBytes::read_le(&mut reader)?;
+        let proof: N::OuterProof = FromBytes::read_le(&mut reader).in_field("Transition", "proof")?;
 
         Ok(
             Self::from(transition_id, serial_numbers, ciphertexts, value_balance, events, proof)
-                .expect("Failed to deserialize a transition from bytes"),
+                .map_err(|error| DeserializationError::InvalidObject("transition", error.to_string()))?,
         )
     }
 }
@@ -412,6 +508,11 @@ impl<N: Network> Hash for Transition<N> {
 mod tests {
     use super::*;
     use crate::testnet2::Testnet2;
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, TestConstraintSystem};
+    use snarkvm_utilities::{io::Cursor, to_bytes_le, SerializationError, UniformRand};
+
+    use itertools::Itertools;
+    use rand::{thread_rng, Rng};
 
     #[test]
     fn test_size() {
@@ -430,6 +531,157 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_public_input_layout() {
+        let layout = Transition::<Testnet2>::public_input_layout().unwrap();
+        let names = layout
+            .iter()
+            .map(|descriptor| descriptor.name)
+            .dedup()
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "ledger_root",
+                "local_transitions_root",
+                "value_balance",
+                "transition_id",
+                "inner_circuit_id"
+            ],
+            names
+        );
+        assert_eq!(
+            layout.len(),
+            OuterPublicVariables::<Testnet2>::blank()
+                .to_field_elements()
+                .unwrap()
+                .len()
+        );
+        assert_eq!("ledger_root[0]", layout[0].to_string());
+    }
+
+    #[test]
+    fn test_id_preimage() {
+        let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
+        let transition = transaction.transitions().first().unwrap().clone();
+        assert!(transition.verify_id());
+
+        // Recompute the transition ID from the preimage.
+        let leaves = transition.id_preimage().unwrap();
+        assert_eq!(
+            <Testnet2 as Network>::NUM_INPUT_RECORDS + <Testnet2 as Network>::NUM_OUTPUT_RECORDS,
+            leaves.len()
+        );
+        assert_eq!(
+            to_bytes_le![transition.serial_numbers().next().unwrap()].unwrap(),
+            leaves[0]
+        );
+        assert_eq!(
+            to_bytes_le![transition.commitments().next().unwrap()].unwrap(),
+            leaves[2]
+        );
+        let parameters = Arc::new(Testnet2::transition_id_parameters().clone());
+        let tree =
+            MerkleTree::<<Testnet2 as Network>::TransitionIDParameters>::new(parameters.clone(), &leaves).unwrap();
+        assert_eq!(transition.transition_id(), (*tree.root()).into());
+
+        // Check a single-bit corruption in a leaf changes the transition ID.
+        let mut corrupted_leaves = leaves;
+        corrupted_leaves[3][0] ^= 1;
+        let tree =
+            MerkleTree::<<Testnet2 as Network>::TransitionIDParameters>::new(parameters, &corrupted_leaves).unwrap();
+        assert_ne!(transition.transition_id(), (*tree.root()).into());
+
+        // Check a single-bit corruption in a commitment is detected.
+        let mut corrupted_transition = transition;
+        let mut commitment_bytes = corrupted_transition.commitments[1].to_bytes_le().unwrap();
+        commitment_bytes[0] ^= 1;
+        corrupted_transition.commitments[1] = FromBytes::read_le(&commitment_bytes[..]).unwrap();
+        assert!(!corrupted_transition.verify_id());
+    }
+
+    #[test]
+    fn test_recompute_ids_from_primitives() {
+        let rng = &mut thread_rng();
+
+        let recipient = Account::<Testnet2>::new(rng);
+        let amount = AleoAmount::from_i64(10);
+        let request = Request::new_coinbase(recipient.address(), amount, false, rng).unwrap();
+        let response = ResponseBuilder::new()
+            .add_request(request.clone())
+            .add_output(Output::new(recipient.address(), amount, Default::default(), None).unwrap())
+            .build(rng)
+            .unwrap();
+
+        // Recomputes the record commitment from the little-endian bytes of its ciphertext.
+        let recompute_commitment = |record: &Record<Testnet2>| {
+            let bytes = record.ciphertext().to_bytes_le().unwrap();
+            let mut cursor = Cursor::new(&bytes);
+            let randomizer = FromBytes::read_le(&mut cursor).unwrap();
+            let record_view_key_commitment = FromBytes::read_le(&mut cursor).unwrap();
+            let record_bytes = &bytes[cursor.position() as usize..];
+            Ciphertext::<Testnet2>::compute_commitment(&randomizer, &record_view_key_commitment, record_bytes).unwrap()
+        };
+
+        // Recompute the serial numbers from the input commitments and the request signature.
+        let compute_key = ComputeKey::<Testnet2>::from_signature(request.signature()).unwrap();
+        let serial_numbers = request
+            .records()
+            .iter()
+            .map(|record| {
+                let commitment = recompute_commitment(record);
+                assert_eq!(record.commitment(), commitment);
+                compute_key.to_serial_number(&commitment).unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(request.to_serial_numbers().unwrap(), serial_numbers);
+
+        // Recompute the output commitments from the output ciphertexts.
+        let commitments = response.records().iter().map(recompute_commitment).collect::<Vec<_>>();
+        assert_eq!(response.commitments(), commitments);
+
+        // Recompute the transition ID from the serial numbers and commitments.
+        let transition_id = Transition::<Testnet2>::compute_transition_id(&serial_numbers, &commitments).unwrap();
+        assert_eq!(response.transition_id(), transition_id);
+
+        // Check the inner circuit is satisfied by the recomputed transition ID.
+        let inner_public = InnerPublicVariables::new(
+            transition_id,
+            response.value_balance(),
+            LedgerTree::<Testnet2>::new().unwrap().root(),
+            Transitions::<Testnet2>::new().unwrap().root(),
+            Some(request.to_program_id().unwrap()),
+        );
+        let inner_private = InnerPrivateVariables::new(&request, &response).unwrap();
+        let mut inner_cs = TestConstraintSystem::<<Testnet2 as Network>::InnerScalarField>::new();
+        InnerCircuit::new(inner_public, inner_private)
+            .generate_constraints(&mut inner_cs.ns(|| "Inner circuit"))
+            .unwrap();
+        assert!(inner_cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_output_randomizers() {
+        let rng = &mut thread_rng();
+        let recipient = Account::<Testnet2>::new(rng);
+
+        // Execute a fresh transition.
+        let request = Request::new_coinbase(recipient.address(), AleoAmount::from_i64(1234), true, rng).unwrap();
+        let (vm, response) = VirtualMachine::<Testnet2>::new(LedgerTree::<Testnet2>::new().unwrap().root())
+            .unwrap()
+            .execute(&request, rng)
+            .unwrap();
+        let transaction = vm.finalize().unwrap();
+        let transition = transaction.transitions().first().unwrap();
+
+        // Check the randomizers match the ones the execution encrypted the output records with.
+        let expected_randomizers = response.records().iter().map(Record::randomizer).collect::<Vec<_>>();
+        assert_eq!(Testnet2::NUM_OUTPUT_RECORDS, expected_randomizers.len());
+        assert_eq!(
+            expected_randomizers,
+            transition.output_randomizers().collect::<Vec<_>>()
+        );
+    }
+
     #[test]
     fn test_transition_serde_json() {
         let transaction = Testnet2::genesis_block().to_coinbase_transaction().unwrap();
@@ -462,4 +714,67 @@ mod tests {
         assert_eq!(expected_transition, Transition::read_le(&expected_bytes[..]).unwrap());
         assert_eq!(expected_transition, bincode::deserialize(&candidate_bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_deserialize_too_many_events() {
+        let rng = &mut thread_rng();
+        let owner = Account::<Testnet2>::new(rng).address();
+
+        let mut prefix = to_bytes_le![<Testnet2 as Network>::TransitionID::rand(rng)].unwrap();
+        for _ in 0..Testnet2::NUM_INPUT_RECORDS {
+            prefix.extend(to_bytes_le![<Testnet2 as Network>::SerialNumber::rand(rng)].unwrap());
+        }
+        for _ in 0..Testnet2::NUM_OUTPUT_RECORDS {
+            prefix.extend(to_bytes_le![Record::new_noop(owner, rng).unwrap().ciphertext()].unwrap());
+        }
+        prefix.extend(to_bytes_le![AleoAmount::ZERO].unwrap());
+
+        for num_events in [Testnet2::NUM_EVENTS + 1, 1000, u16::MAX] {
+            let bytes = [&prefix[..], &num_events.to_le_bytes()].concat();
+            let error = Transition::<Testnet2>::read_le(&bytes[..]).unwrap_err();
+            assert!(matches!(
+                error.get_ref().and_then(|error| error.downcast_ref()),
+                Some(DeserializationError::TooManyItems("events", ..))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_deserialize_error_field_path() {
+        let rng = &mut thread_rng();
+        let transition = crate::test_helpers::sample_transition::<Testnet2, _>(rng).unwrap();
+        let transaction = Transaction::from_unchecked(rng.gen(), rng.gen(), vec![transition.clone()]).unwrap();
+        let transition_bytes = transition.to_bytes_le().unwrap();
+
+        let field_path = |error: std::io::Error| {
+            error
+                .get_ref()
+                .and_then(|error| error.downcast_ref::<SerializationError>())
+                .and_then(|error| error.field_path())
+        };
+
+        // Corrupt the first serial number with a non-canonical field element.
+        let offset = transition.transition_id().to_bytes_le().unwrap().len();
+        let size = transition.serial_numbers().next().unwrap().to_bytes_le().unwrap().len();
+        let mut corrupted_bytes = transition_bytes.clone();
+        corrupted_bytes[offset..offset + size].fill(0xFF);
+
+        let error = Transition::<Testnet2>::read_le(&corrupted_bytes[..]).unwrap_err();
+        assert_eq!(Some("Transition.serial_numbers".to_string()), field_path(error));
+
+        // Check the field path starts from the enclosing transaction.
+        let transaction_bytes = transaction.to_bytes_le().unwrap();
+        let start = transaction_bytes
+            .windows(transition_bytes.len())
+            .position(|window| window == &transition_bytes[..])
+            .unwrap();
+        let mut corrupted_transaction_bytes = transaction_bytes.clone();
+        corrupted_transaction_bytes[start..start + transition_bytes.len()].copy_from_slice(&corrupted_bytes);
+
+        let error = Transaction::<Testnet2>::read_le(&corrupted_transaction_bytes[..]).unwrap_err();
+        assert_eq!(
+            Some("Transaction.transitions.serial_numbers".to_string()),
+            field_path(error)
+        );
+    }
 }
@@ -16,17 +16,21 @@
 
 use snarkvm_utilities::{FromBytes, ToBytes};
 
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
 use std::{
+    convert::TryFrom,
     fmt,
     io::{Read, Result as IoResult, Write},
     iter::Sum,
+    str::FromStr,
 };
 
 /// Represents the amount of ALEOs.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct AleoAmount(pub i64);
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Denomination {
     /// AB
     BYTE,
@@ -115,6 +119,73 @@ impl AleoAmount {
     pub const fn as_i64(&self) -> i64 {
         self.0
     }
+
+    /// Returns the sum of two `AleoAmount`s, or `None` if the sum overflows.
+    pub fn checked_add(self, b: Self) -> Option<Self> {
+        self.0.checked_add(b.0).map(Self)
+    }
+
+    /// Returns the difference of two `AleoAmount`s, or `None` if the difference overflows.
+    pub fn checked_sub(self, b: Self) -> Option<Self> {
+        self.0.checked_sub(b.0).map(Self)
+    }
+
+    /// Returns the amount multiplied by the given factor, or `None` if the product overflows.
+    pub fn checked_mul(self, factor: i64) -> Option<Self> {
+        self.0.checked_mul(factor).map(Self)
+    }
+
+    ///
+    /// Returns the amount in credits with the given number of decimal places, such as "1.50 ALEO".
+    ///
+    /// At most 6 decimal places are shown, and further digits are truncated toward zero.
+    /// The string is computed with integer arithmetic, so no precision is lost to rounding.
+    ///
+    pub fn to_display_string(&self, decimal_places: usize) -> String {
+        let precision = Denomination::CREDIT.precision();
+        let decimal_places = decimal_places.min(precision as usize);
+
+        let magnitude = self.0.unsigned_abs();
+        let credits = magnitude / 10_u64.pow(precision);
+        let fraction = magnitude % 10_u64.pow(precision) / 10_u64.pow(precision - decimal_places as u32);
+        let sign = match self.is_negative() && (credits != 0 || fraction != 0) {
+            true => "-",
+            false => "",
+        };
+
+        match decimal_places {
+            0 => format!("{}{} {}", sign, credits, Denomination::CREDIT),
+            _ => format!(
+                "{}{}.{:0width$} {}",
+                sign,
+                credits,
+                fraction,
+                Denomination::CREDIT,
+                width = decimal_places
+            ),
+        }
+    }
+}
+
+/// Returns the value of the given digits, which may be separated by underscores, and the number of digits.
+fn parse_digits(digits: &str) -> Result<(i128, u32)> {
+    if digits.is_empty() || digits.starts_with('_') || digits.ends_with('_') || digits.contains("__") {
+        return Err(anyhow!("Invalid digits in amount: '{}'", digits));
+    }
+
+    let mut value = 0i128;
+    let mut num_digits = 0u32;
+    for character in digits.chars().filter(|character| *character != '_') {
+        let digit = character
+            .to_digit(10)
+            .ok_or_else(|| anyhow!("Invalid digit in amount: '{}'", character))?;
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit as i128))
+            .ok_or_else(|| anyhow!("Amount overflowed"))?;
+        num_digits += 1;
+    }
+    Ok((value, num_digits))
 }
 
 impl Sum for AleoAmount {
@@ -138,6 +209,59 @@ impl FromBytes for AleoAmount {
     }
 }
 
+impl FromStr for AleoAmount {
+    type Err = anyhow::Error;
+
+    ///
+    /// Parses an amount of bytes such as "1_000_000" or "1000000 AB", or an amount of gates or credits
+    /// such as "1.5 AG" or "-1.5 ALEO", with at most as many decimal places as the denomination allows.
+    ///
+    fn from_str(amount: &str) -> Result<Self, Self::Err> {
+        let amount = amount.trim();
+        let (number, denomination) = match [Denomination::CREDIT, Denomination::GATE, Denomination::BYTE]
+            .iter()
+            .find_map(|denomination| Some((amount.strip_suffix(&denomination.to_string())?, *denomination)))
+        {
+            Some((number, denomination)) => (number.trim_end(), denomination),
+            None => (amount, Denomination::BYTE),
+        };
+
+        let (is_negative, number) = match number.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, number),
+        };
+        let (integer, fraction) = match number.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (number, None),
+        };
+
+        let precision = denomination.precision();
+        let (integer, _) = parse_digits(integer)?;
+        let (fraction, num_decimal_places) = match fraction {
+            Some(fraction) => parse_digits(fraction)?,
+            None => (0, 0),
+        };
+        if num_decimal_places > precision {
+            return Err(anyhow!(
+                "Amount in {} has more than {} decimal places",
+                denomination,
+                precision
+            ));
+        }
+
+        let magnitude = integer
+            .checked_mul(10_i128.pow(precision))
+            .and_then(|integer| integer.checked_add(fraction * 10_i128.pow(precision - num_decimal_places)))
+            .ok_or_else(|| anyhow!("Amount overflowed"))?;
+        let value = match is_negative {
+            true => -magnitude,
+            false => magnitude,
+        };
+
+        Ok(Self(i64::try_from(value).map_err(|_| anyhow!("Amount overflowed"))?))
+    }
+}
+
 impl fmt::Display for AleoAmount {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -262,6 +386,98 @@ mod tests {
         }
     }
 
+    mod parsing_and_formatting {
+        use super::*;
+
+        #[test]
+        fn test_from_str() {
+            for (string, expected) in [
+                ("0", 0),
+                ("1_000_000", 1_000_000),
+                ("1000000 AB", 1_000_000),
+                ("1.5 AG", 1_500),
+                ("1.5 ALEO", 1_500_000),
+                ("1.5ALEO", 1_500_000),
+                ("  0.000001 ALEO ", 1),
+                ("-1.5 ALEO", -1_500_000),
+                ("1_000.000_001 ALEO", 1_000_000_001),
+                ("9223372036854775807", i64::MAX),
+                ("9223372036854.775807 ALEO", i64::MAX),
+                ("-9223372036854.775808 ALEO", i64::MIN),
+            ] {
+                assert_eq!(AleoAmount(expected), AleoAmount::from_str(string).unwrap(), "{}", string);
+            }
+        }
+
+        #[test]
+        fn test_from_str_invalid() {
+            for string in [
+                "",
+                "ALEO",
+                "1.",
+                ".5 ALEO",
+                "1.5",
+                "1.5 AB",
+                "1.5555 AG",
+                "1.0000001 ALEO",
+                "_1 ALEO",
+                "1_ ALEO",
+                "1__0 ALEO",
+                "1.5.5 ALEO",
+                "--1 ALEO",
+                "+1 ALEO",
+                "1e6",
+                "1 credits",
+                "9223372036854775808",
+                "9223372036854.775808 ALEO",
+                "-9223372036854.775809 ALEO",
+                "999999999999999999999999999999999999999999 ALEO",
+            ] {
+                assert!(AleoAmount::from_str(string).is_err(), "{}", string);
+            }
+        }
+
+        #[test]
+        fn test_to_display_string() {
+            for (amount, decimal_places, expected) in [
+                (0, 0, "0 ALEO"),
+                (0, 6, "0.000000 ALEO"),
+                (1, 6, "0.000001 ALEO"),
+                (1, 5, "0.00000 ALEO"),
+                (-1, 5, "0.00000 ALEO"),
+                (1_500_000, 0, "1 ALEO"),
+                (1_500_000, 1, "1.5 ALEO"),
+                (1_500_000, 2, "1.50 ALEO"),
+                (1_999_999, 2, "1.99 ALEO"),
+                (1_500_000, 9, "1.500000 ALEO"),
+                (-1_500_000, 6, "-1.500000 ALEO"),
+                (i64::MAX, 6, "9223372036854.775807 ALEO"),
+                (i64::MIN, 6, "-9223372036854.775808 ALEO"),
+            ] {
+                assert_eq!(expected, AleoAmount(amount).to_display_string(decimal_places));
+            }
+        }
+
+        #[test]
+        fn test_display_string_round_trip() {
+            for amount in [0, 1, -1, 999_999, 1_000_000, 123_456_789, i64::MAX, i64::MIN] {
+                let string = AleoAmount(amount).to_display_string(6);
+                assert_eq!(AleoAmount(amount), AleoAmount::from_str(&string).unwrap());
+                assert_eq!(AleoAmount(amount), AleoAmount::from_str(&amount.to_string()).unwrap());
+            }
+        }
+
+        #[test]
+        fn test_checked_arithmetic() {
+            assert_eq!(Some(AleoAmount(3)), AleoAmount(1).checked_add(AleoAmount(2)));
+            assert_eq!(None, AleoAmount(i64::MAX).checked_add(AleoAmount::ONE_BYTE));
+            assert_eq!(Some(AleoAmount(-1)), AleoAmount(1).checked_sub(AleoAmount(2)));
+            assert_eq!(None, AleoAmount(i64::MIN).checked_sub(AleoAmount::ONE_BYTE));
+            assert_eq!(Some(AleoAmount::from_aleo(3)), AleoAmount::ONE_CREDIT.checked_mul(3));
+            assert_eq!(None, AleoAmount(i64::MAX).checked_mul(2));
+        }
+    }
+
     mod test_invalid {
         use super::*;
 
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Network, Operation};
+use crate::{DeserializationError, Network, Operation};
 use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
 use serde::{de, ser::SerializeStruct, Deserialize, Deserializer, Serialize, Serializer};
@@ -63,7 +63,7 @@ impl<N: Network> FromBytes for Event<N> {
                 Ok(Self::RecordViewKey(index, record_view_key))
             }
             2 => Ok(Self::Operation(FromBytes::read_le(&mut reader)?)),
-            _ => unreachable!("Invalid event ID during deserialization"),
+            id => Err(DeserializationError::InvalidId("event", id.into()).into()),
         }
     }
 }
@@ -153,7 +153,7 @@ impl<'de, N: Network> Deserialize<'de> for Event<N> {
                     2 => Ok(Self::Operation(
                         serde_json::from_value(event["operation"].clone()).map_err(de::Error::custom)?,
                     )),
-                    _ => unreachable!(format!("Invalid event id {}", event_id)),
+                    id => Err(de::Error::custom(DeserializationError::InvalidId("event", id.into()))),
                 }
             }
             false => FromBytesDeserializer::<Self>::deserialize_with_size_encoding(deserializer, "event"),
@@ -0,0 +1,318 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_algorithms::{crh::sha256::sha256, SNARK};
+use snarkvm_utilities::{FromBytes, ToBytes};
+
+use anyhow::{anyhow, Result};
+use std::{
+    fmt,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Identifies the circuit keys of a function, by the program it belongs to,
+/// the function itself, and the universal SRS the keys were derived from.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct KeyCacheId {
+    program_id: String,
+    function_id: String,
+    srs_checksum: String,
+}
+
+impl KeyCacheId {
+    /// Initializes a new key cache ID. The universal SRS is identified by the checksum of its bytes.
+    pub fn new(
+        program_id: impl fmt::Display,
+        function_id: impl fmt::Display,
+        universal_srs: &impl ToBytes,
+    ) -> Result<Self> {
+        Ok(Self {
+            program_id: program_id.to_string(),
+            function_id: function_id.to_string(),
+            srs_checksum: hex::encode(sha256(&universal_srs.to_bytes_le()?)),
+        })
+    }
+
+    /// Returns a filename that is unique to this ID.
+    fn filename(&self) -> String {
+        let preimage = format!("{}.{}.{}", self.program_id, self.function_id, self.srs_checksum);
+        hex::encode(sha256(preimage.as_bytes()))
+    }
+}
+
+impl fmt::Display for KeyCacheId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} (SRS {})",
+            self.program_id, self.function_id, self.srs_checksum
+        )
+    }
+}
+
+/// A store for the proving and verifying keys of functions, to avoid synthesizing them repeatedly.
+pub trait KeyCache<S: SNARK> {
+    /// Returns the keys for the given ID, or `None` if they are not cached.
+    /// Returns an error if the cached keys are corrupted.
+    fn load(&self, id: &KeyCacheId) -> Result<Option<(S::ProvingKey, S::VerifyingKey)>>;
+
+    /// Stores the keys for the given ID.
+    fn store(&self, id: &KeyCacheId, proving_key: &S::ProvingKey, verifying_key: &S::VerifyingKey) -> Result<()>;
+
+    /// Returns the keys for the given ID, calling `synthesize` and storing the result if they are not cached.
+    /// Cached keys that fail to load are synthesized again, and overwritten.
+    fn get_or_synthesize(
+        &self,
+        id: &KeyCacheId,
+        synthesize: impl FnOnce() -> Result<(S::ProvingKey, S::VerifyingKey)>,
+    ) -> Result<(S::ProvingKey, S::VerifyingKey)> {
+        match self.load(id) {
+            Ok(Some(keys)) => return Ok(keys),
+            Ok(None) => (),
+            Err(error) => eprintln!("Synthesizing the keys for {} again: {}", id, error),
+        }
+        let (proving_key, verifying_key) = synthesize()?;
+        self.store(id, &proving_key, &verifying_key)?;
+        Ok((proving_key, verifying_key))
+    }
+}
+
+/// A key cache that stores keys in a directory on the filesystem.
+///
+/// Each entry consists of a `.proving` and a `.verifying` file, and a `.metadata` file holding
+/// their sizes and checksums. Entries whose keys do not match their metadata are refused,
+/// and `get_or_synthesize` replaces them with freshly synthesized keys.
+#[derive(Clone, Debug)]
+pub struct FileKeyCache {
+    directory: PathBuf,
+}
+
+impl FileKeyCache {
+    /// Initializes a new key cache in the given directory, creating the directory if it does not exist.
+    pub fn new(directory: impl AsRef<Path>) -> Result<Self> {
+        fs::create_dir_all(directory.as_ref())?;
+        Ok(Self {
+            directory: directory.as_ref().to_path_buf(),
+        })
+    }
+
+    /// Returns the directory of the key cache.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Returns the path of the given file type for the given ID.
+    fn path(&self, id: &KeyCacheId, file_type: &str) -> PathBuf {
+        self.directory.join(format!("{}.{}", id.filename(), file_type))
+    }
+
+    /// Returns the bytes of the given file type for the given ID, ensuring they match the metadata.
+    fn read_checked(&self, id: &KeyCacheId, metadata: &serde_json::Value, file_type: &str) -> Result<Vec<u8>> {
+        let bytes = fs::read(self.path(id, file_type))?;
+
+        let expected_size = metadata[format!("{}_size", file_type)].as_u64();
+        let expected_checksum = metadata[format!("{}_checksum", file_type)].as_str();
+        let candidate_checksum = hex::encode(sha256(&bytes));
+        match expected_size == Some(bytes.len() as u64) && expected_checksum == Some(&candidate_checksum) {
+            true => Ok(bytes),
+            false => Err(anyhow!(
+                "Refusing to load the cached {} key for {}: expected checksum {:?}, found {}",
+                file_type,
+                id,
+                expected_checksum,
+                candidate_checksum
+            )),
+        }
+    }
+
+    /// Writes the given bytes to the given path, replacing any existing file atomically.
+    /// The temporary file is unique to this write, so concurrent writes do not clobber each other.
+    fn write_atomic(path: &Path, bytes: &[u8]) -> Result<()> {
+        let filename = path
+            .file_name()
+            .ok_or_else(|| anyhow!("Invalid key cache path {}", path.display()))?;
+        let temporary_path = path.with_file_name(format!(
+            "{}.{}.{:016x}.tmp",
+            filename.to_string_lossy(),
+            std::process::id(),
+            rand::random::<u64>()
+        ));
+        fs::write(&temporary_path, bytes)?;
+        fs::rename(&temporary_path, path)?;
+        Ok(())
+    }
+}
+
+impl<S: SNARK> KeyCache<S> for FileKeyCache {
+    fn load(&self, id: &KeyCacheId) -> Result<Option<(S::ProvingKey, S::VerifyingKey)>> {
+        // The metadata is written last, so keys without metadata are incomplete.
+        let metadata_path = self.path(id, "metadata");
+        if !metadata_path.exists() {
+            return Ok(None);
+        }
+        let metadata: serde_json::Value = serde_json::from_slice(&fs::read(metadata_path)?)?;
+
+        let proving_key = FromBytes::from_bytes_le(&self.read_checked(id, &metadata, "proving")?)?;
+        let verifying_key = FromBytes::from_bytes_le(&self.read_checked(id, &metadata, "verifying")?)?;
+        Ok(Some((proving_key, verifying_key)))
+    }
+
+    fn store(&self, id: &KeyCacheId, proving_key: &S::ProvingKey, verifying_key: &S::VerifyingKey) -> Result<()> {
+        let proving_key = proving_key.to_bytes_le()?;
+        let verifying_key = verifying_key.to_bytes_le()?;
+        let metadata = serde_json::json!({
+            "proving_checksum": hex::encode(sha256(&proving_key)),
+            "proving_size": proving_key.len(),
+            "verifying_checksum": hex::encode(sha256(&verifying_key)),
+            "verifying_size": verifying_key.len(),
+        });
+
+        Self::write_atomic(&self.path(id, "proving"), &proving_key)?;
+        Self::write_atomic(&self.path(id, "verifying"), &verifying_key)?;
+        Self::write_atomic(&self.path(id, "metadata"), &serde_json::to_vec_pretty(&metadata)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm_algorithms::{snark::groth16::Groth16, SRS};
+    use snarkvm_curves::bls12_377::{Bls12_377, Fr};
+    use snarkvm_r1cs::{ConstraintSynthesizer, ConstraintSystem, SynthesisError};
+
+    use rand::thread_rng;
+    use std::cell::Cell;
+
+    type TestSNARK = Groth16<Bls12_377, Vec<Fr>>;
+
+    struct TestCircuit;
+
+    impl ConstraintSynthesizer<Fr> for TestCircuit {
+        fn generate_constraints<CS: ConstraintSystem<Fr>>(&self, cs: &mut CS) -> Result<(), SynthesisError> {
+            let a = cs.alloc_input(|| "a", || Ok(Fr::from(3u64)))?;
+            let b = cs.alloc(|| "b", || Ok(Fr::from(9u64)))?;
+            cs.enforce(|| "a * a = b", |lc| lc + a, |lc| lc + a, |lc| lc + b);
+            Ok(())
+        }
+    }
+
+    fn temporary_directory(name: &str) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("snarkvm-key-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn test_get_or_synthesize() {
+        let directory = temporary_directory("get_or_synthesize");
+        let id = KeyCacheId::new("program", "function", &vec![0u8; 32]).unwrap();
+
+        let num_syntheses = Cell::new(0);
+        let synthesize = || {
+            num_syntheses.set(num_syntheses.get() + 1);
+            Ok(TestSNARK::setup(
+                &TestCircuit,
+                &mut SRS::CircuitSpecific(&mut thread_rng()),
+            )?)
+        };
+
+        // Ensure the first load synthesizes the keys.
+        let cache = FileKeyCache::new(&directory).unwrap();
+        let (_, expected_verifying_key) = KeyCache::<TestSNARK>::get_or_synthesize(&cache, &id, synthesize).unwrap();
+        assert_eq!(1, num_syntheses.get());
+        drop(cache);
+
+        // Ensure a new cache over the same directory loads the keys without synthesizing them.
+        let cache = FileKeyCache::new(&directory).unwrap();
+        let (_, candidate_verifying_key) = KeyCache::<TestSNARK>::get_or_synthesize(&cache, &id, synthesize).unwrap();
+        assert_eq!(1, num_syntheses.get());
+        assert_eq!(
+            expected_verifying_key.to_bytes_le().unwrap(),
+            candidate_verifying_key.to_bytes_le().unwrap()
+        );
+
+        // Ensure keys derived from a different SRS are not shared.
+        let other_id = KeyCacheId::new("program", "function", &vec![1u8; 32]).unwrap();
+        assert!(KeyCache::<TestSNARK>::load(&cache, &other_id).unwrap().is_none());
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_refuses_corrupted_keys() {
+        let directory = temporary_directory("refuses_corrupted_keys");
+        let id = KeyCacheId::new("program", "function", &vec![0u8; 32]).unwrap();
+
+        let cache = FileKeyCache::new(&directory).unwrap();
+        let (proving_key, verifying_key) =
+            TestSNARK::setup(&TestCircuit, &mut SRS::CircuitSpecific(&mut thread_rng())).unwrap();
+        KeyCache::<TestSNARK>::store(&cache, &id, &proving_key, &verifying_key).unwrap();
+        assert!(KeyCache::<TestSNARK>::load(&cache, &id).unwrap().is_some());
+
+        // Overwrite the verifying key with another valid verifying key.
+        let (_, other_verifying_key) =
+            TestSNARK::setup(&TestCircuit, &mut SRS::CircuitSpecific(&mut thread_rng())).unwrap();
+        fs::write(cache.path(&id, "verifying"), other_verifying_key.to_bytes_le().unwrap()).unwrap();
+
+        // Ensure the cache refuses to load the keys.
+        assert!(KeyCache::<TestSNARK>::load(&cache, &id).is_err());
+
+        // Ensure the keys are synthesized again, and overwrite the corrupted keys.
+        let (_, expected_verifying_key) = KeyCache::<TestSNARK>::get_or_synthesize(&cache, &id, || {
+            Ok(TestSNARK::setup(
+                &TestCircuit,
+                &mut SRS::CircuitSpecific(&mut thread_rng()),
+            )?)
+        })
+        .unwrap();
+        let (_, candidate_verifying_key) = KeyCache::<TestSNARK>::load(&cache, &id).unwrap().unwrap();
+        assert_eq!(
+            expected_verifying_key.to_bytes_le().unwrap(),
+            candidate_verifying_key.to_bytes_le().unwrap()
+        );
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_store_leaves_no_temporary_files() {
+        let directory = temporary_directory("store_leaves_no_temporary_files");
+        let id = KeyCacheId::new("program", "function", &vec![0u8; 32]).unwrap();
+
+        let cache = FileKeyCache::new(&directory).unwrap();
+        let (proving_key, verifying_key) =
+            TestSNARK::setup(&TestCircuit, &mut SRS::CircuitSpecific(&mut thread_rng())).unwrap();
+        KeyCache::<TestSNARK>::store(&cache, &id, &proving_key, &verifying_key).unwrap();
+
+        // Ensure only the three files of the entry remain.
+        let mut filenames = fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        filenames.sort();
+        assert_eq!(
+            vec![
+                format!("{}.metadata", id.filename()),
+                format!("{}.proving", id.filename()),
+                format!("{}.verifying", id.filename()),
+            ],
+            filenames
+        );
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
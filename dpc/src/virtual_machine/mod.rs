@@ -32,6 +32,9 @@ pub use function_inputs::*;
 pub mod function_type;
 pub use function_type::*;
 
+pub mod key_cache;
+pub use key_cache::*;
+
 pub mod noop;
 pub use noop::*;
 
@@ -47,5 +50,8 @@ pub use program::*;
 pub mod program_public_variables;
 pub use program_public_variables::*;
 
+pub mod timings;
+pub use timings::*;
+
 pub mod virtual_machine;
 pub use virtual_machine::*;
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Function, FunctionType, Network, ProgramPrivateVariables, ProgramPublicVariables};
+use crate::{CryptoRngCore, Function, FunctionType, Network, ProgramPrivateVariables, ProgramPublicVariables};
 use snarkvm_algorithms::{MerkleParameters, SNARK};
 use snarkvm_fields::ConstraintFieldError;
 use snarkvm_gadgets::prelude::*;
@@ -62,11 +62,12 @@ impl<N: Network> Function<N> for Noop<N> {
         &self,
         public: ProgramPublicVariables<N>,
         _private: &dyn ProgramPrivateVariables<N>,
+        mut rng: &mut dyn CryptoRngCore,
     ) -> Result<N::ProgramProof> {
         let proof = <N::ProgramSNARK as SNARK>::prove(
             N::noop_circuit_proving_key(),
             &SynthesizedCircuit::Noop(public),
-            &mut rand::thread_rng(),
+            &mut rng,
         )?
         .into();
         assert!(self.verify(&public, &proof));
@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Address, AleoAmount, FunctionInputs, FunctionType, Network};
+use crate::{Address, AleoAmount, DeserializationError, FunctionInputs, FunctionType, Network};
 use snarkvm_fields::{ConstraintFieldError, ToConstraintField};
 use snarkvm_utilities::{FromBytes, FromBytesDeserializer, ToBytes, ToBytesSerializer};
 
@@ -121,7 +121,7 @@ impl<N: Network> FromBytes for Operation<N> {
                 let function_inputs = FromBytes::read_le(&mut reader)?;
                 Ok(Self::Evaluate(function_id, function_type, function_inputs))
             }
-            _ => unreachable!("Invalid operation during deserialization"),
+            id => Err(DeserializationError::InvalidId("operation", id).into()),
         }
     }
 }
@@ -172,11 +172,11 @@ impl<N: Network> FromStr for Operation<N> {
             }
             3 => {
                 let function_id = serde_json::from_value(operation["function_id"].clone())?;
-                let function_type = serde_json::from_value(operation["function_type"].clone())?;
+                let function_type = FunctionType::from_id(serde_json::from_value(operation["function_type"].clone())?)?;
                 let function_inputs = serde_json::from_value(operation["function_inputs"].clone())?;
                 Ok(Self::Evaluate(function_id, function_type, function_inputs))
             }
-            _ => unreachable!(format!("Invalid operation id {}", operation_id)),
+            id => Err(DeserializationError::InvalidId("operation", id.into()).into()),
         }
     }
 }
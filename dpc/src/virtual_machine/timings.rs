@@ -0,0 +1,184 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::Network;
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+/// The time spent in each stage of executing a single transition.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct TransitionTiming<N: Network> {
+    /// The ID of the transition.
+    pub transition_id: N::TransitionID,
+    /// The time spent verifying the request.
+    pub request_verification: Duration,
+    /// The time spent computing the response.
+    pub response: Duration,
+    /// The time spent proving the program execution.
+    pub execution: Duration,
+    /// The time spent proving and verifying the inner circuit.
+    pub inner_proof: Duration,
+    /// The time spent proving and verifying the outer circuit.
+    pub outer_proof: Duration,
+}
+
+impl<N: Network> TransitionTiming<N> {
+    /// Returns the total time spent executing the transition.
+    pub fn total(&self) -> Duration {
+        self.request_verification + self.response + self.execution + self.inner_proof + self.outer_proof
+    }
+}
+
+/// The per-transition timings collected by a virtual machine, in execution order.
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = "N: Network"),
+    Debug(bound = "N: Network"),
+    Default(bound = "N: Network"),
+    PartialEq(bound = "N: Network"),
+    Eq(bound = "N: Network")
+)]
+pub struct ExecutionTimings<N: Network> {
+    transitions: Vec<TransitionTiming<N>>,
+}
+
+impl<N: Network> ExecutionTimings<N> {
+    /// Returns the number of timed transitions.
+    pub fn len(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Returns `true` if no transitions have been timed.
+    pub fn is_empty(&self) -> bool {
+        self.transitions.is_empty()
+    }
+
+    /// Returns an iterator over the transition timings.
+    pub fn iter(&self) -> impl Iterator<Item = &TransitionTiming<N>> {
+        self.transitions.iter()
+    }
+
+    /// Returns the total time spent executing all transitions.
+    pub fn total(&self) -> Duration {
+        self.transitions.iter().map(TransitionTiming::total).sum()
+    }
+
+    /// Adds the given transition timing.
+    pub(crate) fn push(&mut self, timing: TransitionTiming<N>) {
+        self.transitions.push(timing);
+    }
+}
+
+impl<N: Network> fmt::Display for ExecutionTimings<N> {
+    /// Renders the timings as a table with one row per transition, in milliseconds.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<64} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "transition", "request", "response", "execution", "inner", "outer", "total"
+        )?;
+        for timing in &self.transitions {
+            writeln!(
+                f,
+                "{:<64} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                timing.transition_id.to_string(),
+                timing.request_verification.as_millis(),
+                timing.response.as_millis(),
+                timing.execution.as_millis(),
+                timing.inner_proof.as_millis(),
+                timing.outer_proof.as_millis(),
+                timing.total().as_millis(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Measures consecutive stages of an execution.
+pub(crate) struct StageTimer(Instant);
+
+impl StageTimer {
+    /// Starts timing the first stage.
+    pub(crate) fn start() -> Self {
+        Self(Instant::now())
+    }
+
+    /// Returns the time spent in the current stage, and starts timing the next stage.
+    pub(crate) fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.0);
+        self.0 = now;
+        elapsed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        testnet2::Testnet2,
+        Account,
+        AccountScheme,
+        AleoAmount,
+        LedgerTree,
+        LedgerTreeScheme,
+        Request,
+        VirtualMachine,
+    };
+
+    use rand::thread_rng;
+
+    #[test]
+    fn test_execution_timings() {
+        let rng = &mut thread_rng();
+        let account = Account::<Testnet2>::new(rng);
+
+        // Execute two coinbase transitions.
+        let mut vm = VirtualMachine::<Testnet2>::new(LedgerTree::<Testnet2>::new().unwrap().root()).unwrap();
+        for _ in 0..2 {
+            let request = Request::new_coinbase(account.address(), AleoAmount(1234), true, rng).unwrap();
+            vm = vm.execute(&request, rng).unwrap().0;
+        }
+
+        let timings = vm.timings();
+        assert_eq!(vm.num_transitions(), timings.len());
+        for timing in timings.iter() {
+            assert!(timing.inner_proof > Duration::ZERO);
+            assert!(timing.outer_proof > Duration::ZERO);
+            assert!(timing.total() >= timing.inner_proof + timing.outer_proof);
+        }
+        assert_eq!(timings.total(), timings.iter().map(TransitionTiming::total).sum());
+
+        // The table has a header row and one row per transition.
+        assert_eq!(1 + timings.len(), timings.to_string().lines().count());
+    }
+
+    #[test]
+    fn test_stage_timer() {
+        let mut timer = StageTimer::start();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(timer.lap() >= Duration::from_millis(5));
+    }
+}
@@ -30,6 +30,8 @@ pub struct VirtualMachine<N: Network> {
     local_transitions: Transitions<N>,
     /// The current list of transitions.
     transitions: Vec<Transition<N>>,
+    /// The time spent executing each transition.
+    timings: ExecutionTimings<N>,
 }
 
 impl<N: Network> VirtualMachine<N> {
@@ -39,6 +41,7 @@ impl<N: Network> VirtualMachine<N> {
             ledger_root,
             local_transitions: Transitions::new()?,
             transitions: Default::default(),
+            timings: Default::default(),
         })
     }
 
@@ -52,12 +55,20 @@ impl<N: Network> VirtualMachine<N> {
         self.transitions.len()
     }
 
+    /// Returns the time spent executing each transition in the virtual machine.
+    pub fn timings(&self) -> &ExecutionTimings<N> {
+        &self.timings
+    }
+
     /// Executes the request, returning a transaction.
     pub fn execute<R: Rng + CryptoRng>(mut self, request: &Request<N>, rng: &mut R) -> Result<(Self, Response<N>)> {
+        let mut timer = StageTimer::start();
+
         // Ensure the request is valid.
         if !request.is_valid() {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
+        let request_verification = timer.lap();
 
         // Compute the operation.
         let operation = request.operation().clone();
@@ -76,6 +87,8 @@ impl<N: Network> VirtualMachine<N> {
             )?,
         };
 
+        let response_time = timer.lap();
+
         let program_id = request.to_program_id()?;
         let transition_id = response.transition_id();
         let value_balance = response.value_balance();
@@ -88,8 +101,10 @@ impl<N: Network> VirtualMachine<N> {
             proof: Noop::<N>::new().execute(
                 ProgramPublicVariables::new(transition_id),
                 &NoopPrivateVariables::<N>::new_blank()?,
+                rng,
             )?,
         };
+        let execution_time = timer.lap();
 
         // Compute the inner circuit proof, and verify that the inner proof passes.
         let inner_public = InnerPublicVariables::new(
@@ -108,6 +123,7 @@ impl<N: Network> VirtualMachine<N> {
             &inner_public,
             &inner_proof
         )?);
+        let inner_proof_time = timer.lap();
 
         // Construct the outer circuit public and private variables.
         let outer_public = OuterPublicVariables::new(inner_public, N::inner_circuit_id());
@@ -120,6 +136,7 @@ impl<N: Network> VirtualMachine<N> {
             &outer_public,
             &outer_proof
         )?);
+        let outer_proof_time = timer.lap();
 
         // Construct the transition.
         let transition = Transition::<N>::new(request, &response, outer_proof.into())?;
@@ -127,6 +144,14 @@ impl<N: Network> VirtualMachine<N> {
         // Update the state of the virtual machine.
         self.local_transitions.add(&transition)?;
         self.transitions.push(transition);
+        self.timings.push(TransitionTiming {
+            transition_id,
+            request_verification,
+            response: response_time,
+            execution: execution_time,
+            inner_proof: inner_proof_time,
+            outer_proof: outer_proof_time,
+        });
 
         Ok((self, response))
     }
@@ -268,10 +293,13 @@ impl<N: Network> VirtualMachine<N> {
         custom_events: Vec<Vec<u8>>,
         rng: &mut R,
     ) -> Result<(Self, Response<N>)> {
+        let mut timer = StageTimer::start();
+
         // Ensure the request is valid.
         if !request.is_valid() {
             return Err(anyhow!("Virtual machine received an invalid request"));
         }
+        let request_verification = timer.lap();
 
         // Compute the operation.
         let operation = request.operation().clone();
@@ -288,6 +316,8 @@ impl<N: Network> VirtualMachine<N> {
             _ => return Err(anyhow!("Invalid Operation")),
         };
 
+        let response_time = timer.lap();
+
         let transition_id = response.transition_id();
         let value_balance = response.value_balance();
 
@@ -308,9 +338,10 @@ impl<N: Network> VirtualMachine<N> {
             &inner_public,
             &inner_proof
         )?);
+        let inner_proof_time = timer.lap();
 
         // Compute the execution.
-        let proof = function.execute(ProgramPublicVariables::new(transition_id), private_variables)?;
+        let proof = function.execute(ProgramPublicVariables::new(transition_id), private_variables, rng)?;
         let public_variables = ProgramPublicVariables::new(transition_id);
 
         assert!(function.verify(&public_variables, &proof));
@@ -322,6 +353,7 @@ impl<N: Network> VirtualMachine<N> {
             verifying_key: function_verifying_key,
             proof,
         };
+        let execution_time = timer.lap();
 
         // Construct the outer circuit public and private variables.
         let outer_public = OuterPublicVariables::new(inner_public, N::inner_circuit_id());
@@ -334,6 +366,7 @@ impl<N: Network> VirtualMachine<N> {
             &outer_public,
             &outer_proof
         )?);
+        let outer_proof_time = timer.lap();
 
         // Construct the transition.
         let transition = Transition::<N>::new(request, &response, outer_proof.into())?;
@@ -341,6 +374,14 @@ impl<N: Network> VirtualMachine<N> {
         // Update the state of the virtual machine.
         self.local_transitions.add(&transition)?;
         self.transitions.push(transition);
+        self.timings.push(TransitionTiming {
+            transition_id,
+            request_verification,
+            response: response_time,
+            execution: execution_time,
+            inner_proof: inner_proof_time,
+            outer_proof: outer_proof_time,
+        });
 
         Ok((self, response))
     }
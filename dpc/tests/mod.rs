@@ -349,3 +349,34 @@ fn test_posw_setup_vs_load_weak_sanity_check() {
     println!("------ Checking circuit arithmetization size ------");
     check_arithmetization_sizes!(joint_arith);
 }
+
+#[cfg(feature = "zeroize-on-drop")]
+#[test]
+fn test_account_zeroize_on_drop() {
+    use snarkvm_dpc::{Account, AccountScheme, PrivateKey, ViewKey};
+    use snarkvm_utilities::ToBytes;
+    use zeroize::{Zeroize, ZeroizeOnDrop};
+
+    // Ensure the account and its secrets are zeroized when they are dropped.
+    fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+    assert_zeroize_on_drop::<Account<Testnet2>>();
+    assert_zeroize_on_drop::<PrivateKey<Testnet2>>();
+    assert_zeroize_on_drop::<ViewKey<Testnet2>>();
+
+    // Ensure zeroizing the secrets of a live account, as their drop does, clears them.
+    let account = Account::<Testnet2>::new(&mut thread_rng());
+
+    let mut private_key = account.private_key().clone();
+    private_key.zeroize();
+    let zero_seed = <Testnet2 as Network>::AccountSeed::default();
+    assert_ne!(account.private_key(), &private_key);
+    assert_eq!(
+        PrivateKey::<Testnet2>::from(&zero_seed).to_string(),
+        private_key.to_string()
+    );
+
+    let mut view_key = account.view_key().clone();
+    view_key.zeroize();
+    assert_ne!(account.view_key(), &view_key);
+    assert_eq!(vec![0u8; 32], view_key.to_bytes_le().unwrap());
+}
@@ -0,0 +1,134 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Montgomery multiplication on x86 and x86_64 CPUs with AVX2.
+//!
+//! The operands are split into 32-bit digits, one per 64-bit lane, so that `vpmuludq` computes
+//! four 32x32->64-bit products at once. As in the CIOS method, each round adds `a[i] * b` and
+//! `m * p` into the accumulator and shifts it down by one digit. The low and high halves of each
+//! product are added to the lanes of consecutive digits, so the lanes never overflow and the
+//! carries are only propagated once, at the end.
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Returns `true` if the CPU supports AVX2.
+#[inline]
+pub(crate) fn has_avx2() -> bool {
+    std::is_x86_feature_detected!("avx2")
+}
+
+/// Returns the 32-bit digits of the given limbs, one per 64-bit lane.
+#[target_feature(enable = "avx2")]
+#[inline]
+#[allow(unsafe_code)]
+unsafe fn load<const V: usize>(limbs: &[u64]) -> [__m256i; V] {
+    let mut digits = [_mm256_setzero_si256(); V];
+    for (k, digits) in digits.iter_mut().enumerate() {
+        *digits = _mm256_set_epi64x(
+            super::digit(limbs, 4 * k + 3) as i64,
+            super::digit(limbs, 4 * k + 2) as i64,
+            super::digit(limbs, 4 * k + 1) as i64,
+            super::digit(limbs, 4 * k) as i64,
+        );
+    }
+    digits
+}
+
+/// Adds `x * y` into the accumulator `t, top`, where `y` holds one digit per lane.
+#[target_feature(enable = "avx2")]
+#[inline]
+#[allow(unsafe_code)]
+unsafe fn mul_add<const V: usize>(t: &mut [__m256i; V], top: &mut u64, x: u32, y: &[__m256i; V]) {
+    let x = _mm256_set1_epi64x(x as i64);
+    let mask = _mm256_set1_epi64x(0xffff_ffff);
+    let mut previous = _mm256_setzero_si256();
+    for (t, y) in t.iter_mut().zip(y) {
+        let product = _mm256_mul_epu32(x, *y);
+        // Rotate the high halves up by one lane, and fill the lowest lane from the previous vector.
+        let high = _mm256_permute4x64_epi64(_mm256_srli_epi64(product, 32), 0b10_01_00_11);
+        let high_shifted = _mm256_blend_epi32(high, previous, 0b0000_0011);
+        *t = _mm256_add_epi64(*t, _mm256_add_epi64(_mm256_and_si256(product, mask), high_shifted));
+        previous = high;
+    }
+    *top += lowest_digit(previous) as u64;
+}
+
+/// Shifts the accumulator `t, top` down by one digit, carrying the high half of the lowest lane,
+/// whose low half must be zero.
+#[target_feature(enable = "avx2")]
+#[inline]
+#[allow(unsafe_code)]
+unsafe fn shift<const V: usize>(t: &mut [__m256i; V], top: &mut u64) {
+    let carry = _mm256_blend_epi32(_mm256_setzero_si256(), _mm256_srli_epi64(t[0], 32), 0b0000_0011);
+    // Rotate each vector down by one lane, and fill the highest lane from the next vector.
+    let mut rotated = _mm256_permute4x64_epi64(t[0], 0b00_11_10_01);
+    for k in 0..V {
+        let next = match k + 1 < V {
+            true => _mm256_permute4x64_epi64(t[k + 1], 0b00_11_10_01),
+            false => _mm256_set_epi64x(*top as i64, 0, 0, 0),
+        };
+        t[k] = _mm256_blend_epi32(rotated, next, 0b1100_0000);
+        rotated = next;
+    }
+    t[0] = _mm256_add_epi64(t[0], carry);
+    *top = 0;
+}
+
+/// Returns the low half of the lowest lane.
+#[target_feature(enable = "avx2")]
+#[inline]
+#[allow(unsafe_code)]
+unsafe fn lowest_digit(v: __m256i) -> u32 {
+    _mm_cvtsi128_si32(_mm256_castsi256_si128(v)) as u32
+}
+
+/// Returns `a * b * 2^-(64 * L) mod p`, in the range `[0, 2p)`, where `L = 2 * V`.
+///
+/// # Safety
+///
+/// The CPU must support AVX2, and `a`, `b` must be less than the modulus `p`, which must be
+/// less than `2^(64 * L - 1)`. `inv` is `-p^-1 mod 2^64`.
+#[target_feature(enable = "avx2")]
+#[allow(unsafe_code)]
+pub(crate) unsafe fn mul<const L: usize, const V: usize>(
+    a: &[u64; L],
+    b: &[u64; L],
+    modulus: &[u64; L],
+    inv: u64,
+) -> [u64; L] {
+    debug_assert_eq!(2 * L, 4 * V);
+    let b = load::<V>(b);
+    let p = load::<V>(modulus);
+    let inv = inv as u32;
+
+    let mut t = [_mm256_setzero_si256(); V];
+    let mut top = 0;
+    for i in 0..2 * L {
+        mul_add(&mut t, &mut top, super::digit(a, i), &b);
+        let m = lowest_digit(t[0]).wrapping_mul(inv);
+        mul_add(&mut t, &mut top, m, &p);
+        shift(&mut t, &mut top);
+    }
+
+    let mut accumulators = [0u64; 12];
+    for (k, t) in t.iter().enumerate() {
+        _mm256_storeu_si256(accumulators[4 * k..].as_mut_ptr() as *mut __m256i, *t);
+    }
+    super::from_accumulators(&accumulators[..2 * L])
+}
@@ -0,0 +1,188 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Montgomery multiplication on x86_64 CPUs with the BMI2 and ADX extensions.
+//!
+//! The multiplication is performed with `mulx`, which leaves the flags untouched, and two
+//! independent carry chains (`adcx` on the carry flag and `adox` on the overflow flag), as in
+//! the CIOS method of Koç, Acar and Kaliski. This requires the modulus to leave the most
+//! significant bit of its top limb unused, so that the accumulator fits in one extra limb.
+
+/// Returns `true` if the CPU supports the BMI2 and ADX extensions.
+#[inline]
+pub(crate) fn has_bmi2_adx() -> bool {
+    std::is_x86_feature_detected!("bmi2") && std::is_x86_feature_detected!("adx")
+}
+
+/// Adds `rdx * [$src]` into the accumulator `$r0, ..., $r4`, where `$src` points to 4 limbs.
+/// The flags must be clear; clobbers `rax` and `r15`.
+#[rustfmt::skip]
+macro_rules! mul_add_4 {
+    ($src:literal, $r0:literal, $r1:literal, $r2:literal, $r3:literal, $r4:literal) => {
+        concat!(
+            "mulx rax, r15, qword ptr [", $src, "]\n",
+            "adox ", $r0, ", r15\n",
+            "adcx ", $r1, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 8]\n",
+            "adox ", $r1, ", r15\n",
+            "adcx ", $r2, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 16]\n",
+            "adox ", $r2, ", r15\n",
+            "adcx ", $r3, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 24]\n",
+            "adox ", $r3, ", r15\n",
+            "adcx ", $r4, ", rax\n",
+            "mov r15d, 0\n",
+            "adox ", $r4, ", r15\n",
+        )
+    };
+}
+
+/// Adds `rdx * [$src]` into the accumulator `$r0, ..., $r6`, where `$src` points to 6 limbs.
+/// The flags must be clear; clobbers `rax` and `r15`.
+#[rustfmt::skip]
+macro_rules! mul_add_6 {
+    ($src:literal, $r0:literal, $r1:literal, $r2:literal, $r3:literal, $r4:literal, $r5:literal, $r6:literal) => {
+        concat!(
+            "mulx rax, r15, qword ptr [", $src, "]\n",
+            "adox ", $r0, ", r15\n",
+            "adcx ", $r1, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 8]\n",
+            "adox ", $r1, ", r15\n",
+            "adcx ", $r2, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 16]\n",
+            "adox ", $r2, ", r15\n",
+            "adcx ", $r3, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 24]\n",
+            "adox ", $r3, ", r15\n",
+            "adcx ", $r4, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 32]\n",
+            "adox ", $r4, ", r15\n",
+            "adcx ", $r5, ", rax\n",
+            "mulx rax, r15, qword ptr [", $src, " + 40]\n",
+            "adox ", $r5, ", r15\n",
+            "adcx ", $r6, ", rax\n",
+            "mov r15d, 0\n",
+            "adox ", $r6, ", r15\n",
+        )
+    };
+}
+
+/// Performs one round of the Montgomery multiplication on 4 limbs: adds `a[$i] * b` into the
+/// accumulator, then adds the multiple of the modulus that clears `$r0`. The caller rotates the
+/// registers, so that `$r1, ..., $r4, $r0` is the accumulator of the next round.
+#[rustfmt::skip]
+macro_rules! round_4 {
+    ($i:literal, $r0:literal, $r1:literal, $r2:literal, $r3:literal, $r4:literal) => {
+        concat!(
+            // Clear the top limb and the flags.
+            "xor ", $r4, ", ", $r4, "\n",
+            "mov rdx, qword ptr [rdi + ", $i, " * 8]\n",
+            mul_add_4!("rsi", $r0, $r1, $r2, $r3, $r4),
+            // m = r0 * inv mod 2^64
+            "mov rdx, ", $r0, "\n",
+            "imul rdx, qword ptr [rcx + 32]\n",
+            "xor r15d, r15d\n",
+            mul_add_4!("rcx", $r0, $r1, $r2, $r3, $r4),
+        )
+    };
+}
+
+/// Performs one round of the Montgomery multiplication on 6 limbs; see `round_4`.
+#[rustfmt::skip]
+macro_rules! round_6 {
+    ($i:literal, $r0:literal, $r1:literal, $r2:literal, $r3:literal, $r4:literal, $r5:literal, $r6:literal) => {
+        concat!(
+            // Clear the top limb and the flags.
+            "xor ", $r6, ", ", $r6, "\n",
+            "mov rdx, qword ptr [rdi + ", $i, " * 8]\n",
+            mul_add_6!("rsi", $r0, $r1, $r2, $r3, $r4, $r5, $r6),
+            // m = r0 * inv mod 2^64
+            "mov rdx, ", $r0, "\n",
+            "imul rdx, qword ptr [rcx + 48]\n",
+            "xor r15d, r15d\n",
+            mul_add_6!("rcx", $r0, $r1, $r2, $r3, $r4, $r5, $r6),
+        )
+    };
+}
+
+/// Returns `a * b * 2^-256 mod p`, in the range `[0, 2p)`.
+///
+/// # Safety
+///
+/// The CPU must support BMI2 and ADX, and `a`, `b` must be less than the modulus `p`,
+/// which must be less than `2^255`. `modulus_and_inv` holds the limbs of `p` followed by
+/// `-p^-1 mod 2^64`.
+#[inline]
+#[allow(unsafe_code)]
+pub(crate) unsafe fn mul_4(a: &[u64; 4], b: &[u64; 4], modulus_and_inv: &[u64; 5]) -> [u64; 4] {
+    let (r0, r1, r2, r3);
+    std::arch::asm!(
+        round_4!("0", "r8", "r9", "r10", "r11", "r12"),
+        round_4!("1", "r9", "r10", "r11", "r12", "r8"),
+        round_4!("2", "r10", "r11", "r12", "r8", "r9"),
+        round_4!("3", "r11", "r12", "r8", "r9", "r10"),
+        in("rdi") a.as_ptr(),
+        in("rsi") b.as_ptr(),
+        in("rcx") modulus_and_inv.as_ptr(),
+        out("rax") _,
+        out("rdx") _,
+        inout("r8") 0u64 => r1,
+        inout("r9") 0u64 => r2,
+        inout("r10") 0u64 => r3,
+        inout("r11") 0u64 => _,
+        inout("r12") 0u64 => r0,
+        out("r15") _,
+        options(pure, readonly, nostack)
+    );
+    [r0, r1, r2, r3]
+}
+
+/// Returns `a * b * 2^-384 mod p`, in the range `[0, 2p)`.
+///
+/// # Safety
+///
+/// The CPU must support BMI2 and ADX, and `a`, `b` must be less than the modulus `p`,
+/// which must be less than `2^383`. `modulus_and_inv` holds the limbs of `p` followed by
+/// `-p^-1 mod 2^64`.
+#[inline]
+#[allow(unsafe_code)]
+pub(crate) unsafe fn mul_6(a: &[u64; 6], b: &[u64; 6], modulus_and_inv: &[u64; 7]) -> [u64; 6] {
+    let (r0, r1, r2, r3, r4, r5);
+    std::arch::asm!(
+        round_6!("0", "r8", "r9", "r10", "r11", "r12", "r13", "r14"),
+        round_6!("1", "r9", "r10", "r11", "r12", "r13", "r14", "r8"),
+        round_6!("2", "r10", "r11", "r12", "r13", "r14", "r8", "r9"),
+        round_6!("3", "r11", "r12", "r13", "r14", "r8", "r9", "r10"),
+        round_6!("4", "r12", "r13", "r14", "r8", "r9", "r10", "r11"),
+        round_6!("5", "r13", "r14", "r8", "r9", "r10", "r11", "r12"),
+        in("rdi") a.as_ptr(),
+        in("rsi") b.as_ptr(),
+        in("rcx") modulus_and_inv.as_ptr(),
+        out("rax") _,
+        out("rdx") _,
+        inout("r8") 0u64 => r1,
+        inout("r9") 0u64 => r2,
+        inout("r10") 0u64 => r3,
+        inout("r11") 0u64 => r4,
+        inout("r12") 0u64 => r5,
+        inout("r13") 0u64 => _,
+        inout("r14") 0u64 => r0,
+        out("r15") _,
+        options(pure, readonly, nostack)
+    );
+    [r0, r1, r2, r3, r4, r5]
+}
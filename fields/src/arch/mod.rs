@@ -0,0 +1,70 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! Architecture-specific backends for the Montgomery multiplication.
+//!
+//! On x86_64 CPUs with the BMI2 and ADX extensions, the multiplication uses `mulx` and two
+//! independent carry chains, see `bmi2_adx`. On 32-bit x86 CPUs with AVX2, the multiplication
+//! and squaring use vectors of 32x32->64-bit products, see `avx2`, which take half the time of
+//! the portable code for 384-bit fields. The portable code is used in all other cases.
+//!
+//! The AVX2 backend is also available on x86_64, and the NEON backend on AArch64, but neither is
+//! selected there. With native 64x64->128-bit multiplies, the portable code is 3 to 4 times faster
+//! than the AVX2 backend on x86_64, and AArch64 has them too, while NEON has half as many lanes.
+//!
+//! Each backend requires the modulus to leave the most significant bit of its top limb unused,
+//! and returns a result in the range `[0, 2p)`, which the caller reduces.
+
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod bmi2_adx;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) mod avx2;
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+pub(crate) mod neon;
+
+/// Returns the 32-bit digit `i` of the given limbs, least significant first.
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+#[inline(always)]
+fn digit(limbs: &[u64], i: usize) -> u32 {
+    (limbs[i / 2] >> (32 * (i % 2))) as u32
+}
+
+/// Returns the limbs of the number whose 32-bit digits, least significant first, are the given
+/// accumulators, after propagating their carries. The number must fit in the limbs.
+#[cfg(any(
+    target_arch = "x86",
+    target_arch = "x86_64",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+#[inline(always)]
+fn from_accumulators<const L: usize>(accumulators: &[u64]) -> [u64; L] {
+    debug_assert_eq!(accumulators.len(), 2 * L);
+    let mut limbs = [0u64; L];
+    let mut carry = 0u64;
+    for (i, accumulator) in accumulators.iter().enumerate() {
+        let sum = accumulator + carry;
+        limbs[i / 2] |= (sum & 0xffff_ffff) << (32 * (i % 2));
+        carry = sum >> 32;
+    }
+    debug_assert_eq!(carry, 0);
+    limbs
+}
@@ -0,0 +1,106 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The Montgomery multiplication on AArch64 CPUs with NEON.
+//!
+//! This follows the AVX2 backend, with two 32x32->64-bit products per `umull` instead of four.
+//! The operands are split into 32-bit digits, and each round adds `a[i] * b` and `m * p` into
+//! the accumulator and shifts it down by one digit. The low and high halves of each product are
+//! added to the lanes of consecutive digits, so the lanes never overflow and the carries are only
+//! propagated once, at the end.
+
+use std::arch::aarch64::*;
+
+/// Returns the 32-bit digits of the given limbs, two per vector.
+#[inline(always)]
+#[allow(unsafe_code)]
+unsafe fn load<const L: usize>(limbs: &[u64; L]) -> [uint32x2_t; L] {
+    let mut digits = [vdup_n_u32(0); L];
+    for (k, digits) in digits.iter_mut().enumerate() {
+        *digits = vset_lane_u32(
+            super::digit(limbs, 2 * k + 1),
+            vdup_n_u32(super::digit(limbs, 2 * k)),
+            1,
+        );
+    }
+    digits
+}
+
+/// Adds `x * y` into the accumulator `t, top`, where `y` holds two digits per vector,
+/// and `t` one digit per 64-bit lane.
+#[inline(always)]
+#[allow(unsafe_code)]
+unsafe fn mul_add<const L: usize>(t: &mut [uint64x2_t; L], top: &mut u64, x: u32, y: &[uint32x2_t; L]) {
+    let x = vdup_n_u32(x);
+    let mask = vdupq_n_u64(0xffff_ffff);
+    let mut previous = vdupq_n_u64(0);
+    for (t, y) in t.iter_mut().zip(y) {
+        let product = vmull_u32(x, *y);
+        // Shift the high halves up by one lane, and fill the lowest lane from the previous vector.
+        let high = vshrq_n_u64(product, 32);
+        let high_shifted = vextq_u64(previous, high, 1);
+        *t = vaddq_u64(*t, vaddq_u64(vandq_u64(product, mask), high_shifted));
+        previous = high;
+    }
+    *top += vgetq_lane_u64(previous, 1);
+}
+
+/// Shifts the accumulator `t, top` down by one digit, carrying the high half of the lowest lane,
+/// whose low half must be zero.
+#[inline(always)]
+#[allow(unsafe_code)]
+unsafe fn shift<const L: usize>(t: &mut [uint64x2_t; L], top: &mut u64) {
+    let carry = vgetq_lane_u64(t[0], 0) >> 32;
+    // Shift each vector down by one lane, and fill the highest lane from the next vector.
+    for k in 0..L {
+        let next = match k + 1 < L {
+            true => t[k + 1],
+            false => vsetq_lane_u64(*top, vdupq_n_u64(0), 0),
+        };
+        t[k] = vextq_u64(t[k], next, 1);
+    }
+    t[0] = vaddq_u64(t[0], vsetq_lane_u64(carry, vdupq_n_u64(0), 0));
+    *top = 0;
+}
+
+/// Returns `a * b * 2^-(64 * L) mod p`, in the range `[0, 2p)`.
+///
+/// # Safety
+///
+/// `a`, `b` must be less than the modulus `p`, which must be less than `2^(64 * L - 1)`.
+/// `inv` is `-p^-1 mod 2^64`.
+#[inline]
+#[allow(unsafe_code)]
+pub(crate) unsafe fn mul<const L: usize>(a: &[u64; L], b: &[u64; L], modulus: &[u64; L], inv: u64) -> [u64; L] {
+    let b = load(b);
+    let p = load(modulus);
+    let inv = inv as u32;
+
+    let mut t = [vdupq_n_u64(0); L];
+    let mut top = 0;
+    for i in 0..2 * L {
+        mul_add(&mut t, &mut top, super::digit(a, i), &b);
+        let m = (vgetq_lane_u64(t[0], 0) as u32).wrapping_mul(inv);
+        mul_add(&mut t, &mut top, m, &p);
+        shift(&mut t, &mut top);
+    }
+
+    let mut accumulators = [0u64; 12];
+    for (k, t) in t.iter().enumerate() {
+        vst1q_u64(accumulators[2 * k..].as_mut_ptr(), *t);
+    }
+    super::from_accumulators(&accumulators[..2 * L])
+}
@@ -14,9 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{fp6_3over2::*, Field, Fp2, Fp2Parameters, One, Zero};
+use crate::{fp6_3over2::*, ConditionallyNegatable, ConditionallySelectable, Field, Fp2, Fp2Parameters, One, Zero};
 use snarkvm_utilities::{
-    bititerator::BitIteratorBE,
+    bititerator::{BitIteratorBE, BitIteratorLE},
     errors::SerializationError,
     rand::UniformRand,
     serialize::*,
@@ -35,6 +35,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use zeroize::Zeroize;
 
 pub trait Fp12Parameters: 'static + Send + Sync + Copy {
     type Fp6Params: Fp6Parameters;
@@ -204,6 +205,131 @@ impl<P: Fp12Parameters> Fp12<P> {
         }
         res
     }
+
+    /// Squares an element of the cyclotomic subgroup in Karabina's compressed form.
+    ///
+    /// Only the coefficients `c0.c1`, `c0.c2`, `c1.c0` and `c1.c2` are read and written;
+    /// `c0.c0` and `c1.c1` are left as zero and must be recovered with
+    /// [`Self::decompress_karabina`] before the element is used in a multiplication.
+    pub fn cyclotomic_square_compressed(&self) -> Self {
+        let mut result = Self::zero();
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        let z4 = self.c0.c1;
+        let z3 = self.c0.c2;
+        let z2 = self.c1.c0;
+        let z5 = self.c1.c2;
+
+        // t0 + t1*y = (z2 + z3*y)^2 = b^2
+        let mut tmp = z2 * z3;
+        let t0 = (z2 + z3) * (z2 + fp2_nr(&z3)) - tmp - fp2_nr(&tmp);
+        let t1 = tmp.double();
+
+        // t2 + t3*y = (z4 + z5*y)^2 = c^2
+        tmp = z4 * z5;
+        let t2 = (z4 + z5) * (z4 + fp2_nr(&z5)) - tmp - fp2_nr(&tmp);
+        let t3 = tmp.double();
+
+        // z2 = 3 * (xi * t3) + 2 * z2
+        tmp = fp2_nr(&t3);
+        result.c1.c0 = (tmp + z2).double() + tmp;
+
+        // z3 = 3 * t2 - 2 * z3
+        result.c0.c2 = (t2 - z3).double() + t2;
+
+        // z4 = 3 * t0 - 2 * z4
+        result.c0.c1 = (t0 - z4).double() + t0;
+
+        // z5 = 3 * t1 + 2 * z5
+        result.c1.c2 = (t1 + z5).double() + t1;
+
+        result
+    }
+
+    /// Recovers the full element from its Karabina compressed form.
+    ///
+    /// This costs one `Fp2` inversion; use [`Self::batch_decompress_karabina`]
+    /// to share the inversion across several elements.
+    pub fn decompress_karabina(&self) -> Self {
+        let mut elements = [*self];
+        Self::batch_decompress_karabina(&mut elements);
+        elements[0]
+    }
+
+    /// Recovers the full elements from their Karabina compressed forms, in place,
+    /// using a single batched `Fp2` inversion.
+    pub fn batch_decompress_karabina(elements: &mut [Self]) {
+        let fp2_nr = <P::Fp6Params as Fp6Parameters>::mul_fp2_by_nonresidue;
+
+        // z1 = num / den, where:
+        //   if z2 != 0, z1 = (xi * z5^2 + 3 * z4^2 - 2 * z3) / (4 * z2)
+        //   if z2 == 0, z1 = (2 * z4 * z5) / z3
+        // If both z2 and z3 are zero, the element is the identity.
+        let mut numerators = Vec::with_capacity(elements.len());
+        let mut denominators = Vec::with_capacity(elements.len());
+        for element in elements.iter() {
+            let z4 = element.c0.c1;
+            let z3 = element.c0.c2;
+            let z2 = element.c1.c0;
+            let z5 = element.c1.c2;
+
+            if !z2.is_zero() {
+                let z4_sq = z4.square();
+                numerators.push(fp2_nr(&z5.square()) + (z4_sq - z3).double() + z4_sq);
+                denominators.push(z2.double().double());
+            } else {
+                numerators.push((z4 * z5).double());
+                denominators.push(z3);
+            }
+        }
+
+        crate::batch_inversion(&mut denominators);
+
+        for ((element, numerator), denominator) in elements.iter_mut().zip(numerators).zip(denominators) {
+            if element.c1.c0.is_zero() && element.c0.c2.is_zero() {
+                *element = Self::one();
+                continue;
+            }
+
+            let z4 = element.c0.c1;
+            let z3 = element.c0.c2;
+            let z2 = element.c1.c0;
+            let z5 = element.c1.c2;
+
+            let z1 = numerator * denominator;
+
+            // z0 = xi * (2 * z1^2 + z2 * z5 - 3 * z3 * z4) + 1
+            let z3_z4 = z3 * z4;
+            let tmp = (z1.square() - z3_z4).double() - z3_z4 + z2 * z5;
+
+            element.c0.c0 = fp2_nr(&tmp) + Fp2::one();
+            element.c1.c1 = z1;
+        }
+    }
+
+    /// Exponentiates an element of the cyclotomic subgroup, using compressed squarings
+    /// and a single batched decompression.
+    ///
+    /// This returns the same result as [`Self::cyclotomic_exp`], and is faster for
+    /// sparse exponents such as the BLS12 curve parameter.
+    pub fn cyclotomic_exp_compressed<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let bits = BitIteratorLE::new_without_trailing_zeros(exp).collect::<Vec<_>>();
+        let mut powers = Vec::with_capacity(bits.iter().filter(|bit| **bit).count());
+
+        // Collect the compressed self^(2^i) for every set bit i of the exponent.
+        let mut current = *self;
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit {
+                powers.push(current);
+            }
+            if i + 1 < bits.len() {
+                current = current.cyclotomic_square_compressed();
+            }
+        }
+
+        Self::batch_decompress_karabina(&mut powers);
+        powers.iter().fold(Self::one(), |acc, power| acc * power)
+    }
 }
 
 impl<P: Fp12Parameters> std::fmt::Display for Fp12<P> {
@@ -239,6 +365,16 @@ impl<P: Fp12Parameters> One for Fp12<P> {
     }
 }
 
+impl<P: Fp12Parameters> Zeroize for Fp12<P>
+where
+    Fp6<P::Fp6Params>: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
 impl<P: Fp12Parameters> Field for Fp12<P> {
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
@@ -338,6 +474,29 @@ impl<P: Fp12Parameters> Field for Fp12<P> {
     }
 }
 
+impl<P: Fp12Parameters> ConditionallySelectable for Fp12<P>
+where
+    Fp6<P::Fp6Params>: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl<P: Fp12Parameters> ConditionallyNegatable for Fp12<P>
+where
+    Fp6<P::Fp6Params>: ConditionallyNegatable,
+{
+    /// Negates each coefficient of `self` if `choice` is `true`.
+    fn conditional_negate_in_place(&mut self, choice: bool) {
+        self.c0.conditional_negate_in_place(choice);
+        self.c1.conditional_negate_in_place(choice);
+    }
+}
+
 impl<P: Fp12Parameters> Neg for Fp12<P> {
     type Output = Self;
 
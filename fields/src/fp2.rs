@@ -14,7 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
+use crate::{
+    ConditionallyNegatable,
+    ConditionallySelectable,
+    Field,
+    LegendreSymbol,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{errors::SerializationError, rand::UniformRand, serialize::*, FromBytes, ToBits, ToBytes};
 
 use rand::{
@@ -27,6 +36,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use zeroize::Zeroize;
 
 pub trait Fp2Parameters: 'static + Send + Sync + Serialize + for<'a> Deserialize<'a> {
     type Fp: PrimeField;
@@ -98,6 +108,16 @@ impl<P: Fp2Parameters> One for Fp2<P> {
     }
 }
 
+impl<P: Fp2Parameters> Zeroize for Fp2<P>
+where
+    P::Fp: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
 impl<P: Fp2Parameters> Field for Fp2<P> {
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
@@ -188,6 +208,29 @@ impl<P: Fp2Parameters> Field for Fp2<P> {
     }
 }
 
+impl<P: Fp2Parameters> ConditionallySelectable for Fp2<P>
+where
+    P::Fp: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl<P: Fp2Parameters> ConditionallyNegatable for Fp2<P>
+where
+    P::Fp: ConditionallyNegatable,
+{
+    /// Negates each coefficient of `self` if `choice` is `true`.
+    fn conditional_negate_in_place(&mut self, choice: bool) {
+        self.c0.conditional_negate_in_place(choice);
+        self.c1.conditional_negate_in_place(choice);
+    }
+}
+
 impl<'a, P: Fp2Parameters> SquareRootField for Fp2<P>
 where
     P::Fp: SquareRootField,
@@ -14,7 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, LegendreSymbol, One, PrimeField, SquareRootField, Zero};
+use crate::{
+    ConditionallyNegatable,
+    ConditionallySelectable,
+    Field,
+    LegendreSymbol,
+    One,
+    PrimeField,
+    SquareRootField,
+    Zero,
+};
 use snarkvm_utilities::{errors::SerializationError, rand::UniformRand, serialize::*, FromBytes, ToBits, ToBytes};
 
 use rand::{
@@ -28,6 +37,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 pub trait Fp3Parameters: 'static + Send + Sync {
     type Fp: PrimeField + SquareRootField;
@@ -124,6 +134,17 @@ impl<P: Fp3Parameters> One for Fp3<P> {
     }
 }
 
+impl<P: Fp3Parameters> Zeroize for Fp3<P>
+where
+    P::Fp: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+        self.c2.zeroize();
+    }
+}
+
 impl<P: Fp3Parameters> Field for Fp3<P> {
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
@@ -251,6 +272,31 @@ impl<P: Fp3Parameters> Field for Fp3<P> {
     }
 }
 
+impl<P: Fp3Parameters> ConditionallySelectable for Fp3<P>
+where
+    P::Fp: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+            ConditionallySelectable::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl<P: Fp3Parameters> ConditionallyNegatable for Fp3<P>
+where
+    P::Fp: ConditionallyNegatable,
+{
+    /// Negates each coefficient of `self` if `choice` is `true`.
+    fn conditional_negate_in_place(&mut self, choice: bool) {
+        self.c0.conditional_negate_in_place(choice);
+        self.c1.conditional_negate_in_place(choice);
+        self.c2.conditional_negate_in_place(choice);
+    }
+}
+
 impl<P: Fp3Parameters> SquareRootField for Fp3<P> {
     /// Returns the Legendre symbol.
     fn legendre(&self) -> LegendreSymbol {
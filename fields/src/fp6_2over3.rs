@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, Fp3, Fp3Parameters, One, Zero};
+use crate::{ConditionallyNegatable, ConditionallySelectable, Field, Fp3, Fp3Parameters, One, Zero};
 use snarkvm_utilities::{
     biginteger::BigInteger,
     errors::SerializationError,
@@ -35,6 +35,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use zeroize::Zeroize;
 
 pub trait Fp6Parameters: 'static + Send + Sync {
     type Fp3Params: Fp3Parameters;
@@ -201,6 +202,16 @@ impl<P: Fp6Parameters> One for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> Zeroize for Fp6<P>
+where
+    Fp3<P::Fp3Params>: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
 impl<P: Fp6Parameters> Field for Fp6<P> {
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
@@ -294,6 +305,29 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> ConditionallySelectable for Fp6<P>
+where
+    Fp3<P::Fp3Params>: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl<P: Fp6Parameters> ConditionallyNegatable for Fp6<P>
+where
+    Fp3<P::Fp3Params>: ConditionallyNegatable,
+{
+    /// Negates each coefficient of `self` if `choice` is `true`.
+    fn conditional_negate_in_place(&mut self, choice: bool) {
+        self.c0.conditional_negate_in_place(choice);
+        self.c1.conditional_negate_in_place(choice);
+    }
+}
+
 /// `Fp6` elements are ordered lexicographically.
 impl<P: Fp6Parameters> Ord for Fp6<P> {
     #[inline(always)]
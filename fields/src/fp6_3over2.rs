@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{Field, Fp2, Fp2Parameters, One, Zero};
+use crate::{ConditionallyNegatable, ConditionallySelectable, Field, Fp2, Fp2Parameters, One, Zero};
 use snarkvm_utilities::{errors::SerializationError, rand::UniformRand, serialize::*, FromBytes, ToBits, ToBytes};
 
 use rand::{
@@ -27,6 +27,7 @@ use std::{
     io::{Read, Result as IoResult, Write},
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
+use zeroize::Zeroize;
 
 pub trait Fp6Parameters: 'static + Send + Sync + Copy {
     type Fp2Params: Fp2Parameters;
@@ -169,6 +170,17 @@ impl<P: Fp6Parameters> One for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> Zeroize for Fp6<P>
+where
+    Fp2<P::Fp2Params>: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+        self.c2.zeroize();
+    }
+}
+
 impl<P: Fp6Parameters> Field for Fp6<P> {
     #[inline]
     fn characteristic<'a>() -> &'a [u64] {
@@ -289,6 +301,31 @@ impl<P: Fp6Parameters> Field for Fp6<P> {
     }
 }
 
+impl<P: Fp6Parameters> ConditionallySelectable for Fp6<P>
+where
+    Fp2<P::Fp2Params>: ConditionallySelectable,
+{
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        Self::new(
+            ConditionallySelectable::conditional_select(&a.c0, &b.c0, choice),
+            ConditionallySelectable::conditional_select(&a.c1, &b.c1, choice),
+            ConditionallySelectable::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl<P: Fp6Parameters> ConditionallyNegatable for Fp6<P>
+where
+    Fp2<P::Fp2Params>: ConditionallyNegatable,
+{
+    /// Negates each coefficient of `self` if `choice` is `true`.
+    fn conditional_negate_in_place(&mut self, choice: bool) {
+        self.c0.conditional_negate_in_place(choice);
+        self.c1.conditional_negate_in_place(choice);
+        self.c2.conditional_negate_in_place(choice);
+    }
+}
+
 impl<P: Fp6Parameters> std::fmt::Display for Fp6<P> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "Fq6_3over2({} + {} * v, {} * v^2)", self.c0, self.c1, self.c2)
@@ -43,6 +43,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 pub trait Fp256Parameters: FieldParameters<BigInteger = BigInteger> {}
 
@@ -80,6 +81,26 @@ impl<P: Fp256Parameters> Fp256<P> {
         }
     }
 
+    /// Reduces `self`, where `carry` is the bit above the backing capacity.
+    /// This bit can only be set when the modulus occupies all 256 bits.
+    #[inline]
+    fn reduce_with_carry(&mut self, carry: bool) {
+        if carry {
+            self.0.sub_noborrow(&P::MODULUS);
+        } else {
+            self.reduce();
+        }
+    }
+
+    /// Halves `self`, where `carry` is the bit above the backing capacity.
+    #[inline]
+    fn div2_with_carry(&mut self, carry: bool) {
+        self.0.div2();
+        if carry {
+            (self.0).0[3] |= 1 << 63;
+        }
+    }
+
     #[inline]
     #[allow(clippy::too_many_arguments)]
     fn mont_reduce(
@@ -132,6 +153,136 @@ impl<P: Fp256Parameters> Fp256<P> {
         (self.0).0[1] = r5;
         (self.0).0[2] = r6;
         (self.0).0[3] = r7;
+        self.reduce_with_carry(carry != 0);
+    }
+
+    /// Multiplies `self` by `other`, using the portable Montgomery multiplication.
+    #[inline]
+    pub fn mul_assign_portable(&mut self, other: &Self) {
+        let mut carry = 0;
+        let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
+        let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
+        let r2 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[2], &mut carry);
+        let r3 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[3], &mut carry);
+        let r4 = carry;
+        let mut carry = 0;
+        let r1 = fa::mac_with_carry(r1, (self.0).0[1], (other.0).0[0], &mut carry);
+        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (other.0).0[1], &mut carry);
+        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (other.0).0[2], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (other.0).0[3], &mut carry);
+        let r5 = carry;
+        let mut carry = 0;
+        let r2 = fa::mac_with_carry(r2, (self.0).0[2], (other.0).0[0], &mut carry);
+        let r3 = fa::mac_with_carry(r3, (self.0).0[2], (other.0).0[1], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (other.0).0[2], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (other.0).0[3], &mut carry);
+        let r6 = carry;
+        let mut carry = 0;
+        let r3 = fa::mac_with_carry(r3, (self.0).0[3], (other.0).0[0], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[3], (other.0).0[1], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[3], (other.0).0[2], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (other.0).0[3], &mut carry);
+        let r7 = carry;
+        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7);
+    }
+
+    /// Returns `true` if the BMI2 and ADX backend can be used for this field on this CPU.
+    #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+    #[inline]
+    pub fn has_bmi2_adx_backend() -> bool {
+        P::MODULUS_BITS < 256 && crate::arch::bmi2_adx::has_bmi2_adx()
+    }
+
+    /// Multiplies `self` by `other`, using `mulx`, `adcx` and `adox`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::has_bmi2_adx_backend()` does not hold.
+    #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+    #[inline]
+    pub fn mul_assign_bmi2_adx(&mut self, other: &Self) {
+        assert!(Self::has_bmi2_adx_backend(), "the BMI2 and ADX backend is unavailable");
+        #[allow(unsafe_code)]
+        unsafe {
+            self.mul_assign_bmi2_adx_unchecked(other)
+        };
+    }
+
+    /// Multiplies `self` by `other`, using `mulx`, `adcx` and `adox`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `Self::has_bmi2_adx_backend()` holds.
+    #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+    #[inline]
+    #[allow(unsafe_code)]
+    unsafe fn mul_assign_bmi2_adx_unchecked(&mut self, other: &Self) {
+        let modulus_and_inv = [
+            P::MODULUS.0[0],
+            P::MODULUS.0[1],
+            P::MODULUS.0[2],
+            P::MODULUS.0[3],
+            P::INV,
+        ];
+        (self.0).0 = crate::arch::bmi2_adx::mul_4(&(self.0).0, &(other.0).0, &modulus_and_inv);
+        self.reduce();
+    }
+
+    /// Returns `true` if the AVX2 backend can be used for this field on this CPU.
+    #[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[inline]
+    pub fn has_avx2_backend() -> bool {
+        P::MODULUS_BITS < 256 && crate::arch::avx2::has_avx2()
+    }
+
+    /// Multiplies `self` by `other`, using AVX2 vectors of 32-bit digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::has_avx2_backend()` does not hold.
+    #[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[inline]
+    pub fn mul_assign_avx2(&mut self, other: &Self) {
+        assert!(Self::has_avx2_backend(), "the AVX2 backend is unavailable");
+        #[allow(unsafe_code)]
+        unsafe {
+            self.mul_assign_avx2_unchecked(other)
+        };
+    }
+
+    /// Multiplies `self` by `other`, using AVX2 vectors of 32-bit digits.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `Self::has_avx2_backend()` holds.
+    #[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[inline]
+    #[allow(unsafe_code)]
+    unsafe fn mul_assign_avx2_unchecked(&mut self, other: &Self) {
+        (self.0).0 = crate::arch::avx2::mul::<4, 2>(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV);
+        self.reduce();
+    }
+
+    /// Returns `true` if the NEON backend can be used for this field.
+    #[cfg(all(feature = "target-specific", target_arch = "aarch64", target_feature = "neon"))]
+    #[inline]
+    pub fn has_neon_backend() -> bool {
+        P::MODULUS_BITS < 256
+    }
+
+    /// Multiplies `self` by `other`, using NEON vectors of 32-bit digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::has_neon_backend()` does not hold.
+    #[cfg(all(feature = "target-specific", target_arch = "aarch64", target_feature = "neon"))]
+    #[inline]
+    pub fn mul_assign_neon(&mut self, other: &Self) {
+        assert!(Self::has_neon_backend(), "the NEON backend is unavailable");
+        #[allow(unsafe_code)]
+        unsafe {
+            (self.0).0 = crate::arch::neon::mul(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV)
+        };
         self.reduce();
     }
 }
@@ -160,6 +311,12 @@ impl<P: Fp256Parameters> One for Fp256<P> {
     }
 }
 
+impl<P: Fp256Parameters> Zeroize for Fp256<P> {
+    fn zeroize(&mut self) {
+        self.0.as_mut().zeroize();
+    }
+}
+
 impl<P: Fp256Parameters> Field for Fp256<P> {
     // 256/64 = 4 limbs.
     impl_field_from_random_bytes_with_flags!(4);
@@ -173,10 +330,10 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn double_in_place(&mut self) {
-        // This cannot exceed the backing capacity.
+        // This may only exceed the backing capacity if the modulus occupies all 256 bits.
+        let carry = (self.0).0[3] >> 63 == 1;
         self.0.mul2();
-        // However, it may need to be reduced.
-        self.reduce();
+        self.reduce_with_carry(carry);
     }
 
     #[inline]
@@ -193,6 +350,16 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
 
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
+        #[cfg(all(feature = "target-specific", target_arch = "x86"))]
+        if Self::has_avx2_backend() {
+            let other = *self;
+            #[allow(unsafe_code)]
+            unsafe {
+                self.mul_assign_avx2_unchecked(&other)
+            };
+            return self;
+        }
+
         let mut carry = 0;
         let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
         let r2 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[2], &mut carry);
@@ -252,8 +419,8 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if b.0.is_even() {
                         b.0.div2();
                     } else {
-                        b.0.add_nocarry(&P::MODULUS);
-                        b.0.div2();
+                        let carry = b.0.add_nocarry(&P::MODULUS);
+                        b.div2_with_carry(carry);
                     }
                 }
 
@@ -263,8 +430,8 @@ impl<P: Fp256Parameters> Field for Fp256<P> {
                     if c.0.is_even() {
                         c.0.div2();
                     } else {
-                        c.0.add_nocarry(&P::MODULUS);
-                        c.0.div2();
+                        let carry = c.0.add_nocarry(&P::MODULUS);
+                        c.div2_with_carry(carry);
                     }
                 }
 
@@ -573,11 +740,9 @@ impl<'a, P: Fp256Parameters> Div<&'a Fp256<P>> for Fp256<P> {
 impl<'a, P: Fp256Parameters> AddAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn add_assign(&mut self, other: &Self) {
-        // This cannot exceed the backing capacity.
-        self.0.add_nocarry(&other.0);
-        // However, it may need to be reduced
-
-        self.reduce();
+        // This may only exceed the backing capacity if the modulus occupies all 256 bits.
+        let carry = self.0.add_nocarry(&other.0);
+        self.reduce_with_carry(carry);
     }
 }
 
@@ -596,31 +761,26 @@ impl<'a, P: Fp256Parameters> SubAssign<&'a Self> for Fp256<P> {
 impl<'a, P: Fp256Parameters> MulAssign<&'a Self> for Fp256<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
-        let mut carry = 0;
-        let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
-        let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
-        let r2 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[2], &mut carry);
-        let r3 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[3], &mut carry);
-        let r4 = carry;
-        let mut carry = 0;
-        let r1 = fa::mac_with_carry(r1, (self.0).0[1], (other.0).0[0], &mut carry);
-        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (other.0).0[1], &mut carry);
-        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (other.0).0[2], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (other.0).0[3], &mut carry);
-        let r5 = carry;
-        let mut carry = 0;
-        let r2 = fa::mac_with_carry(r2, (self.0).0[2], (other.0).0[0], &mut carry);
-        let r3 = fa::mac_with_carry(r3, (self.0).0[2], (other.0).0[1], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (other.0).0[2], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (other.0).0[3], &mut carry);
-        let r6 = carry;
-        let mut carry = 0;
-        let r3 = fa::mac_with_carry(r3, (self.0).0[3], (other.0).0[0], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[3], (other.0).0[1], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[3], (other.0).0[2], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (other.0).0[3], &mut carry);
-        let r7 = carry;
-        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7);
+        #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+        if Self::has_bmi2_adx_backend() {
+            #[allow(unsafe_code)]
+            unsafe {
+                self.mul_assign_bmi2_adx_unchecked(other)
+            };
+            return;
+        }
+
+        // On x86_64, the vector backend is slower than the portable code, which multiplies 64-bit limbs.
+        #[cfg(all(feature = "target-specific", target_arch = "x86"))]
+        if Self::has_avx2_backend() {
+            #[allow(unsafe_code)]
+            unsafe {
+                self.mul_assign_avx2_unchecked(other)
+            };
+            return;
+        }
+
+        self.mul_assign_portable(other);
     }
 }
 
@@ -43,6 +43,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 pub trait Fp384Parameters: FieldParameters<BigInteger = BigInteger> {}
 
@@ -168,6 +169,162 @@ impl<P: Fp384Parameters> Fp384<P> {
         (self.0).0[5] = r11;
         self.reduce();
     }
+
+    /// Multiplies `self` by `other`, using the portable Montgomery multiplication.
+    #[inline]
+    pub fn mul_assign_portable(&mut self, other: &Self) {
+        let mut carry = 0;
+        let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
+        let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
+        let r2 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[2], &mut carry);
+        let r3 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[3], &mut carry);
+        let r4 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[4], &mut carry);
+        let r5 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[5], &mut carry);
+        let r6 = carry;
+        let mut carry = 0;
+        let r1 = fa::mac_with_carry(r1, (self.0).0[1], (other.0).0[0], &mut carry);
+        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (other.0).0[1], &mut carry);
+        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (other.0).0[2], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (other.0).0[3], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[1], (other.0).0[4], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[1], (other.0).0[5], &mut carry);
+        let r7 = carry;
+        let mut carry = 0;
+        let r2 = fa::mac_with_carry(r2, (self.0).0[2], (other.0).0[0], &mut carry);
+        let r3 = fa::mac_with_carry(r3, (self.0).0[2], (other.0).0[1], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (other.0).0[2], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (other.0).0[3], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[2], (other.0).0[4], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[2], (other.0).0[5], &mut carry);
+        let r8 = carry;
+        let mut carry = 0;
+        let r3 = fa::mac_with_carry(r3, (self.0).0[3], (other.0).0[0], &mut carry);
+        let r4 = fa::mac_with_carry(r4, (self.0).0[3], (other.0).0[1], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[3], (other.0).0[2], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (other.0).0[3], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[3], (other.0).0[4], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[3], (other.0).0[5], &mut carry);
+        let r9 = carry;
+        let mut carry = 0;
+        let r4 = fa::mac_with_carry(r4, (self.0).0[4], (other.0).0[0], &mut carry);
+        let r5 = fa::mac_with_carry(r5, (self.0).0[4], (other.0).0[1], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[4], (other.0).0[2], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[4], (other.0).0[3], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[4], (other.0).0[4], &mut carry);
+        let r9 = fa::mac_with_carry(r9, (self.0).0[4], (other.0).0[5], &mut carry);
+        let r10 = carry;
+        let mut carry = 0;
+        let r5 = fa::mac_with_carry(r5, (self.0).0[5], (other.0).0[0], &mut carry);
+        let r6 = fa::mac_with_carry(r6, (self.0).0[5], (other.0).0[1], &mut carry);
+        let r7 = fa::mac_with_carry(r7, (self.0).0[5], (other.0).0[2], &mut carry);
+        let r8 = fa::mac_with_carry(r8, (self.0).0[5], (other.0).0[3], &mut carry);
+        let r9 = fa::mac_with_carry(r9, (self.0).0[5], (other.0).0[4], &mut carry);
+        let r10 = fa::mac_with_carry(r10, (self.0).0[5], (other.0).0[5], &mut carry);
+        let r11 = carry;
+        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
+    }
+
+    /// Returns `true` if the BMI2 and ADX backend can be used for this field on this CPU.
+    #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+    #[inline]
+    pub fn has_bmi2_adx_backend() -> bool {
+        P::MODULUS_BITS < 384 && crate::arch::bmi2_adx::has_bmi2_adx()
+    }
+
+    /// Multiplies `self` by `other`, using `mulx`, `adcx` and `adox`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::has_bmi2_adx_backend()` does not hold.
+    #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+    #[inline]
+    pub fn mul_assign_bmi2_adx(&mut self, other: &Self) {
+        assert!(Self::has_bmi2_adx_backend(), "the BMI2 and ADX backend is unavailable");
+        #[allow(unsafe_code)]
+        unsafe {
+            self.mul_assign_bmi2_adx_unchecked(other)
+        };
+    }
+
+    /// Multiplies `self` by `other`, using `mulx`, `adcx` and `adox`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `Self::has_bmi2_adx_backend()` holds.
+    #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+    #[inline]
+    #[allow(unsafe_code)]
+    unsafe fn mul_assign_bmi2_adx_unchecked(&mut self, other: &Self) {
+        let modulus_and_inv = [
+            P::MODULUS.0[0],
+            P::MODULUS.0[1],
+            P::MODULUS.0[2],
+            P::MODULUS.0[3],
+            P::MODULUS.0[4],
+            P::MODULUS.0[5],
+            P::INV,
+        ];
+        (self.0).0 = crate::arch::bmi2_adx::mul_6(&(self.0).0, &(other.0).0, &modulus_and_inv);
+        self.reduce();
+    }
+
+    /// Returns `true` if the AVX2 backend can be used for this field on this CPU.
+    #[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[inline]
+    pub fn has_avx2_backend() -> bool {
+        P::MODULUS_BITS < 384 && crate::arch::avx2::has_avx2()
+    }
+
+    /// Multiplies `self` by `other`, using AVX2 vectors of 32-bit digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::has_avx2_backend()` does not hold.
+    #[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[inline]
+    pub fn mul_assign_avx2(&mut self, other: &Self) {
+        assert!(Self::has_avx2_backend(), "the AVX2 backend is unavailable");
+        #[allow(unsafe_code)]
+        unsafe {
+            self.mul_assign_avx2_unchecked(other)
+        };
+    }
+
+    /// Multiplies `self` by `other`, using AVX2 vectors of 32-bit digits.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `Self::has_avx2_backend()` holds.
+    #[cfg(all(feature = "target-specific", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[inline]
+    #[allow(unsafe_code)]
+    unsafe fn mul_assign_avx2_unchecked(&mut self, other: &Self) {
+        (self.0).0 = crate::arch::avx2::mul::<6, 3>(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV);
+        self.reduce();
+    }
+
+    /// Returns `true` if the NEON backend can be used for this field.
+    #[cfg(all(feature = "target-specific", target_arch = "aarch64", target_feature = "neon"))]
+    #[inline]
+    pub fn has_neon_backend() -> bool {
+        P::MODULUS_BITS < 384
+    }
+
+    /// Multiplies `self` by `other`, using NEON vectors of 32-bit digits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Self::has_neon_backend()` does not hold.
+    #[cfg(all(feature = "target-specific", target_arch = "aarch64", target_feature = "neon"))]
+    #[inline]
+    pub fn mul_assign_neon(&mut self, other: &Self) {
+        assert!(Self::has_neon_backend(), "the NEON backend is unavailable");
+        #[allow(unsafe_code)]
+        unsafe {
+            (self.0).0 = crate::arch::neon::mul(&(self.0).0, &(other.0).0, &P::MODULUS.0, P::INV)
+        };
+        self.reduce();
+    }
 }
 
 impl<P: Fp384Parameters> Zero for Fp384<P> {
@@ -194,6 +351,12 @@ impl<P: Fp384Parameters> One for Fp384<P> {
     }
 }
 
+impl<P: Fp384Parameters> Zeroize for Fp384<P> {
+    fn zeroize(&mut self) {
+        self.0.as_mut().zeroize();
+    }
+}
+
 impl<P: Fp384Parameters> Field for Fp384<P> {
     // 384/64 = 6 limbs.
     impl_field_from_random_bytes_with_flags!(6);
@@ -227,6 +390,16 @@ impl<P: Fp384Parameters> Field for Fp384<P> {
 
     #[inline]
     fn square_in_place(&mut self) -> &mut Self {
+        #[cfg(all(feature = "target-specific", target_arch = "x86"))]
+        if Self::has_avx2_backend() {
+            let other = *self;
+            #[allow(unsafe_code)]
+            unsafe {
+                self.mul_assign_avx2_unchecked(&other)
+            };
+            return self;
+        }
+
         let mut carry = 0;
         let r1 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[1], &mut carry);
         let r2 = fa::mac_with_carry(0, (self.0).0[0], (self.0).0[2], &mut carry);
@@ -655,55 +828,26 @@ impl<'a, P: Fp384Parameters> SubAssign<&'a Self> for Fp384<P> {
 impl<'a, P: Fp384Parameters> MulAssign<&'a Self> for Fp384<P> {
     #[inline]
     fn mul_assign(&mut self, other: &Self) {
-        let mut carry = 0;
-        let r0 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[0], &mut carry);
-        let r1 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[1], &mut carry);
-        let r2 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[2], &mut carry);
-        let r3 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[3], &mut carry);
-        let r4 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[4], &mut carry);
-        let r5 = fa::mac_with_carry(0, (self.0).0[0], (other.0).0[5], &mut carry);
-        let r6 = carry;
-        let mut carry = 0;
-        let r1 = fa::mac_with_carry(r1, (self.0).0[1], (other.0).0[0], &mut carry);
-        let r2 = fa::mac_with_carry(r2, (self.0).0[1], (other.0).0[1], &mut carry);
-        let r3 = fa::mac_with_carry(r3, (self.0).0[1], (other.0).0[2], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[1], (other.0).0[3], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[1], (other.0).0[4], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[1], (other.0).0[5], &mut carry);
-        let r7 = carry;
-        let mut carry = 0;
-        let r2 = fa::mac_with_carry(r2, (self.0).0[2], (other.0).0[0], &mut carry);
-        let r3 = fa::mac_with_carry(r3, (self.0).0[2], (other.0).0[1], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[2], (other.0).0[2], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[2], (other.0).0[3], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[2], (other.0).0[4], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[2], (other.0).0[5], &mut carry);
-        let r8 = carry;
-        let mut carry = 0;
-        let r3 = fa::mac_with_carry(r3, (self.0).0[3], (other.0).0[0], &mut carry);
-        let r4 = fa::mac_with_carry(r4, (self.0).0[3], (other.0).0[1], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[3], (other.0).0[2], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[3], (other.0).0[3], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[3], (other.0).0[4], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[3], (other.0).0[5], &mut carry);
-        let r9 = carry;
-        let mut carry = 0;
-        let r4 = fa::mac_with_carry(r4, (self.0).0[4], (other.0).0[0], &mut carry);
-        let r5 = fa::mac_with_carry(r5, (self.0).0[4], (other.0).0[1], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[4], (other.0).0[2], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[4], (other.0).0[3], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[4], (other.0).0[4], &mut carry);
-        let r9 = fa::mac_with_carry(r9, (self.0).0[4], (other.0).0[5], &mut carry);
-        let r10 = carry;
-        let mut carry = 0;
-        let r5 = fa::mac_with_carry(r5, (self.0).0[5], (other.0).0[0], &mut carry);
-        let r6 = fa::mac_with_carry(r6, (self.0).0[5], (other.0).0[1], &mut carry);
-        let r7 = fa::mac_with_carry(r7, (self.0).0[5], (other.0).0[2], &mut carry);
-        let r8 = fa::mac_with_carry(r8, (self.0).0[5], (other.0).0[3], &mut carry);
-        let r9 = fa::mac_with_carry(r9, (self.0).0[5], (other.0).0[4], &mut carry);
-        let r10 = fa::mac_with_carry(r10, (self.0).0[5], (other.0).0[5], &mut carry);
-        let r11 = carry;
-        self.mont_reduce(r0, r1, r2, r3, r4, r5, r6, r7, r8, r9, r10, r11);
+        #[cfg(all(feature = "target-specific", target_arch = "x86_64"))]
+        if Self::has_bmi2_adx_backend() {
+            #[allow(unsafe_code)]
+            unsafe {
+                self.mul_assign_bmi2_adx_unchecked(other)
+            };
+            return;
+        }
+
+        // On x86_64, the vector backend is slower than the portable code, which multiplies 64-bit limbs.
+        #[cfg(all(feature = "target-specific", target_arch = "x86"))]
+        if Self::has_avx2_backend() {
+            #[allow(unsafe_code)]
+            unsafe {
+                self.mul_assign_avx2_unchecked(other)
+            };
+            return;
+        }
+
+        self.mul_assign_portable(other);
     }
 }
 
@@ -43,6 +43,7 @@ use std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
     str::FromStr,
 };
+use zeroize::Zeroize;
 
 pub trait Fp768Parameters: FieldParameters<BigInteger = BigInteger> {}
 
@@ -339,6 +340,12 @@ impl<P: Fp768Parameters> One for Fp768<P> {
     }
 }
 
+impl<P: Fp768Parameters> Zeroize for Fp768<P> {
+    fn zeroize(&mut self) {
+        self.0.as_mut().zeroize();
+    }
+}
+
 impl<P: Fp768Parameters> Field for Fp768<P> {
     // 768/64 = 12 limbs.
     impl_field_from_random_bytes_with_flags!(12);
@@ -15,7 +15,8 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 #![allow(clippy::module_inception)]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "target-specific"), forbid(unsafe_code))]
+#![cfg_attr(feature = "target-specific", deny(unsafe_code))]
 
 #[macro_use]
 extern crate derivative;
@@ -26,6 +27,9 @@ extern crate thiserror;
 #[macro_use]
 mod macros;
 
+#[cfg(feature = "target-specific")]
+mod arch;
+
 pub mod errors;
 pub use errors::*;
 
@@ -77,6 +81,18 @@ impl_field_into_biginteger!(Fp256, BigInteger256, Fp256Parameters);
 impl_field_into_biginteger!(Fp384, BigInteger384, Fp384Parameters);
 impl_field_into_biginteger!(Fp768, BigInteger768, Fp768Parameters);
 
+impl_primefield_montgomery_limbs!(Fp256, BigInteger256, Fp256Parameters, 4);
+impl_primefield_montgomery_limbs!(Fp384, BigInteger384, Fp384Parameters, 6);
+impl_primefield_montgomery_limbs!(Fp768, BigInteger768, Fp768Parameters, 12);
+
+impl_primefield_conditional_select!(Fp256, Fp256Parameters);
+impl_primefield_conditional_select!(Fp384, Fp384Parameters);
+impl_primefield_conditional_select!(Fp768, Fp768Parameters);
+
+impl_primefield_rand!(Fp256, Fp256Parameters, 4);
+impl_primefield_rand!(Fp384, Fp384Parameters, 6);
+impl_primefield_rand!(Fp768, Fp768Parameters, 12);
+
 impl_primefield_serializer!(Fp256, Fp256Parameters, 32);
 impl_primefield_serializer!(Fp384, Fp384Parameters, 48);
 impl_primefield_serializer!(Fp768, Fp768Parameters, 96);
@@ -45,9 +45,86 @@ macro_rules! impl_field_into_biginteger {
     };
 }
 
+/// Implements access to the Montgomery form of a prime field element.
+///
+/// An element `a` is stored in Montgomery form as `a * R mod MODULUS`, where `R = 2^(64 * $num_limbs)`.
+/// This matches the internal representation used by arkworks for the same moduli.
+macro_rules! impl_primefield_montgomery_limbs {
+    ($field: ident, $biginteger: ident, $parameters: ident, $num_limbs: expr) => {
+        impl<P: $parameters> $field<P> {
+            /// The modulus of the field, as little-endian limbs.
+            pub const MODULUS_LIMBS: [u64; $num_limbs] = P::MODULUS.0;
+            /// The Montgomery constant `R^2 mod MODULUS`, as little-endian limbs.
+            pub const R2: [u64; $num_limbs] = P::R2.0;
+
+            /// Returns the little-endian limbs of `self` in Montgomery form.
+            #[inline]
+            pub fn to_montgomery_limbs(&self) -> [u64; $num_limbs] {
+                (self.0).0
+            }
+
+            /// Returns a field element from little-endian limbs in Montgomery form,
+            /// or `None` if the limbs are not less than the modulus.
+            #[inline]
+            pub fn from_montgomery_limbs_checked(limbs: [u64; $num_limbs]) -> Option<Self> {
+                let limbs = $biginteger::new(limbs);
+                match limbs < P::MODULUS {
+                    true => Some(Self(limbs, std::marker::PhantomData)),
+                    false => None,
+                }
+            }
+
+            /// Returns a field element from little-endian limbs in Montgomery form, without checking them.
+            /// The caller must ensure the limbs are less than the modulus, as the arithmetic on
+            /// an unreduced element is not guaranteed to be correct.
+            #[inline]
+            pub fn from_montgomery_limbs_unchecked(limbs: [u64; $num_limbs]) -> Self {
+                Self($biginteger::new(limbs), std::marker::PhantomData)
+            }
+        }
+    };
+}
+
+/// Implements the samplers of a prime field element.
+///
+/// `rand_uniform` is exactly uniform, and is the sampler of `UniformRand::rand`.
+/// `rand_reduced` is statistically close to uniform, and consumes a fixed number of random bytes.
+macro_rules! impl_primefield_rand {
+    ($field: ident, $parameters: ident, $num_limbs: expr) => {
+        impl<P: $parameters> $field<P> {
+            /// The number of random bytes consumed by `Self::rand_reduced`.
+            pub const RAND_REDUCED_BYTES: usize = 2 * $num_limbs * 8;
+
+            /// Samples a field element by rejection sampling, which is exactly uniform.
+            /// The number of random bytes consumed varies with the number of rejected samples.
+            /// This is the sampler of `UniformRand::rand`.
+            #[inline]
+            pub fn rand_uniform<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                rng.sample(rand::distributions::Standard)
+            }
+
+            /// Samples a field element by reducing twice as many random limbs as the modulus has,
+            /// which is statistically close to uniform.
+            /// Consumes exactly `Self::RAND_REDUCED_BYTES` random bytes, as little-endian `u64` limbs.
+            pub fn rand_reduced<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+                // Accumulate the limbs from the least significant one, each scaled by a power of 2^64.
+                let shift = Self::from(1u128 << 64);
+                let mut power = Self::one();
+                let mut element = Self::zero();
+                for _ in 0..2 * $num_limbs {
+                    element += power * Self::from(rng.next_u64());
+                    power *= shift;
+                }
+                element
+            }
+        }
+    };
+}
+
 macro_rules! impl_primefield_standard_sample {
     ($field: ident, $params: ident) => {
         impl<P: $params> rand::distributions::Distribution<$field<P>> for rand::distributions::Standard {
+            /// Samples a field element by rejection sampling, which is exactly uniform.
             #[inline]
             fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> $field<P> {
                 loop {
@@ -340,6 +417,43 @@ macro_rules! impl_field_from_random_bytes_with_flags {
     };
 }
 
+/// Implements the constant-time selection and negation of a prime field element, on its Montgomery limbs.
+macro_rules! impl_primefield_conditional_select {
+    ($field: ident, $params: ident) => {
+        impl<P: $params> ConditionallySelectable for $field<P> {
+            #[inline]
+            fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+                // The mask is all ones if `choice` is set, and all zeros otherwise.
+                let mask = (choice as u64).wrapping_neg();
+
+                let mut result = *a;
+                for (limb, (a, b)) in (result.0).0.iter_mut().zip((a.0).0.iter().zip((b.0).0.iter())) {
+                    *limb = a ^ (mask & (a ^ b));
+                }
+                result
+            }
+        }
+
+        impl<P: $params> ConditionallyNegatable for $field<P> {
+            #[inline]
+            fn conditional_negate_in_place(&mut self, choice: bool) {
+                // Compute `modulus - self`, which is the modulus itself if `self` is zero.
+                let mut negated = P::MODULUS;
+                negated.sub_noborrow(&self.0);
+
+                // The mask is all ones if `self` is nonzero, and all zeros otherwise, so zero negates to zero.
+                let nonzero = (self.0).0.iter().fold(0u64, |acc, limb| acc | limb);
+                let nonzero_mask = ((nonzero | nonzero.wrapping_neg()) >> 63).wrapping_neg();
+                let mask = (choice as u64).wrapping_neg() & nonzero_mask;
+
+                for (limb, negated) in (self.0).0.iter_mut().zip(negated.0.iter()) {
+                    *limb ^= mask & (*limb ^ negated);
+                }
+            }
+        }
+    };
+}
+
 /// Implements Add, Sub, AddAssign, and SubAssign on Self by deferring to an implementation on &Self
 #[macro_export]
 macro_rules! impl_add_sub_from_field_ref {
@@ -15,7 +15,6 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use crate::{ConstraintFieldError, Field, FieldParameters, Fp2, Fp2Parameters, PrimeField, ToConstraintField};
-use snarkvm_utilities::FromBits;
 
 impl<F: PrimeField> ToConstraintField<F> for F {
     fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
@@ -61,7 +60,7 @@ impl<F: PrimeField> ToConstraintField<F> for [bool] {
     fn to_field_elements(&self) -> Result<Vec<F>, ConstraintFieldError> {
         Ok(self
             .chunks(<F as PrimeField>::Parameters::CAPACITY as usize)
-            .map(|chunk| F::from_repr(F::BigInteger::from_bits_le(chunk)).unwrap())
+            .map(|chunk| F::from_bits_le_checked(chunk).unwrap())
             .collect::<Vec<F>>())
     }
 }
@@ -0,0 +1,28 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+/// A type whose values can be selected between without branching on the choice.
+pub trait ConditionallySelectable: Sized {
+    /// Returns `a` if `choice` is `false`, and `b` if `choice` is `true`,
+    /// without branching on `choice`.
+    fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self;
+}
+
+/// A type whose values can be negated without branching on the choice.
+pub trait ConditionallyNegatable {
+    /// Negates `self` if `choice` is `true`, without branching on `choice` or on `self`.
+    fn conditional_negate_in_place(&mut self, choice: bool);
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+mod conditionally_selectable;
+pub use conditionally_selectable::*;
+
 mod fft_field;
 pub use fft_field::*;
 
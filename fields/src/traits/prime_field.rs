@@ -34,6 +34,18 @@ pub trait PrimeField: FftField<FftParameters = <Self as PrimeField>::Parameters>
     /// Returns the underlying raw representation of the prime field element.
     fn to_repr_unchecked(&self) -> Self::BigInteger;
 
+    /// Returns a prime field element from a slice of bits in little-endian order,
+    /// or `None` if the bits do not fit in the representation or are not less than the modulus.
+    fn from_bits_le_checked(bits: &[bool]) -> Option<Self> {
+        Self::from_repr(Self::BigInteger::from_bits_le_checked(bits)?)
+    }
+
+    /// Returns a prime field element from a slice of bits in big-endian order,
+    /// or `None` if the bits do not fit in the representation or are not less than the modulus.
+    fn from_bits_be_checked(bits: &[bool]) -> Option<Self> {
+        Self::from_repr(Self::BigInteger::from_bits_be_checked(bits)?)
+    }
+
     /// Returns the field size in bits.
     fn size_in_bits() -> usize {
         Self::Parameters::MODULUS_BITS as usize
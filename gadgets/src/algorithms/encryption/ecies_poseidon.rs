@@ -48,6 +48,7 @@ use snarkvm_utilities::{borrow::Borrow, to_bytes_le, ToBytes};
 use anyhow::{anyhow, Result};
 use itertools::Itertools;
 use std::{marker::PhantomData, sync::Arc};
+use zeroize::Zeroize;
 
 type TEAffineGadget<TE, F> = crate::curves::templates::twisted_edwards::AffineGadget<TE, F, FpGadget<F>>;
 
@@ -558,8 +559,10 @@ fn symmetric_encryption<F: PoseidonDefaultParametersField>(
     Ok(ciphertext)
 }
 
-impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
+impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField + Zeroize>
     EncryptionGadget<ECIESPoseidonEncryption<TE>, F> for ECIESPoseidonEncryptionGadget<TE, F>
+where
+    TE::ScalarField: Zeroize,
 {
     type CiphertextRandomizer = ECIESPoseidonCiphertextRandomizerGadget<TE, F>;
     type PrivateKeyGadget = ECIESPoseidonEncryptionPrivateKeyGadget<TE, F>;
@@ -0,0 +1,223 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use itertools::Itertools;
+use std::borrow::Borrow;
+
+use snarkvm_algorithms::{merkle_tree::KaryMerklePath, traits::MerkleParameters};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::{boolean::Boolean, ToBytesGadget},
+    traits::{algorithms::CRHGadget, alloc::AllocGadget, eq::ConditionalEqGadget, select::CondSelectGadget},
+    EqGadget,
+};
+
+/// A Merkle path in a tree in which each inner node has `ARITY` children.
+pub struct KaryMerklePathGadget<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField, const ARITY: u8> {
+    /// `positions[i][j]` is true iff the `i`th node from bottom to top is at most the `j`th child of its parent,
+    /// for `j < ARITY - 1`. The booleans of each node are monotone, so they encode exactly one position.
+    positions: Vec<Vec<Boolean>>,
+    /// `siblings[i]` are the `ARITY - 1` siblings of the `i`th node from bottom to top, from left to right.
+    siblings: Vec<Vec<HG::OutputGadget>>,
+}
+
+impl<P: MerkleParameters, HG: CRHGadget<P::H, F>, F: PrimeField, const ARITY: u8>
+    KaryMerklePathGadget<P, HG, F, ARITY>
+{
+    pub fn calculate_root<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        leaf: impl ToBytesGadget<F>,
+    ) -> Result<HG::OutputGadget, SynthesisError> {
+        let leaf_bytes = leaf.to_bytes(&mut cs.ns(|| "leaf_to_bytes"))?;
+        let mut curr_hash = crh.check_evaluation_gadget(cs.ns(|| "leaf_hash"), leaf_bytes)?;
+
+        // To traverse up the tree, we iterate over the path from bottom to top.
+        //
+        // If the current node is at or after the `j`th child, the `j`th child is either the current node or
+        // the `j`th sibling. Otherwise, the current node is before the `j`th child, which is the `j - 1`th sibling.
+        let arity = ARITY as usize;
+        for (i, (positions, siblings)) in self.positions.iter().zip_eq(self.siblings.iter()).enumerate() {
+            let mut children = Vec::with_capacity(arity);
+            for j in 0..arity {
+                let child = match j + 1 < arity {
+                    true => HG::OutputGadget::conditionally_select(
+                        cs.ns(|| format!("cond_select_current_{}_{}", i, j)),
+                        &positions[j],
+                        &curr_hash,
+                        &siblings[j],
+                    )?,
+                    false => curr_hash.clone(),
+                };
+                let child = match j > 0 {
+                    true => HG::OutputGadget::conditionally_select(
+                        cs.ns(|| format!("cond_select_child_{}_{}", i, j)),
+                        &positions[j - 1],
+                        &siblings[j - 1],
+                        &child,
+                    )?,
+                    false => child,
+                };
+                children.push(child);
+            }
+
+            curr_hash = hash_children_gadget::<P, HG, F, _>(cs.ns(|| format!("hash_children_{}", i)), crh, &children)?;
+        }
+
+        Ok(curr_hash)
+    }
+
+    /// Returns `true` if the path is a valid membership proof of `leaf` for the given root.
+    pub fn verify<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        leaf: impl ToBytesGadget<F>,
+    ) -> Result<Boolean, SynthesisError> {
+        let expected_root = self.calculate_root(cs.ns(|| "calculate_root"), crh, leaf)?;
+
+        root.is_eq(cs.ns(|| "root_is_eq"), &expected_root)
+    }
+
+    pub fn check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        leaf: impl ToBytesGadget<F>,
+    ) -> Result<(), SynthesisError> {
+        self.conditionally_check_membership(cs, crh, root, leaf, &Boolean::Constant(true))
+    }
+
+    pub fn conditionally_check_membership<CS: ConstraintSystem<F>>(
+        &self,
+        mut cs: CS,
+        crh: &HG,
+        root: &HG::OutputGadget,
+        leaf: impl ToBytesGadget<F>,
+        should_enforce: &Boolean,
+    ) -> Result<(), SynthesisError> {
+        let expected_root = self.calculate_root(cs.ns(|| "calculate_root"), crh, leaf)?;
+
+        root.conditional_enforce_equal(&mut cs.ns(|| "root_is_eq"), &expected_root, should_enforce)
+    }
+
+    fn alloc_path<CS: ConstraintSystem<F>>(
+        mut cs: CS,
+        merkle_path: &KaryMerklePath<P, ARITY>,
+        is_input: bool,
+    ) -> Result<Self, SynthesisError> {
+        if ARITY < 2
+            || merkle_path
+                .siblings
+                .iter()
+                .any(|siblings| siblings.len() != ARITY as usize - 1)
+        {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut positions = Vec::with_capacity(merkle_path.siblings.len());
+        for (i, position) in merkle_path.position_list().enumerate() {
+            let mut is_at_most = Vec::with_capacity(ARITY as usize);
+            for j in 0..ARITY - 1 {
+                let mut cs = cs.ns(|| format!("alloc_position_{}_{}", i, j));
+                is_at_most.push(match is_input {
+                    true => Boolean::alloc_input(cs.ns(|| "alloc_input"), || Ok(position <= j))?,
+                    false => Boolean::alloc(cs.ns(|| "alloc"), || Ok(position <= j))?,
+                });
+            }
+            is_at_most.push(Boolean::Constant(true));
+
+            // Ensure the node is at most the `j + 1`th child if it is at most the `j`th child.
+            for (j, (current, next)) in is_at_most.iter().tuple_windows().enumerate() {
+                let is_decreasing = Boolean::and(
+                    cs.ns(|| format!("position_{}_{}_is_decreasing", i, j)),
+                    current,
+                    &next.not(),
+                )?;
+                is_decreasing.enforce_equal(
+                    cs.ns(|| format!("position_{}_{}_is_monotone", i, j)),
+                    &Boolean::Constant(false),
+                )?;
+            }
+            is_at_most.pop();
+            positions.push(is_at_most);
+        }
+
+        let mut siblings = Vec::with_capacity(merkle_path.siblings.len());
+        for (i, level) in merkle_path.siblings.iter().enumerate() {
+            let mut level_siblings = Vec::with_capacity(level.len());
+            for (j, node) in level.iter().enumerate() {
+                let mut cs = cs.ns(|| format!("alloc_node_{}_{}", i, j));
+                level_siblings.push(match is_input {
+                    true => HG::OutputGadget::alloc_input(cs.ns(|| "alloc_input"), || Ok(*node))?,
+                    false => HG::OutputGadget::alloc(cs.ns(|| "alloc"), || Ok(*node))?,
+                });
+            }
+            siblings.push(level_siblings);
+        }
+
+        Ok(KaryMerklePathGadget { positions, siblings })
+    }
+}
+
+/// Returns the hash of the given children of an inner node, from left to right.
+pub(crate) fn hash_children_gadget<P, HG, F, CS>(
+    mut cs: CS,
+    crh: &HG,
+    children: &[HG::OutputGadget],
+) -> Result<HG::OutputGadget, SynthesisError>
+where
+    P: MerkleParameters,
+    HG: CRHGadget<P::H, F>,
+    F: PrimeField,
+    CS: ConstraintSystem<F>,
+{
+    let mut bytes = Vec::new();
+    for (i, child) in children.iter().enumerate() {
+        bytes.extend(child.to_bytes(&mut cs.ns(|| format!("child_{}_to_bytes", i)))?);
+    }
+
+    crh.check_evaluation_gadget(cs, bytes)
+}
+
+impl<P, HGadget, F, const ARITY: u8> AllocGadget<KaryMerklePath<P, ARITY>, F>
+    for KaryMerklePathGadget<P, HGadget, F, ARITY>
+where
+    P: MerkleParameters,
+    HGadget: CRHGadget<P::H, F>,
+    F: PrimeField,
+{
+    fn alloc<Fn, T, CS: ConstraintSystem<F>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<KaryMerklePath<P, ARITY>>,
+    {
+        Self::alloc_path(cs, value_gen()?.borrow(), false)
+    }
+
+    fn alloc_input<Fn, T, CS: ConstraintSystem<F>>(cs: CS, value_gen: Fn) -> Result<Self, SynthesisError>
+    where
+        Fn: FnOnce() -> Result<T, SynthesisError>,
+        T: Borrow<KaryMerklePath<P, ARITY>>,
+    {
+        Self::alloc_path(cs, value_gen()?.borrow(), true)
+    }
+}
@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod kary_merkle_path;
+pub use kary_merkle_path::*;
+
 pub mod masked_tree;
 pub use masked_tree::*;
 
@@ -21,7 +21,7 @@ use rand::{thread_rng, Rng};
 
 use snarkvm_algorithms::{
     crh::{PedersenCRH, PedersenCompressedCRH, BHPCRH},
-    merkle_tree::{MaskedMerkleTreeParameters, MerkleTree},
+    merkle_tree::{KaryMerkleTree, MaskedMerkleTreeParameters, MerkleTree, MerkleTreeParameters},
     traits::{MaskedMerkleParameters, MerkleParameters, CRH},
 };
 use snarkvm_curves::{bls12_377::Fr, edwards_bls12::EdwardsProjective};
@@ -237,6 +237,52 @@ fn update_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>
     assert!(satisfied);
 }
 
+fn generate_kary_merkle_tree<P: MerkleParameters, F: PrimeField, HG: CRHGadget<P::H, F>, const ARITY: u8>(
+    leaves: &[[u8; 30]],
+) {
+    let parameters = P::setup("merkle_tree_test");
+    let tree = KaryMerkleTree::<P, ARITY>::new(Arc::new(parameters.clone()), leaves).unwrap();
+    let root = tree.root();
+    for (i, leaf) in leaves.iter().enumerate() {
+        let proof = tree.generate_proof(i, &leaf).unwrap();
+        assert!(proof.verify(root, &leaf).unwrap());
+
+        // Swap the first two distinct siblings on the path, which moves the on-path node for binary trees.
+        let mut swapped_proof = proof.clone();
+        let (level, siblings) = swapped_proof
+            .siblings
+            .iter_mut()
+            .enumerate()
+            .find(|(_, siblings)| ARITY == 2 || siblings[0] != siblings[1])
+            .unwrap();
+        match ARITY == 2 {
+            true => swapped_proof.leaf_index ^= 1 << level,
+            false => siblings.swap(0, 1),
+        }
+        assert!(!swapped_proof.verify(root, &leaf).unwrap());
+
+        for (proof, is_valid) in [(proof, true), (swapped_proof, false)] {
+            let mut cs = TestConstraintSystem::<F>::new();
+
+            let root = HG::OutputGadget::alloc(&mut cs.ns(|| "root"), || Ok(*root)).unwrap();
+            let crh = HG::alloc_constant(&mut cs.ns(|| "parameters"), || Ok(parameters.crh().clone())).unwrap();
+            let leaf = UInt8::constant_vec(leaf);
+            let path = KaryMerklePathGadget::<P, HG, F, ARITY>::alloc(&mut cs.ns(|| "path"), || Ok(proof)).unwrap();
+
+            // Ensure the in-circuit verification matches the native verification.
+            let candidate = path
+                .verify(&mut cs.ns(|| "verify"), &crh, &root, leaf.as_slice())
+                .unwrap();
+            assert_eq!(Some(is_valid), candidate.get_value());
+            assert!(cs.is_satisfied());
+
+            path.check_membership(&mut cs.ns(|| "check_membership"), &crh, &root, leaf.as_slice())
+                .unwrap();
+            assert_eq!(is_valid, cs.is_satisfied());
+        }
+    }
+}
+
 mod merkle_tree_pedersen_crh_on_projective {
     use super::*;
 
@@ -467,3 +513,59 @@ mod merkle_tree_poseidon {
         update_merkle_tree::<EdwardsMerkleParameters, Fr, HG>(&leaves);
     }
 }
+
+mod kary_merkle_tree_poseidon {
+    use super::*;
+    use crate::algorithms::crh::PoseidonCRHGadget;
+    use snarkvm_algorithms::crh::PoseidonCRH;
+
+    type H = PoseidonCRH<Fr, 5>;
+    type HG = PoseidonCRHGadget<Fr, 5>;
+
+    fn run_test<const DEPTH: usize, const ARITY: u8>(num_leaves: usize) {
+        let mut rng = thread_rng();
+        let mut leaves = Vec::new();
+
+        for _ in 0..num_leaves {
+            let mut input = [0u8; 30];
+            rng.fill(&mut input);
+            leaves.push(input);
+        }
+        generate_kary_merkle_tree::<MerkleTreeParameters<H, DEPTH>, Fr, HG, ARITY>(&leaves);
+    }
+
+    #[test]
+    fn binary_merkle_tree_test() {
+        run_test::<3, 2>(5);
+    }
+
+    #[test]
+    fn ternary_merkle_tree_test() {
+        run_test::<2, 3>(7);
+    }
+
+    #[test]
+    fn quaternary_merkle_tree_test() {
+        run_test::<2, 4>(6);
+    }
+}
+
+mod kary_merkle_tree_bowe_hopwood_pedersen_crh_on_projective {
+    use super::*;
+
+    type H = BHPCRH<EdwardsProjective, BHP_NUM_WINDOWS, BHP_WINDOW_SIZE>;
+    type HG = BHPCRHGadget<EdwardsProjective, Fr, EdwardsBls12Gadget, BHP_NUM_WINDOWS, BHP_WINDOW_SIZE>;
+
+    #[test]
+    fn ternary_merkle_tree_test() {
+        let mut rng = thread_rng();
+        let mut leaves = Vec::new();
+
+        for _ in 0..4 {
+            let mut input = [0u8; 30];
+            rng.fill(&mut input);
+            leaves.push(input);
+        }
+        generate_kary_merkle_tree::<MerkleTreeParameters<H, 2>, Fr, HG, 3>(&leaves);
+    }
+}
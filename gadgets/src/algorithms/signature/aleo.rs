@@ -44,6 +44,7 @@ use snarkvm_utilities::{FromBytes, ToBytes};
 use anyhow::Result;
 use itertools::Itertools;
 use std::{borrow::Borrow, marker::PhantomData};
+use zeroize::Zeroize;
 
 type TEAffineGadget<TE, F> = crate::curves::templates::twisted_edwards::AffineGadget<TE, F, FpGadget<F>>;
 
@@ -402,6 +403,8 @@ impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaul
 
 impl<TE: TwistedEdwardsParameters<BaseField = F>, F: PrimeField + PoseidonDefaultParametersField>
     SignatureGadget<AleoSignatureScheme<TE>, F> for AleoSignatureSchemeGadget<TE, F>
+where
+    TE::ScalarField: Zeroize,
 {
     type ComputeKeyGadget = AleoComputeKeyGadget;
     type PublicKeyGadget = AleoSignaturePublicKeyGadget<TE, F>;
@@ -0,0 +1,71 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::Boolean,
+    curves::bls12_377::{Fq12Gadget, G1Gadget, G2Gadget, PairingGadget},
+    traits::{alloc::AllocGadget, curves::PairingGadget as _, eq::EqGadget, fields::FieldGadget},
+};
+use snarkvm_curves::{
+    bls12_377::{Fq, G1Affine},
+    AffineCurve,
+};
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+/// A gadget that verifies BLS signatures over BLS12-377, with public keys in G1 and signatures in G2,
+/// in a circuit over the base field of BLS12-377.
+///
+/// The message is hashed to G2 outside of the circuit with `BLSSignature::hash_message`,
+/// and the public key and signature are assumed to be in the prime order subgroup.
+pub struct BLSVerificationGadget;
+
+impl BLSVerificationGadget {
+    /// Returns `true` if the signature on the hashed message is valid under the public key,
+    /// as in `BLSPublicKey::verify`.
+    ///
+    /// Checks that the public key and signature are not zero, and that the product of
+    /// `e(public_key, message_hash)` and `e(-g1, signature)` is one.
+    pub fn check_verification<CS: ConstraintSystem<Fq>>(
+        mut cs: CS,
+        public_key: &G1Gadget,
+        message_hash: &G2Gadget,
+        signature: &G2Gadget,
+    ) -> Result<Boolean, SynthesisError> {
+        let generator = G1Gadget::alloc_constant(cs.ns(|| "negated generator"), || {
+            Ok((-G1Affine::prime_subgroup_generator()).into_projective())
+        })?;
+
+        let public_key_prepared = PairingGadget::prepare_g1(cs.ns(|| "prepare public key"), public_key.clone())?;
+        let message_hash_prepared = PairingGadget::prepare_g2(cs.ns(|| "prepare message hash"), message_hash.clone())?;
+        let generator_prepared = PairingGadget::prepare_g1(cs.ns(|| "prepare generator"), generator)?;
+        let signature_prepared = PairingGadget::prepare_g2(cs.ns(|| "prepare signature"), signature.clone())?;
+
+        let product = PairingGadget::product_of_pairings(
+            cs.ns(|| "product of pairings"),
+            &[public_key_prepared, generator_prepared],
+            &[message_hash_prepared, signature_prepared],
+        )?;
+        let one = Fq12Gadget::one(cs.ns(|| "one"))?;
+        let is_one = product.is_eq(cs.ns(|| "product is one"), &one)?;
+
+        let is_nonzero = Boolean::and(
+            cs.ns(|| "public key and signature are nonzero"),
+            &public_key.infinity.not(),
+            &signature.infinity.not(),
+        )?;
+        Boolean::and(cs.ns(|| "is valid"), &is_nonzero, &is_one)
+    }
+}
@@ -17,5 +17,8 @@
 pub mod aleo;
 pub use aleo::*;
 
+pub mod bls;
+pub use bls::*;
+
 #[cfg(test)]
 pub mod tests;
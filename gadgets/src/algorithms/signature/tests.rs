@@ -152,3 +152,66 @@ mod aleo {
         assert!(cs.is_satisfied());
     }
 }
+
+mod bls {
+    use crate::{
+        algorithms::signature::BLSVerificationGadget,
+        curves::bls12_377::{G1Gadget, G2Gadget},
+        traits::alloc::AllocGadget,
+    };
+    use snarkvm_algorithms::signature::{BLSPrivateKey, BLSPublicKey, BLSSignature};
+    use snarkvm_curves::{
+        bls12_377::Fq,
+        AffineCurve,
+    };
+    use snarkvm_r1cs::{ConstraintSystem, TestConstraintSystem};
+
+    use rand::SeedableRng;
+    use rand_chacha::ChaChaRng;
+
+    /// Checks that the gadget agrees with `BLSPublicKey::verify` on the given inputs.
+    fn check_verification(public_key: &BLSPublicKey, message: &[u8], signature: &BLSSignature) {
+        let expected = public_key.verify(message, signature);
+
+        let mut cs = TestConstraintSystem::<Fq>::new();
+        let public_key_gadget =
+            G1Gadget::alloc(cs.ns(|| "public key"), || Ok(public_key.to_affine().into_projective())).unwrap();
+        let message_hash_gadget = G2Gadget::alloc_input(cs.ns(|| "message hash"), || {
+            Ok(BLSSignature::hash_message(message).into_projective())
+        })
+        .unwrap();
+        let signature_gadget =
+            G2Gadget::alloc(cs.ns(|| "signature"), || Ok(signature.to_affine().into_projective())).unwrap();
+
+        let is_valid = BLSVerificationGadget::check_verification(
+            cs.ns(|| "verify"),
+            &public_key_gadget,
+            &message_hash_gadget,
+            &signature_gadget,
+        )
+        .unwrap();
+
+        assert_eq!(Some(expected), is_valid.get_value());
+        assert!(cs.is_satisfied());
+    }
+
+    #[test]
+    fn test_bls_signature_verification() {
+        let message = "Hi, I am a BLS signature!".as_bytes();
+        let rng = &mut ChaChaRng::seed_from_u64(1231275789u64);
+
+        let private_key = BLSPrivateKey::new(rng);
+        let public_key = private_key.to_public_key();
+        let signature = private_key.sign(message);
+        assert!(public_key.verify(message, &signature));
+
+        // A valid signature.
+        check_verification(&public_key, message, &signature);
+        // A signature on another message.
+        check_verification(&public_key, "Hi, I am another message!".as_bytes(), &signature);
+        // A signature under another public key.
+        check_verification(&BLSPrivateKey::new(rng).to_public_key(), message, &signature);
+        // A signature by another private key.
+        check_verification(&public_key, message, &BLSPrivateKey::new(rng).sign(message));
+    }
+}
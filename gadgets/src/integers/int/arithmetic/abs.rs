@@ -0,0 +1,93 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+    bits::Boolean,
+    errors::SignedIntegerError,
+    integers::int::*,
+    traits::{
+        eq::EqGadget,
+        integers::{Abs, Integer, Neg},
+        select::CondSelectGadget,
+    },
+};
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::ConstraintSystem;
+
+macro_rules! abs_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl<F: PrimeField> Abs<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn abs_checked<CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS
+            ) -> Result<Self, Self::ErrorType> {
+                // If `self` = MIN, reject as this causes an overflow.
+                let value = match self.value {
+                    Some(val) => match val.checked_abs() {
+                        Some(val_abs) => Some(val_abs),
+                        None => return Err(SignedIntegerError::Overflow),
+                    },
+                    None => None,
+                };
+
+                let result = self.abs_wrapped(cs.ns(|| "abs_wrapped"))?;
+
+                // The wrapped absolute value is only negative for MIN.
+                result.bits.last().unwrap().enforce_equal(cs.ns(|| "result_cannot_be_negative"), &Boolean::constant(false))?;
+
+                Ok(Self {
+                    bits: result.bits,
+                    value,
+                })
+            }
+
+            fn abs_wrapped<CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS
+            ) -> Result<Self, Self::ErrorType> {
+                let value = self.value.map(<$gadget as Integer>::IntegerType::wrapping_abs);
+
+                // If `self` is a constant, return the constant result instead of generating constraints.
+                if self.is_constant() {
+                    return Ok(Self::constant(value.unwrap()));
+                }
+
+                // Select the two's complement of `self` if it is negative. MIN wraps to itself.
+                let is_negative = self.bits.last().unwrap();
+                let negated_bits = self.bits.neg(cs.ns(|| "neg_self_bits"))?;
+
+                let mut bits = Vec::with_capacity(self.bits.len());
+                for i in 0..self.bits.len() {
+                    bits.push(Boolean::conditionally_select(
+                        cs.ns(|| format!("select_the_absolute_value_bit_{}", i)),
+                        is_negative,
+                        &negated_bits[i],
+                        &self.bits[i],
+                    )?);
+                }
+
+                Ok(Self {
+                    bits,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+abs_int_impl!(Int8 Int16 Int32 Int64 Int128);
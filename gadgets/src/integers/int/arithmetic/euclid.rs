@@ -0,0 +1,150 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::PrimeField;
+use snarkvm_r1cs::{errors::SynthesisError, ConstraintSystem};
+
+use crate::{
+    bits::Boolean,
+    errors::SignedIntegerError,
+    integers::int::*,
+    traits::{
+        bits::RippleCarryAdder,
+        integers::{Abs, Div, Euclid, Integer, Mul, Neg},
+    },
+};
+
+/// Returns the sum of the little-endian `a` and `b` bits, wrapping around at the boundary of the type.
+fn add_bits_wrapped<F: PrimeField, CS: ConstraintSystem<F>>(
+    cs: CS,
+    a: &Vec<Boolean>,
+    b: &Vec<Boolean>,
+) -> Result<Vec<Boolean>, SynthesisError> {
+    let mut bits = a.add_bits(cs, b)?;
+    let _carry = bits.pop();
+
+    Ok(bits)
+}
+
+macro_rules! euclid_int_impl {
+    ($($gadget: ident)*) => ($(
+        impl $gadget {
+            /// Returns the truncated quotient and remainder of `self` by `other`.
+            fn div_rem_truncated<F: PrimeField, CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self
+            ) -> Result<(Self, Vec<Boolean>), SignedIntegerError> {
+                let quotient = self.div(cs.ns(|| "div"), other)?;
+
+                // The remainder `self - quotient * other` always fits, but the intermediate product
+                // may be MIN, so it is negated and added with wrapping arithmetic.
+                let product = quotient.mul_unsafe(cs.ns(|| "mul_quotient"), other)?;
+                let negated_product_bits = product.bits.neg(cs.ns(|| "neg_product_bits"))?;
+                let remainder_bits = add_bits_wrapped(cs.ns(|| "sub_product"), &self.bits, &negated_product_bits)?;
+
+                Ok((quotient, remainder_bits))
+            }
+        }
+
+        impl<F: PrimeField> Euclid<F> for $gadget {
+            type ErrorType = SignedIntegerError;
+
+            fn div_euclid<CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self
+            ) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => match a.checked_div_euclid(b) {
+                        Some(quotient) => Some(quotient),
+                        None if b == 0 => return Err(SignedIntegerError::DivisionByZero),
+                        None => return Err(SignedIntegerError::Overflow),
+                    },
+                    _ => None,
+                };
+
+                // If `self` and `other` are both constants, return the constant result instead of generating constraints.
+                if self.is_constant() && other.is_constant() {
+                    return Ok(Self::constant(value.unwrap()));
+                }
+
+                let (quotient, remainder_bits) = self.div_rem_truncated(cs.ns(|| "div_rem_truncated"), other)?;
+
+                // A negative remainder moves the quotient one step away from zero:
+                // down by one for a positive divisor, and up by one for a negative divisor.
+                let is_remainder_negative = remainder_bits.last().unwrap();
+                let is_other_negative = other.bits.last().unwrap();
+                let is_decrement = Boolean::and(cs.ns(|| "is_decrement"), is_remainder_negative, &is_other_negative.not())?;
+
+                let mut adjustment_bits = vec![*is_remainder_negative];
+                adjustment_bits.extend(vec![is_decrement; quotient.bits.len() - 1]);
+
+                let bits = add_bits_wrapped(cs.ns(|| "adjust_quotient"), &quotient.bits, &adjustment_bits)?;
+
+                Ok(Self {
+                    bits,
+                    value,
+                })
+            }
+
+            fn rem_euclid<CS: ConstraintSystem<F>>(
+                &self,
+                mut cs: CS,
+                other: &Self
+            ) -> Result<Self, Self::ErrorType> {
+                let value = match (self.value, other.value) {
+                    (Some(a), Some(b)) => match a.checked_rem_euclid(b) {
+                        Some(remainder) => Some(remainder),
+                        None if b == 0 => return Err(SignedIntegerError::DivisionByZero),
+                        None => return Err(SignedIntegerError::Overflow),
+                    },
+                    _ => None,
+                };
+
+                // If `self` and `other` are both constants, return the constant result instead of generating constraints.
+                if self.is_constant() && other.is_constant() {
+                    return Ok(Self::constant(value.unwrap()));
+                }
+
+                let (_quotient, remainder_bits) = self.div_rem_truncated(cs.ns(|| "div_rem_truncated"), other)?;
+
+                // A negative remainder is shifted into range by adding `|other|`.
+                // For `other` = MIN the wrapped absolute value is MIN, which still yields the correct bits.
+                let is_remainder_negative = remainder_bits.last().unwrap();
+                let other_absolute = other.abs_wrapped(cs.ns(|| "abs_wrapped_other"))?;
+
+                let mut adjustment_bits = Vec::with_capacity(other_absolute.bits.len());
+                for (i, bit) in other_absolute.bits.iter().enumerate() {
+                    adjustment_bits.push(Boolean::and(
+                        cs.ns(|| format!("select_the_adjustment_bit_{}", i)),
+                        is_remainder_negative,
+                        bit,
+                    )?);
+                }
+
+                let bits = add_bits_wrapped(cs.ns(|| "adjust_remainder"), &remainder_bits, &adjustment_bits)?;
+
+                Ok(Self {
+                    bits,
+                    value,
+                })
+            }
+        }
+    )*)
+}
+
+euclid_int_impl!(Int8 Int16 Int32 Int64 Int128);
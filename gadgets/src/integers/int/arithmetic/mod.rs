@@ -21,12 +21,16 @@ pub use add::*;
 pub mod neg;
 pub use neg::*;
 
+mod abs;
+
 pub mod sub;
 pub use sub::*;
 
 pub mod div;
 pub use div::*;
 
+mod euclid;
+
 pub mod mul;
 pub use mul::*;
 
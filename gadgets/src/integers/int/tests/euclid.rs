@@ -0,0 +1,156 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use rand::{Rng, SeedableRng};
+use rand_xorshift::XorShiftRng;
+
+use snarkvm_r1cs::{ConstraintSystem, Fr, TestConstraintChecker, TestConstraintSystem};
+
+use crate::{
+    bits::Boolean,
+    errors::SignedIntegerError,
+    integers::int::*,
+    traits::{alloc::AllocGadget, integers::*},
+};
+
+macro_rules! euclid_tests {
+    ($($module:ident: $gadget:ident, $integer:ident, $cs:ident, $iterations:expr;)*) => ($(
+        mod $module {
+            use super::*;
+
+            fn check_all_bits(expected: $integer, actual: $gadget, is_constant: bool) {
+                assert_eq!(Some(expected), actual.value);
+                for (i, bit) in actual.bits.iter().enumerate() {
+                    assert_eq!(is_constant, matches!(bit, Boolean::Constant(_)));
+                    assert_eq!(Some((expected >> i) & 1 == 1), bit.get_value());
+                }
+            }
+
+            #[test]
+            fn test_div_euclid_and_rem_euclid_constants() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                for _ in 0..1000 {
+                    let mut cs = $cs::<Fr>::new();
+
+                    let a: $integer = rng.gen();
+                    let b: $integer = rng.gen();
+
+                    let a_bit = $gadget::constant(a);
+                    let b_bit = $gadget::constant(b);
+
+                    match a.checked_div_euclid(b) {
+                        Some(expected) => {
+                            let r = a_bit.div_euclid(cs.ns(|| "div_euclid"), &b_bit).unwrap();
+                            check_all_bits(expected, r, true);
+                        }
+                        None => assert!(a_bit.div_euclid(cs.ns(|| "div_euclid"), &b_bit).is_err()),
+                    }
+
+                    match a.checked_rem_euclid(b) {
+                        Some(expected) => {
+                            let r = a_bit.rem_euclid(cs.ns(|| "rem_euclid"), &b_bit).unwrap();
+                            check_all_bits(expected, r, true);
+                        }
+                        None => assert!(a_bit.rem_euclid(cs.ns(|| "rem_euclid"), &b_bit).is_err()),
+                    }
+                }
+            }
+
+            #[test]
+            fn test_div_euclid_and_rem_euclid() {
+                let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+                // Negative dividends, negative divisors, and MIN operands, followed by random cases.
+                let mut cases: Vec<($integer, $integer)> = vec![
+                    (-7, 3),
+                    (7, -3),
+                    (-7, -3),
+                    (-6, 3),
+                    ($integer::MIN, 1),
+                    ($integer::MIN, 3),
+                    ($integer::MIN, -3),
+                    ($integer::MIN, $integer::MIN),
+                    (-1, $integer::MIN),
+                    ($integer::MAX, $integer::MIN),
+                ];
+                while cases.len() < 10 + $iterations {
+                    let (a, b): ($integer, $integer) = (rng.gen(), rng.gen());
+                    // Skip the division by zero and the overflow, which are checked separately.
+                    if b != 0 && !(a == $integer::MIN && b == -1) {
+                        cases.push((a, b));
+                    }
+                }
+
+                for (a, b) in cases {
+                    let mut cs = $cs::<Fr>::new();
+
+                    let a_bit = $gadget::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+                    let b_bit = $gadget::alloc(cs.ns(|| "b_bit"), || Ok(b)).unwrap();
+
+                    let quotient = a_bit.div_euclid(cs.ns(|| "div_euclid"), &b_bit).unwrap();
+                    let remainder = a_bit.rem_euclid(cs.ns(|| "rem_euclid"), &b_bit).unwrap();
+
+                    assert!(cs.is_satisfied());
+                    check_all_bits(a.div_euclid(b), quotient, false);
+                    check_all_bits(a.rem_euclid(b), remainder, false);
+                }
+            }
+
+            #[test]
+            fn test_div_euclid_and_rem_euclid_by_zero() {
+                let mut cs = $cs::<Fr>::new();
+
+                let a_bit = $gadget::alloc(cs.ns(|| "a_bit"), || Ok(7)).unwrap();
+                let zero = $gadget::alloc(cs.ns(|| "zero"), || Ok(0)).unwrap();
+
+                assert!(matches!(
+                    a_bit.div_euclid(cs.ns(|| "div_euclid"), &zero),
+                    Err(SignedIntegerError::DivisionByZero)
+                ));
+                assert!(matches!(
+                    a_bit.rem_euclid(cs.ns(|| "rem_euclid"), &zero),
+                    Err(SignedIntegerError::DivisionByZero)
+                ));
+            }
+
+            #[test]
+            fn test_div_euclid_and_rem_euclid_overflow() {
+                let mut cs = $cs::<Fr>::new();
+
+                let min = $gadget::alloc(cs.ns(|| "min"), || Ok($integer::MIN)).unwrap();
+                let minus_one = $gadget::alloc(cs.ns(|| "minus_one"), || Ok(-1)).unwrap();
+
+                assert!(matches!(
+                    min.div_euclid(cs.ns(|| "div_euclid"), &minus_one),
+                    Err(SignedIntegerError::Overflow)
+                ));
+                assert!(matches!(
+                    min.rem_euclid(cs.ns(|| "rem_euclid"), &minus_one),
+                    Err(SignedIntegerError::Overflow)
+                ));
+            }
+        }
+    )*)
+}
+
+euclid_tests! {
+    int8: Int8, i8, TestConstraintSystem, 100;
+    int16: Int16, i16, TestConstraintSystem, 10;
+    int32: Int32, i32, TestConstraintSystem, 10;
+    int64: Int64, i64, TestConstraintSystem, 3;
+    int128: Int128, i128, TestConstraintChecker, 2;
+}
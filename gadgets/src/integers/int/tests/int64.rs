@@ -233,6 +233,62 @@ fn test_int64_sub() {
     }
 }
 
+#[test]
+fn test_int64_abs_constants() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    for _ in 0..1000 {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a: i64 = rng.gen();
+
+        let a_bit = Int64::constant(a);
+
+        let r = a_bit.abs_wrapped(cs.ns(|| "abs_wrapped")).unwrap();
+        assert!(r.value == Some(a.wrapping_abs()));
+        check_all_constant_bits(a.wrapping_abs(), r);
+
+        match a.checked_abs() {
+            Some(expected) => {
+                let r = a_bit.abs_checked(cs.ns(|| "abs_checked")).unwrap();
+                assert!(r.value == Some(expected));
+                check_all_constant_bits(expected, r);
+            }
+            None => assert!(a_bit.abs_checked(cs.ns(|| "abs_checked")).is_err()),
+        }
+    }
+}
+
+#[test]
+fn test_int64_abs() {
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+
+    let mut values: Vec<i64> = vec![i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX];
+    values.extend((0..100).map(|_| rng.gen::<i64>()));
+
+    for a in values {
+        let mut cs = TestConstraintSystem::<Fr>::new();
+
+        let a_bit = Int64::alloc(cs.ns(|| "a_bit"), || Ok(a)).unwrap();
+
+        let r = a_bit.abs_wrapped(cs.ns(|| "abs_wrapped")).unwrap();
+        assert!(cs.is_satisfied());
+        assert!(r.value == Some(a.wrapping_abs()));
+        check_all_allocated_bits(a.wrapping_abs(), r);
+
+        match a.checked_abs() {
+            Some(expected) => {
+                let r = a_bit.abs_checked(cs.ns(|| "abs_checked")).unwrap();
+                assert!(cs.is_satisfied());
+                assert!(r.value == Some(expected));
+                check_all_allocated_bits(expected, r);
+            }
+            // The absolute value of MIN overflows.
+            None => assert!(a_bit.abs_checked(cs.ns(|| "abs_checked")).is_err()),
+        }
+    }
+}
+
 #[test]
 fn test_int64_mul_constants() {
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -0,0 +1,32 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use snarkvm_fields::Field;
+use snarkvm_r1cs::ConstraintSystem;
+
+/// Returns the Euclidean division and remainder of `self` by `other` in the constraint system.
+pub trait Euclid<F: Field, Rhs = Self>
+where
+    Self: std::marker::Sized,
+{
+    type ErrorType;
+
+    /// Returns the quotient `q` such that `self = q * other + r` with `0 <= r < |other|`.
+    fn div_euclid<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType>;
+
+    /// Returns the least non-negative remainder of `self` modulo `other`.
+    fn rem_euclid<CS: ConstraintSystem<F>>(&self, cs: CS, other: &Self) -> Result<Self, Self::ErrorType>;
+}
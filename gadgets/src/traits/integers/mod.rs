@@ -14,12 +14,18 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
+pub mod abs;
+pub use abs::*;
+
 pub mod add;
 pub use add::*;
 
 pub mod div;
 pub use div::*;
 
+pub mod euclid;
+pub use euclid::*;
+
 pub mod integer;
 pub use integer::*;
 
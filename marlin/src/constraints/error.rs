@@ -62,6 +62,12 @@ impl From<MarlinError> for MarlinConstraintsError {
             MarlinError::Terminated => Self {
                 error_msg: "terminated".to_string(),
             },
+            MarlinError::UnsupportedProofVersion(version) => Self {
+                error_msg: format!("unsupported proof format version {}", version),
+            },
+            MarlinError::SerializationError(err) => Self {
+                error_msg: err.to_string(),
+            },
         }
     }
 }
@@ -28,7 +28,7 @@ use snarkvm_gadgets::{
     },
     overhead,
 };
-use snarkvm_utilities::{FromBits, ToBits};
+use snarkvm_utilities::ToBits;
 
 use rand_core::{Error, RngCore};
 
@@ -101,7 +101,7 @@ where
         }
         let elements = bits
             .chunks(capacity)
-            .map(|bits| BaseField::from_repr(BaseField::BigInteger::from_bits_be(bits)).unwrap())
+            .map(|bits| BaseField::from_bits_be_checked(bits).unwrap())
             .collect::<Vec<BaseField>>();
 
         self.s.absorb(&elements);
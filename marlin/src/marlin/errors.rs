@@ -15,6 +15,7 @@
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
 use snarkvm_algorithms::SNARKError;
+use snarkvm_utilities::errors::SerializationError;
 
 use core::fmt::Debug;
 
@@ -34,6 +35,10 @@ pub enum MarlinError {
     /// There was an error in the underlying polynomial commitment.
     PolynomialCommitmentError(snarkvm_polycommit::Error),
     Terminated,
+    /// The proof was serialized in a format version newer than this library supports.
+    UnsupportedProofVersion(u8),
+    /// There was an error in (de)serialization.
+    SerializationError(SerializationError),
 }
 
 impl From<AHPError> for MarlinError {
@@ -63,6 +68,12 @@ impl From<snarkvm_polycommit::Error> for MarlinError {
     }
 }
 
+impl From<SerializationError> for MarlinError {
+    fn from(err: SerializationError) -> Self {
+        MarlinError::SerializationError(err)
+    }
+}
+
 impl From<MarlinError> for SNARKError {
     fn from(error: MarlinError) -> Self {
         match error {
@@ -17,7 +17,16 @@
 use crate::{
     ahp::{AHPError, AHPForR1CS, EvaluationsProvider},
     fiat_shamir::traits::FiatShamirRng,
-    marlin::{CircuitProvingKey, CircuitVerifyingKey, MarlinError, MarlinMode, Proof, UniversalSRS},
+    marlin::{
+        CircuitProvingKey,
+        CircuitVerifyingKey,
+        MarlinError,
+        MarlinMode,
+        Proof,
+        ProverCallback,
+        ProvingStage,
+        UniversalSRS,
+    },
     prover::ProverConstraintSystem,
     String,
     ToString,
@@ -44,6 +53,7 @@ use snarkvm_utilities::println;
 use crate::marlin::PreparedCircuitVerifyingKey;
 use core::{
     marker::PhantomData,
+    ops::ControlFlow,
     sync::atomic::{AtomicBool, Ordering},
 };
 use rand_core::RngCore;
@@ -267,12 +277,54 @@ impl<
         terminator: &AtomicBool,
         zk_rng: &mut R,
     ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
-        let prover_time = start_timer!(|| "Marlin::Prover");
-        // TODO: Add check that c is in the correct mode.
+        Self::prove_with_terminator_and_callback(circuit_proving_key, circuit, terminator, &(), zk_rng)
+    }
+
+    /// Same as [`prove`] with an added progress hook, `callback`, invoked at each prover stage.
+    pub fn prove_with_callback<C: ConstraintSynthesizer<TargetField>, R: RngCore, CB: ProverCallback>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuit: &C,
+        callback: &CB,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        Self::prove_with_terminator_and_callback(
+            circuit_proving_key,
+            circuit,
+            &AtomicBool::new(false),
+            callback,
+            zk_rng,
+        )
+    }
 
+    /// Returns an error if the prover should stop upon reaching the given stage.
+    fn check_progress<CB: ProverCallback>(
+        stage: ProvingStage,
+        terminator: &AtomicBool,
+        callback: &CB,
+    ) -> Result<(), MarlinError> {
         if terminator.load(Ordering::Relaxed) {
             return Err(MarlinError::Terminated);
         }
+        match callback.on_progress(stage, stage.fraction()) {
+            ControlFlow::Continue(()) => Ok(()),
+            ControlFlow::Break(()) => Err(MarlinError::Terminated),
+        }
+    }
+
+    /// Creates a zkSNARK, checking the termination flag and progress hook at each prover stage.
+    fn prove_with_terminator_and_callback<C: ConstraintSynthesizer<TargetField>, R: RngCore, CB: ProverCallback>(
+        circuit_proving_key: &CircuitProvingKey<TargetField, BaseField, PC, MM>,
+        circuit: &C,
+        terminator: &AtomicBool,
+        callback: &CB,
+        zk_rng: &mut R,
+    ) -> Result<Proof<TargetField, BaseField, PC>, MarlinError> {
+        let prover_time = start_timer!(|| "Marlin::Prover");
+        #[cfg(feature = "metrics")]
+        let prover_start = std::time::Instant::now();
+        // TODO: Add check that c is in the correct mode.
+
+        Self::check_progress(ProvingStage::Init, terminator, callback)?;
 
         let prover_init_state = AHPForR1CS::<_, MM>::prover_init(&circuit_proving_key.circuit, circuit)?;
         let public_input = prover_init_state.public_input();
@@ -298,9 +350,7 @@ impl<
         // --------------------------------------------------------------------
         // First round
 
-        if terminator.load(Ordering::Relaxed) {
-            return Err(MarlinError::Terminated);
-        }
+        Self::check_progress(ProvingStage::FirstRound, terminator, callback)?;
 
         let (prover_first_message, prover_first_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_first_round(prover_init_state, zk_rng)?;
@@ -339,9 +389,7 @@ impl<
         // --------------------------------------------------------------------
         // Second round
 
-        if terminator.load(Ordering::Relaxed) {
-            return Err(MarlinError::Terminated);
-        }
+        Self::check_progress(ProvingStage::SecondRound, terminator, callback)?;
 
         let (prover_second_message, prover_second_oracles, prover_state) =
             AHPForR1CS::<_, MM>::prover_second_round(&verifier_first_message, prover_state, zk_rng);
@@ -375,9 +423,7 @@ impl<
         // --------------------------------------------------------------------
         // Third round
 
-        if terminator.load(Ordering::Relaxed) {
-            return Err(MarlinError::Terminated);
-        }
+        Self::check_progress(ProvingStage::ThirdRound, terminator, callback)?;
 
         let (prover_third_message, prover_third_oracles) =
             AHPForR1CS::<_, MM>::prover_third_round(&verifier_second_msg, prover_state, zk_rng)?;
@@ -491,9 +537,7 @@ impl<
         let (query_set, verifier_state) = AHPForR1CS::<_, MM>::verifier_query_set(verifier_state, &mut fs_rng);
         let lc_s = AHPForR1CS::<_, MM>::construct_linear_combinations(&public_input, &polynomials, &verifier_state)?;
 
-        if terminator.load(Ordering::Relaxed) {
-            return Err(MarlinError::Terminated);
-        }
+        Self::check_progress(ProvingStage::Evaluation, terminator, callback)?;
 
         let eval_time = start_timer!(|| "Evaluating linear combinations over query set");
         let mut evaluations_unsorted = Vec::new();
@@ -512,9 +556,7 @@ impl<
         let evaluations = evaluations_unsorted.iter().map(|x| x.1).collect::<Vec<TargetField>>();
         end_timer!(eval_time);
 
-        if terminator.load(Ordering::Relaxed) {
-            return Err(MarlinError::Terminated);
-        }
+        Self::check_progress(ProvingStage::Opening, terminator, callback)?;
 
         if MM::RECURSION {
             fs_rng.absorb_nonnative_field_elements(&evaluations, OptimizationType::Weight);
@@ -554,10 +596,6 @@ impl<
             )?
         };
 
-        if terminator.load(Ordering::Relaxed) {
-            return Err(MarlinError::Terminated);
-        }
-
         // Gather prover messages together.
         let prover_messages = vec![prover_first_message, prover_second_message, prover_third_message];
 
@@ -566,6 +604,16 @@ impl<
         proof.print_size_info();
         end_timer!(prover_time);
 
+        // The proof is complete, so it is returned even if the callback asks to stop here.
+        let _ = callback.on_progress(ProvingStage::Finished, ProvingStage::Finished.fraction());
+
+        #[cfg(feature = "metrics")]
+        crate::marlin::telemetry::record_prove(
+            prover_start.elapsed(),
+            circuit_proving_key.circuit.index_info.num_constraints,
+            proof.to_bytes_le().map(|bytes| bytes.len()).unwrap_or_default(),
+        );
+
         Ok(proof)
     }
 
@@ -588,6 +636,8 @@ impl<
         proof: &Proof<TargetField, BaseField, PC>,
     ) -> Result<bool, MarlinError> {
         let verifier_time = start_timer!(|| "Marlin::Verify");
+        #[cfg(feature = "metrics")]
+        let (verifier_start, num_public_inputs) = (std::time::Instant::now(), public_input.len());
         let first_commitments = &proof.commitments[0];
         let second_commitments = &proof.commitments[1];
         let third_commitments = &proof.commitments[2];
@@ -602,6 +652,8 @@ impl<
                 first_commitments.len(),
                 proof.pc_proof.is_hiding()
             );
+            #[cfg(feature = "metrics")]
+            crate::marlin::telemetry::record_verify(verifier_start.elapsed(), num_public_inputs);
             return Ok(false);
         }
 
@@ -781,6 +833,8 @@ impl<
             " PC::Check for AHP Verifier linear equations: {}",
             evaluations_are_correct & proof_has_correct_zk_mode
         ));
+        #[cfg(feature = "metrics")]
+        crate::marlin::telemetry::record_verify(verifier_start.elapsed(), num_public_inputs);
         Ok(evaluations_are_correct & proof_has_correct_zk_mode)
     }
 
@@ -42,6 +42,14 @@ pub use prepared_circuit_verifying_key::*;
 mod proof;
 pub use proof::*;
 
+/// The Marlin prover progress hooks.
+mod prover_callback;
+pub use prover_callback::*;
+
+/// The Marlin prover and verifier metrics.
+#[cfg(feature = "metrics")]
+pub mod telemetry;
+
 #[cfg(test)]
 pub mod tests;
 
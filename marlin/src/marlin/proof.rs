@@ -14,7 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{ahp::prover::ProverMessage, Vec};
+use crate::{
+    ahp::{prover::ProverMessage, AHPForR1CS},
+    marlin::{CircuitVerifyingKey, MarlinError, MarlinMode},
+    Vec,
+};
 use snarkvm_fields::PrimeField;
 use snarkvm_polycommit::{BatchLCProof, PCCommitment, PolynomialCommitment};
 use snarkvm_utilities::{error, errors::SerializationError, serialize::*, FromBytes, ToBytes};
@@ -35,6 +39,9 @@ pub struct Proof<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>>
 }
 
 impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> Proof<F, CF, PC> {
+    /// The version of the byte layout written by `Proof::to_bytes_versioned`.
+    pub const FORMAT_VERSION: u8 = 1;
+
     /// Construct a new proof.
     pub fn new(
         commitments: Vec<Vec<PC::Commitment>>,
@@ -50,6 +57,100 @@ impl<F: PrimeField, CF: PrimeField, PC: PolynomialCommitment<F, CF>> Proof<F, CF
         }
     }
 
+    /// Returns the commitments sent by the prover, grouped by round.
+    pub fn commitments(&self) -> &[Vec<PC::Commitment>] {
+        &self.commitments
+    }
+
+    /// Returns the evaluations of the queried linear combinations, sorted by label,
+    /// excluding those that are known to evaluate to zero.
+    pub fn evaluations(&self) -> &[F] {
+        &self.evaluations
+    }
+
+    /// Returns the field elements sent by the prover, one message per round.
+    pub fn prover_messages(&self) -> &[ProverMessage<F>] {
+        &self.prover_messages
+    }
+
+    /// Returns the evaluation proof from the polynomial commitment.
+    pub fn pc_proof(&self) -> &BatchLCProof<F, CF, PC> {
+        &self.pc_proof
+    }
+
+    /// Returns the size in bytes of `self.to_bytes_le()` for any proof under the given verifying key.
+    /// The versioned encoding is one byte longer.
+    pub fn serialized_size_hint<MM: MarlinMode>(vk: &CircuitVerifyingKey<F, CF, PC, MM>) -> usize {
+        // Every vector is prefixed with its length.
+        let length_size = Vec::<F>::new().serialized_size();
+
+        let commitments_size = length_size
+            + [
+                AHPForR1CS::<F, MM>::prover_first_round_degree_bounds(&vk.circuit_info).collect::<Vec<_>>(),
+                AHPForR1CS::<F, MM>::prover_second_round_degree_bounds(&vk.circuit_info).collect(),
+                AHPForR1CS::<F, MM>::prover_third_round_degree_bounds(&vk.circuit_info).collect(),
+            ]
+            .iter()
+            .map(|bounds| {
+                length_size
+                    + bounds
+                        .iter()
+                        .map(|bound| PC::commitment_serialized_size(bound.is_some()))
+                        .sum::<usize>()
+            })
+            .sum::<usize>();
+
+        // The query set holds 6 linear combinations, plus a vanishing polynomial per point when recursing.
+        let num_evaluations = if MM::RECURSION { 9 } else { 6 } - AHPForR1CS::<F, MM>::LC_WITH_ZERO_EVAL.len();
+        let evaluations_size = length_size + num_evaluations * F::zero().serialized_size();
+
+        let prover_messages_size = length_size + 3 * ProverMessage::<F>::default().serialized_size();
+
+        // There is one opening proof per query point (`alpha` is only queried when recursing).
+        // Only the opening at `beta` involves hiding polynomials.
+        let num_non_hiding_proofs = if MM::RECURSION { 2 } else { 1 };
+        let pc_proof_size = length_size
+            + PC::proof_serialized_size(MM::ZK)
+            + num_non_hiding_proofs * PC::proof_serialized_size(false)
+            + None::<Vec<F>>.serialized_size();
+
+        commitments_size + evaluations_size + prover_messages_size + pc_proof_size
+    }
+
+    /// Returns the proof in the versioned byte layout.
+    ///
+    /// The layout (version 1) consists of, in order:
+    /// - the format version, as a single byte;
+    /// - the commitments, as a `u64` number of rounds followed by, for each round,
+    ///   a `u64` number of commitments and the compressed commitments;
+    /// - the evaluations, as a `u64` count followed by the field elements;
+    /// - the prover messages, as a `u64` count followed by, for each message,
+    ///   a `u64` count and the field elements;
+    /// - the evaluation proof, as a `u64` number of opening proofs followed by the
+    ///   compressed opening proofs, and a one-byte flag for the (always absent) evaluations.
+    ///
+    /// All integers and field elements are little-endian.
+    pub fn to_bytes_versioned(&self) -> Result<Vec<u8>, SerializationError> {
+        let mut bytes = Vec::with_capacity(1 + self.serialized_size());
+        Self::FORMAT_VERSION.serialize(&mut bytes)?;
+        self.serialize(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Returns the proof from the versioned byte layout of `Proof::to_bytes_versioned`.
+    /// Fails with `MarlinError::UnsupportedProofVersion` if the proof was written by a newer format version.
+    pub fn from_bytes_versioned(mut bytes: &[u8]) -> Result<Self, MarlinError> {
+        let version = u8::deserialize(&mut bytes)?;
+        if version == 0 || version > Self::FORMAT_VERSION {
+            return Err(MarlinError::UnsupportedProofVersion(version));
+        }
+        let proof = Self::deserialize(&mut bytes)?;
+        match bytes.is_empty() {
+            true => Ok(proof),
+            false => Err(SerializationError::InvalidData.into()),
+        }
+    }
+
     /// Prints information about the size of the proof.
     pub fn print_size_info(&self) {
         let size_of_fe_in_bytes = F::zero().to_repr().as_ref().len() * 8;
@@ -0,0 +1,94 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use core::{
+    ops::ControlFlow,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+/// The stages of the Marlin prover, in the order they are reached.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProvingStage {
+    /// Synthesizing the circuit and initializing the prover state.
+    Init,
+    /// Computing and committing to the first round oracles.
+    FirstRound,
+    /// Computing and committing to the second round oracles.
+    SecondRound,
+    /// Computing and committing to the third round oracles.
+    ThirdRound,
+    /// Evaluating the linear combinations over the query set.
+    Evaluation,
+    /// Opening the polynomial commitments.
+    Opening,
+    /// The proof is complete.
+    Finished,
+}
+
+impl ProvingStage {
+    /// Returns the fraction of the prover stages completed upon reaching this stage.
+    pub fn fraction(&self) -> f32 {
+        *self as u8 as f32 / ProvingStage::Finished as u8 as f32
+    }
+}
+
+/// A hook invoked by the Marlin prover at each stage boundary,
+/// allowing the caller to report progress, yield, or abort.
+pub trait ProverCallback {
+    /// Called when the prover reaches the given stage, with the fraction of stages completed.
+    /// Returning `ControlFlow::Break` aborts the proof with `MarlinError::Terminated`,
+    /// except at `ProvingStage::Finished`, where the completed proof is still returned.
+    fn on_progress(&self, stage: ProvingStage, fraction: f32) -> ControlFlow<()>;
+}
+
+impl ProverCallback for () {
+    fn on_progress(&self, _stage: ProvingStage, _fraction: f32) -> ControlFlow<()> {
+        ControlFlow::Continue(())
+    }
+}
+
+impl ProverCallback for AtomicBool {
+    /// Aborts the proof once the flag is set.
+    fn on_progress(&self, _stage: ProvingStage, _fraction: f32) -> ControlFlow<()> {
+        match self.load(Ordering::Relaxed) {
+            true => ControlFlow::Break(()),
+            false => ControlFlow::Continue(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stage_fraction() {
+        assert_eq!(0.0, ProvingStage::Init.fraction());
+        assert_eq!(1.0, ProvingStage::Finished.fraction());
+        assert!(ProvingStage::FirstRound.fraction() < ProvingStage::Opening.fraction());
+    }
+
+    #[test]
+    fn test_terminator_callback() {
+        let terminator = AtomicBool::new(false);
+        assert_eq!(
+            ControlFlow::Continue(()),
+            terminator.on_progress(ProvingStage::Init, 0.0)
+        );
+        terminator.store(true, Ordering::Relaxed);
+        assert_eq!(ControlFlow::Break(()), terminator.on_progress(ProvingStage::Init, 0.0));
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+//! The metrics emitted by the Marlin prover and verifier through the `metrics` facade.
+//! These names are stable; dashboards may rely on them.
+
+use std::time::Duration;
+
+/// Counter: the number of proofs created.
+pub const PROVE_TOTAL: &str = "snarkvm_marlin_prove_total";
+/// Histogram: the time spent creating a proof, in seconds.
+pub const PROVE_DURATION_SECONDS: &str = "snarkvm_marlin_prove_duration_seconds";
+/// Histogram: the number of constraints in the proven circuit.
+pub const PROVE_CONSTRAINTS: &str = "snarkvm_marlin_prove_constraints";
+/// Histogram: the size of each created proof, in bytes.
+pub const PROOF_BYTES: &str = "snarkvm_marlin_proof_bytes";
+/// Counter: the number of proofs checked to completion, whether or not they were valid.
+pub const VERIFY_TOTAL: &str = "snarkvm_marlin_verify_total";
+/// Histogram: the time spent verifying a proof, in seconds.
+pub const VERIFY_DURATION_SECONDS: &str = "snarkvm_marlin_verify_duration_seconds";
+/// Histogram: the number of public inputs given to the verifier.
+pub const VERIFY_PUBLIC_INPUTS: &str = "snarkvm_marlin_verify_public_inputs";
+
+/// Records the metrics for a created proof.
+pub(crate) fn record_prove(duration: Duration, num_constraints: usize, proof_bytes: usize) {
+    metrics::increment_counter!(PROVE_TOTAL);
+    metrics::histogram!(PROVE_DURATION_SECONDS, duration.as_secs_f64());
+    metrics::histogram!(PROVE_CONSTRAINTS, num_constraints as f64);
+    metrics::histogram!(PROOF_BYTES, proof_bytes as f64);
+}
+
+/// Records the metrics for a verified proof.
+pub(crate) fn record_verify(duration: Duration, num_public_inputs: usize) {
+    metrics::increment_counter!(VERIFY_TOTAL);
+    metrics::histogram!(VERIFY_DURATION_SECONDS, duration.as_secs_f64());
+    metrics::histogram!(VERIFY_PUBLIC_INPUTS, num_public_inputs as f64);
+}
@@ -73,14 +73,21 @@ mod marlin {
     use super::*;
     use crate::{
         fiat_shamir::FiatShamirChaChaRng,
-        marlin::{MarlinPoswMode, MarlinSNARK, MarlinTestnet1Mode},
+        marlin::{MarlinError, MarlinPoswMode, MarlinSNARK, MarlinTestnet1Mode, Proof, ProverCallback, ProvingStage},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_polycommit::{marlin_pc::MarlinKZG10, sonic_pc::SonicKZG10};
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        ToBytes,
+    };
 
-    use blake2::Blake2s;
-    use core::ops::MulAssign;
+    use blake2::{Blake2s, Digest};
+    use core::{
+        cell::RefCell,
+        ops::{ControlFlow, MulAssign},
+    };
+    use rand::{rngs::StdRng, SeedableRng};
 
     type MultiPC = MarlinKZG10<Bls12_377>;
     type MarlinInst = MarlinSNARK<Fr, Fq, MultiPC, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinTestnet1Mode>;
@@ -91,7 +98,7 @@ mod marlin {
     type MarlinSonicPoswInst = MarlinSNARK<Fr, Fq, MultiPCSonic, FiatShamirChaChaRng<Fr, Fq, Blake2s>, MarlinPoswMode>;
 
     macro_rules! impl_marlin_test {
-        ($test_struct: ident, $marlin_inst: tt, $marlin_mode: tt) => {
+        ($test_struct: ident, $marlin_inst: tt, $pc: tt, $marlin_mode: tt) => {
             struct $test_struct {}
             impl $test_struct {
                 pub(crate) fn test_circuit(num_constraints: usize, num_variables: usize) {
@@ -127,13 +134,87 @@ mod marlin {
                         assert!(!$marlin_inst::verify(&index_vk, &[a, a], &proof).unwrap());
                     }
                 }
+
+                pub(crate) fn test_seeded_proving() {
+                    let rng = &mut test_rng();
+
+                    let max_degree = crate::ahp::AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(max_degree, rng).unwrap();
+
+                    let a = Fr::rand(rng);
+                    let b = Fr::rand(rng);
+                    let mut c = a;
+                    c.mul_assign(&b);
+                    let mut d = c;
+                    d.mul_assign(&b);
+
+                    let circ = Circuit {
+                        a: Some(a),
+                        b: Some(b),
+                        num_constraints: 25,
+                        num_variables: 25,
+                    };
+
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+
+                    // The blinding factors are drawn from the given RNG, so a seeded RNG yields a reproducible proof.
+                    let prove_with_seed = |seed: u64| {
+                        let proof = $marlin_inst::prove(&index_pk, &circ, &mut StdRng::seed_from_u64(seed)).unwrap();
+                        assert!($marlin_inst::verify(&index_vk, &[c, d], &proof).unwrap());
+                        proof.to_bytes_le().unwrap()
+                    };
+
+                    assert_eq!(prove_with_seed(1), prove_with_seed(1));
+                    assert_ne!(prove_with_seed(1), prove_with_seed(2));
+                }
+
+                pub(crate) fn test_serialization(expected_digest: &str) {
+                    let rng = &mut test_rng();
+
+                    let max_degree = crate::ahp::AHPForR1CS::<Fr, $marlin_mode>::max_degree(100, 25, 300).unwrap();
+                    let universal_srs = $marlin_inst::universal_setup(max_degree, rng).unwrap();
+
+                    let circ = Circuit {
+                        a: Some(Fr::rand(rng)),
+                        b: Some(Fr::rand(rng)),
+                        num_constraints: 25,
+                        num_variables: 25,
+                    };
+
+                    let (index_pk, index_vk) = $marlin_inst::circuit_setup(&universal_srs, &circ).unwrap();
+                    let proof = $marlin_inst::prove(&index_pk, &circ, rng).unwrap();
+
+                    // Ensure the size hint matches the canonical encoding.
+                    let bytes = proof.to_bytes_le().unwrap();
+                    assert_eq!(Proof::serialized_size_hint(&index_vk), bytes.len());
+
+                    // Ensure the versioned encoding round-trips.
+                    let versioned = proof.to_bytes_versioned().unwrap();
+                    assert_eq!(Proof::<Fr, Fq, $pc>::FORMAT_VERSION, versioned[0]);
+                    assert_eq!(&bytes[..], &versioned[1..]);
+                    assert_eq!(proof, Proof::from_bytes_versioned(&versioned).unwrap());
+
+                    // Ensure the layout has not drifted.
+                    assert_eq!(expected_digest, format!("{:x}", Blake2s::digest(&versioned)));
+
+                    // Ensure unknown versions and trailing bytes are rejected.
+                    let mut future = versioned.clone();
+                    future[0] = Proof::<Fr, Fq, $pc>::FORMAT_VERSION + 1;
+                    assert!(matches!(
+                        Proof::<Fr, Fq, $pc>::from_bytes_versioned(&future),
+                        Err(MarlinError::UnsupportedProofVersion(version)) if version == future[0]
+                    ));
+                    let mut trailing = versioned;
+                    trailing.push(0);
+                    assert!(Proof::<Fr, Fq, $pc>::from_bytes_versioned(&trailing).is_err());
+                }
             }
         };
     }
 
-    impl_marlin_test!(MarlinPCTest, MarlinInst, MarlinTestnet1Mode);
-    impl_marlin_test!(SonicPCTest, MarlinSonicInst, MarlinTestnet1Mode);
-    impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MarlinPoswMode);
+    impl_marlin_test!(MarlinPCTest, MarlinInst, MultiPC, MarlinTestnet1Mode);
+    impl_marlin_test!(SonicPCTest, MarlinSonicInst, MultiPCSonic, MarlinTestnet1Mode);
+    impl_marlin_test!(SonicPCPoswTest, MarlinSonicPoswInst, MultiPCSonic, MarlinPoswMode);
 
     #[test]
     fn prove_and_verify_with_tall_matrix_big() {
@@ -184,18 +265,201 @@ mod marlin {
         SonicPCTest::test_circuit(num_constraints, num_variables);
         SonicPCPoswTest::test_circuit(num_constraints, num_variables);
     }
+
+    #[test]
+    fn proof_serialization() {
+        MarlinPCTest::test_serialization("61a4620cfad1bf50620ef07a96d3c5e9edf9d69e5d673d0fb62e76260eb4eff0");
+        SonicPCTest::test_serialization("d742a6ab657b1f7f737c241983c8a34a6c915aa86326f1bf6d18d49e88d5f6fa");
+        SonicPCPoswTest::test_serialization("226d82310808c949f261b6af3adcea4f3035b83b71817b712046a7570da5fa6f");
+    }
+
+    #[test]
+    fn prove_with_seeded_rng() {
+        MarlinPCTest::test_seeded_proving();
+        SonicPCTest::test_seeded_proving();
+        SonicPCPoswTest::test_seeded_proving();
+    }
+
+    #[test]
+    fn prove_with_callback() {
+        struct Recorder {
+            stages: RefCell<Vec<(ProvingStage, f32)>>,
+            abort_at: Option<ProvingStage>,
+        }
+
+        impl ProverCallback for Recorder {
+            fn on_progress(&self, stage: ProvingStage, fraction: f32) -> ControlFlow<()> {
+                self.stages.borrow_mut().push((stage, fraction));
+                match Some(stage) == self.abort_at {
+                    true => ControlFlow::Break(()),
+                    false => ControlFlow::Continue(()),
+                }
+            }
+        }
+
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+
+        // Ensure every stage is reported once, in order.
+        let recorder = Recorder {
+            stages: Default::default(),
+            abort_at: None,
+        };
+        let proof = MarlinInst::prove_with_callback(&index_pk, &circuit, &recorder, rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+
+        let stages = recorder.stages.into_inner();
+        assert_eq!(
+            vec![
+                ProvingStage::Init,
+                ProvingStage::FirstRound,
+                ProvingStage::SecondRound,
+                ProvingStage::ThirdRound,
+                ProvingStage::Evaluation,
+                ProvingStage::Opening,
+                ProvingStage::Finished,
+            ],
+            stages.iter().map(|(stage, _)| *stage).collect::<Vec<_>>()
+        );
+        assert!(stages.windows(2).all(|pair| pair[0].1 < pair[1].1));
+        assert_eq!(1.0, stages.last().unwrap().1);
+
+        // Ensure aborting stops the prover at the requested stage.
+        let recorder = Recorder {
+            stages: Default::default(),
+            abort_at: Some(ProvingStage::SecondRound),
+        };
+        assert!(matches!(
+            MarlinInst::prove_with_callback(&index_pk, &circuit, &recorder, rng),
+            Err(MarlinError::Terminated)
+        ));
+        assert_eq!(
+            Some(ProvingStage::SecondRound),
+            recorder.stages.borrow().last().map(|(stage, _)| *stage)
+        );
+
+        // Ensure aborting once the proof is finished still returns the proof.
+        let recorder = Recorder {
+            stages: Default::default(),
+            abort_at: Some(ProvingStage::Finished),
+        };
+        let proof = MarlinInst::prove_with_callback(&index_pk, &circuit, &recorder, rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+        assert_eq!(
+            Some(ProvingStage::Finished),
+            recorder.stages.borrow().last().map(|(stage, _)| *stage)
+        );
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn prove_and_verify_with_metrics() {
+        use crate::marlin::telemetry::*;
+        use metrics::{Counter, Gauge, Histogram, Key, KeyName, Recorder, SharedString, Unit};
+        use std::sync::Mutex;
+
+        /// Records the name of every metric emitted.
+        #[derive(Default)]
+        struct NameRecorder(Mutex<Vec<String>>);
+
+        impl Recorder for NameRecorder {
+            fn describe_counter(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+            fn describe_gauge(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+            fn describe_histogram(&self, _key: KeyName, _unit: Option<Unit>, _description: SharedString) {}
+
+            fn register_counter(&self, key: &Key) -> Counter {
+                self.0.lock().unwrap().push(key.name().to_string());
+                Counter::noop()
+            }
+
+            fn register_gauge(&self, key: &Key) -> Gauge {
+                self.0.lock().unwrap().push(key.name().to_string());
+                Gauge::noop()
+            }
+
+            fn register_histogram(&self, key: &Key) -> Histogram {
+                self.0.lock().unwrap().push(key.name().to_string());
+                Histogram::noop()
+            }
+        }
+
+        let recorder: &'static NameRecorder = Box::leak(Box::new(NameRecorder::default()));
+        metrics::set_recorder(recorder).unwrap();
+
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinTestnet1Mode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree, rng).unwrap();
+
+        let a = Fr::rand(rng);
+        let b = Fr::rand(rng);
+        let mut c = a;
+        c.mul_assign(&b);
+        let mut d = c;
+        d.mul_assign(&b);
+
+        let circuit = Circuit {
+            a: Some(a),
+            b: Some(b),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+        assert!(MarlinInst::verify(&index_vk, &[c, d], &proof).unwrap());
+
+        let names = recorder.0.lock().unwrap();
+        for name in [
+            PROVE_TOTAL,
+            PROVE_DURATION_SECONDS,
+            PROVE_CONSTRAINTS,
+            PROOF_BYTES,
+            VERIFY_TOTAL,
+            VERIFY_DURATION_SECONDS,
+            VERIFY_PUBLIC_INPUTS,
+        ] {
+            assert!(
+                names.iter().any(|candidate| candidate == name),
+                "Missing metric {}",
+                name
+            );
+        }
+    }
 }
 
 mod marlin_recursion {
     use super::*;
     use crate::{
         fiat_shamir::{FiatShamirAlgebraicSpongeRng, PoseidonSponge},
-        marlin::{MarlinRecursiveMode, MarlinSNARK},
+        marlin::{MarlinRecursiveMode, MarlinSNARK, Proof},
     };
     use snarkvm_curves::bls12_377::{Bls12_377, Fq, Fr};
     use snarkvm_polycommit::sonic_pc::SonicKZG10;
-    use snarkvm_utilities::rand::{test_rng, UniformRand};
+    use snarkvm_utilities::{
+        rand::{test_rng, UniformRand},
+        ToBytes,
+    };
 
+    use blake2::{Blake2s, Digest};
     use core::ops::MulAssign;
 
     type MultiPC = SonicKZG10<Bls12_377>;
@@ -281,6 +545,34 @@ mod marlin_recursion {
         test_circuit(num_constraints, num_variables);
     }
 
+    #[test]
+    fn proof_serialization() {
+        let rng = &mut test_rng();
+
+        let max_degree = crate::ahp::AHPForR1CS::<Fr, MarlinRecursiveMode>::max_degree(100, 25, 300).unwrap();
+        let universal_srs = MarlinInst::universal_setup(max_degree, rng).unwrap();
+
+        let circuit = Circuit {
+            a: Some(Fr::rand(rng)),
+            b: Some(Fr::rand(rng)),
+            num_constraints: 25,
+            num_variables: 25,
+        };
+
+        let (index_pk, index_vk) = MarlinInst::circuit_setup(&universal_srs, &circuit).unwrap();
+        let proof = MarlinInst::prove(&index_pk, &circuit, rng).unwrap();
+
+        let bytes = proof.to_bytes_le().unwrap();
+        assert_eq!(Proof::serialized_size_hint(&index_vk), bytes.len());
+
+        let versioned = proof.to_bytes_versioned().unwrap();
+        assert_eq!(proof, Proof::from_bytes_versioned(&versioned).unwrap());
+        assert_eq!(
+            "881e70317e0952c3ba3914a1bc0958c34298c10363c8daef2492512d073bcb59",
+            format!("{:x}", Blake2s::digest(&versioned))
+        );
+    }
+
     // #[test]
     // /// Test on a constraint system that will trigger outlining.
     // fn prove_and_test_outlining() {
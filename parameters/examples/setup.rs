@@ -203,6 +203,7 @@ pub fn outer_setup<N: Network>() -> Result<()> {
             proof: Noop::<N>::new().execute(
                 ProgramPublicVariables::blank(),
                 &NoopPrivateVariables::<N>::new_blank().unwrap(),
+                &mut thread_rng(),
             )?,
         }),
         &mut SRS::CircuitSpecific(&mut thread_rng()),
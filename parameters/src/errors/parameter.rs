@@ -24,6 +24,15 @@ pub enum ParameterError {
     #[error("{}: {}", _0, _1)]
     Crate(&'static str, String),
 
+    #[error("{}: expected checksum of {}, found checksum of {}", _0, _1, _2)]
+    FileChecksumMismatch(String, String, String),
+
+    #[error("{}: file is missing", _0)]
+    FileMissing(String),
+
+    #[error("{}: expected size of {}, found size of {}", _0, _1, _2)]
+    FileSizeMismatch(String, usize, usize),
+
     #[error("{}", _0)]
     Message(String),
 
@@ -54,6 +63,12 @@ impl From<std::io::Error> for ParameterError {
     }
 }
 
+impl From<serde_json::Error> for ParameterError {
+    fn from(error: serde_json::Error) -> Self {
+        ParameterError::Crate("serde_json", format!("{:?}", error))
+    }
+}
+
 impl From<std::path::StripPrefixError> for ParameterError {
     fn from(error: std::path::StripPrefixError) -> Self {
         ParameterError::Crate("std::path", format!("{:?}", error))
@@ -30,6 +30,9 @@ pub mod macros;
 pub mod errors;
 pub use errors::*;
 
+pub mod manifest;
+pub use manifest::*;
+
 pub mod testnet1;
 
 pub mod testnet2;
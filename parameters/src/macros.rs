@@ -51,19 +51,13 @@ macro_rules! impl_local {
 
                 let buffer = include_bytes!(concat!($local_dir, $fname, ".", $ftype));
 
-                // Ensure the size matches.
-                if expected_size != buffer.len() {
-                    return Err(crate::errors::ParameterError::SizeMismatch(
-                        expected_size,
-                        buffer.len(),
-                    ));
-                }
-
-                // Ensure the checksum matches.
-                let candidate_checksum = checksum!(buffer);
-                if expected_checksum != candidate_checksum {
-                    return checksum_error!(expected_checksum, candidate_checksum);
+                // Ensure the size and checksum match.
+                crate::KeyManifestEntry {
+                    filename: concat!($fname, ".", $ftype).to_string(),
+                    checksum: expected_checksum,
+                    size: expected_size,
                 }
+                .verify_bytes(buffer)?;
 
                 return Ok(buffer.to_vec());
             }
@@ -172,16 +166,13 @@ macro_rules! impl_remote {
                     }
                 };
 
-                 // Ensure the size matches.
-                if expected_size != buffer.len() {
-                    return Err(crate::errors::ParameterError::SizeMismatch(expected_size, buffer.len()));
-                }
-
-                // Ensure the checksum matches.
-                let candidate_checksum = checksum!(buffer.as_slice());
-                if expected_checksum != candidate_checksum {
-                    return checksum_error!(expected_checksum, candidate_checksum)
+                // Ensure the size and checksum match, as a stored file may be truncated or corrupted.
+                crate::KeyManifestEntry {
+                    filename,
+                    checksum: expected_checksum,
+                    size: expected_size,
                 }
+                .verify_bytes(&buffer)?;
 
                 return Ok(buffer)
             }
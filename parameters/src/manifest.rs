@@ -0,0 +1,208 @@
+// Copyright (C) 2019-2021 Aleo Systems Inc.
+// This file is part of the snarkVM library.
+
+// The snarkVM library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkVM library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkVM library. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::errors::ParameterError;
+
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+
+/// The file types of key and SRS files, which may be followed by a versioning suffix.
+const KEY_FILE_TYPES: [&str; 3] = ["proving", "verifying", "srs"];
+
+/// The expected size and checksum of a parameter file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyManifestEntry {
+    /// The name of the file, such as `inner.proving.846ac36`.
+    pub filename: String,
+    /// The hex-encoded SHA-256 checksum of the file.
+    pub checksum: String,
+    /// The size of the file in bytes.
+    pub size: usize,
+}
+
+impl KeyManifestEntry {
+    /// Initializes a new entry for the given file contents.
+    pub fn new(filename: String, bytes: &[u8]) -> Self {
+        Self {
+            filename,
+            checksum: checksum!(bytes),
+            size: bytes.len(),
+        }
+    }
+
+    /// Ensures the given file contents match the size and checksum of the entry.
+    pub fn verify_bytes(&self, bytes: &[u8]) -> Result<(), ParameterError> {
+        // Check the size first, so a truncated file is reported as such.
+        if self.size != bytes.len() {
+            return Err(ParameterError::FileSizeMismatch(
+                self.filename.clone(),
+                self.size,
+                bytes.len(),
+            ));
+        }
+
+        let candidate_checksum = checksum!(bytes);
+        if self.checksum != candidate_checksum {
+            return Err(ParameterError::FileChecksumMismatch(
+                self.filename.clone(),
+                self.checksum.clone(),
+                candidate_checksum,
+            ));
+        }
+        Ok(())
+    }
+}
+
+///
+/// A manifest of the parameter files in a directory, for validating keys that are distributed out of band.
+///
+/// Key files are the files with a `proving`, `verifying` or `srs` file type, such as `inner.proving`
+/// or the versioned `inner.proving.846ac36`.
+///
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyManifest {
+    /// The entries of the manifest, sorted by filename.
+    pub entries: Vec<KeyManifestEntry>,
+}
+
+impl KeyManifest {
+    /// Returns a manifest of the key files in the given directory.
+    pub fn generate(directory: &Path) -> Result<Self, ParameterError> {
+        let mut entries = Vec::new();
+        for entry in fs::read_dir(directory)? {
+            let entry = entry?;
+            let filename = entry.file_name().to_string_lossy().into_owned();
+            if entry.file_type()?.is_file() && Self::is_key_file(&filename) {
+                entries.push(KeyManifestEntry::new(filename, &fs::read(entry.path())?));
+            }
+        }
+        entries.sort_by(|a, b| a.filename.cmp(&b.filename));
+
+        Ok(Self { entries })
+    }
+
+    /// Ensures every entry of the manifest is present in the given directory, with a matching size and checksum.
+    /// Returns an error naming the first entry that does not match.
+    pub fn verify(&self, directory: &Path) -> Result<(), ParameterError> {
+        for entry in &self.entries {
+            let file_path = directory.join(&entry.filename);
+            if !file_path.is_file() {
+                return Err(ParameterError::FileMissing(entry.filename.clone()));
+            }
+            entry.verify_bytes(&fs::read(file_path)?)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the manifest as a JSON string.
+    pub fn to_json_string(&self) -> Result<String, ParameterError> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Returns the manifest from the given JSON string.
+    pub fn from_json_str(manifest: &str) -> Result<Self, ParameterError> {
+        Ok(serde_json::from_str(manifest)?)
+    }
+
+    /// Returns `true` if the given filename has a key file type.
+    fn is_key_file(filename: &str) -> bool {
+        filename
+            .split('.')
+            .skip(1)
+            .any(|file_type| KEY_FILE_TYPES.contains(&file_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use rand::{thread_rng, Rng};
+    use std::path::PathBuf;
+
+    /// Returns a new directory with a proving key, a verifying key, and a file that is not a key.
+    fn sample_directory() -> PathBuf {
+        let directory = std::env::temp_dir().join(format!("snarkvm_key_manifest_{}", thread_rng().gen::<u64>()));
+        fs::create_dir_all(&directory).unwrap();
+        fs::write(directory.join("noop.proving"), vec![1u8; 64]).unwrap();
+        fs::write(directory.join("noop.verifying.a7884c9"), vec![2u8; 32]).unwrap();
+        fs::write(directory.join("noop.metadata"), b"{}").unwrap();
+        directory
+    }
+
+    #[test]
+    fn test_generate_and_verify() {
+        let directory = sample_directory();
+        let manifest = KeyManifest::generate(&directory).unwrap();
+
+        let filenames: Vec<_> = manifest.entries.iter().map(|entry| entry.filename.as_str()).collect();
+        assert_eq!(vec!["noop.proving", "noop.verifying.a7884c9"], filenames);
+        assert_eq!(64, manifest.entries[0].size);
+        assert_eq!(checksum!(&[1u8; 64]), manifest.entries[0].checksum);
+        assert!(manifest.verify(&directory).is_ok());
+
+        // Check the manifest round trips through JSON.
+        let json = manifest.to_json_string().unwrap();
+        assert_eq!(manifest, KeyManifest::from_json_str(&json).unwrap());
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_verify_corrupted_file() {
+        let directory = sample_directory();
+        let manifest = KeyManifest::generate(&directory).unwrap();
+
+        // Check a corrupted file is named in the error.
+        let mut bytes = fs::read(directory.join("noop.proving")).unwrap();
+        bytes[17] ^= 1;
+        fs::write(directory.join("noop.proving"), &bytes).unwrap();
+        match manifest.verify(&directory) {
+            Err(ParameterError::FileChecksumMismatch(filename, expected, candidate)) => {
+                assert_eq!("noop.proving", filename);
+                assert_eq!(manifest.entries[0].checksum, expected);
+                assert_eq!(checksum!(&bytes), candidate);
+            }
+            result => panic!("Expected a checksum mismatch, found {:?}", result),
+        }
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn test_verify_truncated_file() {
+        let directory = sample_directory();
+        let manifest = KeyManifest::generate(&directory).unwrap();
+
+        // Check a truncated file is named in the error.
+        fs::write(directory.join("noop.verifying.a7884c9"), vec![2u8; 31]).unwrap();
+        match manifest.verify(&directory) {
+            Err(ParameterError::FileSizeMismatch(filename, 32, 31)) => {
+                assert_eq!("noop.verifying.a7884c9", filename)
+            }
+            result => panic!("Expected a size mismatch, found {:?}", result),
+        }
+
+        // Check a missing file is named in the error.
+        fs::remove_file(directory.join("noop.verifying.a7884c9")).unwrap();
+        match manifest.verify(&directory) {
+            Err(ParameterError::FileMissing(filename)) => assert_eq!("noop.verifying.a7884c9", filename),
+            result => panic!("Expected a missing file, found {:?}", result),
+        }
+
+        fs::remove_dir_all(directory).unwrap();
+    }
+}
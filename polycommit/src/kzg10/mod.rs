@@ -24,6 +24,7 @@
 use crate::{BTreeMap, Error, LabeledPolynomial, PCRandomness, Polynomial, ToString, Vec};
 use snarkvm_algorithms::{
     cfg_iter,
+    fft::DenseOrSparsePolynomial,
     msm::{FixedBaseMSM, VariableBaseMSM},
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
@@ -221,14 +222,16 @@ impl<E: PairingEngine> KZG10<E> {
         Ok(pp)
     }
 
-    /// Outputs a commitment to `polynomial`.
-    pub fn commit(
+    /// Outputs a commitment to `polynomial`, which may be in dense or sparse form.
+    /// A sparse polynomial is committed to with an MSM over only its non-zero terms.
+    pub fn commit<'a>(
         powers: &Powers<E>,
-        polynomial: &Polynomial<E::Fr>,
+        polynomial: impl Into<DenseOrSparsePolynomial<'a, E::Fr>>,
         hiding_bound: Option<usize>,
         terminator: &AtomicBool,
         rng: Option<&mut dyn RngCore>,
     ) -> Result<(Commitment<E>, Randomness<E>), Error> {
+        let polynomial = polynomial.into();
         Self::check_degree_is_too_large(polynomial.degree(), powers.size())?;
 
         let commit_time = start_timer!(|| format!(
@@ -237,11 +240,27 @@ impl<E: PairingEngine> KZG10<E> {
             hiding_bound,
         ));
 
-        let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
+        let mut commitment = match &polynomial {
+            DenseOrSparsePolynomial::DPolynomial(polynomial) => {
+                let (num_leading_zeros, plain_coeffs) = skip_leading_zeros_and_convert_to_bigints(polynomial);
 
-        let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
-        let mut commitment = VariableBaseMSM::multi_scalar_mul(&powers.powers_of_g[num_leading_zeros..], &plain_coeffs);
-        end_timer!(msm_time);
+                let msm_time = start_timer!(|| "MSM to compute commitment to plaintext poly");
+                let commitment =
+                    VariableBaseMSM::multi_scalar_mul(&powers.powers_of_g[num_leading_zeros..], &plain_coeffs);
+                end_timer!(msm_time);
+                commitment
+            }
+            DenseOrSparsePolynomial::SPolynomial(polynomial) => {
+                let (bases, plain_coeffs): (Vec<_>, Vec<_>) = cfg_iter!(polynomial.coeffs)
+                    .map(|(i, c)| (powers.powers_of_g[*i], c.to_repr()))
+                    .unzip();
+
+                let msm_time = start_timer!(|| "MSM to compute commitment to sparse plaintext poly");
+                let commitment = VariableBaseMSM::multi_scalar_mul(&bases, &plain_coeffs);
+                end_timer!(msm_time);
+                commitment
+            }
+        };
 
         if terminator.load(Ordering::Relaxed) {
             return Err(Error::Terminated);
@@ -520,6 +539,7 @@ fn convert_to_bigints<F: PrimeField>(p: &[F]) -> Vec<F::BigInteger> {
 mod tests {
     #![allow(non_camel_case_types)]
     use crate::{kzg10::*, *};
+    use snarkvm_algorithms::fft::SparsePolynomial;
     use snarkvm_curves::bls12_377::{Bls12_377, Fr};
     use snarkvm_utilities::rand::test_rng;
 
@@ -592,6 +612,43 @@ mod tests {
         assert_eq!(f_comm, f_comm_2);
     }
 
+    #[test]
+    fn sparse_and_dense_commitments_test() {
+        let rng = &mut test_rng();
+
+        let degree = 20;
+        let pp = KZG_Bls12_377::setup(degree, &KZG10DegreeBoundsConfig::NONE, false, rng).unwrap();
+        let (powers, _) = KZG_Bls12_377::trim(&pp, degree);
+
+        for num_terms in [1, 3, 10] {
+            let sparse = SparsePolynomial::from_coefficients(
+                (0..num_terms).map(|_| (usize::rand(rng) % (degree + 1), Fr::rand(rng))),
+            );
+            let dense: Polynomial<Fr> = sparse.clone().into();
+
+            for hiding_bound in [None, Some(1)] {
+                let (sparse_comm, sparse_rand) = KZG10::commit(
+                    &powers,
+                    &sparse,
+                    hiding_bound,
+                    &AtomicBool::new(false),
+                    Some(&mut test_rng()),
+                )
+                .unwrap();
+                let (dense_comm, dense_rand) = KZG10::commit(
+                    &powers,
+                    &dense,
+                    hiding_bound,
+                    &AtomicBool::new(false),
+                    Some(&mut test_rng()),
+                )
+                .unwrap();
+                assert_eq!(sparse_comm, dense_comm);
+                assert_eq!(sparse_rand, dense_rand);
+            }
+        }
+    }
+
     fn end_to_end_test_template<E: PairingEngine>() -> Result<(), Error> {
         let rng = &mut test_rng();
         for _ in 0..100 {
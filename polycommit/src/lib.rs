@@ -199,6 +199,14 @@ pub trait PolynomialCommitment<F: PrimeField, CF: PrimeField>: Sized + Clone + D
         + Send
         + Sync;
 
+    /// Returns the size in bytes of a serialized commitment,
+    /// with or without an enforced degree bound.
+    fn commitment_serialized_size(has_degree_bound: bool) -> usize;
+
+    /// Returns the size in bytes of a serialized evaluation proof for a single point,
+    /// with or without hiding.
+    fn proof_serialized_size(is_hiding: bool) -> usize;
+
     /// Constructs public parameters when given as input the maximum degree `degree`
     /// for the polynomial commitment scheme.
     fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self::UniversalParams, Error>;
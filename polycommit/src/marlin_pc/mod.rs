@@ -24,6 +24,7 @@ use crate::{
     LabeledCommitment,
     LabeledPolynomial,
     LinearCombination,
+    PCCommitment,
     PCCommitterKey,
     PCRandomness,
     PCUniversalParams,
@@ -37,6 +38,7 @@ use crate::{
 };
 use snarkvm_curves::traits::{AffineCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{Field, One, Zero};
+use snarkvm_utilities::serialize::CanonicalSerialize;
 
 use core::{
     convert::TryInto,
@@ -100,6 +102,22 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for MarlinKZG10<E> {
     type UniversalParams = UniversalParams<E>;
     type VerifierKey = VerifierKey<E>;
 
+    fn commitment_serialized_size(has_degree_bound: bool) -> usize {
+        let commitment = Commitment::<E> {
+            comm: kzg10::Commitment::empty(),
+            shifted_comm: if has_degree_bound { Some(kzg10::Commitment::empty()) } else { None },
+        };
+        commitment.serialized_size()
+    }
+
+    fn proof_serialized_size(is_hiding: bool) -> usize {
+        let proof = kzg10::Proof::<E> {
+            w: E::G1Affine::zero(),
+            random_v: if is_hiding { Some(E::Fr::zero()) } else { None },
+        };
+        proof.serialized_size()
+    }
+
     /// Constructs public parameters when given as input the maximum degree `max_degree`
     /// for the polynomial commitment scheme.
     fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self::UniversalParams, Error> {
@@ -24,6 +24,7 @@ use crate::{
     LabeledCommitment,
     LabeledPolynomial,
     LinearCombination,
+    PCCommitment,
     PCCommitterKey,
     PCRandomness,
     PCUniversalParams,
@@ -37,7 +38,7 @@ use crate::{
 };
 use snarkvm_curves::traits::{AffineCurve, PairingCurve, PairingEngine, ProjectiveCurve};
 use snarkvm_fields::{One, Zero};
-use snarkvm_utilities::rand::UniformRand;
+use snarkvm_utilities::{rand::UniformRand, serialize::CanonicalSerialize};
 
 use core::{
     convert::TryInto,
@@ -105,6 +106,18 @@ impl<E: PairingEngine> PolynomialCommitment<E::Fr, E::Fq> for SonicKZG10<E> {
     type UniversalParams = UniversalParams<E>;
     type VerifierKey = VerifierKey<E>;
 
+    fn commitment_serialized_size(_has_degree_bound: bool) -> usize {
+        Commitment::<E>::empty().serialized_size()
+    }
+
+    fn proof_serialized_size(is_hiding: bool) -> usize {
+        let proof = kzg10::Proof::<E> {
+            w: E::G1Affine::zero(),
+            random_v: if is_hiding { Some(E::Fr::zero()) } else { None },
+        };
+        proof.serialized_size()
+    }
+
     fn setup<R: RngCore>(max_degree: usize, rng: &mut R) -> Result<Self::UniversalParams, Error> {
         kzg10::KZG10::setup(max_degree, &kzg10::KZG10DegreeBoundsConfig::MARLIN, true, rng).map_err(Into::into)
     }
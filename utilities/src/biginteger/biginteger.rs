@@ -107,6 +107,24 @@ pub trait BigInteger:
 
     /// Returns a vector for wnaf.
     fn find_wnaf(&self) -> Vec<i64>;
+
+    /// Returns a `BigInteger` from a slice of bits in little-endian order,
+    /// or `None` if there are more bits than limbs to hold them.
+    fn from_bits_le_checked(bits: &[bool]) -> Option<Self> {
+        match bits.len() <= Self::NUM_LIMBS * 64 {
+            true => Some(Self::from_bits_le(bits)),
+            false => None,
+        }
+    }
+
+    /// Returns a `BigInteger` from a slice of bits in big-endian order,
+    /// or `None` if there are more bits than limbs to hold them.
+    fn from_bits_be_checked(bits: &[bool]) -> Option<Self> {
+        match bits.len() <= Self::NUM_LIMBS * 64 {
+            true => Some(Self::from_bits_be(bits)),
+            false => None,
+        }
+    }
 }
 
 pub mod arithmetic {
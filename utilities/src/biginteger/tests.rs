@@ -68,6 +68,36 @@ fn biginteger_bits_test<B: BigInteger>() {
     assert!(thirty_two.get_bit(5), "{:?}", thirty_two);
 }
 
+fn biginteger_bits_roundtrip_test<B: BigInteger>() {
+    const ITERATIONS: usize = 1000;
+
+    let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
+    let num_bits = B::NUM_LIMBS * 64;
+
+    for _ in 0..ITERATIONS {
+        let candidate: B = UniformRand::rand(&mut rng);
+
+        let bits_le = candidate.to_bits_le();
+        assert_eq!(num_bits, bits_le.len());
+        assert_eq!(candidate, B::from_bits_le_checked(&bits_le).unwrap());
+
+        let bits_be = candidate.to_bits_be();
+        assert_eq!(num_bits, bits_be.len());
+        assert_eq!(candidate, B::from_bits_be_checked(&bits_be).unwrap());
+
+        // The two endiannesses are the reverse of one another.
+        assert_eq!(bits_le, bits_be.iter().rev().copied().collect::<Vec<_>>());
+    }
+
+    // Inputs shorter than the width are zero-extended.
+    assert_eq!(B::from(5u64), B::from_bits_le_checked(&[true, false, true]).unwrap());
+    assert_eq!(B::from(5u64), B::from_bits_be_checked(&[true, false, true]).unwrap());
+
+    // Inputs longer than the width are rejected, even if the extra bits are zero.
+    assert!(B::from_bits_le_checked(&vec![false; num_bits + 1]).is_none());
+    assert!(B::from_bits_be_checked(&vec![false; num_bits + 1]).is_none());
+}
+
 fn biginteger_bytes_test<B: BigInteger>() {
     let mut bytes = [0u8; 256];
     let mut rng = XorShiftRng::seed_from_u64(1231275789u64);
@@ -119,6 +149,7 @@ fn test_biginteger<B: BigInteger>(zero: B) {
     biginteger_arithmetic_test(a, b, zero);
     biginteger_bytes_test::<B>();
     biginteger_bits_test::<B>();
+    biginteger_bits_roundtrip_test::<B>();
     biginteger_to_string_test::<B>();
 }
 
@@ -142,6 +173,16 @@ fn test_biginteger384() {
     test_biginteger(BigInteger384::new([0u64; 6]));
 }
 
+#[test]
+fn test_biginteger384_rejects_385_bits() {
+    let mut bits = vec![false; 385];
+    bits[384] = true;
+    assert!(BigInteger384::from_bits_le_checked(&bits).is_none());
+    assert!(BigInteger384::from_bits_be_checked(&bits).is_none());
+    assert!(BigInteger384::from_bits_le_checked(&bits[..384]).is_some());
+    assert!(BigInteger384::from_bits_be_checked(&bits[1..]).is_some());
+}
+
 #[test]
 fn test_biginteger768() {
     test_biginteger(BigInteger768::new([0u64; 12]));
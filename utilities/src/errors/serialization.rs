@@ -32,10 +32,68 @@ pub enum SerializationError {
     /// expected.
     #[error("the call expects empty flags")]
     UnexpectedFlags,
+    /// During deserialization, a field of a composite type was invalid.
+    #[error("in field `{1}` of `{0}`: {2}")]
+    InField(&'static str, &'static str, Box<SerializationError>),
+}
+
+impl SerializationError {
+    /// Returns the error, wrapped with the type and field name of the composite type it occurred in.
+    pub fn in_field(self, type_name: &'static str, field: &'static str) -> Self {
+        Self::InField(type_name, field, Box::new(self))
+    }
+
+    /// Returns the path of fields the error occurred in, starting from the outermost type,
+    /// such as `Transaction.transitions.proof`, or `None` if the error has no field context.
+    pub fn field_path(&self) -> Option<String> {
+        let mut path = Vec::new();
+        let mut error = self;
+        loop {
+            match error {
+                Self::InField(type_name, field, inner) => {
+                    if path.is_empty() {
+                        path.push(*type_name);
+                    }
+                    path.push(*field);
+                    error = inner;
+                }
+                // Continue through field errors that were converted into I/O errors.
+                Self::IoError(io_error) => match io_error.get_ref().and_then(|inner| inner.downcast_ref::<Self>()) {
+                    Some(inner) => error = inner,
+                    None => break,
+                },
+                _ => break,
+            }
+        }
+
+        match path.is_empty() {
+            true => None,
+            false => Some(path.join(".")),
+        }
+    }
 }
 
 impl From<SerializationError> for crate::io::Error {
     fn from(error: SerializationError) -> Self {
-        crate::io::Error::new(crate::io::ErrorKind::Other, format!("{}", error))
+        crate::io::Error::new(crate::io::ErrorKind::Other, error)
+    }
+}
+
+/// Adds the type and field name of a composite type to the error of deserializing one of its fields.
+/// The context is only attached, and allocated, on failure.
+pub trait FieldContext<T> {
+    /// Returns the error, wrapped with the given type and field name.
+    fn in_field(self, type_name: &'static str, field: &'static str) -> Result<T, SerializationError>;
+}
+
+impl<T> FieldContext<T> for Result<T, SerializationError> {
+    fn in_field(self, type_name: &'static str, field: &'static str) -> Result<T, SerializationError> {
+        self.map_err(|error| error.in_field(type_name, field))
+    }
+}
+
+impl<T> FieldContext<T> for Result<T, crate::io::Error> {
+    fn in_field(self, type_name: &'static str, field: &'static str) -> Result<T, SerializationError> {
+        self.map_err(|error| SerializationError::from(error).in_field(type_name, field))
     }
 }
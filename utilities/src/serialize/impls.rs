@@ -115,11 +115,12 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
     #[inline]
     fn serialized_size(&self) -> usize {
-        8 + if let Some(item) = self {
-            item.serialized_size()
-        } else {
-            0
-        }
+        self.is_some().serialized_size()
+            + if let Some(item) = self {
+                item.serialized_size()
+            } else {
+                0
+            }
     }
 
     #[inline]
@@ -131,6 +132,16 @@ impl<T: CanonicalSerialize> CanonicalSerialize for Option<T> {
 
         Ok(())
     }
+
+    #[inline]
+    fn uncompressed_size(&self) -> usize {
+        self.is_some().uncompressed_size()
+            + if let Some(item) = self {
+                item.uncompressed_size()
+            } else {
+                0
+            }
+    }
 }
 
 impl<T: CanonicalDeserialize> CanonicalDeserialize for Option<T> {
@@ -536,10 +547,40 @@ mod test {
     fn test_option() {
         test_serialize(Some(3u32));
         test_serialize(None::<u32>);
+
+        assert_eq!(Some(3u32).serialized_size(), 5);
+        assert_eq!(None::<u32>.serialized_size(), 1);
     }
 
     #[test]
     fn test_phantomdata() {
         test_serialize(std::marker::PhantomData::<u64>);
     }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn test_derive_field_path() {
+        use crate::serialize::*;
+
+        #[derive(Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+        struct Inner {
+            flag: bool,
+        }
+
+        #[derive(Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+        struct Outer(u64, Inner);
+
+        test_serialize(Outer(7, Inner { flag: true }));
+
+        // Check a truncated inner field is named in the error.
+        let error = Outer::deserialize(&mut &[0u8; 8][..]).unwrap_err();
+        assert_eq!(Some("Outer.1.flag".to_string()), error.field_path());
+        assert!(matches!(error, SerializationError::InField("Outer", "1", _)));
+
+        let error = Outer::deserialize(&mut &[0u8; 4][..]).unwrap_err();
+        assert_eq!(Some("Outer.0".to_string()), error.field_path());
+
+        // Check errors outside of composite types have no field path.
+        assert_eq!(None, u64::deserialize(&mut &[0u8; 4][..]).unwrap_err().field_path());
+    }
 }